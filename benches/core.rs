@@ -0,0 +1,205 @@
+use std::{collections::HashSet, env, fs, hint::black_box};
+
+use bairiak::{
+    generate_bairiak, generate_bairiak_enums_from_str, generate_bairiak_from_slice, load_spec,
+    Bairiak, BairiakEnum,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[path = "../src/test_support.rs"]
+mod test_support;
+
+// A flag at any position of a `WIDTH`-bit Bairiak.
+#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+struct Flag<const WIDTH: u8>(u8);
+
+impl<const WIDTH: u8> BairiakEnum for Flag<WIDTH> {
+    fn get_zero_bairiak() -> Bairiak {
+        match WIDTH {
+            8 => Bairiak::U8(0u8),
+            16 => Bairiak::U16(0u16),
+            32 => Bairiak::U32(0u32),
+            64 => Bairiak::U64(0u64),
+            _ => Bairiak::U128(0u128),
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        (position < WIDTH).then_some(Flag(position))
+    }
+
+    fn name(&self) -> &'static str {
+        "Flag"
+    }
+}
+
+fn bench_generate_bairiak_with<const WIDTH: u8>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("generate_bairiak/u{}", WIDTH));
+    for count in [1u8, 10, 100].into_iter().filter(|&count| count <= WIDTH) {
+        let flags: HashSet<Flag<WIDTH>> = (0..count).map(Flag).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(count), &flags, |b, flags| {
+            b.iter(|| generate_bairiak(black_box(flags.clone())))
+        });
+    }
+    group.finish();
+}
+
+fn bench_generate_bairiak(c: &mut Criterion) {
+    bench_generate_bairiak_with::<8>(c);
+    bench_generate_bairiak_with::<16>(c);
+    bench_generate_bairiak_with::<32>(c);
+    bench_generate_bairiak_with::<64>(c);
+    bench_generate_bairiak_with::<128>(c);
+}
+
+// `generate_bairiak` matches on the width for every flag, while
+// `generate_bairiak_from_slice` picks it once.
+fn bench_generate_bairiak_from_slice(c: &mut Criterion) {
+    let flags: Vec<Flag<64>> = (0..64).map(Flag).collect();
+    let set: HashSet<Flag<64>> = flags.iter().copied().collect();
+    let mut group = c.benchmark_group("generate_bairiak_64_flags");
+    group.bench_function("set", |b| {
+        b.iter(|| generate_bairiak(black_box(set.clone())))
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| generate_bairiak_from_slice(black_box(&flags)))
+    });
+    group.finish();
+}
+
+// These call across the crate boundary, so they only stay cheap if the bit
+// queries are inlined.
+fn bench_is_true(c: &mut Criterion) {
+    let bairiak = Bairiak::U64(0xaaaa_aaaa_aaaa_aaaa);
+    c.bench_function("is_true/u64", |b| {
+        b.iter(|| {
+            (0..64)
+                .filter(|&position| black_box(&bairiak).is_true(Flag::<64>(position)))
+                .count()
+        })
+    });
+    c.bench_function("is_false/u128", |b| {
+        let bairiak = Bairiak::U128(u128::MAX / 3);
+        b.iter(|| {
+            (0..128)
+                .filter(|&position| black_box(&bairiak).is_false(Flag::<128>(position)))
+                .count()
+        })
+    });
+    c.bench_function("set/u64", |b| {
+        b.iter(|| {
+            let mut bairiak = Bairiak::U64(0);
+            for position in (0..64).step_by(3) {
+                bairiak.set(black_box(Flag::<64>(position)));
+            }
+            bairiak
+        })
+    });
+}
+
+fn bench_validate(c: &mut Criterion) {
+    // 1,000 variants in total, as no single enum can hold more than 128.
+    let spec_path = env::temp_dir().join("bairiak_bench_validate.yaml");
+    fs::write(&spec_path, test_support::large_spec(8, 125)).unwrap();
+    let spec = load_spec(&spec_path).unwrap();
+    fs::remove_file(&spec_path).unwrap();
+
+    c.bench_function("validate/1000_variants", |b| {
+        b.iter(|| black_box(&spec).validate().unwrap())
+    });
+}
+
+fn bench_generate_from_str(c: &mut Criterion) {
+    let spec = test_support::large_spec(100, 128);
+    c.bench_function("generate_bairiak_enums_from_str/100x128", |b| {
+        b.iter(|| generate_bairiak_enums_from_str(black_box(&spec)).unwrap())
+    });
+}
+
+// 10,000 variants in total, dominated by formatting the generated code.
+fn bench_generate_many_variants(c: &mut Criterion) {
+    let spec = test_support::large_spec(80, 125);
+    let mut group = c.benchmark_group("generate_bairiak_enums_from_str");
+    group.sample_size(10);
+    group.bench_function("10000_variants", |b| {
+        b.iter(|| generate_bairiak_enums_from_str(black_box(&spec)).unwrap())
+    });
+    group.finish();
+}
+
+// Compare runs with and without the `rayon` feature.
+fn bench_generate_many_enums(c: &mut Criterion) {
+    let spec = test_support::large_spec(500, 64);
+    c.bench_function("generate_bairiak_enums_from_str/500x64", |b| {
+        b.iter(|| generate_bairiak_enums_from_str(black_box(&spec)).unwrap())
+    });
+}
+
+mod lookup {
+    include!("../test_data/expected/lookup_spec.rs");
+}
+
+// The same 120 flags parsed through a `match` on names and one on bytes,
+// which the generator picks for enums with many names.
+fn bench_from_str(c: &mut Criterion) {
+    use lookup::{ExactBytes, ExactMatch, FoldedBytes, FoldedMatch};
+
+    // Misses are left out, as building their error dwarfs the lookup.
+    let names: Vec<String> = ExactMatch::all_variants()
+        .iter()
+        .map(|flag| flag.name().to_string())
+        .collect();
+    let folded: Vec<String> = names.iter().map(|name| name.to_ascii_uppercase()).collect();
+
+    let mut group = c.benchmark_group("from_str/120_variants");
+    group.bench_function("exact/match", |b| {
+        b.iter(|| {
+            black_box(&names)
+                .iter()
+                .filter(|name| name.parse::<ExactMatch>().is_ok())
+                .count()
+        })
+    });
+    group.bench_function("exact/bytes", |b| {
+        b.iter(|| {
+            black_box(&names)
+                .iter()
+                .filter(|name| name.parse::<ExactBytes>().is_ok())
+                .count()
+        })
+    });
+    group.bench_function("case_insensitive/match", |b| {
+        b.iter(|| {
+            black_box(&folded)
+                .iter()
+                .filter(|name| name.parse::<FoldedMatch>().is_ok())
+                .count()
+        })
+    });
+    group.bench_function("case_insensitive/bytes", |b| {
+        b.iter(|| {
+            black_box(&folded)
+                .iter()
+                .filter(|name| name.parse::<FoldedBytes>().is_ok())
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_generate_bairiak,
+    bench_generate_bairiak_from_slice,
+    bench_is_true,
+    bench_validate,
+    bench_generate_from_str,
+    bench_generate_many_variants,
+    bench_generate_many_enums,
+    bench_from_str
+);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+// Values decoded from the compact and the stable byte formats either decode
+// to a value that encodes back to the same bytes, or fail with a decoding
+// error.
+
+#![no_main]
+
+use bairiak::{Bairiak, BairiakErrorKind};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    match Bairiak::decode_compact(data) {
+        Ok((value, len)) => {
+            let mut buf = [0u8; 17];
+            assert_eq!(value.encode_compact(&mut buf), Ok(len));
+            assert_eq!(&buf[..len], &data[..len]);
+        }
+        Err(err) => assert!(matches!(
+            err.kind(),
+            BairiakErrorKind::BufferTooShortError | BairiakErrorKind::UnknownWidthError
+        )),
+    }
+
+    match Bairiak::from_stable_bytes(data) {
+        Ok(value) => assert_eq!(value.to_stable_bytes(), data),
+        Err(err) => assert!(matches!(
+            err.kind(),
+            BairiakErrorKind::BufferTooShortError
+                | BairiakErrorKind::UnknownWidthError
+                | BairiakErrorKind::UnknownVersionError
+                | BairiakErrorKind::TrailingBytesError
+        )),
+    }
+});
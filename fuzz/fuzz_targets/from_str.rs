@@ -0,0 +1,37 @@
+// Values and flag names parsed from configuration strings, through the
+// `FromStr` impls of `Bairiak` and of generated enums.
+
+#![no_main]
+
+use bairiak::BairiakErrorKind;
+use libfuzzer_sys::fuzz_target;
+
+// Brings `Bairiak` and `BairiakEnum` into scope along with the enums.
+include!("../../test_data/expected/from_str_spec.rs");
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    match s.parse::<Bairiak>() {
+        Ok(value) => assert!(value.width() == 128 || value.bits() >> value.width() == 0),
+        Err(err) => assert!(matches!(
+            err.kind(),
+            BairiakErrorKind::ParseValueError | BairiakErrorKind::ValueOutOfRangeError
+        )),
+    }
+
+    match s.parse::<Permissions>() {
+        Ok(flag) => assert!(Permissions::from_u8(flag.to_u8()).is_some()),
+        Err(err) => {
+            assert_eq!(err.input(), s);
+            if let Some(suggestion) = err.suggestion() {
+                assert!(suggestion.parse::<Permissions>().is_ok());
+            }
+        }
+    }
+    if let Err(err) = s.parse::<Mode>() {
+        assert_eq!(err.input(), s);
+    }
+});
@@ -0,0 +1,19 @@
+// Specs uploaded by tenants go through parsing, validation and generation,
+// which must fail with a spec error rather than panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(yaml) = std::str::from_utf8(data) else {
+        return;
+    };
+    match bairiak::generate_bairiak_enums_from_str(yaml) {
+        Ok(code) => assert!(code.starts_with("// ")),
+        Err(err) => {
+            assert!(err.is_spec(), "{:?} for a spec", err.kind());
+            assert!(!err.message().is_empty());
+        }
+    }
+});
@@ -0,0 +1,123 @@
+// Layout constants for checking across an FFI boundary that both sides have
+// the same flags at the same positions. An enum with `abi: true` in the spec
+// gets a module of them:
+//
+//     pub mod perm_abi {
+//         pub const WIDTH_BITS: u32 = 8;
+//         pub const VARIANT_COUNT: u32 = 3;
+//         pub const LAYOUT_HASH: u64 = 0x...;
+//     }
+//
+// The layout hash is the 64-bit FNV-1a hash (offset basis 0xcbf29ce484222325,
+// prime 0x100000001b3) of the variants in ascending position, each as its
+// name in UTF-8, a zero byte and its position as one byte. Placeholders and
+// docs don't take part. The algorithm is stable: the same names at the same
+// positions hash the same in every release, so the other side can compute it
+// on its own.
+
+use std::fmt::{self, Write};
+
+use crate::{fnv1a_64, to_snake_case, BairiakEnum, Enum};
+
+// The layout hash of `B`, as generated in its `LAYOUT_HASH`, for checking a
+// hand-written impl or comparing with the other side at runtime.
+pub fn layout_hash_for<B: BairiakEnum>() -> u64 {
+    layout_hash(
+        B::all_variants()
+            .iter()
+            .map(|flag| (flag.name(), flag.to_u8())),
+    )
+}
+
+fn layout_hash<'a>(variants: impl IntoIterator<Item = (&'a str, u8)>) -> u64 {
+    let mut bytes = Vec::new();
+    for (name, position) in variants {
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.push(0);
+        bytes.push(position);
+    }
+    fnv1a_64(&bytes)
+}
+
+pub(crate) fn write_abi(e: &Enum, width: u8, allow: &str, out: &mut String) -> fmt::Result {
+    let hash = layout_hash(
+        e.positioned_variants()
+            .map(|(position, name)| (name, position as u8)),
+    );
+    write!(
+        out,
+        "
+{allow}pub mod {module}_abi {{
+    pub const WIDTH_BITS: u32 = {width};
+    pub const VARIANT_COUNT: u32 = {count};
+    pub const LAYOUT_HASH: u64 = {hash:#018x};
+}}
+",
+        allow = allow,
+        module = to_snake_case(&e.name),
+        width = width,
+        count = e.variant_count(),
+        hash = hash,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_bairiak_enums_from_str;
+
+    fn generated_hash(spec: &str) -> String {
+        let code = generate_bairiak_enums_from_str(spec).unwrap();
+        let line = code
+            .lines()
+            .find(|line| line.contains("LAYOUT_HASH"))
+            .unwrap();
+        line.trim().to_string()
+    }
+
+    #[test]
+    fn test_layout_hash_algorithm() {
+        // FNV-1a 64 of `Read\0\x00Write\0\x01`, pinned as the algorithm is
+        // stable.
+        let mut bytes = b"Read\0\x00".to_vec();
+        bytes.extend_from_slice(b"Write\0\x01");
+        assert_eq!(layout_hash([("Read", 0), ("Write", 1)]), fnv1a_64(&bytes));
+        assert_eq!(layout_hash([("Read", 0), ("Write", 1)]), 0xbd44074d2c6da1f7);
+        assert_eq!(layout_hash([]), 0xcbf29ce484222325);
+        assert_ne!(
+            layout_hash([("Read", 0), ("Write", 1)]),
+            layout_hash([("Write", 0), ("Read", 1)])
+        );
+    }
+
+    #[test]
+    fn test_layout_hash_changes_with_positions_only() {
+        let spec = "enums:\n  - name: Perm\n    abi: true\n    variants: [Read, Write]\n";
+        let hash = generated_hash(spec);
+
+        let moved = "enums:\n  - name: Perm\n    abi: true\n    variants: [Read, ~, Write]\n";
+        assert_ne!(generated_hash(moved), hash);
+        let renamed = "enums:\n  - name: Perm\n    abi: true\n    variants: [Read, Modify]\n";
+        assert_ne!(generated_hash(renamed), hash);
+
+        let documented = "
+enums:
+  - name: Perm
+    abi: true
+    variants:
+      - name: Read
+        doc: Lets the user read.
+      - name: Write
+        deprecated: Use a role instead.
+";
+        assert_eq!(generated_hash(documented), hash);
+    }
+
+    #[test]
+    fn test_no_abi_module_by_default() {
+        let spec = "enums:\n  - name: Perm\n    variants: [Read, Write]\n";
+        assert!(!generate_bairiak_enums_from_str(spec)
+            .unwrap()
+            .contains("perm_abi"));
+    }
+}
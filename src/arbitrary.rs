@@ -0,0 +1,90 @@
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection, prop_oneof,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{Bairiak, BairiakEnum};
+
+// Picks a width first and then any value that fits it.
+impl Arbitrary for Bairiak {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Bairiak>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            any::<u8>().prop_map(Bairiak::U8),
+            any::<u16>().prop_map(Bairiak::U16),
+            any::<u32>().prop_map(Bairiak::U32),
+            any::<u64>().prop_map(Bairiak::U64),
+            any::<u128>().prop_map(Bairiak::U128),
+        ]
+        .boxed()
+    }
+}
+
+// Values of `B`'s width with only the positions of `B`'s variants set.
+pub fn bairiak_for<B>() -> BoxedStrategy<Bairiak>
+where
+    B: BairiakEnum + Arbitrary + 'static,
+{
+    collection::vec(any::<B>(), 0..=128)
+        .prop_map(|flags| {
+            let mut bairiak = B::get_zero_bairiak();
+            for flag in flags {
+                bairiak.set(flag);
+            }
+            bairiak
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::fixtures::TestEnum;
+    use proptest::{proptest, strategy::Just};
+
+    include!("../test_data/expected/arbitrary_spec.rs");
+
+    impl Arbitrary for TestEnum {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<TestEnum>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            prop_oneof![
+                Just(TestEnum::Flag0),
+                Just(TestEnum::Flag1),
+                Just(TestEnum::Flag9)
+            ]
+            .boxed()
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_set_then_is_true(mut bairiak in bairiak_for::<TestEnum>(), flag in any::<TestEnum>()) {
+            bairiak.set(flag);
+            assert!(bairiak.is_true(flag));
+        }
+
+        #[test]
+        fn test_bairiak_for_sets_defined_positions(bairiak in bairiak_for::<Perm>()) {
+            assert_eq!(bairiak.width(), 8);
+            assert!(bairiak.positions().all(|position| position < 3));
+        }
+
+        #[test]
+        fn test_union_is_commutative(a in any::<Bairiak>(), b in any::<Bairiak>()) {
+            let (ab, ba) = (a.union(&b), b.union(&a));
+            assert_eq!(ab.bits(), ba.bits());
+            assert_eq!(ab.width(), ba.width());
+            assert_eq!(ab.bits(), a.bits() | b.bits());
+        }
+
+        #[test]
+        fn test_arbitrary_fits_width(bairiak in any::<Bairiak>()) {
+            assert!(bairiak.width() == 128 || bairiak.bits() >> bairiak.width() == 0);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// Zero-copy archiving with rkyv. The archived form is a format guarantee: 17
+// bytes, the width in bits (8, 16, 32, 64 or 128) followed by the value as 16
+// little-endian bytes, with every bit beyond the width zero. Validation
+// rejects anything else.
+
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Source},
+    traits::NoUndef,
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::{Bairiak, BairiakError, BairiakErrorKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct ArchivedBairiak {
+    width: u8,
+    bits: [u8; 16],
+}
+
+// SAFETY: `ArchivedBairiak` is `repr(C)` and made of bytes only, so it has the
+// same layout on every platform and no padding.
+unsafe impl Portable for ArchivedBairiak {}
+unsafe impl NoUndef for ArchivedBairiak {}
+
+impl ArchivedBairiak {
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    pub fn bits(&self) -> u128 {
+        u128::from_le_bytes(self.bits)
+    }
+
+    pub fn to_bairiak(&self) -> Bairiak {
+        Bairiak::with_width(self.width, self.bits())
+    }
+}
+
+// SAFETY: every bit pattern of the fields is checked before it is accepted.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedBairiak
+where
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees `value` points to enough initialized
+        // bytes, and any bytes are a valid `ArchivedBairiak`.
+        let value = unsafe { &*value };
+        let valid = match value.width {
+            128 => true,
+            8 | 16 | 32 | 64 => value.bits() >> value.width == 0,
+            _ => false,
+        };
+        if !valid {
+            return Err(C::Error::new(BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!(
+                    "Invalid archived Bairiak with width {} and bits {:#x}.",
+                    value.width,
+                    value.bits()
+                ),
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Archive for Bairiak {
+    type Archived = ArchivedBairiak;
+    type Resolver = ();
+
+    fn resolve(&self, _: (), out: Place<ArchivedBairiak>) {
+        out.write(ArchivedBairiak {
+            width: self.width(),
+            bits: self.bits().to_le_bytes(),
+        });
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Bairiak {
+    fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Bairiak, D> for ArchivedBairiak {
+    fn deserialize(&self, _: &mut D) -> Result<Bairiak, D::Error> {
+        Ok(self.to_bairiak())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn test_archive_round_trip() {
+        for bairiak in [
+            Bairiak::U8(0b101),
+            Bairiak::U16(u16::MAX),
+            Bairiak::U32(1 << 31),
+            Bairiak::U64(42),
+            Bairiak::U128(1 << 127 | 1),
+        ] {
+            let bytes = rkyv::to_bytes::<Error>(&bairiak).unwrap();
+            assert_eq!(bytes.len(), 17);
+
+            let archived = rkyv::access::<ArchivedBairiak, Error>(&bytes).unwrap();
+            assert_eq!(archived.width(), bairiak.width());
+            assert_eq!(archived.bits(), bairiak.bits());
+            assert_eq!(rkyv::from_bytes::<Bairiak, Error>(&bytes).unwrap(), bairiak);
+        }
+    }
+
+    #[test]
+    fn test_archive_layout() {
+        let bytes = rkyv::to_bytes::<Error>(&Bairiak::U16(0x0201)).unwrap();
+        let mut expected = [0u8; 17];
+        expected[..3].copy_from_slice(&[16, 0x01, 0x02]);
+        assert_eq!(bytes.as_slice(), expected);
+    }
+
+    #[test]
+    fn test_archive_rejects_corrupted_buffers() {
+        let mut bytes = [0u8; 17];
+        bytes[0] = 12;
+        assert!(rkyv::access::<ArchivedBairiak, Error>(&bytes).is_err());
+
+        bytes[0] = 8;
+        bytes[2] = 1;
+        assert!(rkyv::access::<ArchivedBairiak, Error>(&bytes).is_err());
+
+        bytes[0] = 16;
+        assert!(rkyv::access::<ArchivedBairiak, Error>(&bytes).is_ok());
+
+        assert!(rkyv::access::<ArchivedBairiak, Error>(&bytes[..16]).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Write;
+
+use crate::{Bairiak, BairiakEnum};
+
+// Asserts that each flag after `set:` is set in a `Bairiak` and each flag after
+// `clear:` is not, checking all of them before failing:
+//
+//     assert_flags!(value, set: [Perm::Read, Perm::Write], clear: [Perm::Admin]);
+//
+// The panic message lists every unmet expectation and decodes the whole value:
+// its width, its bits and the names of its set flags.
+#[macro_export]
+macro_rules! assert_flags {
+    ($value:expr, set: [$($set:expr),* $(,)?], clear: [$($clear:expr),* $(,)?] $(,)?) => {
+        $crate::__assert_flags(&$value, &[$($set),*], &[$($clear),*])
+    };
+    ($value:expr, set: [$($set:expr),* $(,)?] $(,)?) => {
+        $crate::__assert_flags(&$value, &[$($set),*], &[])
+    };
+    ($value:expr, clear: [$($clear:expr),* $(,)?] $(,)?) => {
+        $crate::__assert_flags(&$value, &[], &[$($clear),*])
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_flags<B: BairiakEnum>(value: &Bairiak, set: &[B], clear: &[B]) {
+    let mut unmet = String::new();
+    for flag in set.iter().filter(|flag| value.is_false(**flag)) {
+        let _ = writeln!(unmet, "  expected set:   {}", flag.name());
+    }
+    for flag in clear.iter().filter(|flag| value.is_true(**flag)) {
+        let _ = writeln!(unmet, "  expected clear: {}", flag.name());
+    }
+    // Failing the assertion is the point.
+    #[allow(clippy::panic)]
+    if !unmet.is_empty() {
+        panic!(
+            "assertion failed: flags of {}\n{}",
+            describe::<B>(value),
+            unmet
+        );
+    }
+}
+
+// `U8 0b101 (Read, Admin)`, with set bits `B` doesn't define as `bit(N)`.
+fn describe<B: BairiakEnum>(value: &Bairiak) -> String {
+    let names: Vec<String> = value
+        .positions()
+        .map(|position| match B::from_u8(position) {
+            Some(flag) => flag.name().to_string(),
+            None => format!("bit({})", position),
+        })
+        .collect();
+    format!(
+        "U{} {:#b} ({})",
+        value.width(),
+        value.bits(),
+        names.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::*;
+    use crate::test_support::fixtures::Perm;
+
+    fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+        let payload = panic::catch_unwind(f).unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn test_assert_flags_passes() {
+        let value = Bairiak::U8(0b011);
+        assert_flags!(value, set: [Perm::Read, Perm::Write], clear: [Perm::Admin]);
+        assert_flags!(value, set: [Perm::Write]);
+        assert_flags!(value, clear: [Perm::Admin,]);
+    }
+
+    #[test]
+    fn test_assert_flags_reports_every_unmet_expectation() {
+        let message = panic_message(|| {
+            assert_flags!(
+                Bairiak::U8(0b1000_0101),
+                set: [Perm::Read, Perm::Write],
+                clear: [Perm::Admin],
+            );
+        });
+        assert_eq!(
+            message,
+            "assertion failed: flags of U8 0b10000101 (Read, Admin, bit(7))
+  expected set:   Write
+  expected clear: Admin
+"
+        );
+    }
+
+    #[test]
+    fn test_assert_flags_on_empty_value() {
+        let message = panic_message(|| assert_flags!(Bairiak::U16(0), set: [Perm::Read]));
+        assert_eq!(
+            message,
+            "assertion failed: flags of U16 0b0 ()\n  expected set:   Read\n"
+        );
+    }
+}
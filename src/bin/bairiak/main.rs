@@ -0,0 +1,738 @@
+use std::{env, fs, io::Write, path::Path, process};
+
+use bairiak::{
+    diff_specs, explain_value, generate_bairiak_enums_with, list_enums, load_spec, GenerateOptions,
+};
+
+#[cfg(feature = "watch")]
+mod watch;
+
+const USAGE: &str = "Usage:
+    bairiak diff <old_spec> <new_spec> [--fail-on-breaking]
+    bairiak explain --spec <spec> --enum <enum> <value>
+    bairiak list <spec> [--format table|json]
+    bairiak new <spec> [--force] [--build-rs]
+    bairiak generate <spec> <output> [--force] [--manifest [--allow-renumber]] [--allow-empty] [--watch]
+    bairiak verify --spec <spec> --source <generated>";
+
+const SPEC_TEMPLATE: &str = "# Bairiak spec: every enum below is generated as a Rust enum whose
+# variants are bit positions in a Bairiak value.
+# Optional. The version of the spec, recorded in the generation manifest.
+# version: \"1\"
+# Optional. Defaults for every enum below. A setting given on an enum
+# overrides these, and these override the options passed to the generator.
+settings:
+  # `private`, `pub(crate)` or `pub`.
+  visibility: private
+  # Derives added to the generated ones, e.g. `PartialOrd` or `serde::Serialize`.
+  derives: []
+  # `lenient` allows `_` in names after the first letter; `strict` doesn't.
+  naming: lenient
+  # `false` drops the `#[allow(dead_code)]` of the generated items.
+  allow_dead_code: true
+  # Allows `clippy::upper_case_acronyms` on enums with names like `HTTP`.
+  allow_upper_case_acronyms: false
+  # Lint attributes added to every enum, e.g. `warn(missing_docs)`.
+  lint_attrs: []
+  # Warnings that fail generation instead: `single_variant`, `sparse_width`
+  # or `all_deprecated`.
+  warnings_as_errors: []
+enums:
+  # The enum name. It must be CamelCase.
+  - name: Permissions
+    # The flags of the enum. Names must be CamelCase and each flag takes the
+    # position after the previous one, unless given one as `- name: Admin` and
+    # `position: 8` on the next line. A `~` entry reserves a position without
+    # defining a flag. A flag given by `name` can also have a `doc` and a
+    # `deprecated` note, both added to its docs, and a `status`: `experimental`,
+    # `stable`, `deprecated` or `removed`, which generates `status()` and
+    # `status_at(position)`. A `removed` flag keeps its position reserved
+    # without a variant. An `id` identifies a flag across renumbering and
+    # generates `stable_id()`; once one flag has an id, every flag needs one.
+    # Only ever append new flags: reordering or deleting a flag changes the
+    # meaning of persisted values.
+    # The width of the Bairiak value (u8 to u128) is chosen from the highest
+    # position, up to 127.
+    variants:
+      - Read
+      - Write
+      - Delete
+    # Optional. Requires every flag to be given its `position`, so that
+    # reordering the lines above can't renumber the flags.
+    lock_positions: false
+    # Optional. Leaves `experimental` flags out unless the crate using the
+    # generated code enables this feature.
+    # experimental_feature: experimental-flags
+    # Optional. Forces the width to 8, 16, 32, 64 or 128 bits, e.g. to leave
+    # room for flags to come, instead of fitting it to the highest position.
+    # bits: 32
+    # Optional. Generates `migrate_<enum>_<from>_to_<to>` functions translating
+    # values persisted with an older layout. `positions` maps old positions to
+    # new ones; `~` drops a position, as does leaving it out.
+    migrations: []
+    # migrations:
+    #   - from: v1
+    #     to: v2
+    #     positions:
+    #       0: 0
+    #       1: 2
+    # Optional. Generates a proptest `Arbitrary` impl for the enum, which needs
+    # the `proptest` feature of bairiak.
+    arbitrary: false
+    # Optional. Derives schemars' `JsonSchema` for the enum, with the flag
+    # names as values, which needs the `schemars` feature of bairiak.
+    json_schema: false
+    # Optional. Derives `defmt::Format` for the enum, which needs `defmt` as a
+    # dependency of the crate using the generated code.
+    defmt: false
+    # Optional. Registers the enum in `bairiak::registry`, which needs the
+    # `registry` feature of bairiak.
+    registry: false
+    # Optional. Emits `permissions_abi` with the `WIDTH_BITS`, `VARIANT_COUNT`
+    # and `LAYOUT_HASH` constants, for checking the layout across FFI.
+    abi: false
+    # Optional. Generates a `bitflags!` struct named after the enum with a
+    # `Flags` suffix, with `From` conversions to and from `Bairiak`, which needs
+    # the `bitflags` feature of bairiak.
+    emit_bitflags: false
+    # Optional. Generates `PermissionsBools`, a struct of a `bool` field per
+    # flag, with `From` conversions to and from `Bairiak`.
+    bool_struct: false
+    # Optional. Implements `FromStr` for the enum, matching flag names and the
+    # `aliases` of a flag given as `- name: Write` and `aliases: [modify]`.
+    # `case_insensitive` also ignores ASCII case; otherwise use `exact`.
+    # from_str: case_insensitive
+    # Optional. `bytes` matches the input's bytes, faster for many names, and
+    # `match` compares it with each name in turn. Above 32 names, `bytes` is
+    # the default.
+    # from_str_lookup: bytes
+    # Optional. Generates a `permissions_match!(flag, { Read => .., .. })`
+    # macro, named after the enum, that expands to a `match` and requires an
+    # arm for every flag in this order, so adding a flag breaks each use until
+    # it is handled.
+    match_macro: false
+    # Optional. `associated` or `free` emits the position of every flag as a
+    # constant, `DocumentFlags::IS_PAID_POS` or `IS_PAID_POS`, and with
+    # `position_masks: true` also its mask, `IS_PAID_MASK`, typed to the width.
+    # position_consts: associated
+    # position_masks: true
+    # Optional. Named sets of flags: `Permissions::group(\"Editors\")` returns the
+    # mask of a group by name and `Permissions::groups()` lists them all, for
+    # resolving group names from configuration. `group_lookup:
+    # case_insensitive` makes `group()` ignore ASCII case.
+    # groups:
+    #   - name: Editors
+    #     variants: [Read, Write]
+    # Optional. Sets of flags of which at most one may be set: the generated
+    # `Permissions::validate_exclusive(&value)` reports the first set that has
+    # more, with the names of its set flags.
+    # exclusive:
+    #   - [Write, Delete]
+    # A flag given by `name` can also list the flags it `requires`, e.g.
+    # `requires: [Read]` on `Delete`; the generated
+    # `Permissions::validate_requires(&value)` reports every set flag whose
+    # required flags aren't all set.
+    # A flag given by `name` can also be marked `default: true`, for at most
+    # one flag of the enum, to implement `Default` returning it.
+    # Optional. Override `settings` for this enum; `derives` and `lint_attrs`
+    # replace the spec's lists rather than adding to them.
+    # visibility: pub
+    # derives: [PartialOrd, Ord]
+    # lint_attrs: [\"allow(clippy::enum_variant_names)\"]
+# Optional. Layouts packing several enums into one value, each after the
+# previous ones; `device_state_layout()` returns a `bairiak::PackedLayout`
+# with the offset of every member.
+# packed:
+#   - name: DeviceState
+#     bits: 32
+#     members: [Permissions]
+";
+
+const BUILD_RS_TEMPLATE: &str = "// build.rs
+use bairiak::{generate_bairiak_enums, BairiakError};
+
+fn main() -> Result<(), BairiakError> {
+    println!(\"cargo:rerun-if-changed={spec}\");
+    generate_bairiak_enums(\"{spec}\", \"src/bairiak_enums.rs\")?;
+    Ok(())
+}
+";
+
+fn parse_value(s: &str) -> Option<u128> {
+    let s = s.replace('_', "");
+    let (digits, radix) = match s.get(..2) {
+        Some("0b") | Some("0B") => (&s[2..], 2),
+        Some("0o") | Some("0O") => (&s[2..], 8),
+        Some("0x") | Some("0X") => (&s[2..], 16),
+        _ => (&s[..], 10),
+    };
+    if digits.starts_with(['+', '-']) {
+        return None;
+    }
+    u128::from_str_radix(digits, radix).ok()
+}
+
+fn diff(args: &[String], out: &mut impl Write) -> i32 {
+    let mut paths = Vec::new();
+    let mut fail_on_breaking = false;
+    for arg in args {
+        match arg.as_str() {
+            "--fail-on-breaking" => fail_on_breaking = true,
+            _ => paths.push(arg.as_str()),
+        }
+    }
+
+    let [old_spec_path, new_spec_path] = paths[..] else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    let diff = match diff_specs(old_spec_path, new_spec_path) {
+        Ok(diff) => diff,
+        Err(err) => {
+            eprintln!("{}", err);
+            return 2;
+        }
+    };
+
+    if write!(out, "{}", diff).is_err() {
+        return 2;
+    }
+
+    if fail_on_breaking && diff.has_breaking_changes() {
+        return 1;
+    }
+
+    0
+}
+
+fn explain(args: &[String], out: &mut impl Write) -> i32 {
+    let mut spec_path = None;
+    let mut enum_name = None;
+    let mut value = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--spec" => spec_path = args.next(),
+            "--enum" => enum_name = args.next(),
+            _ => value = Some(arg),
+        }
+    }
+
+    let (Some(spec_path), Some(enum_name), Some(value)) = (spec_path, enum_name, value) else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    let Some(value) = parse_value(value) else {
+        eprintln!("Error parsing value: {}", value);
+        return 2;
+    };
+
+    let explanation =
+        match load_spec(spec_path).and_then(|spec| explain_value(&spec, enum_name, value)) {
+            Ok(explanation) => explanation,
+            Err(err) => {
+                eprintln!("{}", err);
+                return 2;
+            }
+        };
+
+    if write!(out, "{}", explanation).is_err() {
+        return 2;
+    }
+
+    0
+}
+
+fn list(args: &[String], out: &mut impl Write) -> i32 {
+    let mut spec_path = None;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().map(String::as_str) {
+                Some("table") => json = false,
+                Some("json") => json = true,
+                _ => {
+                    eprintln!("{}", USAGE);
+                    return 2;
+                }
+            },
+            _ => spec_path = Some(arg),
+        }
+    }
+
+    let Some(spec_path) = spec_path else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    let listings = match load_spec(spec_path).and_then(|spec| list_enums(&spec)) {
+        Ok(listings) => listings,
+        Err(err) => {
+            eprintln!("{}", err);
+            return 2;
+        }
+    };
+
+    let written = if json {
+        match serde_json::to_string_pretty(&listings) {
+            Ok(json) => writeln!(out, "{}", json),
+            Err(err) => {
+                eprintln!("Error serializing enums: {}", err);
+                return 2;
+            }
+        }
+    } else {
+        listings
+            .iter()
+            .try_for_each(|listing| write!(out, "{}", listing))
+    };
+
+    if written.is_err() {
+        return 2;
+    }
+
+    0
+}
+
+fn new(args: &[String], out: &mut impl Write) -> i32 {
+    let mut spec_path = None;
+    let mut force = false;
+    let mut build_rs = false;
+    for arg in args {
+        match arg.as_str() {
+            "--force" => force = true,
+            "--build-rs" => build_rs = true,
+            _ => spec_path = Some(arg),
+        }
+    }
+
+    let Some(spec_path) = spec_path else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    if Path::new(spec_path).exists() && !force {
+        eprintln!(
+            "Error creating spec: {} already exists. Use --force to overwrite it.",
+            spec_path
+        );
+        return 2;
+    }
+
+    if let Err(err) = fs::write(spec_path, SPEC_TEMPLATE) {
+        eprintln!("Error writing file: {}", err);
+        return 2;
+    }
+
+    if build_rs && write!(out, "{}", BUILD_RS_TEMPLATE.replace("{spec}", spec_path)).is_err() {
+        return 2;
+    }
+
+    0
+}
+
+fn generate(args: &[String], out: &mut impl Write) -> i32 {
+    let mut paths = Vec::new();
+    let mut watch = false;
+    let mut force = false;
+    let mut manifest = false;
+    let mut allow_renumber = false;
+    let mut allow_empty = false;
+    for arg in args {
+        match arg.as_str() {
+            "--watch" => watch = true,
+            "--force" => force = true,
+            "--manifest" => manifest = true,
+            "--allow-renumber" => allow_renumber = true,
+            "--allow-empty" => allow_empty = true,
+            _ => paths.push(arg.as_str()),
+        }
+    }
+
+    let [spec_path, output_path] = paths[..] else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    if watch {
+        #[cfg(feature = "watch")]
+        return watch::watch(
+            spec_path,
+            output_path,
+            watch::DEBOUNCE,
+            out,
+            &std::sync::atomic::AtomicBool::new(false),
+        );
+
+        #[cfg(not(feature = "watch"))]
+        {
+            eprintln!("Error: --watch requires bairiak to be built with the `watch` feature.");
+            return 2;
+        }
+    }
+
+    // Reports every problem of the spec at once, and its warnings. A spec that can't be loaded
+    // fails generation with the same error.
+    if let Ok(spec) = load_spec(spec_path) {
+        let problems = spec.validate_all();
+        if !problems.is_empty() {
+            eprintln!("Error: invalid spec {}:", spec_path);
+            for problem in problems {
+                eprintln!("    {}", problem);
+            }
+            return 2;
+        }
+        for warning in spec.warnings() {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+
+    let options = GenerateOptions::default()
+        .force(force)
+        .manifest(manifest)
+        .allow_renumber(allow_renumber)
+        .allow_empty(allow_empty);
+    let result = generate_bairiak_enums_with(spec_path, output_path, &options);
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        return 2;
+    }
+
+    if writeln!(out, "Generated {} from {}", output_path, spec_path).is_err() {
+        return 2;
+    }
+
+    0
+}
+
+// Checks generated code against its spec without touching either.
+fn verify(args: &[String], out: &mut impl Write) -> i32 {
+    let mut spec_path = None;
+    let mut source_path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--spec" => spec_path = args.next(),
+            "--source" => source_path = args.next(),
+            _ => {
+                eprintln!("{}", USAGE);
+                return 2;
+            }
+        }
+    }
+
+    let (Some(spec_path), Some(source_path)) = (spec_path, source_path) else {
+        eprintln!("{}", USAGE);
+        return 2;
+    };
+
+    #[cfg(feature = "verify")]
+    {
+        let mismatches = match bairiak::verify_generated_file(spec_path, source_path) {
+            Ok(mismatches) => mismatches,
+            Err(err) => {
+                eprintln!("{}", err);
+                return 2;
+            }
+        };
+
+        if mismatches.is_empty() {
+            return match writeln!(out, "No mismatches.") {
+                Ok(()) => 0,
+                Err(_) => 2,
+            };
+        }
+        for mismatch in &mismatches {
+            if writeln!(out, "{}", mismatch).is_err() {
+                return 2;
+            }
+        }
+        1
+    }
+
+    #[cfg(not(feature = "verify"))]
+    {
+        let _ = (spec_path, source_path, out);
+        eprintln!("Error: verify requires bairiak to be built with the `verify` feature.");
+        2
+    }
+}
+
+fn run(args: &[String], out: &mut impl Write) -> i32 {
+    match args.first().map(String::as_str) {
+        Some("diff") => diff(&args[1..], out),
+        Some("explain") => explain(&args[1..], out),
+        Some("list") => list(&args[1..], out),
+        Some("new") => new(&args[1..], out),
+        Some("generate") => generate(&args[1..], out),
+        Some("verify") => verify(&args[1..], out),
+        _ => {
+            eprintln!("{}", USAGE);
+            2
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    process::exit(run(&args, &mut std::io::stdout()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_cli(args: &[&str]) -> (i32, String) {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        let mut out = Vec::new();
+        let code = run(&args, &mut out);
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_diff_reports_changes() {
+        let (code, out) = run_cli(&[
+            "diff",
+            "test_data/diff_old_spec.yaml",
+            "test_data/diff_new_spec.yaml",
+        ]);
+        assert_eq!(code, 0);
+        assert!(out.contains("~ Write -> Modify (position 1)"));
+    }
+
+    #[test]
+    fn test_diff_fail_on_breaking() {
+        let (code, _) = run_cli(&[
+            "diff",
+            "test_data/diff_old_spec.yaml",
+            "test_data/diff_new_spec.yaml",
+            "--fail-on-breaking",
+        ]);
+        assert_eq!(code, 1);
+    }
+
+    #[test]
+    fn test_diff_fail_on_breaking_without_changes() {
+        let (code, out) = run_cli(&[
+            "diff",
+            "test_data/valid_spec.yaml",
+            "test_data/valid_spec.yaml",
+            "--fail-on-breaking",
+        ]);
+        assert_eq!(code, 0);
+        assert_eq!(out, "No changes.\n");
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("11"), Some(11));
+        assert_eq!(parse_value("0b1011"), Some(11));
+        assert_eq!(parse_value("0x0b"), Some(11));
+        assert_eq!(parse_value("0o13"), Some(11));
+        assert_eq!(parse_value("1_000"), Some(1000));
+        assert_eq!(parse_value("0b102"), None);
+        assert_eq!(parse_value("Read"), None);
+        assert_eq!(parse_value("+5"), None);
+        assert_eq!(parse_value("0x+ff"), None);
+    }
+
+    #[test]
+    fn test_explain() {
+        let (code, out) = run_cli(&[
+            "explain",
+            "--spec",
+            "test_data/valid_spec.yaml",
+            "--enum",
+            "TestEnum",
+            "0b1101",
+        ]);
+        assert_eq!(code, 0);
+        assert!(out.contains("Flag0 (position 0)"));
+        assert!(out.contains("Flag2 (position 2)"));
+        assert!(out.contains("unknown set bits: 3"));
+    }
+
+    #[test]
+    fn test_explain_unknown_enum() {
+        let (code, out) = run_cli(&[
+            "explain",
+            "--spec",
+            "test_data/valid_spec.yaml",
+            "--enum",
+            "Missing",
+            "1",
+        ]);
+        assert_eq!(code, 2);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_list_table() {
+        let (code, out) = run_cli(&["list", "test_data/diff_old_spec.yaml"]);
+        assert_eq!(code, 0);
+        assert_eq!(
+            out,
+            "Perm (u8, 3 variants)
+    0  Read
+    1  Write
+    2  Delete
+Mode (u8, 2 variants)
+    0  Fast
+    1  Slow
+"
+        );
+    }
+
+    #[test]
+    fn test_list_json() {
+        let (code, out) = run_cli(&["list", "test_data/diff_old_spec.yaml", "--format", "json"]);
+        assert_eq!(code, 0);
+
+        let listings: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(listings[0]["name"], "Perm");
+        assert_eq!(listings[0]["width"], 8);
+        assert_eq!(listings[0]["variant_count"], 3);
+        assert_eq!(listings[0]["positions"][2]["name"], "Delete");
+        assert_eq!(listings[0]["positions"][2]["position"], 2);
+        assert_eq!(listings[1]["name"], "Mode");
+    }
+
+    #[test]
+    fn test_list_unknown_format() {
+        let (code, _) = run_cli(&["list", "test_data/diff_old_spec.yaml", "--format", "xml"]);
+        assert_eq!(code, 2);
+    }
+
+    #[test]
+    fn test_spec_template_is_valid() {
+        let generated_code = bairiak::generate_bairiak_enums_from_str(SPEC_TEMPLATE).unwrap();
+        assert!(generated_code.contains("enum Permissions"));
+        assert!(generated_code.contains("Delete = 2"));
+    }
+
+    #[test]
+    fn test_new() {
+        let spec_path = env::temp_dir().join("bairiak_test_new.yaml");
+        let spec_path = spec_path.to_str().unwrap();
+        let _ = fs::remove_file(spec_path);
+
+        let (code, out) = run_cli(&["new", spec_path, "--build-rs"]);
+        assert_eq!(code, 0);
+        assert_eq!(fs::read_to_string(spec_path).unwrap(), SPEC_TEMPLATE);
+        assert!(out.contains(&format!("generate_bairiak_enums(\"{}\"", spec_path)));
+
+        fs::write(spec_path, "enums: []").unwrap();
+        let (code, _) = run_cli(&["new", spec_path]);
+        assert_eq!(code, 2);
+        assert_eq!(fs::read_to_string(spec_path).unwrap(), "enums: []");
+
+        let (code, out) = run_cli(&["new", spec_path, "--force"]);
+        assert_eq!(code, 0);
+        assert!(out.is_empty());
+        assert_eq!(fs::read_to_string(spec_path).unwrap(), SPEC_TEMPLATE);
+
+        fs::remove_file(spec_path).unwrap();
+    }
+
+    #[test]
+    fn test_generate() {
+        let output_path = env::temp_dir().join("bairiak_test_generate.rs");
+        let output_path = output_path.to_str().unwrap();
+        let _ = fs::remove_file(output_path);
+
+        let (code, out) = run_cli(&["generate", "test_data/valid_spec.yaml", output_path]);
+        assert_eq!(code, 0);
+        assert_eq!(
+            out,
+            format!("Generated {} from test_data/valid_spec.yaml\n", output_path)
+        );
+        assert!(fs::read_to_string(output_path)
+            .unwrap()
+            .contains("enum TestEnum"));
+
+        fs::write(output_path, "fn main() {}\n").unwrap();
+        let (code, _) = run_cli(&["generate", "test_data/valid_spec.yaml", output_path]);
+        assert_eq!(code, 2);
+
+        let (code, _) = run_cli(&[
+            "generate",
+            "test_data/valid_spec.yaml",
+            output_path,
+            "--force",
+        ]);
+        assert_eq!(code, 0);
+
+        let (code, _) = run_cli(&[
+            "generate",
+            "test_data/valid_spec.yaml",
+            output_path,
+            "--manifest",
+        ]);
+        assert_eq!(code, 0);
+        let manifest_path = Path::new(output_path).with_extension("manifest.json");
+        assert!(fs::read_to_string(&manifest_path)
+            .unwrap()
+            .contains("\"name\": \"TestEnum\""));
+
+        fs::remove_file(manifest_path).unwrap();
+        fs::remove_file(output_path).unwrap();
+    }
+
+    #[test]
+    fn test_generate_invalid_spec() {
+        let output_path = env::temp_dir().join("bairiak_test_generate_invalid.rs");
+        let output_path = output_path.to_str().unwrap();
+        let _ = fs::remove_file(output_path);
+
+        let (code, out) = run_cli(&["generate", "test_data/problems_spec.yaml", output_path]);
+        assert_eq!(code, 2);
+        assert_eq!(out, "");
+        assert!(!Path::new(output_path).exists());
+    }
+
+    #[cfg(feature = "verify")]
+    #[test]
+    fn test_verify() {
+        let spec_path = "test_data/multi_enum_spec.yaml";
+        let source_path = "test_data/expected/multi_enum_spec.rs";
+        let (code, out) = run_cli(&["verify", "--spec", spec_path, "--source", source_path]);
+        assert_eq!(code, 0);
+        assert_eq!(out, "No mismatches.\n");
+
+        let corrupted_path = env::temp_dir().join("bairiak_test_verify.rs");
+        let corrupted = fs::read_to_string(source_path)
+            .unwrap()
+            .replace("Delete = 2,", "Delete = 5,");
+        fs::write(&corrupted_path, corrupted).unwrap();
+        let (code, out) = run_cli(&[
+            "verify",
+            "--spec",
+            spec_path,
+            "--source",
+            corrupted_path.to_str().unwrap(),
+        ]);
+        assert_eq!(code, 1);
+        assert_eq!(
+            out,
+            "Permissions: variant Delete has position 5, the spec has position 2\n"
+        );
+        fs::remove_file(corrupted_path).unwrap();
+    }
+
+    #[test]
+    fn test_verify_missing_arguments() {
+        let (code, out) = run_cli(&["verify", "--spec", "test_data/valid_spec.yaml"]);
+        assert_eq!(code, 2);
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn test_diff_missing_arguments() {
+        let (code, _) = run_cli(&["diff", "test_data/valid_spec.yaml"]);
+        assert_eq!(code, 2);
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::{Duration, Instant},
+};
+
+use bairiak::generate_bairiak_enums;
+use notify::{RecursiveMode, Watcher};
+
+// Editors often write a file more than once when saving it, so events arriving
+// within this window of each other trigger a single regeneration.
+pub const DEBOUNCE: Duration = Duration::from_millis(200);
+
+const POLL: Duration = Duration::from_millis(100);
+
+fn regenerate(spec_path: &str, output_path: &str, out: &mut impl Write) -> std::io::Result<()> {
+    match generate_bairiak_enums(spec_path, output_path) {
+        Ok(_) => writeln!(out, "Generated {} from {}", output_path, spec_path),
+        Err(err) => writeln!(out, "Error generating {}: {}", output_path, err),
+    }
+}
+
+fn is_spec_event(event: &notify::Result<notify::Event>, spec_file: &Path) -> bool {
+    // Reading the spec while regenerating produces access events, which must
+    // not trigger another run.
+    match event {
+        Ok(event) if !event.kind.is_access() => event
+            .paths
+            .iter()
+            .any(|path| path.file_name() == spec_file.file_name()),
+        _ => false,
+    }
+}
+
+// Regenerates the output every time the spec changes, until `stop` is set.
+// Spec errors are reported and watching continues.
+pub fn watch(
+    spec_path: &str,
+    output_path: &str,
+    debounce: Duration,
+    out: &mut impl Write,
+    stop: &AtomicBool,
+) -> i32 {
+    let spec_file = PathBuf::from(spec_path);
+    let spec_dir = match spec_file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("Error watching {}: {}", spec_path, err);
+            return 2;
+        }
+    };
+
+    // The directory is watched rather than the file so that editors replacing
+    // the file on save don't end the watch.
+    if let Err(err) = watcher.watch(&spec_dir, RecursiveMode::NonRecursive) {
+        eprintln!("Error watching {}: {}", spec_path, err);
+        return 2;
+    }
+
+    if regenerate(spec_path, output_path, out).is_err() {
+        return 2;
+    }
+
+    while !stop.load(Ordering::Relaxed) {
+        match rx.recv_timeout(POLL) {
+            Ok(event) if is_spec_event(&event, &spec_file) => {}
+            Ok(_) | Err(mpsc::RecvTimeoutError::Timeout) => continue,
+            Err(mpsc::RecvTimeoutError::Disconnected) => return 2,
+        }
+
+        let mut quiet_until = Instant::now() + debounce;
+        while let Some(remaining) = quiet_until.checked_duration_since(Instant::now()) {
+            match rx.recv_timeout(remaining) {
+                Ok(event) if is_spec_event(&event, &spec_file) => {
+                    quiet_until = Instant::now() + debounce
+                }
+                Ok(_) => {}
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return 2,
+            }
+        }
+
+        if regenerate(spec_path, output_path, out).is_err() {
+            return 2;
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs, sync::Arc, thread};
+
+    fn wait_for(output_path: &str, expected: &str) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while Instant::now() < deadline {
+            if fs::read_to_string(output_path).is_ok_and(|code| code.contains(expected)) {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        false
+    }
+
+    #[test]
+    fn test_watch_regenerates_on_change() {
+        let dir = env::temp_dir().join("bairiak_test_watch");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let spec_path = dir.join("spec.yaml").to_str().unwrap().to_string();
+        let output_path = dir.join("flags.rs").to_str().unwrap().to_string();
+        fs::write(&spec_path, "enums:\n  - name: Perm\n    variants: [Read]\n").unwrap();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let (spec_path, output_path, stop) =
+                (spec_path.clone(), output_path.clone(), stop.clone());
+            thread::spawn(move || {
+                let mut out = Vec::new();
+                let code = watch(&spec_path, &output_path, DEBOUNCE, &mut out, &stop);
+                (code, String::from_utf8(out).unwrap())
+            })
+        };
+
+        assert!(wait_for(&output_path, "Read = 0"));
+
+        fs::write(&spec_path, "enums:\n  - name: Perm\n    variants: [read]\n").unwrap();
+        thread::sleep(DEBOUNCE * 3);
+        fs::write(
+            &spec_path,
+            "enums:\n  - name: Perm\n    variants: [Read, Write]\n",
+        )
+        .unwrap();
+        assert!(wait_for(&output_path, "Write = 1"));
+
+        stop.store(true, Ordering::Relaxed);
+        let (code, out) = handle.join().unwrap();
+        assert_eq!(code, 0);
+        assert!(out.contains("Error generating"));
+        assert!(out.ends_with(&format!("Generated {} from {}\n", output_path, spec_path)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
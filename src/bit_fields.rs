@@ -0,0 +1,104 @@
+// Fields of bits given by an offset and a width rather than by flags, for
+// partial updates from hardware such as "here is the new low byte".
+
+use crate::{Bairiak, BairiakError, BairiakErrorKind, Result};
+
+impl Bairiak {
+    // Replaces the `width` bits from `offset` with `value`. Fails with a
+    // `ValueOutOfRangeError`, leaving the value alone, if the field goes beyond
+    // the width of the value or `value` has bits beyond `width`.
+    pub fn assign_bits(&mut self, offset: u8, width: u8, value: u128) -> Result<()> {
+        let mask = field_mask(self, offset, width)?;
+        if value & !mask != 0 {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!("{:#x} doesn't fit in {} bits.", value, width),
+            ));
+        }
+        let shift = |bits: u128| bits.checked_shl(offset as u32).unwrap_or(0);
+        *self = Bairiak::with_width(self.width(), self.bits() & !shift(mask) | shift(value));
+        Ok(())
+    }
+
+    // The `width` bits from `offset`, shifted down to position 0. Fails with a
+    // `ValueOutOfRangeError` if the field goes beyond the width of the value.
+    pub fn extract_bits(&self, offset: u8, width: u8) -> Result<u128> {
+        let mask = field_mask(self, offset, width)?;
+        Ok(self.bits().checked_shr(offset as u32).unwrap_or(0) & mask)
+    }
+}
+
+// The mask of `width` bits from position 0, once the field is known to fit in
+// `value`.
+fn field_mask(value: &Bairiak, offset: u8, width: u8) -> Result<u128> {
+    let end = offset as u16 + width as u16;
+    if end > value.width() as u16 {
+        return Err(BairiakError::new(
+            BairiakErrorKind::ValueOutOfRangeError,
+            format!(
+                "Bits {}..{} don't fit in a u{}.",
+                offset,
+                end,
+                value.width()
+            ),
+        ));
+    }
+    Ok(u128::MAX.checked_shr(128 - width as u32).unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_low_byte() {
+        let mut status = Bairiak::U32(0xdead_beef);
+        status.assign_bits(0, 8, 0x42).unwrap();
+        assert_eq!(status, Bairiak::U32(0xdead_be42));
+        assert_eq!(status.extract_bits(0, 8).unwrap(), 0x42);
+        assert_eq!(status.extract_bits(8, 16).unwrap(), 0xadbe);
+
+        status.assign_bits(4, 12, 0).unwrap();
+        assert_eq!(status, Bairiak::U32(0xdead_0002));
+    }
+
+    #[test]
+    fn test_fields_spanning_the_top() {
+        let mut value = Bairiak::U16(0x00ff);
+        value.assign_bits(12, 4, 0xa).unwrap();
+        assert_eq!(value, Bairiak::U16(0xa0ff));
+        assert_eq!(value.extract_bits(0, 16).unwrap(), 0xa0ff);
+
+        let mut value = Bairiak::U128(0);
+        value.assign_bits(0, 128, u128::MAX).unwrap();
+        assert_eq!(value.extract_bits(120, 8).unwrap(), 0xff);
+        value.assign_bits(127, 1, 0).unwrap();
+        assert_eq!(value, Bairiak::U128(u128::MAX >> 1));
+
+        // An empty field, even at the very end, reads as and takes nothing.
+        assert_eq!(value.extract_bits(128, 0).unwrap(), 0);
+        value.assign_bits(128, 0, 0).unwrap();
+        assert_eq!(value, Bairiak::U128(u128::MAX >> 1));
+    }
+
+    #[test]
+    fn test_fields_out_of_range() {
+        let mut value = Bairiak::U8(0b1010);
+        let err = value.assign_bits(4, 8, 0).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        assert_eq!(err.message(), "Bits 4..12 don't fit in a u8.");
+        assert_eq!(
+            value.extract_bits(255, 255).unwrap_err().message(),
+            "Bits 255..510 don't fit in a u8."
+        );
+
+        let err = value.assign_bits(0, 4, 0x10).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        assert_eq!(err.message(), "0x10 doesn't fit in 4 bits.");
+        assert_eq!(
+            value.assign_bits(8, 0, 1).unwrap_err().message(),
+            "0x1 doesn't fit in 0 bits."
+        );
+        assert_eq!(value, Bairiak::U8(0b1010));
+    }
+}
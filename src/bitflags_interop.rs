@@ -0,0 +1,137 @@
+// Conversions between `Bairiak` and types generated by `bitflags!`.
+
+use std::mem;
+
+use bitflags::Flags;
+
+use crate::{Bairiak, BairiakError, BairiakErrorKind, Result};
+
+fn width_of<F: Flags>() -> u8 {
+    (mem::size_of::<F::Bits>() * 8) as u8
+}
+
+impl Bairiak {
+    // Keeps every bit of `flags`, including bits `F` doesn't define. The
+    // width is that of `F::Bits`.
+    pub fn from_flags_retain<F>(flags: &F) -> Bairiak
+    where
+        F: Flags,
+        F::Bits: Into<u128>,
+    {
+        Bairiak::with_width(width_of::<F>(), flags.bits().into())
+    }
+
+    // Like `from_flags_retain`, but clears the bits `F` doesn't define.
+    pub fn from_flags_truncate<F>(flags: &F) -> Bairiak
+    where
+        F: Flags,
+        F::Bits: Into<u128>,
+    {
+        let bits: u128 = flags.bits().into() & F::all().bits().into();
+        Bairiak::with_width(width_of::<F>(), bits)
+    }
+
+    // Keeps every set bit, including bits `F` doesn't define. Fails if a set
+    // bit doesn't fit in `F::Bits`.
+    pub fn to_flags_retain<F>(&self) -> Result<F>
+    where
+        F: Flags,
+        F::Bits: TryFrom<u128>,
+    {
+        match F::Bits::try_from(self.bits()) {
+            Ok(bits) => Ok(F::from_bits_retain(bits)),
+            Err(_) => Err(BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!(
+                    "Value {} does not fit in {} bits.",
+                    self.bits(),
+                    width_of::<F>()
+                ),
+            )),
+        }
+    }
+
+    // Clears the set bits `F` doesn't define, including bits beyond the width
+    // of `F::Bits`.
+    pub fn to_flags_truncate<F>(&self) -> F
+    where
+        F: Flags,
+        F::Bits: Into<u128> + TryFrom<u128>,
+    {
+        let bits = self.bits() & F::all().bits().into();
+        F::Bits::try_from(bits).map_or_else(|_| F::empty(), F::from_bits_truncate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    include!("../test_data/expected/bitflags_spec.rs");
+
+    bitflags::bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Mode: u16 {
+            const FAST = 1 << 0;
+            const SLOW = 1 << 9;
+        }
+    }
+
+    // Test for converting generated flags to `Bairiak` and back
+    #[test]
+    fn test_generated_round_trip() {
+        let flags = PermFlags::READ | PermFlags::DELETE;
+        let bairiak = Bairiak::from(flags);
+        assert_eq!(bairiak, Bairiak::U8(0b101));
+        assert!(bairiak.is_true(Perm::Read));
+        assert!(bairiak.is_true(Perm::Delete));
+        assert_eq!(PermFlags::from(bairiak).bits(), flags.bits());
+
+        let bairiak: Bairiak = [Perm::Write].into_iter().collect();
+        assert_eq!(PermFlags::from(bairiak), PermFlags::WRITE);
+        assert_eq!(Bairiak::from(PermFlags::all()).bits(), Perm::defined_mask());
+    }
+
+    // Test for undefined bits in generated conversions
+    #[test]
+    fn test_generated_undefined_bits() {
+        let flags = PermFlags::from_bits_retain(0b1001);
+        assert_eq!(Bairiak::from(flags).bits(), 0b1001);
+        assert_eq!(PermFlags::from(Bairiak::U8(0b1001)), PermFlags::READ);
+        assert_eq!(
+            PermFlags::from(Bairiak::U128(1 << 100 | 0b10)),
+            PermFlags::WRITE
+        );
+    }
+
+    // Test for the runtime conversions with a hand-written `bitflags!` type
+    #[test]
+    fn test_runtime_conversions() {
+        let flags = Mode::from_bits_retain(Mode::SLOW.bits() | 1 << 4);
+        assert_eq!(
+            Bairiak::from_flags_retain(&flags),
+            Bairiak::U16(1 << 9 | 1 << 4)
+        );
+        assert_eq!(Bairiak::from_flags_truncate(&flags), Bairiak::U16(1 << 9));
+
+        let bairiak = Bairiak::U32(1 << 9 | 1 << 4 | 1);
+        let retained: Mode = bairiak.to_flags_retain().unwrap();
+        assert_eq!(retained.bits(), 1 << 9 | 1 << 4 | 1);
+        let truncated: Mode = bairiak.to_flags_truncate();
+        assert_eq!(truncated, Mode::FAST | Mode::SLOW);
+    }
+
+    // Test for values that don't fit the flags type
+    #[test]
+    fn test_to_flags_retain_out_of_range() {
+        let result = Bairiak::U32(1 << 16).to_flags_retain::<Mode>();
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ValueOutOfRangeError
+        );
+        assert_eq!(
+            Bairiak::U32(1 << 16).to_flags_truncate::<Mode>(),
+            Mode::empty()
+        );
+    }
+}
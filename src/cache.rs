@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use crate::{fnv1a_64, parse_spec, read_spec_file, EnumSpec, Result};
+
+pub const DEFAULT_SPEC_CACHE_CAPACITY: usize = 64;
+
+// Parsed and validated specs, for tools generating from the same specs over
+// and over with different options. A spec is keyed by its path and a hash of
+// its content, so a changed file is parsed again while an unchanged one,
+// even touched or rewritten, is not. At most `capacity` specs are kept; the
+// least recently used are dropped first. Specs that fail to parse or validate
+// aren't kept, so their errors are reported every time.
+//
+// The cache can be shared by threads generating at the same time. Two threads
+// missing the same spec at once both parse it.
+#[derive(Debug)]
+pub struct SpecCache {
+    entries: Mutex<Entries>,
+    capacity: usize,
+    parses: AtomicUsize,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    specs: HashMap<PathBuf, Entry>,
+    // Incremented on every use, ordering the entries by their last use.
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    content_hash: u64,
+    spec: Arc<EnumSpec>,
+    last_used: u64,
+}
+
+impl Default for SpecCache {
+    fn default() -> Self {
+        SpecCache::new()
+    }
+}
+
+impl SpecCache {
+    pub fn new() -> Self {
+        SpecCache::with_capacity(DEFAULT_SPEC_CACHE_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        SpecCache {
+            entries: Mutex::new(Entries::default()),
+            capacity,
+            parses: AtomicUsize::new(0),
+        }
+    }
+
+    // Like `load_spec`, but parses the spec at `path` only if its content
+    // changed since it was last loaded through the cache.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<Arc<EnumSpec>> {
+        let path = path.as_ref();
+        self.spec(path, &read_spec_file(path)?)
+    }
+
+    // Forgets the spec at `path`, so that the next load parses it again.
+    // Returns whether it was cached.
+    pub fn invalidate(&self, path: impl AsRef<Path>) -> bool {
+        self.lock().specs.remove(path.as_ref()).is_some()
+    }
+
+    pub fn clear(&self) {
+        self.lock().specs.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().specs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The spec at `path`, whose content has just been read.
+    pub(crate) fn spec(&self, path: &Path, content: &str) -> Result<Arc<EnumSpec>> {
+        let content_hash = fnv1a_64(content.as_bytes());
+        {
+            let mut entries = self.lock();
+            entries.clock += 1;
+            let clock = entries.clock;
+            if let Some(entry) = entries.specs.get_mut(path) {
+                if entry.content_hash == content_hash {
+                    debug!("Using cached spec {}", path.display());
+                    entry.last_used = clock;
+                    return Ok(Arc::clone(&entry.spec));
+                }
+            }
+        }
+
+        // Parsed without holding the lock, so that threads loading other
+        // specs don't wait.
+        self.parses.fetch_add(1, Ordering::Relaxed);
+        let spec = parse_spec(content)?;
+        spec.validate()?;
+        let spec = Arc::new(spec);
+        if self.capacity == 0 {
+            return Ok(spec);
+        }
+
+        let mut entries = self.lock();
+        if !entries.specs.contains_key(path) && entries.specs.len() >= self.capacity {
+            let oldest = entries
+                .specs
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(path, _)| path.clone());
+            if let Some(oldest) = oldest {
+                entries.specs.remove(&oldest);
+            }
+        }
+        let last_used = entries.clock;
+        entries.specs.insert(
+            path.to_path_buf(),
+            Entry {
+                content_hash,
+                spec: Arc::clone(&spec),
+                last_used,
+            },
+        );
+        Ok(spec)
+    }
+
+    // The number of times a spec was parsed rather than found in the cache.
+    #[cfg(test)]
+    pub(crate) fn parses(&self) -> usize {
+        self.parses.load(Ordering::Relaxed)
+    }
+
+    // A thread panicking while holding the lock leaves the entries
+    // consistent, as each update is a single insert or remove.
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, thread};
+
+    use super::*;
+    use crate::{generate_bairiak_enums_cached, BairiakErrorKind, GenerateOptions};
+
+    fn spec_path(name: &str, content: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("bairiak_test_cache_{}.yaml", name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    const SPEC: &str = "enums:\n  - name: Perm\n    variants: [Read, Write]\n";
+
+    #[test]
+    fn test_spec_cache_send_sync() {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<SpecCache>();
+    }
+
+    #[test]
+    fn test_generate_cached() {
+        let spec_path = spec_path("generate", SPEC);
+        let output_path = env::temp_dir().join("bairiak_test_cache_generate.rs");
+        let _ = fs::remove_file(&output_path);
+        let cache = SpecCache::new();
+
+        let report = generate_bairiak_enums_cached(
+            &spec_path,
+            &output_path,
+            &GenerateOptions::default(),
+            &cache,
+        )
+        .unwrap();
+        assert!(report.rewritten);
+        assert_eq!(cache.parses(), 1);
+
+        // Other options, same spec.
+        let options = GenerateOptions::default().display(true);
+        generate_bairiak_enums_cached(&spec_path, &output_path, &options, &cache).unwrap();
+        assert_eq!(cache.parses(), 1);
+        assert!(fs::read_to_string(&output_path)
+            .unwrap()
+            .contains("impl core::fmt::Display for Perm"));
+
+        // Rewriting the same content keeps the cached spec, while new
+        // content is parsed again.
+        fs::write(&spec_path, SPEC).unwrap();
+        generate_bairiak_enums_cached(&spec_path, &output_path, &options, &cache).unwrap();
+        assert_eq!(cache.parses(), 1);
+        fs::write(&spec_path, SPEC.replace("Write]", "Write, Delete]")).unwrap();
+        generate_bairiak_enums_cached(&spec_path, &output_path, &options, &cache).unwrap();
+        assert_eq!(cache.parses(), 2);
+        assert!(fs::read_to_string(&output_path)
+            .unwrap()
+            .contains("Delete = 2"));
+
+        assert!(cache.invalidate(&spec_path));
+        assert!(!cache.invalidate(&spec_path));
+        cache.load(&spec_path).unwrap();
+        assert_eq!(cache.parses(), 3);
+
+        fs::remove_file(spec_path).unwrap();
+        fs::remove_file(output_path).unwrap();
+    }
+
+    #[test]
+    fn test_spec_cache_keeps_no_invalid_spec() {
+        let spec_path = spec_path("invalid", "enums:\n  - name: perm\n    variants: [Read]\n");
+        let cache = SpecCache::new();
+        for parses in 1..=2 {
+            let err = cache.load(&spec_path).unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+            assert_eq!(cache.parses(), parses);
+        }
+        assert!(cache.is_empty());
+        fs::remove_file(spec_path).unwrap();
+    }
+
+    #[test]
+    fn test_spec_cache_capacity() {
+        let cache = SpecCache::with_capacity(2);
+        let (a, b, c) = (
+            Path::new("a.yaml"),
+            Path::new("b.yaml"),
+            Path::new("c.yaml"),
+        );
+        cache.spec(a, SPEC).unwrap();
+        cache.spec(b, SPEC).unwrap();
+        // `a` is now used more recently than `b`, which is dropped for `c`.
+        cache.spec(a, SPEC).unwrap();
+        cache.spec(c, SPEC).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.parses(), 3);
+        cache.spec(a, SPEC).unwrap();
+        assert_eq!(cache.parses(), 3);
+        cache.spec(b, SPEC).unwrap();
+        assert_eq!(cache.parses(), 4);
+
+        let cache = SpecCache::with_capacity(0);
+        cache.spec(a, SPEC).unwrap();
+        cache.spec(a, SPEC).unwrap();
+        assert_eq!(cache.parses(), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_spec_cache_across_threads() {
+        let cache = SpecCache::new();
+        thread::scope(|scope| {
+            for i in 0..4 {
+                let cache = &cache;
+                scope.spawn(move || {
+                    let path = PathBuf::from(format!("{}.yaml", i % 2));
+                    for _ in 0..10 {
+                        assert_eq!(cache.spec(&path, SPEC).unwrap().enums.len(), 1);
+                    }
+                });
+            }
+        });
+        assert_eq!(cache.len(), 2);
+        // Each spec is parsed at most once per thread.
+        assert!(cache.parses() <= 4);
+    }
+}
@@ -0,0 +1,134 @@
+// A compact binary encoding for tight frames: one byte with the width in bits
+// (8, 16, 32, 64 or 128) followed by exactly width / 8 little-endian bytes.
+
+use crate::{Bairiak, BairiakError, BairiakErrorKind, Result};
+
+fn too_short(needed: usize, len: usize) -> BairiakError {
+    BairiakError::new(
+        BairiakErrorKind::BufferTooShortError,
+        format!("Needed {} bytes but the buffer has {}.", needed, len),
+    )
+}
+
+impl Bairiak {
+    // Returns the number of bytes written.
+    pub fn encode_compact(&self, buf: &mut [u8]) -> Result<usize> {
+        let len = 1 + self.width() as usize / 8;
+        let available = buf.len();
+        let Some((tag, bytes)) = buf.get_mut(..len).and_then(<[u8]>::split_first_mut) else {
+            return Err(too_short(len, available));
+        };
+
+        *tag = self.width();
+        for (byte, bits_byte) in bytes.iter_mut().zip(self.bits().to_le_bytes()) {
+            *byte = bits_byte;
+        }
+        Ok(len)
+    }
+
+    // Returns the value and the number of bytes read.
+    pub fn decode_compact(buf: &[u8]) -> Result<(Bairiak, usize)> {
+        let Some(&width) = buf.first() else {
+            return Err(too_short(1, 0));
+        };
+        if !matches!(width, 8 | 16 | 32 | 64 | 128) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::UnknownWidthError,
+                format!("Unknown width tag {}.", width),
+            ));
+        }
+
+        let len = 1 + width as usize / 8;
+        let Some(bytes) = buf.get(1..len) else {
+            return Err(too_short(len, buf.len()));
+        };
+
+        let mut bits = [0u8; 16];
+        for (bits_byte, &byte) in bits.iter_mut().zip(bytes) {
+            *bits_byte = byte;
+        }
+        Ok((Bairiak::with_width(width, u128::from_le_bytes(bits)), len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [Bairiak; 5] = [
+        Bairiak::U8(0xa5),
+        Bairiak::U16(0x0201),
+        Bairiak::U32(u32::MAX),
+        Bairiak::U64(1 << 63 | 1),
+        Bairiak::U128(1 << 127 | 0xff),
+    ];
+
+    #[test]
+    fn test_compact_round_trip() {
+        for bairiak in VALUES {
+            let mut buf = [0u8; 32];
+            let written = bairiak.encode_compact(&mut buf).unwrap();
+            assert_eq!(written, 1 + bairiak.width() as usize / 8);
+            assert_eq!(Bairiak::decode_compact(&buf), Ok((bairiak, written)));
+        }
+    }
+
+    #[test]
+    fn test_compact_layout() {
+        let mut buf = [0u8; 3];
+        assert_eq!(Bairiak::U16(0x0201).encode_compact(&mut buf), Ok(3));
+        assert_eq!(buf, [16, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_compact_short_buffers() {
+        for bairiak in VALUES {
+            let mut buf = [0u8; 17];
+            let written = bairiak.encode_compact(&mut buf).unwrap();
+            for len in 0..written {
+                assert_eq!(
+                    bairiak
+                        .encode_compact(&mut [0u8; 17][..len])
+                        .unwrap_err()
+                        .kind(),
+                    BairiakErrorKind::BufferTooShortError
+                );
+                assert_eq!(
+                    Bairiak::decode_compact(&buf[..len]).unwrap_err().kind(),
+                    BairiakErrorKind::BufferTooShortError
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_compact_garbage() {
+        let mut seed = 0x2545f4914f6cdd1du64;
+        for _ in 0..1000 {
+            let mut buf = [0u8; 17];
+            for byte in &mut buf {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                *byte = seed as u8;
+            }
+            let len = seed as usize % buf.len();
+
+            match Bairiak::decode_compact(&buf[..len]) {
+                Ok((bairiak, read)) => {
+                    assert!(read <= len);
+                    assert_eq!(bairiak.width(), buf[0]);
+                }
+                Err(err) => assert!(matches!(
+                    err.kind(),
+                    BairiakErrorKind::BufferTooShortError | BairiakErrorKind::UnknownWidthError
+                )),
+            }
+        }
+
+        assert_eq!(
+            Bairiak::decode_compact(&[12, 0]).unwrap_err().kind(),
+            BairiakErrorKind::UnknownWidthError
+        );
+    }
+}
@@ -0,0 +1,93 @@
+// Conversions between `Bairiak` and collections of flags or primitives. The
+// collections go through `generate_bairiak`, so they build the same value as
+// calling it, including its debug assertion on positions out of range.
+
+use std::collections::HashSet;
+
+use crate::{generate_bairiak, Bairiak, BairiakEnum};
+
+/// Builds the value with the given flags set, like `generate_bairiak`.
+///
+/// ```
+/// # use bairiak::{Bairiak, BairiakEnum};
+/// # #[derive(Clone, Copy)]
+/// # enum Flag { A, B }
+/// # impl BairiakEnum for Flag {
+/// #     fn get_zero_bairiak() -> Bairiak { Bairiak::U8(0) }
+/// #     fn to_u8(self) -> u8 { self as u8 }
+/// #     fn from_u8(position: u8) -> Option<Self> {
+/// #         [Flag::A, Flag::B].get(position as usize).copied()
+/// #     }
+/// #     fn name(&self) -> &'static str { ["A", "B"][*self as usize] }
+/// # }
+/// let b: Bairiak = vec![Flag::A, Flag::B].into();
+/// assert_eq!(b, Bairiak::U8(0b11));
+/// ```
+impl<B: BairiakEnum> From<Vec<B>> for Bairiak {
+    fn from(flags: Vec<B>) -> Self {
+        generate_bairiak(flags)
+    }
+}
+
+impl<B: BairiakEnum> From<&[B]> for Bairiak {
+    fn from(flags: &[B]) -> Self {
+        generate_bairiak(flags.iter().copied())
+    }
+}
+
+impl<B: BairiakEnum, S> From<HashSet<B, S>> for Bairiak {
+    fn from(flags: HashSet<B, S>) -> Self {
+        generate_bairiak(flags)
+    }
+}
+
+/// The bits of the value, which every width converts to without loss, unlike
+/// narrower primitives.
+///
+/// ```
+/// use bairiak::Bairiak;
+///
+/// assert_eq!(u128::from(Bairiak::U16(0x0201)), 0x0201);
+/// ```
+impl From<Bairiak> for u128 {
+    fn from(bairiak: Bairiak) -> Self {
+        bairiak.bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::fixtures::TestEnum;
+
+    #[test]
+    fn test_from_vec() {
+        let flags = vec![TestEnum::Flag9, TestEnum::Flag0, TestEnum::Flag9];
+        let bairiak: Bairiak = flags.clone().into();
+        assert_eq!(bairiak, Bairiak::U16(1 << 9 | 0b1));
+        assert_eq!(bairiak, generate_bairiak(flags));
+        assert_eq!(Bairiak::from(Vec::<TestEnum>::new()), Bairiak::U16(0));
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let flags = [TestEnum::Flag1, TestEnum::Flag9];
+        assert_eq!(Bairiak::from(&flags[..]), Bairiak::U16(1 << 9 | 0b10));
+        assert_eq!(Bairiak::from(&flags[..0]), Bairiak::U16(0));
+    }
+
+    #[test]
+    fn test_from_hash_set() {
+        let flags: HashSet<TestEnum> = [TestEnum::Flag0, TestEnum::Flag1, TestEnum::Flag9].into();
+        assert_eq!(Bairiak::from(flags), Bairiak::U16(1 << 9 | 0b11));
+        assert_eq!(Bairiak::from(HashSet::<TestEnum>::new()), Bairiak::U16(0));
+    }
+
+    #[test]
+    fn test_into_u128() {
+        assert_eq!(u128::from(Bairiak::U8(0xa5)), 0xa5);
+        assert_eq!(u128::from(Bairiak::U128(u128::MAX)), u128::MAX);
+        let bits: u128 = Bairiak::from(vec![TestEnum::Flag9]).into();
+        assert_eq!(bits, 1 << 9);
+    }
+}
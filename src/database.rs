@@ -0,0 +1,222 @@
+use std::marker::PhantomData;
+
+use crate::{Bairiak, BairiakEnum, BairiakError, BairiakErrorKind, Result};
+
+impl Bairiak {
+    // Fails if the value doesn't fit in a non-negative `i64`, which is how it
+    // is stored in a `BIGINT` column.
+    pub fn to_i64(&self) -> Result<i64> {
+        i64::try_from(self.bits()).map_err(|_| {
+            BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!("{:#x} does not fit in a BIGINT column.", self.bits()),
+            )
+        })
+    }
+
+    // The inverse of `to_i64`, failing if `value` is negative or sets bits
+    // beyond the width of `B`.
+    pub fn from_i64_for<B: BairiakEnum>(value: i64) -> Result<Bairiak> {
+        let zero = B::get_zero_bairiak();
+        if value < 0 || zero.width() < 64 && value >> zero.width() != 0 {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!(
+                    "{} is not a valid {}-bit Bairiak value.",
+                    value,
+                    zero.width()
+                ),
+            ));
+        }
+        Ok(Bairiak::with_width(zero.width(), value as u128))
+    }
+}
+
+// A `Bairiak` of the flags of `B`, for a column that must read back at the
+// width of `B`: it decodes through `from_i64_for::<B>`, so a stored value that
+// is negative or sets bits beyond the width fails rather than becoming a
+// `Bairiak::U64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BairiakColumn<B> {
+    value: Bairiak,
+    flags: PhantomData<fn() -> B>,
+}
+
+impl<B: BairiakEnum> BairiakColumn<B> {
+    pub fn new(value: Bairiak) -> Self {
+        BairiakColumn {
+            value,
+            flags: PhantomData,
+        }
+    }
+
+    pub fn from_i64(value: i64) -> Result<Self> {
+        Bairiak::from_i64_for::<B>(value).map(BairiakColumn::new)
+    }
+
+    pub fn value(&self) -> Bairiak {
+        self.value
+    }
+}
+
+impl<B> From<BairiakColumn<B>> for Bairiak {
+    fn from(column: BairiakColumn<B>) -> Bairiak {
+        column.value
+    }
+}
+
+// A `Bairiak` is stored as a `BIGINT` and decoded as `Bairiak::U64`, and a
+// `BairiakColumn<B>` at the width of `B`; `INTEGER` columns are accepted too.
+// Negative values fail to decode.
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef},
+        Decode, Encode, Postgres, Type,
+    };
+
+    use super::BairiakColumn;
+    use crate::{Bairiak, BairiakEnum};
+
+    impl Type<Postgres> for Bairiak {
+        fn type_info() -> PgTypeInfo {
+            <i64 as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <i64 as Type<Postgres>>::compatible(ty) || <i32 as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl Encode<'_, Postgres> for Bairiak {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <i64 as Encode<Postgres>>::encode_by_ref(&self.to_i64()?, buf)
+        }
+    }
+
+    impl Decode<'_, Postgres> for Bairiak {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            let value = <i64 as Decode<Postgres>>::decode(value)?;
+            if value < 0 {
+                return Err(format!("{} is not a valid Bairiak value.", value).into());
+            }
+            Ok(Bairiak::U64(value as u64))
+        }
+    }
+
+    impl<B> Type<Postgres> for BairiakColumn<B> {
+        fn type_info() -> PgTypeInfo {
+            <Bairiak as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <Bairiak as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl<B> Encode<'_, Postgres> for BairiakColumn<B> {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            self.value.encode_by_ref(buf)
+        }
+    }
+
+    impl<B: BairiakEnum> Decode<'_, Postgres> for BairiakColumn<B> {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            let value = <i64 as Decode<Postgres>>::decode(value)?;
+            Ok(BairiakColumn::from_i64(value)?)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_postgres_type() {
+            assert_eq!(Bairiak::type_info(), <i64 as Type<Postgres>>::type_info());
+            assert!(Bairiak::compatible(&<i32 as Type<Postgres>>::type_info()));
+            assert!(!Bairiak::compatible(
+                &<String as Type<Postgres>>::type_info()
+            ));
+        }
+
+        #[test]
+        fn test_postgres_encode() {
+            let mut buf = PgArgumentBuffer::default();
+            let result = Bairiak::U8(5).encode_by_ref(&mut buf);
+            assert!(matches!(result, Ok(IsNull::No)));
+            assert_eq!(&buf[..], 5i64.to_be_bytes());
+
+            let mut buf = PgArgumentBuffer::default();
+            assert!(Bairiak::U64(u64::MAX).encode_by_ref(&mut buf).is_err());
+        }
+
+        #[test]
+        fn test_postgres_column() {
+            type Column = BairiakColumn<crate::test_support::fixtures::TestEnum>;
+            assert_eq!(Column::type_info(), Bairiak::type_info());
+            let mut buf = PgArgumentBuffer::default();
+            let result = Column::new(Bairiak::U16(1 << 9)).encode_by_ref(&mut buf);
+            assert!(matches!(result, Ok(IsNull::No)));
+            assert_eq!(&buf[..], 512i64.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::fixtures::TestEnum;
+
+    #[test]
+    fn test_to_i64() {
+        assert_eq!(Bairiak::U16(1 << 9 | 1).to_i64(), Ok(513));
+        assert_eq!(Bairiak::U64(i64::MAX as u64).to_i64(), Ok(i64::MAX));
+        assert_eq!(
+            Bairiak::U64(1 << 63).to_i64().unwrap_err().kind(),
+            BairiakErrorKind::ValueOutOfRangeError
+        );
+        assert!(Bairiak::U128(1 << 100).to_i64().is_err());
+    }
+
+    #[test]
+    fn test_from_i64_for() {
+        assert_eq!(
+            Bairiak::from_i64_for::<TestEnum>(513),
+            Ok(Bairiak::U16(513))
+        );
+        assert_eq!(Bairiak::from_i64_for::<TestEnum>(0), Ok(Bairiak::U16(0)));
+        assert_eq!(
+            Bairiak::from_i64_for::<TestEnum>(-1).unwrap_err().kind(),
+            BairiakErrorKind::ValueOutOfRangeError
+        );
+        assert_eq!(
+            Bairiak::from_i64_for::<TestEnum>(1 << 16)
+                .unwrap_err()
+                .kind(),
+            BairiakErrorKind::ValueOutOfRangeError
+        );
+    }
+
+    #[test]
+    fn test_i64_round_trip() {
+        let bairiak = Bairiak::U16(0b10_0000_0001);
+        let stored = bairiak.to_i64().unwrap();
+        assert_eq!(Bairiak::from_i64_for::<TestEnum>(stored), Ok(bairiak));
+    }
+
+    #[test]
+    fn test_column_keeps_width() {
+        let column = BairiakColumn::<TestEnum>::from_i64(513).unwrap();
+        assert_eq!(column.value(), Bairiak::U16(513));
+        let stored = Bairiak::U16(513).to_i64().unwrap();
+        assert_eq!(
+            Bairiak::from(BairiakColumn::<TestEnum>::from_i64(stored).unwrap()),
+            Bairiak::U16(513)
+        );
+        assert!(BairiakColumn::<TestEnum>::from_i64(1 << 16).is_err());
+        assert!(BairiakColumn::<TestEnum>::from_i64(-1).is_err());
+    }
+}
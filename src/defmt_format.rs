@@ -0,0 +1,31 @@
+// Compact `defmt` output for embedded logging: the width and the bits in hex,
+// as `U16(0x20b)`. `defmt` is `no_std`, so this adds nothing that needs std.
+
+use crate::Bairiak;
+
+impl defmt::Format for Bairiak {
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Bairiak::U8(value) => defmt::write!(f, "U8({=u8:#x})", value),
+            Bairiak::U16(value) => defmt::write!(f, "U16({=u16:#x})", value),
+            Bairiak::U32(value) => defmt::write!(f, "U32({=u32:#x})", value),
+            Bairiak::U64(value) => defmt::write!(f, "U64({=u64:#x})", value),
+            Bairiak::U128(value) => defmt::write!(f, "U128({=u128:#x})", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Formatting needs a defmt logger, which host tests don't have, so this
+    // only checks that the impl exists.
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn test_bairiak_implements_format() {
+        assert_format::<Bairiak>();
+        assert_format::<[Bairiak; 2]>();
+    }
+}
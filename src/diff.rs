@@ -0,0 +1,326 @@
+use std::{collections::HashMap, fmt, path::Path};
+
+use serde::Serialize;
+
+use crate::{load_spec, Enum, EnumSpec, Result};
+
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub struct SpecFlag {
+    pub name: String,
+    pub position: u16,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Rename {
+    pub position: u16,
+    pub old_name: String,
+    pub new_name: String,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct PositionChange {
+    pub name: String,
+    pub old_position: u16,
+    pub new_position: u16,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct EnumDiff {
+    pub name: String,
+    pub additions: Vec<SpecFlag>,
+    pub removals: Vec<SpecFlag>,
+    pub renames: Vec<Rename>,
+    pub position_changes: Vec<PositionChange>,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct SpecDiff {
+    pub enums: Vec<EnumDiff>,
+}
+
+impl EnumDiff {
+    pub fn is_empty(&self) -> bool {
+        self.additions.is_empty()
+            && self.removals.is_empty()
+            && self.renames.is_empty()
+            && self.position_changes.is_empty()
+    }
+
+    // Removed flags and repositioned flags change the meaning of persisted values.
+    pub fn has_breaking_changes(&self) -> bool {
+        !self.removals.is_empty() || !self.position_changes.is_empty()
+    }
+}
+
+impl SpecDiff {
+    pub fn is_empty(&self) -> bool {
+        self.enums.iter().all(EnumDiff::is_empty)
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.enums.iter().any(EnumDiff::has_breaking_changes)
+    }
+}
+
+impl fmt::Display for SpecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+
+        for e in &self.enums {
+            writeln!(f, "{}:", e.name)?;
+            for flag in &e.additions {
+                writeln!(f, "  + {} (position {})", flag.name, flag.position)?;
+            }
+            for flag in &e.removals {
+                writeln!(
+                    f,
+                    "  - {} (position {}) [breaking]",
+                    flag.name, flag.position
+                )?;
+            }
+            for rename in &e.renames {
+                writeln!(
+                    f,
+                    "  ~ {} -> {} (position {})",
+                    rename.old_name, rename.new_name, rename.position
+                )?;
+            }
+            for change in &e.position_changes {
+                writeln!(
+                    f,
+                    "  ! {} moved from position {} to {} [breaking]",
+                    change.name, change.old_position, change.new_position
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn positions(e: &Enum) -> Vec<SpecFlag> {
+    e.positioned_variants()
+        .map(|(position, v)| SpecFlag {
+            name: v.to_string(),
+            position: position as u16,
+        })
+        .collect()
+}
+
+fn diff_enum(name: &str, old: Vec<SpecFlag>, new: Vec<SpecFlag>) -> EnumDiff {
+    let old_positions: HashMap<&str, u16> =
+        old.iter().map(|f| (f.name.as_str(), f.position)).collect();
+    let new_positions: HashMap<&str, u16> =
+        new.iter().map(|f| (f.name.as_str(), f.position)).collect();
+
+    let mut diff = EnumDiff {
+        name: name.to_string(),
+        ..Default::default()
+    };
+
+    for flag in &new {
+        if let Some(&old_position) = old_positions.get(flag.name.as_str()) {
+            if old_position != flag.position {
+                diff.position_changes.push(PositionChange {
+                    name: flag.name.clone(),
+                    old_position,
+                    new_position: flag.position,
+                });
+            }
+        }
+    }
+
+    let mut added: Vec<&SpecFlag> = new
+        .iter()
+        .filter(|f| !old_positions.contains_key(f.name.as_str()))
+        .collect();
+
+    for flag in old
+        .iter()
+        .filter(|f| !new_positions.contains_key(f.name.as_str()))
+    {
+        match added.iter().position(|a| a.position == flag.position) {
+            Some(i) => {
+                let renamed = added.remove(i);
+                diff.renames.push(Rename {
+                    position: flag.position,
+                    old_name: flag.name.clone(),
+                    new_name: renamed.name.clone(),
+                });
+            }
+            None => diff.removals.push(flag.clone()),
+        }
+    }
+
+    diff.additions = added.into_iter().cloned().collect();
+    diff
+}
+
+pub(crate) fn diff_enum_specs(old: &EnumSpec, new: &EnumSpec) -> SpecDiff {
+    let mut enums = Vec::new();
+
+    for old_enum in &old.enums {
+        let new_flags = new
+            .enums
+            .iter()
+            .find(|e| e.name == old_enum.name)
+            .map(positions)
+            .unwrap_or_default();
+        enums.push(diff_enum(&old_enum.name, positions(old_enum), new_flags));
+    }
+
+    for new_enum in &new.enums {
+        if !old.enums.iter().any(|e| e.name == new_enum.name) {
+            enums.push(diff_enum(&new_enum.name, Vec::new(), positions(new_enum)));
+        }
+    }
+
+    enums.retain(|e| !e.is_empty());
+    SpecDiff { enums }
+}
+
+pub fn diff_specs(
+    old_spec_path: impl AsRef<Path>,
+    new_spec_path: impl AsRef<Path>,
+) -> Result<SpecDiff> {
+    let old = load_spec(old_spec_path)?;
+    let new = load_spec(new_spec_path)?;
+    // A position out of range would otherwise be compared cut down to a byte.
+    old.validate()?;
+    new.validate()?;
+    Ok(diff_enum_specs(&old, &new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Variant;
+
+    fn spec(variants: &[&str]) -> EnumSpec {
+        EnumSpec {
+            enums: vec![Enum {
+                name: String::from("Perm"),
+                variants: variants.iter().map(|&v| Variant::from(v)).collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn flag(name: &str, position: u16) -> SpecFlag {
+        SpecFlag {
+            name: String::from(name),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_diff_no_changes() {
+        let diff = diff_enum_specs(&spec(&["Read", "Write"]), &spec(&["Read", "Write"]));
+        assert!(diff.is_empty());
+        assert!(!diff.has_breaking_changes());
+        assert_eq!(diff.to_string(), "No changes.\n");
+    }
+
+    #[test]
+    fn test_diff_addition() {
+        let diff = diff_enum_specs(&spec(&["Read"]), &spec(&["Read", "Write"]));
+        assert_eq!(diff.enums[0].additions, vec![flag("Write", 1)]);
+        assert!(!diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn test_diff_removal() {
+        let diff = diff_enum_specs(&spec(&["Read", "Write"]), &spec(&["Read"]));
+        assert_eq!(diff.enums[0].removals, vec![flag("Write", 1)]);
+        assert!(diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn test_diff_rename() {
+        let diff = diff_enum_specs(&spec(&["Read", "Write"]), &spec(&["Read", "Modify"]));
+        assert_eq!(
+            diff.enums[0].renames,
+            vec![Rename {
+                position: 1,
+                old_name: String::from("Write"),
+                new_name: String::from("Modify"),
+            }]
+        );
+        assert!(diff.enums[0].additions.is_empty());
+        assert!(diff.enums[0].removals.is_empty());
+        assert!(!diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn test_diff_position_change() {
+        let diff = diff_enum_specs(&spec(&["Read", "Write"]), &spec(&["Write", "Read"]));
+        assert_eq!(
+            diff.enums[0].position_changes,
+            vec![
+                PositionChange {
+                    name: String::from("Write"),
+                    old_position: 1,
+                    new_position: 0,
+                },
+                PositionChange {
+                    name: String::from("Read"),
+                    old_position: 0,
+                    new_position: 1,
+                },
+            ]
+        );
+        assert!(diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn test_diff_added_and_removed_enums() {
+        let old = spec(&["Read"]);
+        let new = EnumSpec {
+            enums: vec![Enum {
+                name: String::from("Mode"),
+                variants: vec![Variant::from("Fast")],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let diff = diff_enum_specs(&old, &new);
+        assert_eq!(diff.enums[0].name, "Perm");
+        assert_eq!(diff.enums[0].removals, vec![flag("Read", 0)]);
+        assert_eq!(diff.enums[1].name, "Mode");
+        assert_eq!(diff.enums[1].additions, vec![flag("Fast", 0)]);
+    }
+
+    #[test]
+    fn test_diff_specs_from_files() {
+        let diff = diff_specs(
+            "test_data/diff_old_spec.yaml",
+            "test_data/diff_new_spec.yaml",
+        )
+        .unwrap();
+        assert!(diff.has_breaking_changes());
+        assert_eq!(
+            diff.to_string(),
+            "Perm:
+  - Delete (position 2) [breaking]
+  ~ Write -> Modify (position 1)
+Mode:
+  + Turbo (position 2)
+  ! Slow moved from position 1 to 0 [breaking]
+  ! Fast moved from position 0 to 1 [breaking]
+"
+        );
+    }
+
+    #[test]
+    fn test_diff_specs_validates_positions() {
+        // Cut down to a byte, positions 256 and 257 would be 0 and 1.
+        let out_of_range = "test_data/diff_out_of_range_spec.yaml";
+        let err = diff_specs("test_data/diff_old_spec.yaml", out_of_range).unwrap_err();
+        assert!(err.is_spec());
+        assert!(diff_specs(out_of_range, "test_data/diff_old_spec.yaml").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use serde::Deserialize;
+use serde_yaml::Value;
+
+use crate::{BairiakError, BairiakErrorKind, EnumSpec, Result};
+
+// Parses a spec of one or more YAML documents separated by `---`, such as a
+// file with a document per team. The enums and packed layouts of every
+// document are concatenated in order, and empty documents are skipped. Only
+// the first document may give `settings` and `version`, which apply to the
+// whole spec, and an enum name may only be defined once across documents.
+//
+// Anchors and aliases are resolved by serde_yaml itself, but merge keys
+// (`<<: *base`) are not, so a spec using them goes through `Value`, at the
+// cost of the line numbers in its error messages.
+pub(crate) fn parse_documents(yaml_content: &str) -> Result<EnumSpec> {
+    let merge_keys = yaml_content.contains("<<");
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(yaml_content) {
+        let spec = if merge_keys {
+            let mut value = Value::deserialize(document)?;
+            value.apply_merge()?;
+            Option::<EnumSpec>::deserialize(value)?
+        } else {
+            Option::<EnumSpec>::deserialize(document)?
+        };
+        documents.extend(spec);
+    }
+    merge_documents(documents)
+}
+
+fn merge_documents(documents: Vec<EnumSpec>) -> Result<EnumSpec> {
+    let mut documents = documents.into_iter().enumerate();
+    let Some((_, mut spec)) = documents.next() else {
+        return Ok(EnumSpec::default());
+    };
+    // The document defining each enum, counted from 1 as in the messages.
+    let mut defined_in: Vec<usize> = vec![1; spec.enums.len()];
+    for (i, document) in documents {
+        let number = i + 1;
+        let given = if document.version.is_some() {
+            Some("version")
+        } else if !document.settings.is_default() {
+            Some("settings")
+        } else {
+            None
+        };
+        if let Some(given) = given {
+            return Err(invalid_document(format!(
+                "Document {} of the spec gives `{}`, which only the first document may give.",
+                number, given
+            )));
+        }
+        for e in document.enums {
+            if let Some((_, first)) = spec
+                .enums
+                .iter()
+                .zip(&defined_in)
+                .find(|(other, _)| other.name == e.name)
+            {
+                return Err(invalid_document(format!(
+                    "Duplicate enum `{}`: defined in documents {} and {} of the spec.",
+                    e.name, first, number
+                )));
+            }
+            spec.enums.push(e);
+            defined_in.push(number);
+        }
+        spec.packed.extend(document.packed);
+    }
+    Ok(spec)
+}
+
+fn invalid_document(message: String) -> BairiakError {
+    BairiakError::new(BairiakErrorKind::ParseBairiakEnumsError, message).logged()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_bairiak_enums_from_str, parse_spec, BairiakErrorKind};
+
+    fn names(yaml: &str) -> Vec<String> {
+        parse_spec(yaml)
+            .unwrap()
+            .enums
+            .iter()
+            .map(|e| e.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_documents_are_concatenated() {
+        let yaml = "enums: [{name: Perm, variants: [Read]}]\n---\nenums: [{name: Mode, variants: [Fast]}]\n";
+        assert_eq!(names(yaml), ["Perm", "Mode"]);
+        // A leading separator and empty documents add nothing.
+        let yaml = format!("---\n{}---\n# Nothing yet.\n", yaml);
+        assert_eq!(names(&yaml), ["Perm", "Mode"]);
+    }
+
+    #[test]
+    fn test_documents_with_duplicate_enum() {
+        let yaml = "enums: [{name: Perm, variants: [Read]}, {name: Mode, variants: [Fast]}]\n---\nenums: [{name: Mode, variants: [Slow]}]\n";
+        let err = parse_spec(yaml).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Duplicate enum `Mode`: defined in documents 1 and 2 of the spec."
+        );
+    }
+
+    #[test]
+    fn test_only_first_document_gives_settings() {
+        let first =
+            "settings: {visibility: pub}\nversion: v1\nenums: [{name: Perm, variants: [Read]}]\n";
+        assert!(parse_spec(&format!("{}---\nenums: []\n", first)).is_ok());
+
+        let err = parse_spec(&format!("{}---\nversion: v2\nenums: []\n", first)).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Document 2 of the spec gives `version`, which only the first document may give."
+        );
+        let err = parse_spec(&format!(
+            "{}---\nsettings: {{derives: [Hash]}}\nenums: []\n",
+            first
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Document 2 of the spec gives `settings`, which only the first document may give."
+        );
+    }
+
+    #[test]
+    fn test_merge_keys() {
+        let yaml = "common: &common\n  bits: 16\n  variants: [Read, Write]\nenums:\n  - <<: *common\n    name: Perm\n  - <<: *common\n    name: Role\n    variants: [Admin]\n";
+        let spec = parse_spec(yaml).unwrap();
+        assert_eq!(spec.enums[0].width().unwrap(), 16);
+        assert_eq!(spec.enums[0].variant_count(), 2);
+        // Keys of the mapping itself win over the merged ones.
+        assert_eq!(spec.enums[1].width().unwrap(), 16);
+        assert_eq!(spec.enums[1].variant_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_keys_errors() {
+        let yaml = "common: &common\n  bits: sixteen\nenums:\n  - <<: *common\n    name: Perm\n    variants: [Read]\n";
+        let err = generate_bairiak_enums_from_str(yaml).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::DeserializeYamlError);
+        let err =
+            parse_spec("enums:\n  - <<: [1]\n    name: Perm\n    variants: [Read]\n").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::DeserializeYamlError);
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+
+use crate::{EnumSpec, Result, SpecFlag};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Explanation {
+    pub enum_name: String,
+    pub value: u128,
+    pub width: u8,
+    pub set: Vec<SpecFlag>,
+    pub unset: Vec<SpecFlag>,
+    pub unknown_bits: Vec<u8>,
+}
+
+impl Explanation {
+    pub fn exceeds_width(&self) -> bool {
+        self.width < 128 && self.value >> self.width != 0
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} = {} (0b{:b}), width {}",
+            self.enum_name, self.value, self.value, self.width
+        )?;
+
+        writeln!(f, "  set:")?;
+        for flag in &self.set {
+            writeln!(f, "    {} (position {})", flag.name, flag.position)?;
+        }
+
+        writeln!(f, "  unset:")?;
+        for flag in &self.unset {
+            writeln!(f, "    {} (position {})", flag.name, flag.position)?;
+        }
+
+        if !self.unknown_bits.is_empty() {
+            let bits: Vec<String> = self.unknown_bits.iter().map(|b| b.to_string()).collect();
+            writeln!(f, "  unknown set bits: {}", bits.join(", "))?;
+        }
+
+        if self.exceeds_width() {
+            writeln!(
+                f,
+                "  value exceeds the {}-bit width of {}",
+                self.width, self.enum_name
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn explain_value(spec: &EnumSpec, enum_name: &str, value: u128) -> Result<Explanation> {
+    let e = spec.find_enum(enum_name)?;
+
+    let mut explanation = Explanation {
+        enum_name: e.name.clone(),
+        value,
+        width: e.width()?,
+        set: Vec::new(),
+        unset: Vec::new(),
+        unknown_bits: Vec::new(),
+    };
+
+    for (position, v) in e.positioned_variants() {
+        let flag = SpecFlag {
+            name: v.to_string(),
+            position: position as u16,
+        };
+        if value & 1u128 << position == 0 {
+            explanation.unset.push(flag);
+        } else {
+            explanation.set.push(flag);
+        }
+    }
+
+    let unknown = value & !e.all_mask();
+    explanation.unknown_bits = (0..128)
+        .filter(|&i| unknown & 1u128 << i != 0)
+        .map(|i| i as u8)
+        .collect();
+
+    Ok(explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BairiakErrorKind, Enum, Variant};
+
+    fn spec() -> EnumSpec {
+        EnumSpec {
+            enums: vec![Enum {
+                name: String::from("Perm"),
+                variants: vec![
+                    Variant::from("Read"),
+                    Variant::from("Write"),
+                    Variant::from("Delete"),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn flag(name: &str, position: u16) -> SpecFlag {
+        SpecFlag {
+            name: String::from(name),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_explain_value_with_bit_in_gap() {
+        let mut spec = spec();
+        spec.enums[0].variants[2] = Variant::Detailed {
+            name: String::from("Delete"),
+            position: Some(4),
+            aliases: vec![],
+            doc: None,
+            deprecated: None,
+            status: None,
+            requires: vec![],
+            default: false,
+            id: None,
+        };
+
+        let explanation = explain_value(&spec, "Perm", 0b10100).unwrap();
+        assert_eq!(explanation.set, vec![flag("Delete", 4)]);
+        assert_eq!(explanation.unknown_bits, vec![2]);
+    }
+
+    #[test]
+    fn test_explain_value_with_unknown_bit() {
+        let explanation = explain_value(&spec(), "Perm", 0b1011).unwrap();
+        assert_eq!(explanation.set, vec![flag("Read", 0), flag("Write", 1)]);
+        assert_eq!(explanation.unset, vec![flag("Delete", 2)]);
+        assert_eq!(explanation.unknown_bits, vec![3]);
+        assert!(!explanation.exceeds_width());
+        assert_eq!(
+            explanation.to_string(),
+            "Perm = 11 (0b1011), width 8
+  set:
+    Read (position 0)
+    Write (position 1)
+  unset:
+    Delete (position 2)
+  unknown set bits: 3
+"
+        );
+    }
+
+    #[test]
+    fn test_explain_value_exceeding_width() {
+        let explanation = explain_value(&spec(), "Perm", 1 << 9 | 1).unwrap();
+        assert_eq!(explanation.set, vec![flag("Read", 0)]);
+        assert_eq!(explanation.unknown_bits, vec![9]);
+        assert!(explanation.exceeds_width());
+        assert!(explanation
+            .to_string()
+            .contains("value exceeds the 8-bit width of Perm"));
+    }
+
+    #[test]
+    fn test_explain_value_unknown_enum() {
+        let result = explain_value(&spec(), "Mode", 1);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::UnknownEnumError
+        );
+    }
+}
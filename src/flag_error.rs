@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::{suggest, BairiakEnum, BairiakError, BairiakErrorKind};
+
+// The error of generated `FromStr` impls: a name that is no variant of the
+// enum, with the variant closest to it ignoring ASCII case, if any. It
+// converts into a `BairiakError` of kind `UnknownFlagError`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlagParseError {
+    enum_name: &'static str,
+    input: String,
+    suggestion: Option<&'static str>,
+}
+
+impl FlagParseError {
+    pub fn new<B: BairiakEnum>(enum_name: &'static str, input: &str) -> Self {
+        let variants: Vec<(String, &'static str)> = B::all_variants()
+            .iter()
+            .map(|v| (v.name().to_ascii_lowercase(), v.name()))
+            .collect();
+        let lowercase = input.to_ascii_lowercase();
+        let suggestion = suggest::closest(&lowercase, variants.iter().map(|(key, _)| key.as_str()))
+            .and_then(|closest| variants.iter().find(|(key, _)| key == closest))
+            .map(|&(_, variant)| variant);
+        FlagParseError {
+            enum_name,
+            input: String::from(input),
+            suggestion,
+        }
+    }
+
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    // The name that failed to parse, as it was given.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+// ``unknown Perm flag: `Rread` (did you mean `Read`?)``
+impl fmt::Display for FlagParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} flag: `{}`", self.enum_name, self.input)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " (did you mean `{}`?)", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FlagParseError {}
+
+impl From<FlagParseError> for BairiakError {
+    fn from(err: FlagParseError) -> Self {
+        let suggestion = err
+            .suggestion
+            .map(|variant| format!(" Did you mean `{}`?", variant))
+            .unwrap_or_default();
+        BairiakError::new(
+            BairiakErrorKind::UnknownFlagError,
+            format!(
+                "Unknown {} flag: {}.{}",
+                err.enum_name, err.input, suggestion
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::*;
+    use crate::test_support::fixtures::Perm;
+
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_display() {
+        let err = FlagParseError::new::<Perm>("Perm", "Rread");
+        assert_eq!(
+            err.to_string(),
+            "unknown Perm flag: `Rread` (did you mean `Read`?)"
+        );
+        assert_eq!(err.input(), "Rread");
+        assert_eq!(err.suggestion(), Some("Read"));
+
+        let err = FlagParseError::new::<Perm>("Perm", "execute");
+        assert_eq!(err.to_string(), "unknown Perm flag: `execute`");
+        assert_eq!(err.suggestion(), None);
+        assert_eq!(err.clone(), err);
+    }
+
+    #[test]
+    fn test_into_boxed_error() {
+        assert_send_sync::<FlagParseError>();
+        let parse = || -> Result<(), Box<dyn Error + Send + Sync>> {
+            Err(FlagParseError::new::<Perm>("Perm", "wirte"))?
+        };
+        let err = parse().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown Perm flag: `wirte` (did you mean `Write`?)"
+        );
+        assert!(err.downcast_ref::<FlagParseError>().is_some());
+    }
+
+    #[test]
+    fn test_into_bairiak_error() {
+        let err = BairiakError::from(FlagParseError::new::<Perm>("Perm", "wirte"));
+        assert_eq!(err.kind(), BairiakErrorKind::UnknownFlagError);
+        assert_eq!(
+            err.message(),
+            "Unknown Perm flag: wirte. Did you mean `Write`?"
+        );
+    }
+}
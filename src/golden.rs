@@ -0,0 +1,796 @@
+// Golden-file tests comparing the full generated code for the specs in
+// `test_data/` with `test_data/expected/`. After an intentional change to the
+// generated code, rewrite the expected files with
+//
+//     BAIRIAK_UPDATE_GOLDEN=1 cargo test golden
+//
+// and review the diff.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{generate_bairiak_enums_from_str_with, GenerateOptions, RustEdition, Visibility};
+
+fn assert_golden(name: &str) {
+    assert_golden_with(name, name, &GenerateOptions::default());
+}
+
+fn assert_golden_with(spec_name: &str, expected_name: &str, options: &GenerateOptions) {
+    let spec = fs::read_to_string(format!("test_data/{}.yaml", spec_name)).unwrap();
+    let generated_code = generate_bairiak_enums_from_str_with(&spec, options).unwrap();
+    let expected_path = format!("test_data/expected/{}.rs", expected_name);
+
+    if env::var_os("BAIRIAK_UPDATE_GOLDEN").is_some() {
+        fs::write(&expected_path, &generated_code).unwrap();
+        return;
+    }
+
+    let expected_code = fs::read_to_string(&expected_path).unwrap();
+    let mismatch = generated_code
+        .lines()
+        .zip(expected_code.lines())
+        .enumerate()
+        .find(|(_, (generated, expected))| generated != expected);
+    if let Some((i, (generated, expected))) = mismatch {
+        panic!(
+            "{} differs at line {}:\n  generated: {}\n  expected:  {}\nRun with BAIRIAK_UPDATE_GOLDEN=1 to update it.",
+            expected_path,
+            i + 1,
+            generated,
+            expected
+        );
+    }
+    assert_eq!(
+        generated_code, expected_code,
+        "{} differs in length. Run with BAIRIAK_UPDATE_GOLDEN=1 to update it.",
+        expected_path
+    );
+}
+
+#[test]
+fn test_golden_minimal() {
+    assert_golden("minimal_spec");
+}
+
+#[test]
+fn test_golden_multi_enum() {
+    assert_golden("multi_enum_spec");
+}
+
+#[test]
+fn test_golden_width_8() {
+    assert_golden("width_8_spec");
+}
+
+#[test]
+fn test_golden_width_16() {
+    assert_golden("width_16_spec");
+}
+
+#[test]
+fn test_golden_width_128() {
+    assert_golden("width_128_spec");
+}
+
+#[test]
+fn test_golden_migration() {
+    assert_golden("migration_spec");
+}
+
+#[test]
+fn test_golden_arbitrary() {
+    assert_golden("arbitrary_spec");
+}
+
+#[test]
+fn test_golden_json_schema() {
+    assert_golden("json_schema_spec");
+}
+
+#[test]
+fn test_golden_bitflags() {
+    assert_golden("bitflags_spec");
+}
+
+#[test]
+fn test_golden_settings() {
+    assert_golden("settings_spec");
+}
+
+#[test]
+fn test_golden_positions() {
+    assert_golden("positions_spec");
+}
+
+#[test]
+fn test_golden_from_str() {
+    assert_golden("from_str_spec");
+}
+
+#[test]
+fn test_golden_position_consts() {
+    assert_golden("position_consts_spec");
+}
+
+#[test]
+fn test_golden_match() {
+    assert_golden("match_spec");
+}
+
+#[test]
+fn test_golden_variant_docs() {
+    assert_golden("manifest_spec");
+}
+
+#[test]
+fn test_golden_groups() {
+    assert_golden("groups_spec");
+}
+
+#[test]
+fn test_golden_exclusive() {
+    assert_golden("exclusive_spec");
+}
+
+#[test]
+fn test_golden_requires() {
+    assert_golden("requires_spec");
+}
+
+// A use of the match macro that misses a variant must not compile.
+#[test]
+fn test_golden_registry() {
+    assert_golden("registry_spec");
+}
+
+#[test]
+fn test_golden_packed() {
+    assert_golden("packed_spec");
+}
+
+#[test]
+fn test_golden_abi() {
+    assert_golden("abi_spec");
+}
+
+#[test]
+fn test_golden_status() {
+    assert_golden("status_spec");
+}
+
+#[test]
+fn test_golden_stable_id() {
+    assert_golden("stable_id_spec");
+}
+
+#[test]
+fn test_golden_bool_struct() {
+    assert_golden("bool_struct_spec");
+}
+
+#[test]
+fn test_golden_lookup() {
+    assert_golden("lookup_spec");
+}
+
+#[test]
+fn test_golden_table() {
+    assert_golden("table_spec");
+}
+
+#[test]
+fn test_match_macro_requires_every_variant() {
+    trybuild::TestCases::new().compile_fail("test_data/compile_fail/*.rs");
+}
+
+#[test]
+fn test_golden_anchors() {
+    assert_golden("anchors_spec");
+}
+
+#[test]
+fn test_golden_multi_document() {
+    assert_golden("multi_document_spec");
+}
+
+#[test]
+fn test_golden_fixtures() {
+    assert_golden("fixtures_spec");
+}
+
+#[test]
+fn test_golden_edition_2015() {
+    let options = GenerateOptions::default().display(true);
+    assert_golden_with("edition_spec", "edition_2015_spec", &options);
+}
+
+#[test]
+fn test_golden_edition_2018() {
+    let options = GenerateOptions::default()
+        .display(true)
+        .rust_edition(RustEdition::E2018);
+    assert_golden_with("edition_spec", "edition_2018_spec", &options);
+}
+
+// Nothing generated needs syntax newer than 2018 yet.
+#[test]
+fn test_editions_since_2018_generate_the_same() {
+    let spec = fs::read_to_string("test_data/edition_spec.yaml").unwrap();
+    let generate = |edition| {
+        let options = GenerateOptions::default().rust_edition(edition);
+        generate_bairiak_enums_from_str_with(&spec, &options).unwrap()
+    };
+    let code_2018 = generate(RustEdition::E2018);
+    assert_eq!(generate(RustEdition::E2021), code_2018);
+    assert_eq!(generate(RustEdition::E2024), code_2018);
+
+    let code_2015 = generate(RustEdition::E2015);
+    assert_eq!(code_2015, generate(RustEdition::default()));
+    assert!(code_2015.contains("\nuse bairiak::{Bairiak, BairiakEnum};\n"));
+    assert!(code_2018.contains("\nuse ::bairiak::{Bairiak, BairiakEnum};\n"));
+    let options = GenerateOptions::default()
+        .prelude(true)
+        .module("flags")
+        .rust_edition(RustEdition::E2021);
+    let code = generate_bairiak_enums_from_str_with(&spec, &options).unwrap();
+    assert!(code.contains("\nmod flags {\n    use ::bairiak::prelude::*;\n"));
+}
+
+// trybuild compiles its tests with the crate's own edition, so the 2015 code
+// goes in a crate of its own, built the way trybuild builds its tests: with
+// the same target directory, sharing the dependencies already built.
+#[test]
+fn test_edition_2015_compiles() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir.join("target"));
+    let dir = target_dir.join("tests").join("edition_2015");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"bairiak-edition-2015\"\nversion = \"0.0.0\"\nedition = \"2015\"\n\n[dependencies]\nbairiak = {{ path = {:?} }}\n\n[workspace]\n",
+            manifest_dir
+        ),
+    )
+    .unwrap();
+    if let Ok(lock) = fs::read(manifest_dir.join("Cargo.lock")) {
+        fs::write(dir.join("Cargo.lock"), lock).unwrap();
+    }
+    fs::write(
+        dir.join("src/lib.rs"),
+        format!(
+            "#![deny(warnings)]\nextern crate bairiak;\n\n#[allow(dead_code)]\nmod flags {{\n    include!({:?});\n}}\n",
+            manifest_dir.join("test_data/expected/edition_2015_spec.rs")
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(["check", "--quiet", "--offline"])
+        .current_dir(&dir)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_golden_module() {
+    let options = GenerateOptions::default()
+        .module("flags")
+        .visibility(Visibility::PubCrate);
+    assert_golden_with("multi_enum_spec", "module_spec", &options);
+}
+
+#[test]
+fn test_golden_display() {
+    let options = GenerateOptions::default().display(true);
+    assert_golden_with("valid_spec", "display_spec", &options);
+}
+
+#[test]
+fn test_golden_prelude() {
+    let options = GenerateOptions::default().prelude(true);
+    assert_golden_with("valid_spec", "prelude_spec", &options);
+}
+
+// The expected files must also compile. `migration_spec.rs` and
+// `packed_spec.rs` are compiled in the crate tests, `arbitrary_spec.rs` with
+// the proptest feature, `json_schema_spec.rs` with the schemars feature,
+// `bitflags_spec.rs` with the bitflags feature and `registry_spec.rs` with the
+// registry feature.
+mod minimal {
+    include!("../test_data/expected/minimal_spec.rs");
+
+    #[test]
+    fn test_minimal_spec_constants() {
+        assert_eq!(Minimal::COUNT, 1);
+        assert_eq!(Minimal::ALL_MASK, 0b1);
+        assert_eq!(Minimal::max_position(), 0);
+    }
+}
+
+mod multi_enum {
+    include!("../test_data/expected/multi_enum_spec.rs");
+}
+
+mod anchors {
+    include!("../test_data/expected/anchors_spec.rs");
+
+    #[test]
+    fn test_anchors_spec_shared_variants() {
+        assert_eq!(Permissions::Delete.to_u8(), 2);
+        assert_eq!(Shares::Delete.to_u8(), 2);
+        assert_eq!(Shares::get_zero_bairiak(), Bairiak::U8(0));
+        assert_eq!(Archive::get_zero_bairiak(), Bairiak::U16(0));
+        assert_eq!(Archive::DELETE_POS, 2);
+        assert_eq!(Replication::get_zero_bairiak(), Bairiak::U32(0));
+        assert_eq!(Replication::PULL_POS, 1);
+    }
+}
+
+mod multi_document {
+    include!("../test_data/expected/multi_document_spec.rs");
+
+    #[test]
+    fn test_multi_document_spec_enums() {
+        assert_eq!(Permissions::Write.to_u8(), 1);
+        assert_eq!(Storage::Zstd.to_u8(), 1);
+        assert_eq!(Network::Http2.into_bairiak(), Bairiak::U8(0b10));
+    }
+}
+
+mod edition_2018 {
+    include!("../test_data/expected/edition_2018_spec.rs");
+
+    #[test]
+    fn test_edition_2018_spec_paths() {
+        assert_eq!("Write".parse(), Ok(Perm::Write));
+        assert_eq!(Perm::Admin.to_string(), "Admin");
+        assert_eq!(Perm::status_at(3), Some(crate::FlagStatus::Removed));
+        let value: Bairiak = [Perm::Read, Perm::Admin].into_iter().collect();
+        assert!(Perm::validate_exclusive(&value).is_err());
+        let value: Bairiak = [Perm::Write].into_iter().collect();
+        assert!(Perm::validate_requires(&value).is_err());
+        assert_eq!(settings_layout().width(), 16);
+    }
+}
+
+mod width_8 {
+    include!("../test_data/expected/width_8_spec.rs");
+}
+
+mod width_16 {
+    include!("../test_data/expected/width_16_spec.rs");
+}
+
+mod width_128 {
+    include!("../test_data/expected/width_128_spec.rs");
+}
+
+mod settings {
+    include!("../test_data/expected/settings_spec.rs");
+}
+
+mod positions {
+    include!("../test_data/expected/positions_spec.rs");
+
+    #[test]
+    fn test_positions_spec_constants() {
+        assert_eq!(Permissions::COUNT, 4);
+        assert_eq!(Permissions::ALL_MASK, 0b110_0011);
+        assert_eq!(Permissions::max_position(), 6);
+        assert_eq!(Wide::COUNT, 2);
+        assert_eq!(Wide::ALL_MASK, 1 << 40 | 1);
+        assert_eq!(Wide::max_position(), 40);
+        assert_eq!(Reserved::COUNT, 3);
+        assert_eq!(Reserved::ALL_MASK, 0b1_0011);
+        assert_eq!(Reserved::max_position(), 4);
+    }
+
+    #[test]
+    fn test_positions_spec_discriminants() {
+        assert_eq!(Permissions::Admin.to_u8(), 5);
+        assert_eq!(Permissions::Audit.to_u8(), 6);
+        assert_eq!(Permissions::from_u8(2), None);
+        assert_eq!(Permissions::defined_mask(), Permissions::ALL_MASK);
+        assert_eq!(Permissions::variant_count(), 4);
+        assert_eq!(Wide::get_zero_bairiak(), Bairiak::U64(0));
+        assert_eq!(Wide::High.mask(), Bairiak::U64(1 << 40));
+        assert_eq!(Reserved::Admin.into_bairiak(), Bairiak::U16(1 << 4));
+        assert_eq!(Reserved::Admin.to_u8(), 4);
+        assert_eq!(Reserved::from_u8(2), None);
+        assert_eq!(Reserved::from_u8(3), None);
+        assert_eq!(Reserved::get_zero_bairiak(), Bairiak::U16(0));
+    }
+}
+
+mod position_consts {
+    include!("../test_data/expected/position_consts_spec.rs");
+
+    #[test]
+    fn test_position_consts_spec_values() {
+        assert_eq!(Permissions::READ_POS, 0);
+        assert_eq!(Permissions::WRITE_MASK, 0b10u8);
+        assert_eq!(Permissions::ADMIN_POS, 5);
+        assert_eq!(Permissions::ADMIN_MASK, 0b10_0000u8);
+        assert_eq!(HIGH_BIT_POS, 40);
+        assert_eq!(HIGH_BIT_MASK, 1u64 << 40);
+        assert_eq!(LOW_MASK, 1u64);
+        assert_eq!(FAST_POS, 0);
+        assert_eq!(SLOW_POS, 2);
+        assert_eq!(SLOW_POS, Mode::Slow.to_u8());
+    }
+}
+
+mod from_str {
+    include!("../test_data/expected/from_str_spec.rs");
+
+    #[test]
+    fn test_from_str_spec_ignores_case() {
+        assert_eq!("read".parse(), Ok(Permissions::Read));
+        assert_eq!("READ".parse(), Ok(Permissions::Read));
+        assert_eq!("readWRITE".parse(), Ok(Permissions::ReadWrite));
+        assert_eq!("MODIFY".parse(), Ok(Permissions::Write));
+        assert_eq!("w".parse(), Ok(Permissions::Write));
+        assert_eq!(
+            "execute".parse::<Permissions>().unwrap_err().to_string(),
+            "unknown Permissions flag: `execute`"
+        );
+    }
+
+    #[test]
+    fn test_from_str_spec_exact() {
+        assert_eq!("Fast".parse(), Ok(Mode::Fast));
+        assert_eq!("careful".parse(), Ok(Mode::Slow));
+        let err = "fast".parse::<Mode>().unwrap_err();
+        assert_eq!(err.input(), "fast");
+        assert_eq!(err.suggestion(), Some("Fast"));
+        let err = crate::BairiakError::from(err);
+        assert_eq!(err.kind(), crate::BairiakErrorKind::UnknownFlagError);
+        assert_eq!(
+            err.message(),
+            "Unknown Mode flag: fast. Did you mean `Fast`?"
+        );
+    }
+
+    #[test]
+    fn test_from_str_spec_suggests_closest_variant() {
+        assert_eq!(
+            "Rread".parse::<Permissions>().unwrap_err().to_string(),
+            "unknown Permissions flag: `Rread` (did you mean `Read`?)"
+        );
+    }
+}
+
+mod match_macro {
+    include!("../test_data/expected/match_spec.rs");
+
+    #[test]
+    fn test_match_spec_macro() {
+        let names: Vec<&str> = Permissions::all_variants()
+            .into_iter()
+            .map(|flag| {
+                permissions_match!(flag, {
+                    Read => "r",
+                    Write => "w",
+                    Delete => "d",
+                })
+            })
+            .collect();
+        assert_eq!(names, ["r", "w", "d"]);
+    }
+}
+
+mod groups {
+    include!("../test_data/expected/groups_spec.rs");
+
+    #[test]
+    fn test_groups_spec_lookup() {
+        assert_eq!(Permissions::groups().len(), 3);
+        assert_eq!(Permissions::group("Editors"), Some(Bairiak::U16(0b11)));
+        let operators = Permissions::group("Operators").unwrap();
+        assert_eq!(operators.bits(), 1 << 9 | 0b11);
+        assert!(operators.is_true(Permissions::Admin));
+        assert_eq!(Permissions::group("Nobody"), Some(Bairiak::U16(0)));
+        assert_eq!(Permissions::group("editors"), None);
+        assert_eq!(Permissions::group("Missing"), None);
+        assert_eq!(Mode::group("ALL"), Some(Bairiak::U8(0b11)));
+    }
+}
+
+mod exclusive {
+    include!("../test_data/expected/exclusive_spec.rs");
+
+    #[test]
+    fn test_exclusive_spec_validation() {
+        let mut value: Bairiak = [Codec::CompressZstd, Codec::ModeB].into_iter().collect();
+        assert_eq!(Codec::validate_exclusive(&value), Ok(()));
+
+        value.set(Codec::CompressGzip);
+        let violation = Codec::validate_exclusive(&value).unwrap_err();
+        assert_eq!(violation.group, 0);
+        assert_eq!(violation.set, ["CompressGzip", "CompressZstd"]);
+    }
+}
+
+mod requires {
+    include!("../test_data/expected/requires_spec.rs");
+
+    #[test]
+    fn test_requires_spec_validation() {
+        let mut value: Bairiak = [Access::Read, Access::Admin, Access::AdminPanel]
+            .into_iter()
+            .collect();
+        assert_eq!(Access::validate_requires(&value), Ok(()));
+
+        value.clear_position(Access::Read.to_u8());
+        let violations = Access::validate_requires(&value).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].to_string(), "`Admin` requires `Read`.");
+
+        value.set(Access::Audit);
+        value.clear_position(Access::Admin.to_u8());
+        let violations = Access::validate_requires(&value).unwrap_err();
+        let flags: Vec<&str> = violations.iter().map(|v| v.flag).collect();
+        assert_eq!(flags, ["AdminPanel", "Audit"]);
+        assert_eq!(violations[1].missing, ["Read", "Admin"]);
+    }
+}
+
+mod abi {
+    include!("../test_data/expected/abi_spec.rs");
+
+    #[test]
+    fn test_abi_spec_constants() {
+        assert_eq!(perm_abi::WIDTH_BITS, 8);
+        assert_eq!(perm_abi::VARIANT_COUNT, 3);
+        assert_eq!(perm_abi::LAYOUT_HASH, crate::layout_hash_for::<Perm>());
+        assert_eq!(http_method_abi::WIDTH_BITS, 16);
+        assert_eq!(http_method_abi::VARIANT_COUNT, 2);
+        assert_eq!(
+            http_method_abi::LAYOUT_HASH,
+            crate::layout_hash_for::<HttpMethod>()
+        );
+        assert_ne!(perm_abi::LAYOUT_HASH, http_method_abi::LAYOUT_HASH);
+    }
+}
+
+// The experimental feature isn't one of bairiak's, so the gated variants
+// are left out here.
+#[allow(unexpected_cfgs)]
+mod status {
+    include!("../test_data/expected/status_spec.rs");
+
+    use crate::FlagStatus;
+
+    #[test]
+    fn test_status_spec_lookup() {
+        assert_eq!(Feature::Login.status(), FlagStatus::Stable);
+        assert_eq!(Feature::LegacyExport.status(), FlagStatus::Deprecated);
+        assert_eq!(Feature::Export.status(), FlagStatus::Stable);
+        assert_eq!(Stage::Alpha.status(), FlagStatus::Experimental);
+        assert_eq!(Stage::Beta.status(), FlagStatus::Deprecated);
+
+        // `OldSync` and `Gamma` keep their positions without a variant.
+        assert_eq!(Feature::Export.to_u8(), 4);
+        assert_eq!(Feature::status_at(3), Some(FlagStatus::Removed));
+        assert_eq!(Feature::from_u8(3), None);
+        assert_eq!(Stage::status_at(5), Some(FlagStatus::Removed));
+        assert_eq!(Stage::Delta.to_u8(), 6);
+        assert_eq!(Stage::status_at(6), Some(FlagStatus::Stable));
+        assert_eq!(Stage::status_at(7), None);
+    }
+
+    #[test]
+    fn test_status_spec_gated_variants() {
+        assert_eq!(Feature::COUNT, 3);
+        assert_eq!(Feature::ALL_MASK, 0b10101);
+        assert_eq!(Feature::from_u8(1), None);
+        assert_eq!(Feature::status_at(1), None);
+        assert!("DarkMode".parse::<Feature>().is_err());
+        crate::validate_impl_for::<Feature>().unwrap();
+    }
+}
+
+mod table {
+    include!("../test_data/expected/table_spec.rs");
+
+    #[test]
+    fn test_table_lookup_matches_match_lookup() {
+        for position in 0..=u8::MAX {
+            let by_match = MatchLookup::from_u8(position);
+            let by_table = TableLookup::from_u8(position);
+            assert_eq!(by_match.map(|v| v.name()), by_table.map(|v| v.name()));
+            assert_eq!(by_match.map(|v| v.to_u8()), by_table.map(|v| v.to_u8()));
+        }
+        // Position 2 is a placeholder, 4 a removed variant, and 11 beyond every
+        // variant.
+        assert_eq!(TableLookup::from_u8(2), None);
+        assert_eq!(TableLookup::from_u8(4), None);
+        assert_eq!(TableLookup::from_u8(11), None);
+        assert_eq!(TableLookup::Audit.name(), "Audit");
+        assert_eq!(TableLookup::defined_mask(), MatchLookup::defined_mask());
+    }
+}
+
+mod bool_struct {
+    include!("../test_data/expected/bool_struct_spec.rs");
+
+    #[test]
+    fn test_bool_struct_round_trip() {
+        let value: Bairiak = [Perm::Read, Perm::Type, Perm::AuditLog]
+            .into_iter()
+            .collect();
+        let bools = PermBools::from(&value);
+        assert_eq!(
+            bools,
+            PermBools {
+                read: true,
+                r#type: true,
+                audit_log: true,
+                ..PermBools::default()
+            }
+        );
+        assert_eq!(Bairiak::from(&bools), value);
+        assert_eq!(
+            Bairiak::from(&PermBools::default()),
+            Perm::get_zero_bairiak()
+        );
+        assert_eq!(
+            Bairiak::from(&ModeBools::from(&Mode::Slow.mask())),
+            Mode::Slow.mask()
+        );
+    }
+
+    #[test]
+    fn test_bool_struct_drops_unknown_bits() {
+        // Position 2 is a placeholder and 7 is beyond every variant.
+        let value = Bairiak::U8(0b1000_0101);
+        let bools = PermBools::from(&value);
+        assert!(bools.read && !bools.write);
+        assert_eq!(Bairiak::from(&bools), Bairiak::U8(0b1));
+    }
+
+    #[test]
+    fn test_bool_struct_serde() {
+        let bools = PermBools {
+            write: true,
+            crate_: true,
+            ..PermBools::default()
+        };
+        let json = serde_json::to_string(&bools).unwrap();
+        assert_eq!(
+            json,
+            r#"{"read":false,"write":true,"type":false,"crate_":true,"audit_log":false}"#
+        );
+        assert_eq!(serde_json::from_str::<PermBools>(&json).unwrap(), bools);
+    }
+}
+
+mod stable_id {
+    include!("../test_data/expected/stable_id_spec.rs");
+
+    #[test]
+    fn test_stable_id_spec_lookup() {
+        assert_eq!(Perm::Read.stable_id(), 1001);
+        assert_eq!(Perm::Admin.stable_id(), 1004);
+        assert_eq!(Perm::from_stable_id(1002), Some(Perm::Write));
+        // The id of a removed variant stays taken without finding anything.
+        assert_eq!(Perm::from_stable_id(1003), None);
+        assert_eq!(Perm::from_stable_id(0), None);
+        // Ids don't follow positions.
+        assert_eq!(Perm::Admin.to_u8(), 4);
+    }
+}
+
+mod lookup {
+    include!("../test_data/expected/lookup_spec.rs");
+
+    use core::str::FromStr;
+
+    // The result of parsing `input`, comparable across enums with the same
+    // names.
+    fn parsed<B: BairiakEnum + FromStr<Err = crate::FlagParseError>>(
+        input: &str,
+    ) -> Result<&'static str, (String, Option<&'static str>)> {
+        B::from_str(input)
+            .map(|flag| flag.name())
+            .map_err(|err| (err.input().to_string(), err.suggestion()))
+    }
+
+    #[test]
+    fn test_lookup_spec_lookups_agree() {
+        let mut inputs = vec![
+            String::new(),
+            String::from("Flag"),
+            String::from("Flag120"),
+            String::from("Flag0 "),
+            String::from("Seven"),
+            String::from("SEPT"),
+            String::from("Ｆlag1"),
+            String::from("Flag\u{0}"),
+            String::from("Séptimo"),
+            String::from("sÉptimo"),
+            String::from("Flag1000000"),
+        ];
+        for flag in ExactMatch::all_variants() {
+            let name = flag.name();
+            inputs.extend([
+                name.to_string(),
+                name.to_ascii_lowercase(),
+                name.to_ascii_uppercase(),
+                format!("{}x", name),
+                name[1..].to_string(),
+            ]);
+        }
+
+        for input in &inputs {
+            assert_eq!(
+                parsed::<ExactBytes>(input),
+                parsed::<ExactMatch>(input),
+                "{:?}",
+                input
+            );
+            assert_eq!(
+                parsed::<FoldedBytes>(input),
+                parsed::<FoldedMatch>(input),
+                "{:?}",
+                input
+            );
+        }
+        assert_eq!(parsed::<ExactBytes>("Sept"), Ok("Flag7"));
+        assert_eq!(parsed::<FoldedBytes>("fLaG119"), Ok("Flag119"));
+        assert!(parsed::<ExactBytes>("flag1").is_err());
+        assert_eq!(parsed::<FoldedBytes>("SéPTIMO"), Ok("Flag7"));
+    }
+}
+
+mod variant_docs {
+    include!("../test_data/expected/manifest_spec.rs");
+}
+
+mod module {
+    include!("../test_data/expected/module_spec.rs");
+}
+
+mod display {
+    include!("../test_data/expected/display_spec.rs");
+
+    #[test]
+    fn test_display_spec_prints_names() {
+        assert_eq!(TestEnum::Flag1.to_string(), "Flag1");
+        assert_eq!(format!("{:>6}", TestEnum::Flag2), " Flag2");
+    }
+}
+
+mod prelude {
+    include!("../test_data/expected/prelude_spec.rs");
+
+    #[test]
+    fn test_prelude_spec_brings_the_prelude() {
+        let mut patch = BairiakPatch::new();
+        patch.enable(TestEnum::Flag2);
+        let mut bairiak = generate_bairiak([TestEnum::Flag0]);
+        patch.apply(&mut bairiak);
+        assert_eq!(bairiak.bits(), 0b101);
+    }
+}
+
+#[test]
+fn test_module_spec_is_reachable() {
+    use crate::BairiakEnum;
+
+    let bairiak: crate::Bairiak = [module::flags::Permissions::Write].into_iter().collect();
+    assert_eq!(bairiak.bits(), 0b10);
+    assert_eq!(module::flags::Mode::Slow.name(), "Slow");
+}
@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+
+use crate::Bairiak;
+
+pub const DEFAULT_HISTORY_CAPACITY: usize = 100;
+
+// A `Bairiak` with undo and redo. At most `capacity` previous states are kept;
+// the oldest are dropped first.
+#[derive(Debug, Clone)]
+pub struct BairiakHistory {
+    current: Bairiak,
+    undo: VecDeque<Bairiak>,
+    redo: Vec<Bairiak>,
+    capacity: usize,
+}
+
+impl BairiakHistory {
+    pub fn new(initial: Bairiak) -> Self {
+        BairiakHistory::with_capacity(initial, DEFAULT_HISTORY_CAPACITY)
+    }
+
+    pub fn with_capacity(initial: Bairiak, capacity: usize) -> Self {
+        BairiakHistory {
+            current: initial,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            capacity,
+        }
+    }
+
+    pub fn current(&self) -> &Bairiak {
+        &self.current
+    }
+
+    // Records the current state before applying `f`. A mutation that leaves
+    // the state unchanged is not recorded.
+    pub fn mutate<F: FnOnce(&mut Bairiak)>(&mut self, f: F) {
+        let previous = self.current;
+        f(&mut self.current);
+        if self.current == previous {
+            return;
+        }
+
+        self.redo.clear();
+        self.push_undo(previous);
+    }
+
+    fn push_undo(&mut self, state: Bairiak) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(state);
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo.pop_back() else {
+            return false;
+        };
+        self.redo.push(self.current);
+        self.current = previous;
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        self.push_undo(self.current);
+        self.current = next;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_past_beginning() {
+        let mut history = BairiakHistory::new(Bairiak::U8(0));
+        history.mutate(|b| b.set_position(0));
+        history.mutate(|b| b.set_position(1));
+
+        assert!(history.undo());
+        assert_eq!(*history.current(), Bairiak::U8(0b1));
+        assert!(history.undo());
+        assert_eq!(*history.current(), Bairiak::U8(0));
+        assert!(!history.undo());
+        assert_eq!(*history.current(), Bairiak::U8(0));
+
+        assert!(history.redo());
+        assert!(history.redo());
+        assert!(!history.redo());
+        assert_eq!(*history.current(), Bairiak::U8(0b11));
+    }
+
+    #[test]
+    fn test_mutation_clears_redo() {
+        let mut history = BairiakHistory::new(Bairiak::U8(0));
+        history.mutate(|b| b.set_position(0));
+        assert!(history.undo());
+
+        history.mutate(|b| b.set_position(2));
+        assert!(!history.redo());
+        assert_eq!(*history.current(), Bairiak::U8(0b100));
+    }
+
+    #[test]
+    fn test_unchanged_state_is_not_recorded() {
+        let mut history = BairiakHistory::new(Bairiak::U8(0));
+        history.mutate(|b| b.set_position(0));
+        history.mutate(|b| b.set_position(0));
+
+        assert!(history.undo());
+        assert!(!history.undo());
+    }
+
+    #[test]
+    fn test_capacity_evicts_oldest() {
+        let mut history = BairiakHistory::with_capacity(Bairiak::U8(0), 2);
+        for position in 0..3 {
+            history.mutate(|b| b.set_position(position));
+        }
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(*history.current(), Bairiak::U8(0b1));
+    }
+}
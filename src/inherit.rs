@@ -0,0 +1,151 @@
+use crate::{suggest, BairiakError, BairiakErrorKind, EnumSpec, Result, Variant};
+
+// Prepends the variants of the enum each enum `extends` to its own, in spec
+// order, so that an enum extending one that extends another gets both lists.
+// The base must come earlier in the spec, in the same or an earlier document.
+// Inherited variants keep their positions, and the enum's own variants
+// continue after them unless given a position. Only the variants are shared:
+// the enums remain independent types with settings of their own.
+//
+// As the variants are merged before validation, the index of a problem found
+// in an extending enum counts the inherited variants too.
+pub(crate) fn resolve_extends(spec: &mut EnumSpec) -> Result<()> {
+    for i in 0..spec.enums.len() {
+        let (earlier, rest) = spec.enums.split_at_mut(i);
+        let Some(e) = rest.first_mut() else {
+            break;
+        };
+        let Some(base_name) = &e.extends else {
+            continue;
+        };
+        let Some(base) = earlier.iter().find(|b| &b.name == base_name) else {
+            let suggestion =
+                suggest::did_you_mean(base_name, earlier.iter().map(|e| e.name.as_str()));
+            return Err(invalid_extends(format!(
+                "`{}` extends `{}`, which isn't defined before it.{}",
+                e.name, base_name, suggestion
+            )));
+        };
+
+        if let Some(name) = e.variants.iter().filter_map(entry_name).find(|&name| {
+            base.variants
+                .iter()
+                .filter_map(entry_name)
+                .any(|b| b == name)
+        }) {
+            return Err(invalid_extends(format!(
+                "Variant `{}` of `{}` has the name of a variant it inherits from `{}`.",
+                name, e.name, base.name
+            )));
+        }
+
+        let mut variants = base.variants.clone();
+        variants.append(&mut e.variants);
+        e.variants = variants;
+    }
+    Ok(())
+}
+
+// The name of an entry, removed variants included, as they can't be reused.
+fn entry_name(v: &Variant) -> Option<&str> {
+    match v {
+        Variant::Placeholder => None,
+        Variant::Name(name) if name == "_" => None,
+        Variant::Name(name) | Variant::Detailed { name, .. } => Some(name),
+    }
+}
+
+fn invalid_extends(message: String) -> BairiakError {
+    BairiakError::new(BairiakErrorKind::ParseBairiakEnumsError, message).logged()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_spec, BairiakErrorKind};
+
+    fn positions(yaml: &str, enum_name: &str) -> Vec<(usize, String)> {
+        let spec = parse_spec(yaml).unwrap();
+        spec.validate().unwrap();
+        let e = spec.find_enum(enum_name).unwrap();
+        e.positioned_variants()
+            .map(|(position, name)| (position, name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_extends_positions() {
+        let yaml = "enums:
+  - name: Base
+    variants: [Read, Write, ~]
+  - name: Files
+    extends: Base
+    variants: [Delete, {name: Share, position: 10}]
+  - name: Shares
+    extends: Files
+    variants: [Revoke]
+";
+        let pairs = |pairs: &[(usize, &str)]| -> Vec<(usize, String)> {
+            pairs.iter().map(|&(p, n)| (p, n.to_string())).collect()
+        };
+        assert_eq!(positions(yaml, "Base"), pairs(&[(0, "Read"), (1, "Write")]));
+        // New variants continue after the inherited placeholder.
+        assert_eq!(
+            positions(yaml, "Files"),
+            pairs(&[(0, "Read"), (1, "Write"), (3, "Delete"), (10, "Share")])
+        );
+        assert_eq!(
+            positions(yaml, "Shares"),
+            pairs(&[
+                (0, "Read"),
+                (1, "Write"),
+                (3, "Delete"),
+                (10, "Share"),
+                (11, "Revoke")
+            ])
+        );
+    }
+
+    #[test]
+    fn test_extends_across_documents() {
+        let yaml = "enums: [{name: Base, variants: [Read]}]\n---\nenums: [{name: Files, extends: Base, variants: [Write]}]\n";
+        assert_eq!(positions(yaml, "Files")[1], (1, String::from("Write")));
+    }
+
+    #[test]
+    fn test_extends_errors() {
+        let err = parse_spec(
+            "enums:\n  - name: Base\n    variants: [Read, {name: Old, status: removed}]\n  - name: Files\n    extends: Base\n    variants: [Old]\n",
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Variant `Old` of `Files` has the name of a variant it inherits from `Base`."
+        );
+
+        let err = parse_spec(
+            "enums:\n  - name: Files\n    extends: Base\n    variants: [Write]\n  - name: Base\n    variants: [Read]\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "`Files` extends `Base`, which isn't defined before it."
+        );
+
+        let err = parse_spec(
+            "enums:\n  - name: Base\n    variants: [Read]\n  - name: Files\n    extends: Bsae\n    variants: [Write]\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "`Files` extends `Bsae`, which isn't defined before it. Did you mean `Base`?"
+        );
+    }
+
+    #[test]
+    fn test_extends_beyond_width() {
+        let yaml = "enums:\n  - name: Base\n    variants: [{name: Read, position: 6}, Write]\n  - name: Files\n    extends: Base\n    bits: 8\n    variants: [Delete]\n";
+        let err = parse_spec(yaml).unwrap().validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+    }
+}
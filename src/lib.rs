@@ -1,401 +1,6199 @@
-use regex::Regex;
-use std::{collections::HashSet, fs};
+// No public function of the crate panics on its input, however malformed:
+// specs, bytes, strings, values, positions and manifests all give an error or
+// a well-defined result instead. The exceptions are deliberate and documented
+// where they are: `assert_flags!`, which is an assertion, and the debug
+// assertion of `generate_bairiak`, and so of the `From` conversions of flag
+// collections, against a hand-written `BairiakEnum` giving a position beyond
+// its width, which `generate_bairiak_checked` returns as an error instead.
+// The lints below keep it so, each remaining use allowed where it is with the
+// reason it can't fail.
+#![cfg_attr(
+    not(test),
+    warn(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing
+    )
+)]
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{self, Write},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter},
+    iter,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use serde::Deserialize;
 
-#[derive(PartialEq, Debug)]
-pub enum BairiakError {
-    ReadSpecError,
-    DeserializeYamlError,
-    ParseBairiakEnumsError,
-    WriteFileError,
-    PositionOutOfRangeError,
-}
+extern crate self as bairiak;
+
+#[macro_use]
+mod logging;
+
+mod abi;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(any(test, feature = "test-util"))]
+mod assertions;
+mod bit_fields;
+#[cfg(feature = "bitflags")]
+mod bitflags_interop;
+mod cache;
+mod compact;
+mod convert;
+mod database;
+#[cfg(feature = "defmt")]
+mod defmt_format;
+mod diff;
+mod documents;
+mod explain;
+mod flag_error;
+#[cfg(test)]
+mod golden;
+mod history;
+mod inherit;
+mod list;
+mod manifest;
+mod mask_ops;
+mod options;
+mod packed;
+mod parse;
+mod patch;
+pub mod prelude;
+mod problems;
+#[cfg(feature = "rand")]
+mod random;
+mod region;
+#[cfg(feature = "registry")]
+pub mod registry;
+mod remap;
+mod report;
+mod resolve;
+mod rules;
+#[cfg(all(test, feature = "schemars"))]
+mod schema;
+mod semantic_hash;
+#[cfg(feature = "serde")]
+pub mod serde_names;
+mod source;
+mod stable;
+mod status;
+mod suggest;
+#[cfg(test)]
+mod test_support;
+#[cfg(feature = "clap")]
+mod value_parser;
+#[cfg(feature = "verify")]
+mod verify;
+mod warnings;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod width;
+
+pub use abi::layout_hash_for;
+pub use cache::{SpecCache, DEFAULT_SPEC_CACHE_CAPACITY};
+pub use database::BairiakColumn;
+pub use diff::{diff_specs, EnumDiff, PositionChange, Rename, SpecDiff, SpecFlag};
+pub use explain::{explain_value, Explanation};
+pub use flag_error::FlagParseError;
+pub use history::{BairiakHistory, DEFAULT_HISTORY_CAPACITY};
+pub use list::{list_enums, EnumListing};
+pub use manifest::{DecodedFlags, EnumManifest, Manifest, VariantManifest};
+pub use options::{GenerateOptions, RustEdition, Visibility};
+pub use packed::{PackedLayout, PackedMember};
+pub use patch::BairiakPatch;
+pub use problems::SpecProblem;
+pub use remap::{remap, Remapped, Remapper};
+pub use report::{EnumReport, GenerationReport};
+pub use rules::{check_exclusive_for, check_requires_for, ExclusiveViolation, RequiresViolation};
+pub use source::{generate_from_source, load_spec_from, ReadSource, SpecSource};
+pub use status::FlagStatus;
+#[cfg(feature = "clap")]
+pub use value_parser::BairiakValueParser;
+#[cfg(feature = "verify")]
+pub use verify::{verify_generated, verify_generated_file, VerifyMismatch};
+pub use warnings::{SpecWarning, SpecWarningKind};
+#[cfg(feature = "wasm")]
+pub use wasm::WasmBairiak;
+pub use width::BairiakWidth;
+
+#[cfg(feature = "proptest")]
+pub use arbitrary::bairiak_for;
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedBairiak;
+// `assert_flags!` refers to its implementation through this re-export.
+#[cfg(any(test, feature = "test-util"))]
+#[doc(hidden)]
+pub use assertions::assert_flags as __assert_flags;
+// Generated `Arbitrary` impls refer to proptest through this re-export.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub use proptest;
+// Likewise for generated `JsonSchema` derives.
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+pub use schemars;
+
+#[cfg(feature = "bitflags")]
+#[doc(hidden)]
+pub use bitflags;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum BairiakErrorKind {
+    ReadSpecError,
+    DeserializeYamlError,
+    ParseBairiakEnumsError,
+    WriteFileError,
+    PositionOutOfRangeError,
+    UnknownEnumError,
+    OutputModifiedError,
+    IoError,
+    ValueOutOfRangeError,
+    BufferTooShortError,
+    UnknownWidthError,
+    ParseValueError,
+    UnknownFlagError,
+    InvalidOutputPathError,
+    WidthMismatchError,
+    RenumberedError,
+    UnknownVersionError,
+    TrailingBytesError,
+    MaskConflictError,
+    RegionMarkerError,
+    PackedLayoutError,
+    EmptySpecError,
+    ParseSourceError,
+    ParseManifestError,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct BairiakError {
+    kind: BairiakErrorKind,
+    message: String,
+    path: Option<PathBuf>,
+    position: Option<u16>,
+}
+
+pub type Result<T> = std::result::Result<T, BairiakError>;
+
+impl fmt::Display for BairiakError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            BairiakErrorKind::ReadSpecError => write!(f, "Error reading spec: {}", self.message),
+            BairiakErrorKind::DeserializeYamlError => {
+                write!(f, "Error deserializing spec: {}", self.message)
+            }
+            BairiakErrorKind::ParseBairiakEnumsError
+            | BairiakErrorKind::PositionOutOfRangeError => {
+                write!(f, "Error parsing Bairiak enums: {}", self.message)
+            }
+            BairiakErrorKind::WriteFileError
+            | BairiakErrorKind::OutputModifiedError
+            | BairiakErrorKind::RegionMarkerError => {
+                write!(f, "Error writing file: {}", self.message)
+            }
+            BairiakErrorKind::UnknownEnumError => write!(f, "{}", self.message),
+            BairiakErrorKind::IoError => write!(f, "I/O error: {}", self.message),
+            BairiakErrorKind::ValueOutOfRangeError
+            | BairiakErrorKind::WidthMismatchError
+            | BairiakErrorKind::MaskConflictError => {
+                write!(f, "Error converting value: {}", self.message)
+            }
+            BairiakErrorKind::BufferTooShortError
+            | BairiakErrorKind::UnknownWidthError
+            | BairiakErrorKind::UnknownVersionError
+            | BairiakErrorKind::TrailingBytesError => {
+                write!(f, "Error decoding value: {}", self.message)
+            }
+            BairiakErrorKind::ParseValueError => {
+                write!(f, "Error parsing value: {}", self.message)
+            }
+            BairiakErrorKind::UnknownFlagError => {
+                write!(f, "Error parsing flag: {}", self.message)
+            }
+            BairiakErrorKind::InvalidOutputPathError => {
+                write!(f, "Invalid output path: {}", self.message)
+            }
+            BairiakErrorKind::RenumberedError => {
+                write!(f, "Flags renumbered: {}", self.message)
+            }
+            BairiakErrorKind::PackedLayoutError => {
+                write!(f, "Error packing enums: {}", self.message)
+            }
+            BairiakErrorKind::EmptySpecError => write!(f, "Empty spec: {}", self.message),
+            BairiakErrorKind::ParseSourceError => {
+                write!(f, "Error parsing source: {}", self.message)
+            }
+            BairiakErrorKind::ParseManifestError => {
+                write!(f, "Error parsing manifest: {}", self.message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BairiakError {}
+
+impl BairiakError {
+    pub(crate) fn new(kind: BairiakErrorKind, message: String) -> Self {
+        BairiakError {
+            kind,
+            message,
+            path: None,
+            position: None,
+        }
+    }
+
+    // The message shows the path as text; `path()` keeps it as it was given.
+    pub(crate) fn with_path(mut self, path: &Path) -> Self {
+        self.path = Some(path.to_path_buf());
+        self
+    }
+
+    // Prefixes the message with what it is about, such as a spec source.
+    pub(crate) fn in_context(mut self, context: &str) -> Self {
+        self.message = format!("{}: {}", context, self.message);
+        self
+    }
+
+    pub(crate) fn with_position(mut self, position: u16) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    // The error of `FromStr` impls generated by earlier versions.
+    #[doc(hidden)]
+    pub fn unknown_flag(enum_name: &str, name: &str) -> Self {
+        BairiakError::new(
+            BairiakErrorKind::UnknownFlagError,
+            format!("Unknown {} flag: {}.", enum_name, name),
+        )
+    }
+
+    // The error of `FromStr` impls generated before `FlagParseError`.
+    #[doc(hidden)]
+    pub fn unknown_flag_for<B: BairiakEnum>(enum_name: &'static str, name: &str) -> Self {
+        FlagParseError::new::<B>(enum_name, name).into()
+    }
+
+    pub fn kind(&self) -> BairiakErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    // The file the error is about, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    // The position a checked setter was given beyond the width of its value.
+    pub fn position(&self) -> Option<u16> {
+        self.position
+    }
+
+    pub fn is_io(&self) -> bool {
+        matches!(
+            self.kind,
+            BairiakErrorKind::ReadSpecError
+                | BairiakErrorKind::WriteFileError
+                | BairiakErrorKind::IoError
+        )
+    }
+
+    // Whether the spec itself is at fault, as opposed to the filesystem or
+    // the caller.
+    pub fn is_spec(&self) -> bool {
+        matches!(
+            self.kind,
+            BairiakErrorKind::DeserializeYamlError
+                | BairiakErrorKind::ParseBairiakEnumsError
+                | BairiakErrorKind::PositionOutOfRangeError
+                | BairiakErrorKind::EmptySpecError
+        )
+    }
+
+    // Errors of the generator are logged where they are created, so a debug
+    // trace shows them alongside the phase that failed. Errors of the runtime
+    // API are only returned: the caller decides whether they are worth logging.
+    pub(crate) fn logged(self) -> Self {
+        error!("{}", self);
+        self
+    }
+}
+
+impl From<std::io::Error> for BairiakError {
+    fn from(err: std::io::Error) -> Self {
+        BairiakError::new(BairiakErrorKind::IoError, err.to_string()).logged()
+    }
+}
+
+impl From<serde_yaml::Error> for BairiakError {
+    fn from(err: serde_yaml::Error) -> Self {
+        BairiakError::new(BairiakErrorKind::DeserializeYamlError, err.to_string()).logged()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Bairiak {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+}
+
+// `Bairiak::U32 { bits: 0b1001000, set: [3, 6] }`, listing the set positions
+// in ascending order next to the raw bits, so that a failed assertion can be
+// read without decoding the value.
+impl fmt::Debug for Bairiak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Bairiak::U8(_) => "Bairiak::U8",
+            Bairiak::U16(_) => "Bairiak::U16",
+            Bairiak::U32(_) => "Bairiak::U32",
+            Bairiak::U64(_) => "Bairiak::U64",
+            Bairiak::U128(_) => "Bairiak::U128",
+        };
+        f.debug_struct(name)
+            .field("bits", &format_args!("{:#b}", self.bits()))
+            .field("set", &SetPositions(self.positions()))
+            .finish()
+    }
+}
+
+struct SetPositions(Positions);
+
+impl fmt::Debug for SetPositions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+// Flags are passed by value everywhere, so they must be `Copy`.
+pub trait BairiakEnum: Sized + Copy {
+    fn get_zero_bairiak() -> Bairiak;
+    fn to_u8(self) -> u8;
+
+    // The runtime reads positions through `position`, which will outgrow `u8`
+    // once more than 256 flags are supported. Until then it defaults to
+    // `to_u8`; the next major release deprecates `to_u8` in its favour, and
+    // implementations should then define `position` instead.
+    #[inline]
+    fn position(&self) -> u16 {
+        (*self).to_u8() as u16
+    }
+
+    // The variant at `position`, or `None` if no variant is defined there.
+    fn from_u8(position: u8) -> Option<Self>;
+
+    // A mask of the positions of all variants.
+    fn defined_mask() -> u128 {
+        (0..128)
+            .filter(|&position| Self::from_u8(position).is_some())
+            .fold(0, |mask, position| mask | 1 << position)
+    }
+
+    fn variant_count() -> u32 {
+        Self::defined_mask().count_ones()
+    }
+
+    // The name of the variant as written in the spec.
+    fn name(&self) -> &'static str;
+
+    // All variants in position order.
+    fn all_variants() -> Vec<Self> {
+        (0..128).filter_map(Self::from_u8).collect()
+    }
+
+    // A value of the enum's width with only this flag set. A flag beyond the
+    // width, which only hand-written impls can have, gives the zero value.
+    fn mask(&self) -> Bairiak {
+        let mut bairiak = Self::get_zero_bairiak();
+        bairiak.set(*self);
+        bairiak
+    }
+
+    // Like `mask`, for flags at hand by value.
+    fn into_bairiak(self) -> Bairiak {
+        self.mask()
+    }
+}
+
+// What `Bairiak::retain_for` does with set bits that no variant defines.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum UndefinedBits {
+    Keep,
+    Clear,
+}
+
+impl Bairiak {
+    #[inline]
+    #[must_use]
+    pub fn is_false<B: BairiakEnum>(&self, flag: B) -> bool {
+        // A flag beyond the width is never set.
+        let shift = flag.position() as u32;
+        match self {
+            Bairiak::U8(value) => *value & 1u8.checked_shl(shift).unwrap_or(0) == 0,
+            Bairiak::U16(value) => *value & 1u16.checked_shl(shift).unwrap_or(0) == 0,
+            Bairiak::U32(value) => *value & 1u32.checked_shl(shift).unwrap_or(0) == 0,
+            Bairiak::U64(value) => *value & 1u64.checked_shl(shift).unwrap_or(0) == 0,
+            Bairiak::U128(value) => *value & 1u128.checked_shl(shift).unwrap_or(0) == 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_true<B: BairiakEnum>(&self, flag: B) -> bool {
+        !self.is_false(flag)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bits(&self) -> u128 {
+        match *self {
+            Bairiak::U8(value) => value as u128,
+            Bairiak::U16(value) => value as u128,
+            Bairiak::U32(value) => value as u128,
+            Bairiak::U64(value) => value as u128,
+            Bairiak::U128(value) => value,
+        }
+    }
+
+    pub fn positions(&self) -> Positions {
+        Positions { bits: self.bits() }
+    }
+
+    // Setting, clearing and toggling a flag come in two kinds. `set`, `clear`
+    // and `toggle` ignore flags beyond the width, as happens when a value of a
+    // narrower, older version of the enum meets a newer variant. `try_set`,
+    // `try_clear` and `try_toggle` leave the value alone and fail with a
+    // `PositionOutOfRangeError` recording the position instead. `Extend`,
+    // `FromIterator` and the position setters ignore such flags like `set`,
+    // while `generate_bairiak` requires every flag to fit.
+    #[inline]
+    pub fn set<B: BairiakEnum>(&mut self, flag: B) {
+        if let Some(shift) = self.shift_within(flag.position()) {
+            self.set_bit(shift);
+        }
+    }
+
+    #[inline]
+    pub fn clear<B: BairiakEnum>(&mut self, flag: B) {
+        if let Some(shift) = self.shift_within(flag.position()) {
+            self.clear_bit(shift);
+        }
+    }
+
+    #[inline]
+    pub fn toggle<B: BairiakEnum>(&mut self, flag: B) {
+        if let Some(shift) = self.shift_within(flag.position()) {
+            self.toggle_bit(shift);
+        }
+    }
+
+    pub fn try_set<B: BairiakEnum>(&mut self, flag: B) -> Result<()> {
+        self.check_position(flag.position())?;
+        self.set(flag);
+        Ok(())
+    }
+
+    pub fn try_clear<B: BairiakEnum>(&mut self, flag: B) -> Result<()> {
+        self.check_position(flag.position())?;
+        self.clear(flag);
+        Ok(())
+    }
+
+    pub fn try_toggle<B: BairiakEnum>(&mut self, flag: B) -> Result<()> {
+        self.check_position(flag.position())?;
+        self.toggle(flag);
+        Ok(())
+    }
+
+    fn check_position(&self, position: u16) -> Result<()> {
+        if position < self.width() as u16 {
+            return Ok(());
+        }
+        Err(BairiakError::new(
+            BairiakErrorKind::PositionOutOfRangeError,
+            format!(
+                "Position {} is out of range for a u{} value.",
+                position,
+                self.width()
+            ),
+        )
+        .with_position(position))
+    }
+
+    // Counts the set flags of `B`, ignoring bits `B` doesn't define.
+    pub fn count_set_for<B: BairiakEnum>(&self) -> u32 {
+        (self.bits() & B::defined_mask()).count_ones()
+    }
+
+    #[must_use]
+    pub fn has_at_least_for<B: BairiakEnum>(&self, n: u32) -> bool {
+        self.count_set_for::<B>() >= n
+    }
+
+    // The names of the set flags of `B`, in ascending position, for logs that
+    // want names rather than bits. Bits `B` doesn't define have no name and
+    // are left out; `serde_names` writes them as `bit(N)` instead.
+    pub fn to_names_for<B: BairiakEnum>(&self) -> Vec<&'static str> {
+        self.positions()
+            .filter_map(B::from_u8)
+            .map(|flag| flag.name())
+            .collect()
+    }
+
+    // The flag of `B` at the lowest position that isn't set, such as the first
+    // of a sequence of steps not yet completed. `None` if every flag is set.
+    // Bits `B` doesn't define are ignored.
+    pub fn first_clear_for<B: BairiakEnum>(&self) -> Option<B> {
+        let clear = B::defined_mask() & !self.bits();
+        if clear == 0 {
+            return None;
+        }
+        B::from_u8(clear.trailing_zeros() as u8)
+    }
+
+    // The flags of `B` that aren't set, in ascending position. Bits `B`
+    // doesn't define are ignored.
+    pub fn clear_flags_for<B: BairiakEnum>(&self) -> Vec<B> {
+        Positions {
+            bits: B::defined_mask() & !self.bits(),
+        }
+        .filter_map(B::from_u8)
+        .collect()
+    }
+
+    // Counts how many of `flags` are set. Each flag counts once, however often
+    // it appears.
+    pub fn count_set_among<B, I>(&self, flags: I) -> u32
+    where
+        B: BairiakEnum,
+        I: IntoIterator<Item = B>,
+    {
+        let mask = flags.into_iter().fold(0u128, |mask, flag| {
+            mask | 1u128.checked_shl(flag.position() as u32).unwrap_or(0)
+        });
+        (self.bits() & mask).count_ones()
+    }
+
+    // Positions outside of the width are ignored.
+    #[inline]
+    pub fn clear_position(&mut self, position: u8) {
+        if let Some(shift) = self.shift_within(position as u16) {
+            self.clear_bit(shift);
+        }
+    }
+
+    // Clears every set flag of `B` for which `f` returns false. Set bits that
+    // `B` doesn't define are kept or cleared according to `undefined`.
+    pub fn retain_for<B, F>(&mut self, undefined: UndefinedBits, mut f: F)
+    where
+        B: BairiakEnum,
+        F: FnMut(B) -> bool,
+    {
+        for position in self.positions() {
+            let retain = match B::from_u8(position) {
+                Some(flag) => f(flag),
+                None => undefined == UndefinedBits::Keep,
+            };
+            if !retain {
+                self.clear_position(position);
+            }
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> u8 {
+        match self {
+            Bairiak::U8(_) => 8,
+            Bairiak::U16(_) => 16,
+            Bairiak::U32(_) => 32,
+            Bairiak::U64(_) => 64,
+            Bairiak::U128(_) => 128,
+        }
+    }
+
+    // The union has the wider of both widths, so no set bit is lost.
+    #[must_use]
+    pub fn union(&self, other: &Bairiak) -> Bairiak {
+        Bairiak::with_width(self.width().max(other.width()), self.bits() | other.bits())
+    }
+
+    // Bits beyond `width` are dropped.
+    pub(crate) fn with_width(width: u8, bits: u128) -> Bairiak {
+        match width {
+            8 => Bairiak::U8(bits as u8),
+            16 => Bairiak::U16(bits as u16),
+            32 => Bairiak::U32(bits as u32),
+            64 => Bairiak::U64(bits as u64),
+            _ => Bairiak::U128(bits),
+        }
+    }
+
+    // Positions outside of the width are ignored.
+    #[inline]
+    pub fn set_position(&mut self, position: u8) {
+        if let Some(shift) = self.shift_within(position as u16) {
+            self.set_bit(shift);
+        }
+    }
+
+    // The shift of `position` if it is within the width, for the setters that
+    // ignore positions beyond it.
+    #[inline]
+    fn shift_within(&self, position: u16) -> Option<u32> {
+        (position < self.width() as u16).then_some(position as u32)
+    }
+
+    // Callers check `shift` against the width, which debug builds assert.
+    // Release builds still ignore bits outside of it rather than wrap.
+    #[inline]
+    fn set_bit(&mut self, shift: u32) {
+        self.debug_assert_within(shift);
+        match self {
+            Bairiak::U8(value) => *value |= 1u8.checked_shl(shift).unwrap_or(0),
+            Bairiak::U16(value) => *value |= 1u16.checked_shl(shift).unwrap_or(0),
+            Bairiak::U32(value) => *value |= 1u32.checked_shl(shift).unwrap_or(0),
+            Bairiak::U64(value) => *value |= 1u64.checked_shl(shift).unwrap_or(0),
+            Bairiak::U128(value) => *value |= 1u128.checked_shl(shift).unwrap_or(0),
+        }
+    }
+
+    #[inline]
+    fn clear_bit(&mut self, shift: u32) {
+        self.debug_assert_within(shift);
+        match self {
+            Bairiak::U8(value) => *value &= !1u8.checked_shl(shift).unwrap_or(0),
+            Bairiak::U16(value) => *value &= !1u16.checked_shl(shift).unwrap_or(0),
+            Bairiak::U32(value) => *value &= !1u32.checked_shl(shift).unwrap_or(0),
+            Bairiak::U64(value) => *value &= !1u64.checked_shl(shift).unwrap_or(0),
+            Bairiak::U128(value) => *value &= !1u128.checked_shl(shift).unwrap_or(0),
+        }
+    }
+
+    #[inline]
+    fn toggle_bit(&mut self, shift: u32) {
+        self.debug_assert_within(shift);
+        match self {
+            Bairiak::U8(value) => *value ^= 1u8.checked_shl(shift).unwrap_or(0),
+            Bairiak::U16(value) => *value ^= 1u16.checked_shl(shift).unwrap_or(0),
+            Bairiak::U32(value) => *value ^= 1u32.checked_shl(shift).unwrap_or(0),
+            Bairiak::U64(value) => *value ^= 1u64.checked_shl(shift).unwrap_or(0),
+            Bairiak::U128(value) => *value ^= 1u128.checked_shl(shift).unwrap_or(0),
+        }
+    }
+
+    #[inline]
+    fn debug_assert_within(&self, shift: u32) {
+        debug_assert!(
+            shift < self.width() as u32,
+            "Bit {} is out of range for a u{} value.",
+            shift,
+            self.width()
+        );
+    }
+}
+
+// Collecting starts from `B`'s zero value, so an empty iterator yields a value
+// of `B`'s width.
+impl<B: BairiakEnum> FromIterator<B> for Bairiak {
+    fn from_iter<I: IntoIterator<Item = B>>(flags: I) -> Self {
+        let mut bairiak = B::get_zero_bairiak();
+        bairiak.extend(flags);
+        bairiak
+    }
+}
+
+impl<B: BairiakEnum> Extend<B> for Bairiak {
+    fn extend<I: IntoIterator<Item = B>>(&mut self, flags: I) {
+        for flag in flags {
+            self.set(flag);
+        }
+    }
+}
+
+// Iterates the positions of the set bits in ascending order.
+#[derive(Debug, Clone)]
+pub struct Positions {
+    bits: u128,
+}
+
+impl Iterator for Positions {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let position = self.bits.trailing_zeros() as u8;
+        self.bits &= self.bits - 1;
+        Some(position)
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct EnumSpec {
+    enums: Vec<Enum>,
+    #[serde(default)]
+    settings: Settings,
+    // The spec's own version, recorded in the manifest.
+    #[serde(default)]
+    version: Option<String>,
+    // Layouts packing several enums into one value.
+    #[serde(default)]
+    packed: Vec<packed::Packed>,
+}
+
+impl EnumSpec {
+    // Checks the spec for everything generation would reject, without
+    // generating any code.
+    pub fn validate(&self) -> Result<()> {
+        for e in &self.enums {
+            validate_enum(e, &self.settings)?;
+            validate_derives(e, self.settings.derives_for(e))?;
+            validate_lint_attrs(e, self.settings.lint_attrs_for(e))?;
+        }
+        for layout in &self.packed {
+            packed::validate_packed(self, layout)?;
+        }
+        Ok(())
+    }
+
+    // Like `validate`, but reports every problem of every enum instead of
+    // stopping at the first, in spec order. Empty if the spec is valid.
+    pub fn validate_all(&self) -> Vec<SpecProblem> {
+        let mut problems = problems::Problems::All(Vec::new());
+        let mut free_consts = HashMap::new();
+        for e in &self.enums {
+            let found = problems.len();
+            check_enum(e, &self.settings, &mut problems);
+            // An enum with problems of its own may repeat them here.
+            if e.position_consts == Some(ConstPlacement::Free) && problems.len() == found {
+                problems.check(&e.name, claim_position_consts(e, &mut free_consts));
+            }
+            problems.check(&e.name, validate_derives(e, self.settings.derives_for(e)));
+            problems.check(
+                &e.name,
+                validate_lint_attrs(e, self.settings.lint_attrs_for(e)),
+            );
+        }
+        for layout in &self.packed {
+            problems.check(layout.name(), packed::validate_packed(self, layout));
+        }
+        problems.into_vec()
+    }
+
+    pub(crate) fn find_enum(&self, enum_name: &str) -> Result<&Enum> {
+        self.enums
+            .iter()
+            .find(|e| e.name == enum_name)
+            .ok_or_else(|| {
+                let suggestion =
+                    suggest::did_you_mean(enum_name, self.enums.iter().map(|e| e.name.as_str()));
+                BairiakError::new(
+                    BairiakErrorKind::UnknownEnumError,
+                    format!("Unknown enum: {}.{}", enum_name, suggestion),
+                )
+                .logged()
+            })
+    }
+}
+
+// Defaults for every enum of a spec. A setting of an enum overrides the
+// spec's, which overrides `GenerateOptions`.
+#[derive(Debug, Deserialize, Default)]
+struct Settings {
+    #[serde(default)]
+    visibility: Option<Visibility>,
+    #[serde(default)]
+    derives: Vec<String>,
+    #[serde(default)]
+    naming: Naming,
+    // `false` drops the `#[allow(dead_code)]` of the generated items, so that
+    // unused flags are warned about.
+    #[serde(default)]
+    allow_dead_code: Option<bool>,
+    // Allows `clippy::upper_case_acronyms` on the enums with a name like
+    // `HTTP` or a variant like `TCPFlags`.
+    #[serde(default)]
+    allow_upper_case_acronyms: bool,
+    // More lint attributes for every enum, like `warn(missing_docs)`.
+    #[serde(default)]
+    lint_attrs: Vec<String>,
+    // The kinds of warnings that fail validation instead.
+    #[serde(default)]
+    warnings_as_errors: Vec<SpecWarningKind>,
+}
+
+// Whether names may contain `_`, as they always could.
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Naming {
+    #[default]
+    Lenient,
+    Strict,
+}
+
+impl Settings {
+    // Whether the spec sets nothing, as when it has no `settings` block.
+    fn is_default(&self) -> bool {
+        self.visibility.is_none()
+            && self.derives.is_empty()
+            && self.naming == Naming::Lenient
+            && self.allow_dead_code.is_none()
+            && !self.allow_upper_case_acronyms
+            && self.lint_attrs.is_empty()
+            && self.warnings_as_errors.is_empty()
+    }
+
+    fn visibility_for(&self, e: &Enum, options: &GenerateOptions) -> Visibility {
+        e.visibility
+            .or(self.visibility)
+            .unwrap_or(options.visibility)
+    }
+
+    // The derives of an enum replace the spec's rather than adding to them.
+    fn derives_for<'a>(&'a self, e: &'a Enum) -> &'a [String] {
+        e.derives.as_deref().unwrap_or(&self.derives)
+    }
+
+    // Like derives, the lint attributes of an enum replace the spec's.
+    fn lint_attrs_for<'a>(&'a self, e: &'a Enum) -> &'a [String] {
+        e.lint_attrs.as_deref().unwrap_or(&self.lint_attrs)
+    }
+
+    // The attribute of every generated item, unless dropped.
+    fn dead_code_allow(&self) -> &'static str {
+        if self.allow_dead_code.unwrap_or(true) {
+            "#[allow(dead_code)]\n"
+        } else {
+            ""
+        }
+    }
+
+    // The lint attributes of the enum itself, one per line. Lints the
+    // defaults already allow are skipped.
+    fn enum_lints(&self, e: &Enum) -> String {
+        let mut lints = String::from(self.dead_code_allow());
+        let has_acronym = iter::once(e.name.as_str())
+            .chain(e.positioned_variants().map(|(_, v)| v))
+            .any(is_acronym_style);
+        if self.allow_upper_case_acronyms && has_acronym {
+            lints.push_str("#[allow(clippy::upper_case_acronyms)]\n");
+        }
+        for attr in self.lint_attrs_for(e) {
+            let attr = format!("#[{}]\n", attr);
+            if !lints.contains(&attr) {
+                lints.push_str(&attr);
+            }
+        }
+        lints
+    }
+}
+
+// Two uppercase letters in a row, as in `HTTP` or `TCPFlags`.
+fn is_acronym_style(name: &str) -> bool {
+    name.as_bytes()
+        .windows(2)
+        .any(|pair| pair.iter().all(u8::is_ascii_uppercase))
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct Enum {
+    name: String,
+    variants: Vec<Variant>,
+    // An enum defined earlier whose variants come before this one's. Resolved
+    // when parsing, after which `variants` holds both.
+    #[serde(default)]
+    extends: Option<String>,
+    #[serde(default)]
+    migrations: Vec<Migration>,
+    #[serde(default)]
+    arbitrary: bool,
+    #[serde(default)]
+    json_schema: bool,
+    // Derives `defmt::Format`, for which the crate compiling the generated
+    // code depends on `defmt`.
+    #[serde(default)]
+    defmt: bool,
+    #[serde(default)]
+    emit_bitflags: bool,
+    // Emits `<enum>Bools`, a struct of one `bool` per variant.
+    #[serde(default)]
+    bool_struct: bool,
+    #[serde(default)]
+    visibility: Option<Visibility>,
+    #[serde(default)]
+    derives: Option<Vec<String>>,
+    #[serde(default)]
+    lint_attrs: Option<Vec<String>>,
+    #[serde(default)]
+    from_str: Option<FromStrMode>,
+    // How the generated `FromStr` finds a name, chosen by the number of names
+    // unless given.
+    #[serde(default)]
+    from_str_lookup: Option<FromStrLookup>,
+    #[serde(default)]
+    match_macro: bool,
+    // How `from_u8` and `name()` find a variant, a `match` unless given.
+    #[serde(default)]
+    lookup: Option<Lookup>,
+    // Forces the width instead of fitting it to the positions.
+    #[serde(default)]
+    bits: Option<u8>,
+    // Emits the position of every variant as `<VARIANT>_POS`, and with
+    // `position_masks` its mask as `<VARIANT>_MASK`.
+    #[serde(default)]
+    position_consts: Option<ConstPlacement>,
+    #[serde(default)]
+    position_masks: bool,
+    // Named sets of variants, resolved at runtime by `groups()` and `group()`.
+    #[serde(default)]
+    groups: Vec<Group>,
+    // How `group()` matches names, exactly unless given.
+    #[serde(default)]
+    group_lookup: Option<FromStrMode>,
+    // Sets of variants of which at most one may be set.
+    #[serde(default)]
+    exclusive: Vec<Vec<String>>,
+    // Registers the enum in `bairiak::registry`.
+    #[serde(default)]
+    registry: bool,
+    // Emits a `<enum>_abi` module of layout constants for FFI checks.
+    #[serde(default)]
+    abi: bool,
+    // Requires every variant to give its `position`, so that reordering the
+    // spec can't renumber them.
+    #[serde(default)]
+    lock_positions: bool,
+    // The cargo feature of the crate compiling the generated code that
+    // experimental variants are only available with.
+    #[serde(default)]
+    experimental_feature: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+struct Group {
+    name: String,
+    variants: Vec<String>,
+}
+
+// Either `Name` or `{ name: Name, position: 4, aliases: [..] }`. A variant
+// without a position takes the one after the previous entry's. `~` and `_` are
+// placeholders that take a position without defining a variant, as does a
+// variant with `status: removed`, keeping its name in the spec.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+enum Variant {
+    Placeholder,
+    Name(String),
+    Detailed {
+        name: String,
+        #[serde(default)]
+        position: Option<u16>,
+        // Other names `FromStr` accepts for the variant.
+        #[serde(default)]
+        aliases: Vec<String>,
+        // Documents the variant in the generated code and the manifest.
+        #[serde(default)]
+        doc: Option<String>,
+        // Why the variant should no longer be used.
+        #[serde(default)]
+        deprecated: Option<String>,
+        // The name of a `FlagStatus`, checked when validating so that a
+        // typo gets a message of its own.
+        #[serde(default)]
+        status: Option<String>,
+        // Variants that must be set whenever this one is.
+        #[serde(default)]
+        requires: Vec<String>,
+        // The variant the generated `Default` impl returns, for at most one
+        // variant of an enum.
+        #[serde(default)]
+        default: bool,
+        // An identifier of the variant that survives renumbering, for
+        // analytics. Unique within the enum; once a variant has one, every
+        // variant needs one.
+        #[serde(default)]
+        id: Option<u32>,
+    },
+}
+
+// How the generated `FromStr` matches names: exactly, or ignoring ASCII case.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum FromStrMode {
+    Exact,
+    CaseInsensitive,
+}
+
+// A `match` comparing the input with each name in turn, or one on its bytes,
+// which compiles to a search by length and then byte by byte and is several
+// times faster for many names.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum FromStrLookup {
+    Match,
+    Bytes,
+}
+
+// A `match` on the position or the variant, or static tables indexed by the
+// position, which compile to much less code for many variants at the cost of
+// a slot per position.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Lookup {
+    Match,
+    Table,
+}
+
+// Where the position constants go: on the enum, as `Perm::READ_POS`, or next
+// to it.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum ConstPlacement {
+    Associated,
+    Free,
+}
+
+#[cfg(test)]
+impl From<&str> for Variant {
+    fn from(name: &str) -> Variant {
+        Variant::Name(name.to_string())
+    }
+}
+
+#[cfg(test)]
+impl From<String> for Variant {
+    fn from(name: String) -> Variant {
+        Variant::Name(name)
+    }
+}
+
+impl Variant {
+    fn doc(&self) -> Option<&str> {
+        match self {
+            Variant::Detailed { doc, .. } => doc.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn deprecated(&self) -> Option<&str> {
+        match self {
+            Variant::Detailed { deprecated, .. } => deprecated.as_deref(),
+            _ => None,
+        }
+    }
+
+    // Stable unless given, or deprecated with a `deprecated` note.
+    fn status(&self) -> FlagStatus {
+        match self {
+            Variant::Detailed {
+                status: Some(status),
+                ..
+            } => FlagStatus::from_spec(status).unwrap_or(FlagStatus::Stable),
+            Variant::Detailed {
+                deprecated: Some(_),
+                ..
+            } => FlagStatus::Deprecated,
+            _ => FlagStatus::Stable,
+        }
+    }
+
+    fn is_removed(&self) -> bool {
+        self.status() == FlagStatus::Removed
+    }
+
+    fn requires(&self) -> &[String] {
+        match self {
+            Variant::Detailed { requires, .. } => requires,
+            _ => &[],
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        matches!(self, Variant::Detailed { default: true, .. })
+    }
+
+    fn id(&self) -> Option<u32> {
+        match self {
+            Variant::Detailed { id, .. } => *id,
+            _ => None,
+        }
+    }
+}
+
+impl Enum {
+    // Each entry's position and variant name, `None` for placeholders.
+    fn entries(&self) -> impl Iterator<Item = (usize, Option<&str>)> {
+        let mut next = 0;
+        self.variants.iter().map(move |v| {
+            let (position, name) = match v {
+                Variant::Placeholder => (next, None),
+                Variant::Name(name) if name == "_" => (next, None),
+                Variant::Name(name) => (next, Some(name.as_str())),
+                Variant::Detailed { name, position, .. } => (
+                    position.map_or(next, |position| position as usize),
+                    (!v.is_removed()).then_some(name.as_str()),
+                ),
+            };
+            next = position + 1;
+            (position, name)
+        })
+    }
+
+    // Each variant's position, name and spec entry, in spec order.
+    fn documented_variants(&self) -> impl Iterator<Item = (usize, &str, &Variant)> {
+        self.entries()
+            .zip(&self.variants)
+            .filter_map(|((position, name), v)| Some((position, name?, v)))
+    }
+
+    // Each variant's position and name, in spec order.
+    fn positioned_variants(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.entries()
+            .filter_map(|(position, name)| Some((position, name?)))
+    }
+
+    fn variant_count(&self) -> usize {
+        self.positioned_variants().count()
+    }
+
+    // The names `FromStr` matches, lowercased with `case_insensitive`, each
+    // with its variant.
+    fn parse_names(&self, mode: FromStrMode) -> Vec<(String, &str)> {
+        let mut names = Vec::new();
+        for v in &self.variants {
+            let (name, aliases) = match v {
+                Variant::Placeholder => continue,
+                Variant::Name(name) if name == "_" => continue,
+                Variant::Detailed { .. } if v.is_removed() => continue,
+                Variant::Name(name) => (name, &[][..]),
+                Variant::Detailed { name, aliases, .. } => (name, &aliases[..]),
+            };
+            for key in std::iter::once(name).chain(aliases) {
+                let key = match mode {
+                    FromStrMode::Exact => key.clone(),
+                    FromStrMode::CaseInsensitive => key.to_ascii_lowercase(),
+                };
+                names.push((key, name.as_str()));
+            }
+        }
+        names
+    }
+
+    // The width of the enum's `Bairiak` values, from its highest entry and
+    // `bits`.
+    fn width(&self) -> Result<u8> {
+        if let Some(bits) = self.bits.filter(|bits| !WIDTHS.contains(bits)) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Invalid width `bits: {}` of `{}`. Width should be 8, 16, 32, 64 or 128.",
+                    bits, self.name
+                ),
+            )
+            .logged());
+        }
+
+        let (max_position, name) = self
+            .entries()
+            .max_by_key(|&(position, _)| position)
+            .unwrap_or((0, None));
+        select_width(max_position, self.bits).ok_or_else(|| {
+            let entry = match name {
+                Some(name) => format!("`{}`", name),
+                None => String::from("A placeholder"),
+            };
+            let limit = match self.bits {
+                Some(bits) => format!("`bits: {}` holds positions up to {}", bits, bits - 1),
+                None => String::from("Maximum positions supported is 128"),
+            };
+            BairiakError::new(
+                BairiakErrorKind::PositionOutOfRangeError,
+                format!(
+                    "Position of {} in `{}` out of range: {}. {}.",
+                    entry, self.name, max_position, limit
+                ),
+            )
+            .logged()
+        })
+    }
+
+    // The mask of the variants named `names`, `None` if one of them is
+    // unknown.
+    fn variants_mask(&self, names: &[String]) -> Option<u128> {
+        names.iter().try_fold(0, |mask, name| {
+            let (position, _) = self.positioned_variants().find(|&(_, v)| v == name)?;
+            Some(mask | 1 << position)
+        })
+    }
+
+    // Like `variants_mask`, for names validation has already checked.
+    fn validated_mask(&self, names: &[String]) -> u128 {
+        // Validating the enum rejects unknown names.
+        #[allow(clippy::expect_used)]
+        self.variants_mask(names).expect("validated variant names")
+    }
+
+    // Expects the enum to have been validated.
+    fn all_mask(&self) -> u128 {
+        self.positioned_variants()
+            .fold(0, |mask, (position, _)| mask | 1 << position)
+    }
+
+    // Whether any variant gives its `status`, which generates `status()`.
+    fn has_status(&self) -> bool {
+        self.variants.iter().any(|v| {
+            matches!(
+                v,
+                Variant::Detailed {
+                    status: Some(_),
+                    ..
+                }
+            )
+        })
+    }
+
+    // Whether any variant gives its `id`, which generates `stable_id()`.
+    fn has_ids(&self) -> bool {
+        self.variants.iter().any(|v| v.id().is_some())
+    }
+
+    // The positions and names of the removed variants, in spec order.
+    fn removed_variants(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.entries()
+            .zip(&self.variants)
+            .filter_map(|((position, _), v)| match v {
+                Variant::Detailed { name, .. } if v.is_removed() => Some((position, name.as_str())),
+                _ => None,
+            })
+    }
+
+    // The feature the variant named `name` is only available with, if it is
+    // experimental and the enum gates experimental variants.
+    fn gate(&self, name: &str) -> Option<&str> {
+        let feature = self.experimental_feature.as_deref()?;
+        self.documented_variants()
+            .any(|(_, v, spec)| v == name && spec.status() == FlagStatus::Experimental)
+            .then_some(feature)
+    }
+
+    // Whether some variants are only available with a feature, so that the
+    // constants depending on all variants depend on the feature too.
+    fn is_gated(&self) -> bool {
+        self.positioned_variants()
+            .any(|(_, v)| self.gate(v).is_some())
+    }
+
+    // The mask of the variants available without the experimental feature.
+    fn ungated_mask(&self) -> u128 {
+        self.positioned_variants()
+            .filter(|&(_, v)| self.gate(v).is_none())
+            .fold(0, |mask, (position, _)| mask | 1 << position)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Migration {
+    from: String,
+    to: String,
+    positions: BTreeMap<u8, Option<u8>>,
+}
+
+// Names start with an ASCII uppercase letter, followed by ASCII letters and
+// digits, and `_` unless naming is strict. Describes the first character that
+// breaks the rule, if any.
+fn camel_case_violation(s: &str, naming: Naming) -> Option<String> {
+    let mut chars = s.chars().enumerate();
+    match chars.next() {
+        None => return Some(String::from("it is empty")),
+        Some((_, c)) if c.is_ascii_uppercase() => {}
+        Some((i, c)) => {
+            return Some(format!(
+                "`{}` at index {} is not an ASCII uppercase letter",
+                c, i
+            ))
+        }
+    }
+
+    let underscores = naming == Naming::Lenient;
+    chars
+        .find(|&(_, c)| !(c.is_ascii_alphanumeric() || underscores && c == '_'))
+        .map(|(i, c)| {
+            format!(
+                "`{}` at index {} is not an ASCII letter{}",
+                c,
+                i,
+                if underscores {
+                    ", digit or `_`"
+                } else {
+                    " or digit"
+                }
+            )
+        })
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut snake = String::new();
+    let mut chars = s.chars().peekable();
+    let mut prev: Option<char> = None;
+    while let Some(c) = chars.next() {
+        if let (true, Some(prev)) = (c.is_uppercase(), prev) {
+            let next_is_lower = chars.peek().is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+        prev = Some(c);
+    }
+    snake
+}
+
+fn to_screaming_snake_case(s: &str) -> String {
+    to_snake_case(s).to_ascii_uppercase()
+}
+
+const WIDTHS: [u8; 5] = [8, 16, 32, 64, 128];
+
+// The width holding positions up to `max_position`: `bits` if it is forced,
+// otherwise the smallest. `None` if it doesn't fit. Expects `bits` to be one
+// of `WIDTHS`.
+fn select_width(max_position: usize, bits: Option<u8>) -> Option<u8> {
+    let fits = |width: u8| max_position < width as usize;
+    match bits {
+        Some(bits) => fits(bits).then_some(bits),
+        None => WIDTHS.into_iter().find(|&width| fits(width)),
+    }
+}
+
+fn width_for(variants_len: usize) -> Result<u8> {
+    select_width(variants_len.saturating_sub(1), None).ok_or_else(|| {
+        BairiakError::new(
+            BairiakErrorKind::PositionOutOfRangeError,
+            format!(
+                "Position out of range: {}. Maximum positions supported is 128.",
+                variants_len
+            ),
+        )
+        .logged()
+    })
+}
+
+// Above this many names, `FromStr` matches bytes unless told otherwise.
+const FROM_STR_BYTES_THRESHOLD: usize = 32;
+
+const DERIVES: [&str; 6] = ["Hash", "Eq", "PartialEq", "Debug", "Clone", "Copy"];
+
+fn is_derive_path(s: &str) -> bool {
+    s.split("::").all(|segment| {
+        let mut chars = segment.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+fn validate_derives(e: &Enum, derives: &[String]) -> Result<()> {
+    for derive in derives {
+        if !is_derive_path(derive) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Invalid derive `{}` for `{}`. Derives should be paths like `serde::Serialize`.",
+                    derive, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+
+    let derives_default = derives.iter().any(|derive| is_default_derive(derive));
+    if derives_default && !e.variants.iter().any(Variant::is_default) {
+        return Err(BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!(
+                "`{}` derives `Default`, which needs a variant marked `default`.",
+                e.name
+            ),
+        )
+        .logged());
+    }
+    Ok(())
+}
+
+// A lint level with the lints it applies to, like `allow(clippy::all)`.
+fn is_lint_attr(s: &str) -> bool {
+    let Some((level, lints)) = s.split_once('(') else {
+        return false;
+    };
+    let Some(lints) = lints.strip_suffix(')') else {
+        return false;
+    };
+    matches!(level, "allow" | "warn" | "deny" | "forbid" | "expect")
+        && lints.split(',').all(|lint| is_derive_path(lint.trim()))
+}
+
+fn validate_lint_attrs(e: &Enum, lint_attrs: &[String]) -> Result<()> {
+    match lint_attrs.iter().find(|attr| !is_lint_attr(attr)) {
+        Some(attr) => Err(BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!(
+                "Invalid lint attribute `{}` for `{}`. Lint attributes should be like `allow(clippy::enum_variant_names)`.",
+                attr, e.name
+            ),
+        )
+        .logged()),
+        None => Ok(()),
+    }
+}
+
+// `Perm` for `my_crate::flags::Perm`, for messages about a type.
+pub(crate) fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn is_default_derive(derive: &str) -> bool {
+    matches!(
+        derive,
+        "Default" | "std::default::Default" | "core::default::Default"
+    )
+}
+
+fn validate_enum(e: &Enum, settings: &Settings) -> Result<()> {
+    let mut problems = problems::Problems::First(None);
+    check_enum(e, settings, &mut problems);
+    problems.into_result()
+}
+
+// Records the problems of `e` in `problems` until it says to stop. Problems
+// that make the rest unreliable, like positions out of range, always stop.
+fn check_enum(e: &Enum, settings: &Settings, problems: &mut problems::Problems) {
+    if let Some(violation) = camel_case_violation(&e.name, settings.naming) {
+        let err = BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!(
+                "Invalid enum name `{}`: {}. Enum name should be in CamelCase.",
+                e.name, violation
+            ),
+        )
+        .logged();
+        if !problems.add(&e.name, None, err) {
+            return;
+        }
+    }
+
+    if e.variant_count() == 0 {
+        let err = BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!("Enum variants of `{}` cannot be empty.", e.name),
+        )
+        .logged();
+        problems.add(&e.name, None, err);
+        return;
+    }
+
+    // Every position is below 128 from here on.
+    if let Err(err) = e.width() {
+        problems.add(&e.name, None, err);
+        return;
+    }
+
+    let mut positions = 0u128;
+    let mut default = None;
+    let has_ids = e.has_ids();
+    let mut ids = HashMap::new();
+    for (index, ((position, name), v)) in e.entries().zip(&e.variants).enumerate() {
+        // A removed variant still takes its position.
+        let name = match v {
+            Variant::Detailed { name: removed, .. } if v.is_removed() => Some(removed.as_str()),
+            _ => name,
+        };
+        let Some(name) = name else {
+            continue;
+        };
+
+        if let Err(err) = validate_status(e, name, v) {
+            if !problems.add(&e.name, Some((index, name)), err) {
+                return;
+            }
+        }
+
+        if v.is_default() {
+            if let Some(first) = default {
+                let err = BairiakError::new(
+                    BairiakErrorKind::ParseBairiakEnumsError,
+                    format!(
+                        "Variant `{}` of `{}` is marked `default`, but `{}` already is.",
+                        name, e.name, first
+                    ),
+                )
+                .logged();
+                if !problems.add(&e.name, Some((index, name)), err) {
+                    return;
+                }
+            }
+            default.get_or_insert(name);
+        }
+
+        // A removed variant keeps its id, so that it isn't reused.
+        match v.id() {
+            Some(id) => {
+                if let Some(taken) = ids.insert(id, name) {
+                    let err = BairiakError::new(
+                        BairiakErrorKind::ParseBairiakEnumsError,
+                        format!(
+                            "Duplicate id {} in `{}`: `{}` takes the id of `{}`.",
+                            id, e.name, name, taken
+                        ),
+                    )
+                    .logged();
+                    if !problems.add(&e.name, Some((index, name)), err) {
+                        return;
+                    }
+                }
+            }
+            None if has_ids && !v.is_removed() => {
+                let err = BairiakError::new(
+                    BairiakErrorKind::ParseBairiakEnumsError,
+                    format!(
+                        "Variant `{}` of `{}` has no `id`, which every variant needs once one has.",
+                        name, e.name
+                    ),
+                )
+                .logged();
+                if !problems.add(&e.name, Some((index, name)), err) {
+                    return;
+                }
+            }
+            None => {}
+        }
+
+        let unpositioned = matches!(
+            v,
+            Variant::Name(_) | Variant::Detailed { position: None, .. }
+        );
+        if e.lock_positions && unpositioned {
+            let err = BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Variant `{}` of `{}` has no `position`, which `lock_positions` requires.",
+                    name, e.name
+                ),
+            )
+            .logged();
+            if !problems.add(&e.name, Some((index, name)), err) {
+                return;
+            }
+        }
+
+        if positions & 1 << position != 0 {
+            let err = BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Duplicate position {} in `{}`: `{}` takes a position already taken.",
+                    position, e.name, name
+                ),
+            )
+            .logged();
+            if !problems.add(&e.name, Some((index, name)), err) {
+                return;
+            }
+        }
+        positions |= 1 << position;
+
+        if let Some(violation) = camel_case_violation(name, settings.naming) {
+            let err = BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Invalid enum variant `{}` in `{}`: {}. Enum variant should be in CamelCase.",
+                    name, e.name, violation
+                ),
+            )
+            .logged();
+            if !problems.add(&e.name, Some((index, name)), err) {
+                return;
+            }
+        }
+    }
+
+    if let Some(mode) = e.from_str {
+        if !problems.check(&e.name, validate_parse_names(e, mode)) {
+            return;
+        }
+    } else if e.from_str_lookup.is_some() {
+        let err = BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!("`from_str_lookup` of `{}` requires `from_str`.", e.name),
+        )
+        .logged();
+        if !problems.add(&e.name, None, err) {
+            return;
+        }
+    }
+
+    let consts = match e.position_consts {
+        Some(placement) => {
+            let mut taken = HashMap::new();
+            if placement == ConstPlacement::Associated {
+                for reserved in ["COUNT", "ALL_MASK"] {
+                    taken.insert(
+                        String::from(reserved),
+                        format!("`{}::{}`", e.name, reserved),
+                    );
+                }
+            }
+            claim_position_consts(e, &mut taken)
+        }
+        None if e.position_masks => Err(BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!(
+                "`position_masks` of `{}` requires `position_consts`.",
+                e.name
+            ),
+        )
+        .logged()),
+        None => Ok(()),
+    };
+    if !problems.check(&e.name, consts) {
+        return;
+    }
+
+    let checks = [
+        validate_groups,
+        validate_exclusive,
+        validate_requires,
+        validate_gate,
+        validate_bool_struct,
+        validate_bitflags,
+    ];
+    for check in checks {
+        if !problems.check(&e.name, check(e)) {
+            return;
+        }
+    }
+
+    for m in &e.migrations {
+        if !problems.check(&e.name, validate_migration(e, m)) {
+            return;
+        }
+    }
+
+    for warning in warnings::enum_warnings(e) {
+        if settings.warnings_as_errors.contains(&warning.kind) {
+            let err = BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "{} `warnings_as_errors` makes this an error.",
+                    warning.message
+                ),
+            )
+            .logged();
+            if !problems.add(&e.name, None, err) {
+                return;
+            }
+        }
+    }
+}
+
+// Every group name must pick a single group, the way `group()` matches it.
+fn validate_groups(e: &Enum) -> Result<()> {
+    let mode = e.group_lookup.unwrap_or(FromStrMode::Exact);
+    let mut seen = HashSet::new();
+    for group in &e.groups {
+        if group.name.is_empty() {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!("Empty group name in `{}`.", e.name),
+            )
+            .logged());
+        }
+
+        let key = match mode {
+            FromStrMode::Exact => group.name.clone(),
+            FromStrMode::CaseInsensitive => group.name.to_ascii_lowercase(),
+        };
+        if !seen.insert(key) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!("Duplicate group `{}` in `{}`.", group.name, e.name),
+            )
+            .logged());
+        }
+
+        if let Some(unknown) = unknown_variant(e, &group.variants) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Unknown variant `{}` in group `{}` of `{}`.",
+                    unknown, group.name, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+    Ok(())
+}
+
+fn validate_status(e: &Enum, name: &str, v: &Variant) -> Result<()> {
+    let Variant::Detailed {
+        status: Some(status),
+        deprecated,
+        aliases,
+        requires,
+        default,
+        ..
+    } = v
+    else {
+        return Ok(());
+    };
+
+    let message = match FlagStatus::from_spec(status) {
+        None => format!(
+            "Unknown status `{}` of `{}` in `{}`. Status should be experimental, stable, deprecated or removed.",
+            status, name, e.name
+        ),
+        Some(FlagStatus::Removed) if *default || !aliases.is_empty() || !requires.is_empty() => {
+            format!(
+                "Removed variant `{}` of `{}` can't be the default, have aliases or require other variants.",
+                name, e.name
+            )
+        }
+        Some(status) if status != FlagStatus::Deprecated && deprecated.is_some() => format!(
+            "Variant `{}` of `{}` has a `deprecated` note, but its status is `{}`.",
+            name, e.name, status
+        ),
+        Some(_) => return Ok(()),
+    };
+    Err(BairiakError::new(BairiakErrorKind::ParseBairiakEnumsError, message).logged())
+}
+
+// Experimental variants behind a feature are left out of the generated items
+// with `#[cfg]`, which the items listing every variant in one expression, or
+// mixing them into masks, can't be.
+fn validate_gate(e: &Enum) -> Result<()> {
+    let Some(feature) = &e.experimental_feature else {
+        return Ok(());
+    };
+    let error = |message| {
+        Err(BairiakError::new(BairiakErrorKind::ParseBairiakEnumsError, message).logged())
+    };
+    let is_feature = !feature.is_empty()
+        && feature
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !is_feature {
+        return error(format!(
+            "Invalid experimental feature `{}` of `{}`. Feature names should be like `experimental-flags`.",
+            feature, e.name
+        ));
+    }
+    if !e.is_gated() {
+        return Ok(());
+    }
+
+    let unsupported = [
+        ("arbitrary", e.arbitrary),
+        ("match_macro", e.match_macro),
+        ("emit_bitflags", e.emit_bitflags),
+        ("registry", e.registry),
+        ("abi", e.abi),
+        ("migrations", !e.migrations.is_empty()),
+        ("lookup: table", e.lookup == Some(Lookup::Table)),
+    ];
+    if let Some((option, _)) = unsupported.iter().find(|(_, enabled)| *enabled) {
+        return error(format!(
+            "`{}` gates experimental variants behind `{}`, which `{}` doesn't support.",
+            e.name, feature, option
+        ));
+    }
+
+    let mut references: Vec<(&str, String)> = Vec::new();
+    for group in &e.groups {
+        for v in &group.variants {
+            references.push((v, format!("group `{}`", group.name)));
+        }
+    }
+    for (i, group) in e.exclusive.iter().enumerate() {
+        for v in group {
+            references.push((v, format!("exclusive group {}", i)));
+        }
+    }
+    for (_, name, v) in e.documented_variants() {
+        if !v.requires().is_empty() {
+            references.push((name, String::from("`requires`")));
+        }
+        for required in v.requires() {
+            references.push((required, format!("`requires` of `{}`", name)));
+        }
+        if v.is_default() {
+            references.push((name, String::from("`default`")));
+        }
+    }
+    match references.iter().find(|(v, _)| e.gate(v).is_some()) {
+        Some((v, reference)) => error(format!(
+            "Experimental variant `{}` of `{}` is only available with `{}`, so {} can't refer to it.",
+            v, e.name, feature, reference
+        )),
+        None => Ok(()),
+    }
+}
+
+fn unknown_variant<'a>(e: &Enum, names: &'a [String]) -> Option<&'a str> {
+    names
+        .iter()
+        .find(|name| !e.positioned_variants().any(|(_, v)| v == name.as_str()))
+        .map(String::as_str)
+}
+
+// Requirements may be mutual. A variant requiring itself is always
+// satisfied, so it is only a `self_requirement` warning.
+fn validate_requires(e: &Enum) -> Result<()> {
+    for (_, name, v) in e.documented_variants() {
+        if let Some(unknown) = unknown_variant(e, v.requires()) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Unknown variant `{}` required by `{}` in `{}`.",
+                    unknown, name, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+    Ok(())
+}
+
+// Each variant with requirements and the mask of the variants it requires,
+// leaving out itself. Expects the enum to have been validated.
+fn requirements(e: &Enum) -> Vec<(usize, u128)> {
+    e.documented_variants()
+        .filter(|(_, _, v)| !v.requires().is_empty())
+        .map(|(position, _, v)| {
+            let mask = e.validated_mask(v.requires());
+            (position, mask & !(1 << position))
+        })
+        .collect()
+}
+
+// An exclusive group of fewer than two variants can never be violated, which
+// is more likely a mistake than intended.
+fn validate_exclusive(e: &Enum) -> Result<()> {
+    for (i, group) in e.exclusive.iter().enumerate() {
+        if let Some(unknown) = unknown_variant(e, group) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Unknown variant `{}` in exclusive group {} of `{}`.",
+                    unknown, i, e.name
+                ),
+            )
+            .logged());
+        }
+
+        let mask = e.validated_mask(group);
+        if mask.count_ones() < 2 {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Exclusive group {} of `{}` needs at least two distinct variants.",
+                    i, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+    Ok(())
+}
+
+// Records the names of the position constants of `e` in `taken`, failing on
+// one that is already taken, as names differing only in case or `_` convert
+// to the same constant.
+fn claim_position_consts(e: &Enum, taken: &mut HashMap<String, String>) -> Result<()> {
+    for (_, v) in e.positioned_variants() {
+        let name = to_screaming_snake_case(v);
+        let suffixes: &[&str] = if e.position_masks {
+            &["_POS", "_MASK"]
+        } else {
+            &["_POS"]
+        };
+        for suffix in suffixes {
+            let constant = format!("{}{}", name, suffix);
+            let owner = format!("the constant of `{}` in `{}`", v, e.name);
+            if let Some(other) = taken.get(&constant) {
+                return Err(BairiakError::new(
+                    BairiakErrorKind::ParseBairiakEnumsError,
+                    format!(
+                        "Position constant `{}` of `{}` in `{}` collides with {}.",
+                        constant, v, e.name, other
+                    ),
+                )
+                .logged());
+            }
+            taken.insert(constant, owner);
+        }
+    }
+    Ok(())
+}
+
+// Free position constants share the module, so they must not collide across
+// enums either.
+fn validate_free_consts(enums: &EnumSpec) -> Result<()> {
+    let mut taken = HashMap::new();
+    enums
+        .enums
+        .iter()
+        .filter(|e| e.position_consts == Some(ConstPlacement::Free))
+        .try_for_each(|e| claim_position_consts(e, &mut taken))
+}
+
+// A rough upper bound of the generated code's size, so that the buffer is
+// allocated once per enum in the common case.
+fn estimated_len(e: &Enum) -> usize {
+    512 + 128 * e.variants.len() + 256 * e.migrations.len()
+}
+
+// Each enum on its own, for parallel generation and `generate_enum`.
+fn enum_code(e: &Enum, settings: &Settings, options: &GenerateOptions) -> Result<String> {
+    let mut enum_code = String::with_capacity(estimated_len(e));
+    write_enum(e, settings, options, &mut enum_code)?;
+    Ok(enum_code)
+}
+
+fn write_enum(
+    e: &Enum,
+    settings: &Settings,
+    options: &GenerateOptions,
+    out: &mut String,
+) -> Result<()> {
+    debug!("Generating enum {}", e.name);
+    validate_enum(e, settings)?;
+    validate_derives(e, settings.derives_for(e))?;
+    validate_lint_attrs(e, settings.lint_attrs_for(e))?;
+    let width = e.width()?;
+    let visibility = options.item_visibility(settings.visibility_for(e, options));
+
+    let start = out.len();
+    // Writing to a `String` never fails.
+    #[allow(clippy::expect_used)]
+    write_enum_code(e, width, visibility, settings, options, out)
+        .expect("writing to a String cannot fail");
+    if options.module.is_some() {
+        let enum_code = out.split_off(start);
+        indent_into(&enum_code, out);
+    }
+    Ok(())
+}
+
+fn indent_into(code: &str, out: &mut String) {
+    for line in code.split_inclusive('\n') {
+        if line != "\n" {
+            out.push_str("    ");
+        }
+        out.push_str(line);
+    }
+}
+
+fn write_enum_code(
+    e: &Enum,
+    width: u8,
+    visibility: &str,
+    settings: &Settings,
+    options: &GenerateOptions,
+    out: &mut String,
+) -> fmt::Result {
+    let edition = options.rust_edition;
+    let extra_derives = settings.derives_for(e);
+    let allow = settings.dead_code_allow();
+    out.push_str("\n#[repr(u8)]\n");
+    out.push_str(&settings.enum_lints(e));
+    out.push_str("#[derive(");
+    out.push_str(&DERIVES.join(", "));
+    for derive in extra_derives {
+        if !DERIVES.contains(&derive.as_str()) {
+            write!(out, ", {}", derive)?;
+        }
+    }
+
+    // `defmt`'s derive refers to `defmt` by name, so it can't go through a
+    // re-export like the ones below.
+    if e.defmt && !extra_derives.iter().any(|derive| derive == "defmt::Format") {
+        out.push_str(", defmt::Format");
+    }
+
+    // Requires the `schemars` feature of bairiak wherever the generated code
+    // is compiled.
+    if e.json_schema {
+        write!(
+            out,
+            ", {0}::schemars::JsonSchema)]\n#[schemars(crate = \"{0}::schemars\")]\n",
+            edition.bairiak_path()
+        )?;
+    } else {
+        out.push_str(")]\n");
+    }
+
+    // With `Default` derived, the default variant is marked for the derive
+    // instead of getting an impl.
+    let derives_default = extra_derives.iter().any(|derive| is_default_derive(derive));
+    writeln!(out, "{}enum {} {{", visibility, e.name)?;
+    for (position, name, v) in e.documented_variants() {
+        write_variant_docs(v, e.gate(name), out);
+        write_gate(e, name, "    ", out);
+        if derives_default && v.is_default() {
+            out.push_str("    #[default]\n");
+        }
+        writeln!(out, "    {} = {},", name, position)?;
+    }
+
+    write!(
+        out,
+        "}}
+
+impl BairiakEnum for {name} {{
+    fn get_zero_bairiak() -> Bairiak {{
+        Bairiak::U{width}(0u{width})
+    }}
+
+    fn to_u8(self) -> u8 {{
+        self as u8
+    }}
+",
+        name = e.name,
+        width = width,
+    )?;
+    if e.lookup == Some(Lookup::Table) {
+        write_lookup_tables(e, out)?;
+    } else {
+        write_lookup_matches(e, out)?;
+    }
+    write!(
+        out,
+        "
+    fn defined_mask() -> u128 {{
+        {name}::ALL_MASK
+    }}
+
+    fn variant_count() -> u32 {{
+        {name}::COUNT as u32
+    }}
+}}
+
+{allow}impl {name} {{
+    pub const COUNT: usize = {count};
+    pub const ALL_MASK: u128 = {mask};
+",
+        name = e.name,
+        allow = allow,
+        count = gated_value(e, e.variant_count(), || e.ungated_mask().count_ones()),
+        mask = gated_value(e, format!("{:#x}", e.all_mask()), || {
+            format!("{:#x}", e.ungated_mask())
+        }),
+    )?;
+    write_variants_const(e, out)?;
+    if e.position_consts == Some(ConstPlacement::Associated) {
+        write_position_consts(e, width, "    pub ", out)?;
+    }
+    write!(
+        out,
+        "
+    pub const fn max_position() -> u8 {{
+        {}
+    }}
+}}
+",
+        gated_value(e, max_position(e.positioned_variants()), || {
+            max_position(
+                e.positioned_variants()
+                    .filter(|&(_, v)| e.gate(v).is_none()),
+            )
+        }),
+    )?;
+    if e.position_consts == Some(ConstPlacement::Free) {
+        out.push('\n');
+        write_position_consts(e, width, &format!("{}{}", allow, visibility), out)?;
+    }
+
+    if !e.groups.is_empty() {
+        write_groups(e, width, allow, out)?;
+    }
+
+    if !e.exclusive.is_empty() {
+        write_exclusive(e, allow, edition, out)?;
+    }
+
+    let requirements = requirements(e);
+    if !requirements.is_empty() {
+        write_requires(e, &requirements, allow, edition, out)?;
+    }
+
+    if let Some((_, default, _)) = e.documented_variants().find(|(_, _, v)| v.is_default()) {
+        if !derives_default {
+            write!(
+                out,
+                "
+impl Default for {name} {{
+    fn default() -> Self {{
+        {name}::{default}
+    }}
+}}
+",
+                name = e.name,
+                default = default,
+            )?;
+        }
+    }
+
+    if e.has_status() {
+        write_status(e, allow, edition, out)?;
+    }
+
+    if e.has_ids() {
+        write_stable_ids(e, allow, out)?;
+    }
+
+    if options.display {
+        write_display(e, edition, out)?;
+    }
+
+    if e.arbitrary {
+        write_arbitrary(e, edition, out)?;
+    }
+
+    if let Some(mode) = e.from_str {
+        write_from_str(e, mode, edition, out)?;
+    }
+
+    if e.match_macro {
+        write_match_macro(e, out)?;
+    }
+
+    if e.emit_bitflags {
+        write_bitflags(e, width, visibility, allow, edition, out)?;
+    }
+
+    if e.bool_struct {
+        write_bool_struct(e, visibility, extra_derives, allow, out)?;
+    }
+
+    if e.registry {
+        write_registration(e, width, edition, out)?;
+    }
+
+    if e.abi {
+        abi::write_abi(e, width, allow, out)?;
+    }
+
+    for m in &e.migrations {
+        write_migration(e, m, visibility, allow, out)?;
+    }
+
+    Ok(())
+}
+
+fn write_lookup_matches(e: &Enum, out: &mut String) -> fmt::Result {
+    out.push_str(
+        "
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+",
+    );
+    for (position, v) in e.positioned_variants() {
+        write_gate(e, v, "            ", out);
+        writeln!(out, "            {} => Some({}::{}),", position, e.name, v)?;
+    }
+
+    out.push_str(
+        "            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+",
+    );
+    for (_, v) in e.positioned_variants() {
+        write_gate(e, v, "            ", out);
+        writeln!(out, "            {}::{} => \"{}\",", e.name, v, v)?;
+    }
+    out.push_str("        }\n    }\n");
+    Ok(())
+}
+
+// A slot per position up to the highest, `None` and an empty name for the
+// positions no variant takes. The discriminant of a variant is its position,
+// so `name()` indexes within the table.
+fn write_lookup_tables(e: &Enum, out: &mut String) -> fmt::Result {
+    let mut slots: Vec<Option<&str>> = vec![None; max_position(e.positioned_variants()) + 1];
+    for (position, v) in e.positioned_variants() {
+        if let Some(slot) = slots.get_mut(position) {
+            *slot = Some(v);
+        }
+    }
+
+    write!(
+        out,
+        "
+    fn from_u8(position: u8) -> Option<Self> {{
+        static BY_POSITION: [Option<{}>; {}] = [
+",
+        e.name,
+        slots.len()
+    )?;
+    for slot in &slots {
+        match slot {
+            Some(v) => writeln!(out, "            Some({}::{}),", e.name, v)?,
+            None => out.push_str("            None,\n"),
+        }
+    }
+    write!(
+        out,
+        "        ];
+        BY_POSITION.get(position as usize).copied().flatten()
+    }}
+
+    fn name(&self) -> &'static str {{
+        static NAMES: [&str; {}] = [
+",
+        slots.len()
+    )?;
+    for slot in &slots {
+        writeln!(out, "            {:?},", slot.unwrap_or(""))?;
+    }
+    out.push_str(
+        "        ];
+        NAMES[*self as usize]
+    }
+",
+    );
+    Ok(())
+}
+
+fn max_position<'a>(variants: impl Iterator<Item = (usize, &'a str)>) -> usize {
+    variants.map(|(position, _)| position).max().unwrap_or(0)
+}
+
+// `value`, or with experimental variants behind a feature, an expression
+// picking it or `ungated` depending on the feature, if they differ.
+fn gated_value<T: fmt::Display, U: fmt::Display>(
+    e: &Enum,
+    value: T,
+    ungated: impl FnOnce() -> U,
+) -> String {
+    let value = value.to_string();
+    match &e.experimental_feature {
+        Some(feature) if e.is_gated() => {
+            let ungated = ungated().to_string();
+            if ungated == value {
+                return value;
+            }
+            format!(
+                "if cfg!(feature = {:?}) {{ {} }} else {{ {} }}",
+                feature, value, ungated
+            )
+        }
+        _ => value,
+    }
+}
+
+// Leaves the next item or arm, about the variant named `name`, out without
+// the experimental feature.
+fn write_gate(e: &Enum, name: &str, indent: &str, out: &mut String) {
+    if let Some(feature) = e.gate(name) {
+        out.push_str(indent);
+        out.push_str(&format!("#[cfg(feature = {:?})]\n", feature));
+    }
+}
+
+// The deprecation note goes in the docs rather than `#[deprecated]`, which
+// would warn in the generated impls themselves.
+fn write_variant_docs(v: &Variant, gate: Option<&str>, out: &mut String) {
+    let mut lines: Vec<&str> = v.doc().map_or_else(Vec::new, |doc| doc.lines().collect());
+    let note = match (v.status(), v.deprecated()) {
+        (FlagStatus::Deprecated, Some(note)) => Some(format!("Deprecated: {}", note)),
+        (FlagStatus::Deprecated, None) => Some(String::from("Deprecated.")),
+        (FlagStatus::Experimental, _) => Some(match gate {
+            Some(feature) => format!(
+                "Experimental: may change or be removed in any release. Only available with the `{}` feature.",
+                feature
+            ),
+            None => String::from("Experimental: may change or be removed in any release."),
+        }),
+        _ => None,
+    };
+    if let Some(note) = &note {
+        if !lines.is_empty() {
+            lines.push("");
+        }
+        lines.push(note);
+    }
+    for line in lines {
+        match line.trim_end() {
+            "" => out.push_str("    ///\n"),
+            line => {
+                out.push_str("    /// ");
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+    }
+}
+
+// One line per constant, each starting with `prefix`.
+fn write_position_consts(e: &Enum, width: u8, prefix: &str, out: &mut String) -> fmt::Result {
+    let indent = &prefix[..prefix.len() - prefix.trim_start().len()];
+    for (position, v) in e.positioned_variants() {
+        let name = to_screaming_snake_case(v);
+        write_gate(e, v, indent, out);
+        writeln!(out, "{}const {}_POS: u8 = {};", prefix, name, position)?;
+        if e.position_masks {
+            write_gate(e, v, indent, out);
+            writeln!(
+                out,
+                "{}const {}_MASK: u{} = {:#x};",
+                prefix,
+                name,
+                width,
+                1u128 << position
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// The masks are values of the enum's width. With `group_lookup:
+// case_insensitive`, `group()` ignores ASCII case without allocating.
+fn write_groups(e: &Enum, width: u8, allow: &str, out: &mut String) -> fmt::Result {
+    write!(
+        out,
+        "
+{}impl {} {{
+    pub fn groups() -> &'static [(&'static str, Bairiak)] {{
+        &[
+",
+        allow, e.name
+    )?;
+    for group in &e.groups {
+        writeln!(
+            out,
+            "            ({:?}, Bairiak::U{}({:#x})),",
+            group.name,
+            width,
+            e.validated_mask(&group.variants)
+        )?;
+    }
+
+    write!(
+        out,
+        "        ]
+    }}
+
+    pub fn group(name: &str) -> Option<Bairiak> {{
+        {}::groups()
+            .iter()
+            .find(|(group, _)| {})
+            .map(|&(_, mask)| mask)
+    }}
+}}
+",
+        e.name,
+        match e.group_lookup {
+            Some(FromStrMode::CaseInsensitive) => "group.eq_ignore_ascii_case(name)",
+            _ => "*group == name",
+        }
+    )
+}
+
+fn write_exclusive(e: &Enum, allow: &str, edition: RustEdition, out: &mut String) -> fmt::Result {
+    let masks: Vec<String> = e
+        .exclusive
+        .iter()
+        .map(|group| format!("{:#x}", e.validated_mask(group)))
+        .collect();
+    write!(
+        out,
+        "
+{allow}impl {name} {{
+    pub const EXCLUSIVE_MASKS: [u128; {len}] = [{masks}];
+
+    pub fn validate_exclusive(value: &Bairiak) -> Result<(), {bairiak}::ExclusiveViolation> {{
+        {bairiak}::check_exclusive_for::<{name}>(value, &{name}::EXCLUSIVE_MASKS)
+    }}
+}}
+",
+        name = e.name,
+        bairiak = edition.bairiak_path(),
+        allow = allow,
+        len = masks.len(),
+        masks = masks.join(", "),
+    )
+}
+
+fn write_requires(
+    e: &Enum,
+    requirements: &[(usize, u128)],
+    allow: &str,
+    edition: RustEdition,
+    out: &mut String,
+) -> fmt::Result {
+    let pairs: Vec<String> = requirements
+        .iter()
+        .map(|(position, mask)| format!("({}, {:#x})", position, mask))
+        .collect();
+    write!(
+        out,
+        "
+{allow}impl {name} {{
+    pub const REQUIREMENTS: [(u8, u128); {len}] = [{pairs}];
+
+    pub fn validate_requires(value: &Bairiak) -> Result<(), Vec<{bairiak}::RequiresViolation>> {{
+        {bairiak}::check_requires_for::<{name}>(value, &{name}::REQUIREMENTS)
+    }}
+}}
+",
+        name = e.name,
+        bairiak = edition.bairiak_path(),
+        allow = allow,
+        len = pairs.len(),
+        pairs = pairs.join(", "),
+    )
+}
+
+// `status_at` also knows the positions of removed variants, for deciding what
+// to do with a value persisted while they were still around.
+fn write_status(e: &Enum, allow: &str, edition: RustEdition, out: &mut String) -> fmt::Result {
+    let bairiak = edition.bairiak_path();
+    write!(
+        out,
+        "
+{}impl {} {{
+    pub const fn status(self) -> {}::FlagStatus {{
+        match self {{
+",
+        allow, e.name, bairiak
+    )?;
+    for (_, name, v) in e.documented_variants() {
+        write_gate(e, name, "            ", out);
+        writeln!(
+            out,
+            "            {}::{} => {},",
+            e.name,
+            name,
+            v.status().path(bairiak)
+        )?;
+    }
+    write!(
+        out,
+        "        }}
+    }}
+
+    pub fn status_at(position: u8) -> Option<{}::FlagStatus> {{
+        match position {{
+",
+        bairiak
+    )?;
+    for (position, _) in e.removed_variants() {
+        writeln!(
+            out,
+            "            {} => Some({}),",
+            position,
+            FlagStatus::Removed.path(bairiak)
+        )?;
+    }
+    write!(
+        out,
+        "            _ => <{} as BairiakEnum>::from_u8(position).map({}::status),
+        }}
+    }}
+}}
+",
+        e.name, e.name
+    )
+}
+
+// Validating the enum gives every variant an id once one does.
+#[allow(clippy::expect_used)]
+fn write_stable_ids(e: &Enum, allow: &str, out: &mut String) -> fmt::Result {
+    write!(
+        out,
+        "
+{}impl {} {{
+    pub const fn stable_id(&self) -> u32 {{
+        match self {{
+",
+        allow, e.name
+    )?;
+    for (_, name, v) in e.documented_variants() {
+        write_gate(e, name, "            ", out);
+        writeln!(
+            out,
+            "            {}::{} => {},",
+            e.name,
+            name,
+            v.id().expect("validated variant id")
+        )?;
+    }
+    out.push_str(
+        "        }
+    }
+
+    pub const fn from_stable_id(id: u32) -> Option<Self> {
+        match id {
+",
+    );
+    for (_, name, v) in e.documented_variants() {
+        write_gate(e, name, "            ", out);
+        writeln!(
+            out,
+            "            {} => Some({}::{}),",
+            v.id().expect("validated variant id"),
+            e.name,
+            name
+        )?;
+    }
+    out.push_str(
+        "            _ => None,
+        }
+    }
+}
+",
+    );
+    Ok(())
+}
+
+// Prints the variant's name, sharing the match of `BairiakEnum::name`. `pad`
+// honours width and alignment, as `Display` for `str` does.
+fn write_display(e: &Enum, edition: RustEdition, out: &mut String) -> fmt::Result {
+    write!(
+        out,
+        "
+impl {core}::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut {core}::fmt::Formatter<'_>) -> {core}::fmt::Result {{
+        f.pad(BairiakEnum::name(self))
+    }}
+}}
+",
+        name = e.name,
+        core = edition.core_path(),
+    )
+}
+
+// Neither lookup allocates: with `case_insensitive`, names are compared
+// ignoring ASCII case, or the input is lowercased into a buffer as long as
+// the longest name.
+fn write_from_str(
+    e: &Enum,
+    mode: FromStrMode,
+    edition: RustEdition,
+    out: &mut String,
+) -> fmt::Result {
+    let bairiak = edition.bairiak_path();
+    let names = e.parse_names(mode);
+    let lookup = e
+        .from_str_lookup
+        .unwrap_or(if names.len() > FROM_STR_BYTES_THRESHOLD {
+            FromStrLookup::Bytes
+        } else {
+            FromStrLookup::Match
+        });
+    let error = format!(
+        "{}::FlagParseError::new::<{name}>({name:?}, s)",
+        bairiak,
+        name = e.name
+    );
+    write!(
+        out,
+        "
+impl {core}::str::FromStr for {name} {{
+    type Err = {bairiak}::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+",
+        name = e.name,
+        core = edition.core_path(),
+        bairiak = bairiak,
+    )?;
+    match (lookup, mode) {
+        (FromStrLookup::Match, _) => out.push_str("        match s {\n"),
+        (FromStrLookup::Bytes, FromStrMode::Exact) => {
+            out.push_str("        match s.as_bytes() {\n")
+        }
+        (FromStrLookup::Bytes, FromStrMode::CaseInsensitive) => {
+            let len = names.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+            write!(
+                out,
+                "        let mut key = [0u8; {len}];
+        let Some(key) = key.get_mut(..s.len()) else {{
+            return Err({error});
+        }};
+        key.copy_from_slice(s.as_bytes());
+        key.make_ascii_lowercase();
+        match &*key {{
+",
+                len = len,
+                error = error,
+            )?;
+        }
+    }
+    for (key, v) in names {
+        write_gate(e, v, "            ", out);
+        match (lookup, mode) {
+            (FromStrLookup::Match, FromStrMode::Exact) => {
+                writeln!(out, "            {:?} => Ok({}::{}),", key, e.name, v)?
+            }
+            (FromStrLookup::Match, FromStrMode::CaseInsensitive) => writeln!(
+                out,
+                "            _ if s.eq_ignore_ascii_case({:?}) => Ok({}::{}),",
+                key, e.name, v
+            )?,
+            (FromStrLookup::Bytes, _) => writeln!(
+                out,
+                "            {} => Ok({}::{}),",
+                byte_string_literal(&key),
+                e.name,
+                v
+            )?,
+        }
+    }
+
+    write!(
+        out,
+        "            _ => Err({}),
+        }}
+    }}
+}}
+",
+        error
+    )
+}
+
+// `b"..."`, escaping what a byte string literal can't hold as it is.
+fn byte_string_literal(s: &str) -> String {
+    let mut literal = String::from("b\"");
+    for byte in s.bytes() {
+        match byte {
+            b'"' | b'\\' => {
+                literal.push('\\');
+                literal.push(byte as char);
+            }
+            b' '..=b'~' => literal.push(byte as char),
+            _ => literal.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+// Every variant in ascending position, for loops and `const` contexts that
+// don't import `BairiakEnum`. Gated variants are left out with their feature,
+// as `COUNT` counts them.
+fn write_variants_const(e: &Enum, out: &mut String) -> fmt::Result {
+    let mut variants: Vec<(usize, &str)> = e.positioned_variants().collect();
+    variants.sort_by_key(|&(position, _)| position);
+    writeln!(
+        out,
+        "    pub const VARIANTS: [{name}; {name}::COUNT] = [",
+        name = e.name
+    )?;
+    for (_, v) in variants {
+        write_gate(e, v, "        ", out);
+        writeln!(out, "        {}::{},", e.name, v)?;
+    }
+    out.push_str("    ];\n");
+    Ok(())
+}
+
+// `<enum>_match!(value, { Variant => expr, .. })` expands to a `match` over
+// all variants, and only accepts an arm for every variant in spec order, so
+// adding a variant breaks every use until it is handled. Being a
+// `macro_rules!`, it is in scope after the generated code in the same module.
+fn write_match_macro(e: &Enum, out: &mut String) -> fmt::Result {
+    write!(
+        out,
+        "
+#[allow(unused_macros)]
+macro_rules! {}_match {{
+    ($flag:expr, {{ ",
+        to_snake_case(&e.name)
+    )?;
+    for (i, (_, v)) in e.positioned_variants().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write!(out, "{} => $arm{}:expr", v, i)?;
+    }
+    out.push_str(" $(,)? }) => {\n        match $flag {\n");
+    for (i, (_, v)) in e.positioned_variants().enumerate() {
+        writeln!(out, "            {}::{} => $arm{},", e.name, v, i)?;
+    }
+    out.push_str("        }\n    };\n}\n");
+    Ok(())
+}
+
+// Requires the `proptest` feature of bairiak wherever the generated code is
+// compiled.
+fn write_arbitrary(e: &Enum, edition: RustEdition, out: &mut String) -> fmt::Result {
+    let variants: Vec<&str> = e.positioned_variants().map(|(_, v)| v).collect();
+    // An enum without variants has no value to generate.
+    let Some((last, rest)) = variants.split_last() else {
+        return Ok(());
+    };
+    write!(
+        out,
+        "
+impl {bairiak}::proptest::arbitrary::Arbitrary for {name} {{
+    type Parameters = ();
+    type Strategy = {bairiak}::proptest::strategy::BoxedStrategy<{name}>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {{
+        use {bairiak}::proptest::strategy::Strategy;
+        (0u8..{len})
+            .prop_map(|i| match i {{
+",
+        name = e.name,
+        len = variants.len(),
+        bairiak = edition.bairiak_path(),
+    )?;
+
+    for (i, v) in rest.iter().enumerate() {
+        writeln!(out, "                {} => {}::{},", i, e.name, v)?;
+    }
+
+    write!(
+        out,
+        "                _ => {name}::{last},
+            }})
+            .boxed()
+    }}
+}}
+",
+        name = e.name,
+    )
+}
+
+// Requires the `registry` feature of bairiak wherever the generated code is
+// compiled.
+fn write_registration(e: &Enum, width: u8, edition: RustEdition, out: &mut String) -> fmt::Result {
+    write!(
+        out,
+        "
+{bairiak}::registry::inventory::submit! {{
+    {bairiak}::registry::RegisteredEnum {{
+        name: {name:?},
+        width: {width},
+        variants: &[",
+        name = e.name,
+        width = width,
+        bairiak = edition.bairiak_path(),
+    )?;
+    for (i, (position, v)) in e.positioned_variants().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write!(out, "({}, {:?})", position, v)?;
+    }
+    out.push_str(
+        "],
+    }
+}
+",
+    );
+    Ok(())
+}
+
+// Requires the `bitflags` feature of bairiak wherever the generated code is
+// compiled. Converting from `Bairiak` drops the bits the enum doesn't define;
+// converting to `Bairiak` keeps every bit.
+fn write_bitflags(
+    e: &Enum,
+    width: u8,
+    visibility: &str,
+    allow: &str,
+    edition: RustEdition,
+    out: &mut String,
+) -> fmt::Result {
+    // Both attributes are indented inside the macro.
+    let allow = allow.replace('\n', "\n    ");
+    write!(
+        out,
+        "
+{bairiak}::bitflags::bitflags! {{
+    {allow}#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+    {visibility}struct {name}Flags: u{width} {{
+",
+        allow = allow,
+        visibility = visibility,
+        name = e.name,
+        width = width,
+        bairiak = edition.bairiak_path(),
+    )?;
+    for (position, v) in e.positioned_variants() {
+        writeln!(
+            out,
+            "        const {} = 1 << {};",
+            to_screaming_snake_case(v),
+            position
+        )?;
+    }
+
+    write!(
+        out,
+        "    }}
+}}
+
+impl From<{name}Flags> for Bairiak {{
+    fn from(flags: {name}Flags) -> Bairiak {{
+        Bairiak::from_flags_retain(&flags)
+    }}
+}}
+
+impl From<Bairiak> for {name}Flags {{
+    fn from(bairiak: Bairiak) -> {name}Flags {{
+        bairiak.to_flags_truncate()
+    }}
+}}
+",
+        name = e.name,
+    )
+}
+
+// The field of the variant named `name` in `<enum>Bools`: its name in
+// snake_case, as a raw identifier if it is a keyword. `self`, `super` and
+// `crate` can't be raw, so they get a trailing `_` instead.
+fn bool_field(name: &str) -> String {
+    let field = to_snake_case(name);
+    match field.as_str() {
+        "self" | "super" | "crate" => format!("{}_", field),
+        _ if RUST_KEYWORDS.contains(&field.as_str()) => format!("r#{}", field),
+        _ => field,
+    }
+}
+
+pub(crate) const RUST_KEYWORDS: [&str; 48] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+// Variants whose names only differ in case or underscores would share a field.
+fn validate_bool_struct(e: &Enum) -> Result<()> {
+    if !e.bool_struct {
+        return Ok(());
+    }
+    let mut fields: HashMap<String, &str> = HashMap::new();
+    for (_, v) in e.positioned_variants() {
+        let field = bool_field(v);
+        if let Some(other) = fields.insert(field.clone(), v) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Variants `{}` and `{}` of `{}` both take the field `{}` of `{}Bools`.",
+                    other, v, e.name, field, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+    Ok(())
+}
+
+// Variants whose names only differ in case or underscores would share a
+// constant of the `bitflags!` struct.
+fn validate_bitflags(e: &Enum) -> Result<()> {
+    if !e.emit_bitflags {
+        return Ok(());
+    }
+    let mut consts: HashMap<String, &str> = HashMap::new();
+    for (_, v) in e.positioned_variants() {
+        let constant = to_screaming_snake_case(v);
+        if let Some(other) = consts.insert(constant.clone(), v) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Variants `{}` and `{}` of `{}` both take the constant `{}` of `{}Flags`.",
+                    other, v, e.name, constant, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+    Ok(())
+}
+
+// Serde derives among the enum's derives are repeated on the struct, so that
+// it serializes wherever the enum does. Converting from `Bairiak` drops the
+// bits the enum doesn't define.
+fn write_bool_struct(
+    e: &Enum,
+    visibility: &str,
+    derives: &[String],
+    allow: &str,
+    out: &mut String,
+) -> fmt::Result {
+    write!(
+        out,
+        "\n{}#[derive(Default, Hash, Eq, PartialEq, Debug, Clone, Copy",
+        allow
+    )?;
+    for derive in derives {
+        if derive.ends_with("Serialize") || derive.ends_with("Deserialize") {
+            write!(out, ", {}", derive)?;
+        }
+    }
+    writeln!(out, ")]\n{}struct {}Bools {{", visibility, e.name)?;
+    for (_, v) in e.positioned_variants() {
+        write_gate(e, v, "    ", out);
+        writeln!(out, "    {}{}: bool,", visibility, bool_field(v))?;
+    }
+
+    write!(
+        out,
+        "}}
+
+impl From<&Bairiak> for {name}Bools {{
+    fn from(bairiak: &Bairiak) -> {name}Bools {{
+        {name}Bools {{
+",
+        name = e.name
+    )?;
+    for (_, v) in e.positioned_variants() {
+        write_gate(e, v, "            ", out);
+        writeln!(
+            out,
+            "            {}: bairiak.is_true({}::{}),",
+            bool_field(v),
+            e.name,
+            v
+        )?;
+    }
+
+    write!(
+        out,
+        "        }}
+    }}
+}}
+
+impl From<&{name}Bools> for Bairiak {{
+    fn from(bools: &{name}Bools) -> Bairiak {{
+        let mut bairiak = {name}::get_zero_bairiak();
+",
+        name = e.name
+    )?;
+    for (_, v) in e.positioned_variants() {
+        write_gate(e, v, "        ", out);
+        writeln!(
+            out,
+            "        if bools.{} {{\n            bairiak.set({}::{});\n        }}",
+            bool_field(v),
+            e.name,
+            v
+        )?;
+    }
+    out.push_str("        bairiak\n    }\n}\n");
+    Ok(())
+}
+
+fn is_migration_version(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Every name `FromStr` matches must pick a single variant.
+fn validate_parse_names(e: &Enum, mode: FromStrMode) -> Result<()> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for (key, v) in e.parse_names(mode) {
+        if key.is_empty() {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!("Empty alias of `{}` in `{}`.", v, e.name),
+            )
+            .logged());
+        }
+
+        if let Some(other) = seen.insert(key.clone(), v) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Ambiguous name `{}` in `{}`: it matches both `{}` and `{}`.",
+                    key, e.name, other, v
+                ),
+            )
+            .logged());
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_migration(e: &Enum, m: &Migration) -> Result<()> {
+    if !is_migration_version(&m.from) || !is_migration_version(&m.to) {
+        return Err(BairiakError::new(
+            BairiakErrorKind::ParseBairiakEnumsError,
+            format!(
+                "Invalid migration version in `{}`. Migration versions should be alphanumeric.",
+                e.name
+            ),
+        )
+        .logged());
+    }
+
+    for (&old_position, &new_position) in &m.positions {
+        if old_position >= 128 {
+            return Err(BairiakError::new(
+                BairiakErrorKind::PositionOutOfRangeError,
+                format!(
+                    "Migration position out of range: {}. Maximum positions supported is 128.",
+                    old_position
+                ),
+            )
+            .logged());
+        }
+
+        if let Some(new_position) = new_position
+            .filter(|&new_position| new_position >= 128 || e.all_mask() & 1 << new_position == 0)
+        {
+            return Err(BairiakError::new(
+                BairiakErrorKind::PositionOutOfRangeError,
+                format!(
+                    "Migration position out of range: {}. `{}` has no variant at that position.",
+                    new_position, e.name
+                ),
+            )
+            .logged());
+        }
+    }
+
+    Ok(())
+}
+
+// Expects `m` to have been validated along with its enum.
+fn write_migration(
+    e: &Enum,
+    m: &Migration,
+    visibility: &str,
+    allow: &str,
+    out: &mut String,
+) -> fmt::Result {
+    write!(
+        out,
+        "
+{}{}fn migrate_{}_{}_to_{}(old: Bairiak) -> Bairiak {{
+    let mut new = {}::get_zero_bairiak();
+    for position in old.positions() {{
+        match position {{
+",
+        allow,
+        visibility,
+        to_snake_case(&e.name),
+        m.from.to_lowercase(),
+        m.to.to_lowercase(),
+        e.name,
+    )?;
+    for (&old_position, &new_position) in &m.positions {
+        let Some(new_position) = new_position else {
+            continue;
+        };
+
+        writeln!(
+            out,
+            "            {} => new.set_position({}),",
+            old_position, new_position
+        )?;
+    }
+
+    out.push_str(
+        "            _ => {}
+        }
+    }
+    new
+}
+",
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "rayon"))]
+fn enums_code(enums: &EnumSpec, options: &GenerateOptions) -> Result<String> {
+    let mut enums_code = String::with_capacity(enums.enums.iter().map(estimated_len).sum());
+    for e in &enums.enums {
+        write_enum(e, &enums.settings, options, &mut enums_code)?;
+    }
+    validate_free_consts(enums)?;
+    enums_code.push_str(&packed::packed_code(enums, options)?);
+    Ok(enums_code)
+}
+
+// Every enum is generated before any result is looked at, so the output and
+// the error returned match the serial path: the first failing enum in spec
+// order wins. Later failures are still logged.
+#[cfg(feature = "rayon")]
+fn enums_code(enums: &EnumSpec, options: &GenerateOptions) -> Result<String> {
+    use rayon::prelude::*;
+
+    let results: Vec<Result<String>> = enums
+        .enums
+        .par_iter()
+        .map(|e| enum_code(e, &enums.settings, options))
+        .collect();
+    let mut enums_code = String::new();
+    for code in results {
+        enums_code.push_str(&code?);
+    }
+    validate_free_consts(enums)?;
+    enums_code.push_str(&packed::packed_code(enums, options)?);
+    Ok(enums_code)
+}
+
+/// The code of every enum of `spec`, in spec order, as `generate_bairiak_enums`
+/// writes it, but without the header, the imports and the module around it.
+/// This is for embedding the enums in a file generated by other means; the
+/// code expects `Bairiak` and `BairiakEnum` to be in scope.
+///
+/// The items generated for a spec and their behaviour are stable: the enums,
+/// their variants and discriminants, the `BairiakEnum` impls, and the
+/// constants, functions and impls enabled in the spec. Formatting, comments,
+/// attributes such as `#[allow(dead_code)]` and the order of items and derives
+/// may change in any release.
+///
+/// With `GenerateOptions::module`, the code is indented to go inside that
+/// module and private enums are `pub(super)`, but the module item is left to
+/// the caller.
+///
+/// ```
+/// use bairiak::{generate_enums, load_spec, GenerateOptions};
+///
+/// let spec = load_spec("test_data/valid_spec.yaml")?;
+/// let mut file = String::from("// Flags of our service.\n");
+/// file.push_str("use bairiak::{Bairiak, BairiakEnum};\n");
+/// file.push_str(&generate_enums(&spec, &GenerateOptions::default())?);
+/// assert!(file.contains("enum TestEnum {"));
+/// # Ok::<(), bairiak::BairiakError>(())
+/// ```
+pub fn generate_enums(spec: &EnumSpec, options: &GenerateOptions) -> Result<String> {
+    options.validate()?;
+    validate_not_empty(spec, options)?;
+    enums_code(spec, options)
+}
+
+/// The code of the enum of `spec` named `enum_name`, like `generate_enums`
+/// with the same stability. An unknown name fails with
+/// `BairiakErrorKind::UnknownEnumError`.
+///
+/// ```
+/// use bairiak::{generate_enum, load_spec, GenerateOptions};
+///
+/// let spec = load_spec("test_data/multi_enum_spec.yaml")?;
+/// let options = GenerateOptions::default().module("flags");
+/// let mut file = String::from("pub mod flags {\n    use bairiak::{Bairiak, BairiakEnum};\n");
+/// file.push_str(&generate_enum(&spec, "Permissions", &options)?);
+/// file.push_str("}\n");
+/// assert!(file.contains("    pub(super) enum Permissions {"));
+/// # Ok::<(), bairiak::BairiakError>(())
+/// ```
+pub fn generate_enum(
+    spec: &EnumSpec,
+    enum_name: &str,
+    options: &GenerateOptions,
+) -> Result<String> {
+    options.validate()?;
+    enum_code(spec.find_enum(enum_name)?, &spec.settings, options)
+}
+
+// A file with nothing but whitespace and comments would otherwise fail with
+// serde's generic error about a missing value, which doesn't say what is wrong.
+fn parse_spec(yaml_content: &str) -> Result<EnumSpec> {
+    debug!("Parsing spec");
+    let blank = yaml_content.lines().all(|line| {
+        let line = line.trim();
+        line.is_empty() || line.starts_with('#')
+    });
+    if blank {
+        return Err(BairiakError::new(
+            BairiakErrorKind::EmptySpecError,
+            String::from("The spec has no content. It should list its enums under `enums`."),
+        )
+        .logged());
+    }
+    let mut spec = documents::parse_documents(yaml_content)?;
+    inherit::resolve_extends(&mut spec)?;
+    Ok(spec)
+}
+
+// A spec listing no enums generates no code, which is usually a mistake,
+// unless the caller fills the spec in later.
+fn validate_not_empty(spec: &EnumSpec, options: &GenerateOptions) -> Result<()> {
+    if spec.enums.is_empty() && !options.allow_empty {
+        return Err(BairiakError::new(
+            BairiakErrorKind::EmptySpecError,
+            String::from(
+                "The spec lists no enums. Add some under `enums`, or allow empty specs with `GenerateOptions::allow_empty`.",
+            ),
+        )
+        .logged());
+    }
+    Ok(())
+}
+
+fn read_spec_file(bairiak_spec_path: &Path) -> Result<String> {
+    debug!("Reading spec {}", bairiak_spec_path.display());
+    fs::read_to_string(bairiak_spec_path).map_err(|err| {
+        BairiakError::new(
+            BairiakErrorKind::ReadSpecError,
+            format!("{}: {}", bairiak_spec_path.display(), err),
+        )
+        .with_path(bairiak_spec_path)
+        .logged()
+    })
+}
+
+pub fn load_spec(bairiak_spec_path: impl AsRef<Path>) -> Result<EnumSpec> {
+    parse_spec(&read_spec_file(bairiak_spec_path.as_ref())?)
+}
+
+const GENERATED_MARKER: &str = "@generated";
+const HASH_PREFIX: &str = "// bairiak-hash: ";
+// The imports, given the path of bairiak.
+fn imports_code(bairiak: &str, prelude: bool) -> String {
+    if prelude {
+        format!("use {}::prelude::*;\n", bairiak)
+    } else {
+        format!("use {}::{{Bairiak, BairiakEnum}};\n", bairiak)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    fnv1a_64_update(FNV_OFFSET_BASIS, bytes)
+}
+
+// Continues a hash over more bytes, so that content can be hashed in pieces.
+fn fnv1a_64_update(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+// The header records a hash of the body below it, so that hand edits to a
+// generated file can be detected before it is overwritten.
+fn header(body_hash: u64) -> String {
+    format!(
+        "// {} by bairiak. Do not edit by hand.\n{}{:016x}\n",
+        GENERATED_MARKER, HASH_PREFIX, body_hash
+    )
+}
+
+// The hash in the header of a generated file, if its body still matches it.
+// Reads the file in pieces, as generated files can be large.
+fn unmodified_body_hash(path: &Path) -> Option<u64> {
+    let file = File::open(path).ok()?;
+    let mut reader = BufReader::new(file);
+    let (mut marker, mut hash) = (String::new(), String::new());
+    if reader.read_line(&mut marker).is_err() || reader.read_line(&mut hash).is_err() {
+        return None;
+    }
+
+    let mut body_hash = FNV_OFFSET_BASIS;
+    loop {
+        match reader.fill_buf() {
+            Ok([]) => break,
+            Ok(bytes) => {
+                body_hash = fnv1a_64_update(body_hash, bytes);
+                let len = bytes.len();
+                reader.consume(len);
+            }
+            Err(_) => return None,
+        }
+    }
+
+    (marker.contains(GENERATED_MARKER)
+        && hash.strip_prefix(HASH_PREFIX) == Some(&format!("{:016x}\n", body_hash)))
+    .then_some(body_hash)
+}
+
+// The code before the first enum: the imports, inside the module if any. A
+// spec without enums generates nothing to import for.
+fn body_start(enums: &EnumSpec, options: &GenerateOptions) -> String {
+    let imports = if enums.enums.is_empty() {
+        String::new()
+    } else {
+        imports_code(options.rust_edition.bairiak_path(), options.prelude)
+    };
+    match &options.module {
+        Some(name) if imports.is_empty() => {
+            format!("{}mod {} {{\n", options.module_visibility(), name)
+        }
+        Some(name) => format!(
+            "{}mod {} {{\n    {}",
+            options.module_visibility(),
+            name,
+            imports
+        ),
+        None => imports,
+    }
+}
+
+fn body_end(options: &GenerateOptions) -> &'static str {
+    match options.module {
+        Some(_) => "}\n",
+        None => "",
+    }
+}
+
+pub fn generate_bairiak_enums_from_str(yaml_content: &str) -> Result<String> {
+    generate_bairiak_enums_from_str_with(yaml_content, &GenerateOptions::default())
+}
+
+pub fn generate_bairiak_enums_from_str_with(
+    yaml_content: &str,
+    options: &GenerateOptions,
+) -> Result<String> {
+    options.validate()?;
+    let enums = parse_spec(yaml_content)?;
+    let enums_code = generate_enums(&enums, options)?;
+    for warning in enums.warnings() {
+        warn!("{}", warning);
+    }
+
+    let body = format!(
+        "{}{}{}",
+        body_start(&enums, options),
+        enums_code,
+        body_end(options)
+    );
+    Ok(header(fnv1a_64(body.as_bytes())) + &body)
+}
+
+// Calls `f` with the code of each enum in spec order, holding the code of at
+// most one enum at a time.
+#[cfg(not(feature = "rayon"))]
+fn for_each_enum_code(
+    enums: &EnumSpec,
+    options: &GenerateOptions,
+    mut f: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let mut enum_code = String::new();
+    for e in &enums.enums {
+        enum_code.clear();
+        write_enum(e, &enums.settings, options, &mut enum_code)?;
+        f(&enum_code)?;
+    }
+    Ok(())
+}
+
+// Generates a few enums per thread at a time, so that memory stays bounded
+// while the first failing enum in spec order still wins.
+#[cfg(feature = "rayon")]
+fn for_each_enum_code(
+    enums: &EnumSpec,
+    options: &GenerateOptions,
+    mut f: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    use rayon::prelude::*;
+
+    for chunk in enums.enums.chunks(4 * rayon::current_num_threads()) {
+        let results: Vec<Result<String>> = chunk
+            .par_iter()
+            .map(|e| enum_code(e, &enums.settings, options))
+            .collect();
+        for enum_code in results {
+            f(&enum_code?)?;
+        }
+    }
+    Ok(())
+}
+
+// The header needs the hash of everything below it, so when streaming, the
+// enums are generated twice: once to hash them and once to write them.
+fn hash_enums(enums: &EnumSpec, options: &GenerateOptions) -> Result<u64> {
+    validate_not_empty(enums, options)?;
+    let mut body_hash = fnv1a_64(body_start(enums, options).as_bytes());
+    for_each_enum_code(enums, options, |enum_code| {
+        body_hash = fnv1a_64_update(body_hash, enum_code.as_bytes());
+        Ok(())
+    })?;
+    validate_free_consts(enums)?;
+    for warning in enums.warnings() {
+        warn!("{}", warning);
+    }
+    body_hash = fnv1a_64_update(body_hash, packed::packed_code(enums, options)?.as_bytes());
+    Ok(fnv1a_64_update(body_hash, body_end(options).as_bytes()))
+}
+
+fn write_enums<W: io::Write>(
+    enums: &EnumSpec,
+    options: &GenerateOptions,
+    body_hash: u64,
+    out: &mut W,
+    io_error: impl Fn(io::Error) -> BairiakError,
+) -> Result<()> {
+    out.write_all(header(body_hash).as_bytes())
+        .and_then(|()| out.write_all(body_start(enums, options).as_bytes()))
+        .map_err(&io_error)?;
+    for_each_enum_code(enums, options, |enum_code| {
+        out.write_all(enum_code.as_bytes()).map_err(&io_error)
+    })?;
+    out.write_all(packed::packed_code(enums, options)?.as_bytes())
+        .and_then(|()| out.write_all(body_end(options).as_bytes()))
+        .map_err(&io_error)
+}
+
+// Writes the same code as `generate_bairiak_enums_from_str`, one enum at a
+// time, without holding all of it in memory. Nothing is written if the spec
+// is invalid.
+pub fn generate_bairiak_enums_to_writer<W: io::Write>(
+    yaml_content: &str,
+    out: &mut W,
+) -> Result<()> {
+    let options = GenerateOptions::default();
+    let enums = parse_spec(yaml_content)?;
+    let body_hash = hash_enums(&enums, &options)?;
+    write_enums(&enums, &options, body_hash, out, BairiakError::from)
+}
+
+fn write_file_error(output_path: &Path, err: io::Error) -> BairiakError {
+    BairiakError::new(
+        BairiakErrorKind::WriteFileError,
+        format!("{}: {}", output_path.display(), err),
+    )
+    .with_path(output_path)
+    .logged()
+}
+
+// The code replaces the output through `replace_file`. An output that already
+// holds the code with `body_hash` is left alone, and `false` returned.
+fn write_output(
+    output_path: &Path,
+    force: bool,
+    body_hash: u64,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<()>,
+) -> Result<bool> {
+    if output_path.exists() {
+        let existing_hash = unmodified_body_hash(output_path);
+        if existing_hash == Some(body_hash) {
+            debug!("{} is up to date", output_path.display());
+            return Ok(false);
+        }
+
+        let unmodified = existing_hash.is_some();
+        if !unmodified && force {
+            warn!(
+                "Overwriting {}, which has been edited by hand",
+                output_path.display()
+            );
+        } else if !unmodified {
+            return Err(BairiakError::new(
+                BairiakErrorKind::OutputModifiedError,
+                format!(
+                    "{} was not generated by bairiak or has been edited by hand.",
+                    output_path.display()
+                ),
+            )
+            .with_path(output_path)
+            .logged());
+        }
+    }
+
+    replace_file(output_path, write).map(|()| true)
+}
+
+// Writes to a temporary file next to `output_path`, which then replaces it.
+// On failure the temporary file is removed, leaving the previous file, if any,
+// untouched.
+fn replace_file(
+    output_path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<()>,
+) -> Result<()> {
+    debug!("Writing {}", output_path.display());
+    let mut temp_path = output_path.as_os_str().to_owned();
+    temp_path.push(".bairiak-tmp");
+    let temp_path = PathBuf::from(temp_path);
+    let result = File::create(&temp_path)
+        .map_err(|err| write_file_error(&temp_path, err))
+        .and_then(|file| {
+            let mut out = BufWriter::new(file);
+            write(&mut out)?;
+            out.into_inner()
+                .map_err(|err| write_file_error(&temp_path, err.into_error()))?
+                .sync_all()
+                .map_err(|err| write_file_error(&temp_path, err))
+        })
+        .and_then(|()| {
+            fs::rename(&temp_path, output_path).map_err(|err| write_file_error(output_path, err))
+        });
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+fn invalid_output_path(output_path: &Path, message: String) -> BairiakError {
+    BairiakError::new(BairiakErrorKind::InvalidOutputPathError, message)
+        .with_path(output_path)
+        .logged()
+}
+
+// The output need not exist yet, so only its directory is canonicalized.
+fn resolve_output_path(output_path: &Path) -> Option<PathBuf> {
+    if let Ok(path) = output_path.canonicalize() {
+        return Some(path);
+    }
+    let parent = match output_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    Some(parent.canonicalize().ok()?.join(output_path.file_name()?))
+}
+
+// Catches swapped arguments and other outputs that would clobber something
+// other than generated code.
+fn validate_output_path(bairiak_spec_path: &Path, output_path: &Path, strict: bool) -> Result<()> {
+    if output_path.is_dir() {
+        return Err(invalid_output_path(
+            output_path,
+            format!("{} is a directory.", output_path.display()),
+        ));
+    }
+
+    if let (Ok(spec), Some(resolved_output)) = (
+        bairiak_spec_path.canonicalize(),
+        resolve_output_path(output_path),
+    ) {
+        if spec == resolved_output {
+            return Err(invalid_output_path(
+                output_path,
+                format!(
+                    "{} is the spec {}. Are the arguments swapped?",
+                    output_path.display(),
+                    bairiak_spec_path.display()
+                ),
+            ));
+        }
+    }
+
+    let extension = output_path.extension().and_then(|e| e.to_str());
+    if let Some(extension @ ("yaml" | "yml" | "json")) = extension {
+        if strict {
+            return Err(invalid_output_path(
+                output_path,
+                format!(
+                    "{} has a .{} extension, but the generated code is Rust.",
+                    output_path.display(),
+                    extension
+                ),
+            ));
+        }
+        warn!(
+            "Writing Rust code to {}, which has a .{} extension",
+            output_path.display(),
+            extension
+        );
+    }
+
+    Ok(())
+}
+
+pub fn generate_bairiak_enums(
+    bairiak_spec_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+) -> Result<GenerationReport> {
+    generate_bairiak_enums_with(bairiak_spec_path, output_path, &GenerateOptions::default())
+}
+
+// Like `generate_bairiak_enums`, but overwrites the output even if it has been
+// edited by hand.
+pub fn generate_bairiak_enums_forced(
+    bairiak_spec_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+) -> Result<GenerationReport> {
+    generate_bairiak_enums_with(
+        bairiak_spec_path,
+        output_path,
+        &GenerateOptions::default().force(true),
+    )
+}
+
+pub fn generate_bairiak_enums_with(
+    bairiak_spec_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+    options: &GenerateOptions,
+) -> Result<GenerationReport> {
+    generate_with_cache(
+        bairiak_spec_path.as_ref(),
+        output_path.as_ref(),
+        options,
+        None,
+    )
+}
+
+// Like `generate_bairiak_enums_with`, taking the parsed spec from `cache` if
+// the spec file is unchanged since it was last generated through it.
+pub fn generate_bairiak_enums_cached(
+    bairiak_spec_path: impl AsRef<Path>,
+    output_path: impl AsRef<Path>,
+    options: &GenerateOptions,
+    cache: &SpecCache,
+) -> Result<GenerationReport> {
+    generate_with_cache(
+        bairiak_spec_path.as_ref(),
+        output_path.as_ref(),
+        options,
+        Some(cache),
+    )
+}
+
+fn generate_with_cache(
+    bairiak_spec_path: &Path,
+    output_path: &Path,
+    options: &GenerateOptions,
+    cache: Option<&SpecCache>,
+) -> Result<GenerationReport> {
+    options.validate()?;
+    validate_output_path(bairiak_spec_path, output_path, options.strict)?;
+    let spec_content = read_spec_file(bairiak_spec_path)?;
+    let enums = match cache {
+        Some(cache) => cache.spec(bairiak_spec_path, &spec_content)?,
+        None => Arc::new(parse_spec(&spec_content)?),
+    };
+    let body_hash = hash_enums(&enums, options)?;
+    // The previous manifest records the positions persisted values were
+    // written with, so they are checked before anything is overwritten.
+    let manifest = match options.manifest {
+        true => Some((
+            Manifest::new(&enums, &spec_content)?,
+            manifest::manifest_path(output_path),
+        )),
+        false => None,
+    };
+    if let Some((manifest, manifest_path)) = &manifest {
+        if !options.allow_renumber {
+            manifest::check_renumbering(manifest_path, manifest)?;
+        }
+    }
+    let rewritten = match &options.region {
+        Some((begin, end)) => {
+            let mut code = Vec::new();
+            write_enums(&enums, options, body_hash, &mut code, BairiakError::from)?;
+            let code = String::from_utf8_lossy(&code);
+            region::write_region(
+                output_path,
+                (begin, end),
+                options.strict,
+                options.force,
+                &code,
+            )?
+        }
+        None => write_output(output_path, options.force, body_hash, |out| {
+            write_enums(&enums, options, body_hash, out, |err| {
+                write_file_error(output_path, err)
+            })
+        })?,
+    };
+    let manifest_path = match manifest {
+        Some((manifest, manifest_path)) => {
+            manifest::write_manifest(&manifest_path, &manifest)?;
+            Some(manifest_path)
+        }
+        None => None,
+    };
+    Ok(GenerationReport {
+        output_path: output_path.to_path_buf(),
+        rewritten,
+        manifest_path,
+        enums: report::enum_reports(&enums)?,
+    })
+}
+
+// The value only depends on which flags are given, not on their order or
+// how often each appears, so a `HashSet` iterating in any order gives the
+// same value. Each position must be below the width of `B`'s zero value,
+// which debug builds assert. Release builds ignore the flags beyond it.
+pub fn generate_bairiak<B, I>(flags: I) -> Bairiak
+where
+    B: BairiakEnum,
+    I: IntoIterator<Item = B>,
+{
+    let mut bairiak = B::get_zero_bairiak();
+    let width = bairiak.width();
+    for flag in flags {
+        let flag_value = flag.position();
+        debug_assert!(
+            flag_value < width as u16,
+            "Position {} of `{}` out of range: the zero value of its enum is {} bits wide.",
+            flag_value,
+            flag.name(),
+            width
+        );
+        bairiak.set(flag);
+    }
+    bairiak
+}
+
+// Like `generate_bairiak`, but fails with a `PositionOutOfRangeError` on the
+// first flag whose position doesn't fit the width of `B`'s zero value, for
+// `BairiakEnum` impls that aren't generated.
+pub fn generate_bairiak_checked<B, I>(flags: I) -> Result<Bairiak>
+where
+    B: BairiakEnum,
+    I: IntoIterator<Item = B>,
+{
+    let mut bairiak = B::get_zero_bairiak();
+    for flag in flags {
+        check_fits(&bairiak, flag)?;
+        bairiak.set(flag);
+    }
+    Ok(bairiak)
+}
+
+// Checks that every variant of `B` fits the width of its zero value, which
+// generated impls always do. Meant for the tests of a crate implementing
+// `BairiakEnum` by hand.
+pub fn validate_impl_for<B: BairiakEnum>() -> Result<()> {
+    let zero = B::get_zero_bairiak();
+    B::all_variants()
+        .into_iter()
+        .try_for_each(|flag| check_fits(&zero, flag))
+}
+
+fn check_fits<B: BairiakEnum>(bairiak: &Bairiak, flag: B) -> Result<()> {
+    let position = flag.position();
+    if position < bairiak.width() as u16 {
+        return Ok(());
+    }
+    Err(BairiakError::new(
+        BairiakErrorKind::PositionOutOfRangeError,
+        format!(
+            "Position {} of `{}` out of range: the zero value of its enum is {} bits wide.",
+            position,
+            flag.name(),
+            bairiak.width()
+        ),
+    )
+    .with_position(position))
+}
+
+// Like `generate_bairiak`, but accumulates the flags in a `u128` and picks the
+// width once at the end, without matching on it for every flag. Positions
+// beyond `B`'s width are ignored.
+pub fn generate_bairiak_from_slice<B: BairiakEnum>(flags: &[B]) -> Bairiak {
+    // Two halves with masks instead of branches keep the loop branch-free.
+    let (mut low, mut high) = (0u64, 0u64);
+    for &flag in flags {
+        let position = flag.position();
+        let bit = 1u64 << (position & 63);
+        low |= bit & ((position < 64) as u64).wrapping_neg();
+        high |= bit & ((64..128).contains(&position) as u64).wrapping_neg();
+    }
+    let mask = (high as u128) << 64 | low as u128;
+    Bairiak::with_width(B::get_zero_bairiak().width(), mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashSet, sync::Once};
+
+    static TEST_TEARDOWN: Once = Once::new();
+
+    // Test for Bairiak::is_false and Bairiak::is_true methods
+    #[repr(u8)]
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+    enum TestEnum {
+        Flag0 = 0,
+        Flag1 = 1,
+        Flag2 = 2,
+    }
+
+    impl BairiakEnum for TestEnum {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U8(0u8)
+        }
+
+        fn to_u8(self) -> u8 {
+            self as u8
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            match position {
+                0 => Some(TestEnum::Flag0),
+                1 => Some(TestEnum::Flag1),
+                2 => Some(TestEnum::Flag2),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match self {
+                TestEnum::Flag0 => "Flag0",
+                TestEnum::Flag1 => "Flag1",
+                TestEnum::Flag2 => "Flag2",
+            }
+        }
+    }
+
+    // Test for `position` defaulting to `to_u8` in impls that only define
+    // `to_u8`
+    #[test]
+    fn test_position_defaults_to_to_u8() {
+        assert_eq!(TestEnum::Flag2.position(), 2);
+
+        let mut bairiak = TestEnum::get_zero_bairiak();
+        bairiak.set(TestEnum::Flag2);
+        assert!(bairiak.is_true(TestEnum::Flag2));
+        assert!(bairiak.is_false(TestEnum::Flag1));
+        assert_eq!(bairiak.count_set_among([TestEnum::Flag2]), 1);
+        assert_eq!(generate_bairiak_from_slice(&[TestEnum::Flag2]), bairiak);
+        assert_eq!(generate_bairiak(HashSet::from([TestEnum::Flag2])), bairiak);
+    }
+
+    // Test for the single-flag values of every flag
+    #[test]
+    fn test_flag_mask() {
+        for flag in TestEnum::all_variants() {
+            let mask = flag.mask();
+            assert_eq!(mask.width(), TestEnum::get_zero_bairiak().width());
+            assert_eq!(mask.bits().count_ones(), 1);
+            assert!(mask.is_true(flag));
+            assert_eq!(flag.into_bairiak(), mask);
+        }
+        assert_eq!(TestEnum::Flag2.mask(), Bairiak::U8(0b100));
+        assert_eq!(
+            TestEnum::Flag0.mask().union(&TestEnum::Flag2.mask()),
+            generate_bairiak([TestEnum::Flag0, TestEnum::Flag2])
+        );
+    }
+
+    #[test]
+    fn test_bairiak_is_false() {
+        let bairiak = Bairiak::U8(0);
+        assert!(bairiak.is_false(TestEnum::Flag0));
+        assert!(bairiak.is_false(TestEnum::Flag1));
+        assert!(bairiak.is_false(TestEnum::Flag2));
+    }
+
+    #[test]
+    fn test_bairiak_is_true() {
+        let bairiak = Bairiak::U8(0b101);
+        assert!(bairiak.is_true(TestEnum::Flag0));
+        assert!(bairiak.is_false(TestEnum::Flag1));
+        assert!(bairiak.is_true(TestEnum::Flag2));
+    }
+
+    // Test for Bairiak::set and Bairiak::union methods
+    #[test]
+    fn test_bairiak_set() {
+        let mut bairiak = Bairiak::U8(0);
+        bairiak.set(TestEnum::Flag1);
+        assert!(bairiak.is_true(TestEnum::Flag1));
+        assert!(bairiak.is_false(TestEnum::Flag0));
+    }
+
+    // Test for the saturating and checked setters at the width boundary
+    #[derive(Debug, Clone, Copy)]
+    struct Bit(u16);
+
+    impl BairiakEnum for Bit {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U8(0u8)
+        }
+
+        fn to_u8(self) -> u8 {
+            self.0 as u8
+        }
+
+        fn position(&self) -> u16 {
+            self.0
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            Some(Bit(position as u16))
+        }
+
+        fn name(&self) -> &'static str {
+            "Bit"
+        }
+    }
+
+    #[test]
+    fn test_bairiak_set_clear_toggle_ignore_out_of_range() {
+        for (mut bairiak, last) in [(Bairiak::U8(0b1), 7), (Bairiak::U128(0b1), 127)] {
+            bairiak.set(Bit(last + 1));
+            bairiak.toggle(Bit(last + 1));
+            bairiak.clear(Bit(last + 1));
+            assert_eq!(bairiak.bits(), 0b1);
+
+            bairiak.set(Bit(last));
+            bairiak.toggle(Bit(0));
+            assert_eq!(bairiak.bits(), 1 << last);
+            bairiak.toggle(Bit(0));
+            bairiak.clear(Bit(last));
+            assert_eq!(bairiak.bits(), 0b1);
+        }
+        assert_eq!(Bit(7).mask(), Bairiak::U8(1 << 7));
+        assert_eq!(Bit(8).mask(), Bairiak::U8(0));
+    }
+
+    #[test]
+    fn test_bairiak_try_set_clear_toggle() {
+        for (mut bairiak, last) in [(Bairiak::U8(0), 7), (Bairiak::U128(0), 127)] {
+            bairiak.try_set(Bit(last)).unwrap();
+            bairiak.try_toggle(Bit(0)).unwrap();
+            assert_eq!(bairiak.bits(), 1 << last | 1);
+            bairiak.try_clear(Bit(last)).unwrap();
+            assert_eq!(bairiak.bits(), 1);
+
+            let out_of_range: [fn(&mut Bairiak, Bit) -> Result<()>; 3] =
+                [Bairiak::try_set, Bairiak::try_clear, Bairiak::try_toggle];
+            for try_change in out_of_range {
+                let err = try_change(&mut bairiak, Bit(last + 1)).unwrap_err();
+                assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+                assert_eq!(err.position(), Some(last + 1));
+                assert_eq!(bairiak.bits(), 1);
+            }
+        }
+
+        let err = Bairiak::U8(0).try_set(Bit(8)).unwrap_err();
+        assert_eq!(err.message(), "Position 8 is out of range for a u8 value.");
+    }
+
+    #[test]
+    fn test_bairiak_union() {
+        let union = Bairiak::U8(0b101).union(&Bairiak::U16(1 << 9));
+        assert!(matches!(union, Bairiak::U16(_)));
+        assert_eq!(union.bits(), 1 << 9 | 0b101);
+        assert_eq!(union.width(), 16);
+    }
+
+    // Test for the Debug format, listing the set positions in ascending order
+    #[test]
+    fn test_bairiak_debug() {
+        assert_eq!(
+            format!("{:?}", Bairiak::U8(0)),
+            "Bairiak::U8 { bits: 0b0, set: [] }"
+        );
+        assert_eq!(
+            format!("{:?}", Bairiak::U16(0b1000_0000_0000_0001)),
+            "Bairiak::U16 { bits: 0b1000000000000001, set: [0, 15] }"
+        );
+        assert_eq!(
+            format!("{:?}", Bairiak::U32(1096)),
+            "Bairiak::U32 { bits: 0b10001001000, set: [3, 6, 10] }"
+        );
+        assert_eq!(
+            format!("{:?}", Bairiak::U64(1 << 63 | 1 << 32)),
+            format!(
+                "Bairiak::U64 {{ bits: 0b1{}1{}, set: [32, 63] }}",
+                "0".repeat(30),
+                "0".repeat(32)
+            )
+        );
+        assert_eq!(
+            format!("{:?}", Bairiak::U128(1 << 127 | 0b10)),
+            format!(
+                "Bairiak::U128 {{ bits: 0b1{}10, set: [1, 127] }}",
+                "0".repeat(125)
+            )
+        );
+        assert_eq!(
+            format!("{:#?}", Bairiak::U8(0b101)),
+            "Bairiak::U8 {\n    bits: 0b101,\n    set: [\n        0,\n        2,\n    ],\n}"
+        );
+    }
+
+    // Test for Bairiak::retain_for method
+    #[test]
+    fn test_bairiak_retain_for() {
+        let mut bairiak = Bairiak::U8(0b1000_0111);
+        bairiak.retain_for(UndefinedBits::Keep, |flag: TestEnum| {
+            flag != TestEnum::Flag1
+        });
+        assert_eq!(bairiak.bits(), 0b1000_0101);
+
+        bairiak.retain_for(UndefinedBits::Clear, |_: TestEnum| true);
+        assert_eq!(bairiak.bits(), 0b101);
+
+        bairiak.retain_for(UndefinedBits::Clear, |flag: TestEnum| {
+            flag == TestEnum::Flag2
+        });
+        assert_eq!(bairiak.bits(), 0b100);
+    }
+
+    // Test for the defined mask and variant count derived from from_u8
+    #[test]
+    fn test_defined_mask() {
+        assert_eq!(TestEnum::defined_mask(), 0b111);
+        assert_eq!(TestEnum::variant_count(), 3);
+    }
+
+    // Test for counting set flags of an enum
+    #[test]
+    fn test_bairiak_count_set_for() {
+        let bairiak = Bairiak::U8(0b1100_0101);
+        assert_eq!(bairiak.count_set_for::<TestEnum>(), 2);
+        assert!(bairiak.has_at_least_for::<TestEnum>(2));
+        assert!(!bairiak.has_at_least_for::<TestEnum>(3));
+    }
+
+    // Test for the names of set flags in ascending position, without
+    // undefined bits
+    #[test]
+    fn test_bairiak_to_names_for() {
+        assert_eq!(
+            Bairiak::U8(0b1000_0101).to_names_for::<TestEnum>(),
+            ["Flag0", "Flag2"]
+        );
+        let bairiak: Bairiak = [TestEnum::Flag2, TestEnum::Flag1].into_iter().collect();
+        assert_eq!(bairiak.to_names_for::<TestEnum>(), ["Flag1", "Flag2"]);
+        assert!(Bairiak::U64(1 << 40).to_names_for::<TestEnum>().is_empty());
+    }
+
+    // Test for the unset flags of an enum, without undefined bits
+    #[test]
+    fn test_bairiak_first_clear_for() {
+        let all = Bairiak::U8(0b111);
+        assert_eq!(all.first_clear_for::<TestEnum>(), None);
+        assert!(all.clear_flags_for::<TestEnum>().is_empty());
+
+        let empty = Bairiak::U8(0);
+        assert_eq!(empty.first_clear_for::<TestEnum>(), Some(TestEnum::Flag0));
+        assert_eq!(
+            empty.clear_flags_for::<TestEnum>(),
+            [TestEnum::Flag0, TestEnum::Flag1, TestEnum::Flag2]
+        );
+
+        let gap = Bairiak::U8(0b101);
+        assert_eq!(gap.first_clear_for::<TestEnum>(), Some(TestEnum::Flag1));
+        assert_eq!(gap.clear_flags_for::<TestEnum>(), [TestEnum::Flag1]);
+
+        // Set undefined bits don't fill gaps, and unset ones aren't reported.
+        let undefined = Bairiak::U8(0b1111_1011);
+        assert_eq!(
+            undefined.first_clear_for::<TestEnum>(),
+            Some(TestEnum::Flag2)
+        );
+        assert_eq!(undefined.clear_flags_for::<TestEnum>(), [TestEnum::Flag2]);
+    }
+
+    #[test]
+    fn test_bairiak_count_set_among() {
+        let bairiak = Bairiak::U8(0b1000_0011);
+        let among = [TestEnum::Flag1, TestEnum::Flag2, TestEnum::Flag1];
+        assert_eq!(bairiak.count_set_among(among), 1);
+        assert_eq!(
+            bairiak.count_set_among([TestEnum::Flag0, TestEnum::Flag1]),
+            2
+        );
+    }
+
+    // Test for collecting and extending Bairiak from flags
+    #[test]
+    fn test_bairiak_from_iter_empty() {
+        let bairiak: Bairiak = Vec::<TestEnum>::new().into_iter().collect();
+        assert!(matches!(bairiak, Bairiak::U8(0)));
+
+        #[derive(Clone, Copy)]
+        struct WideFlag;
+
+        impl BairiakEnum for WideFlag {
+            fn get_zero_bairiak() -> Bairiak {
+                Bairiak::U128(0u128)
+            }
+
+            fn to_u8(self) -> u8 {
+                100
+            }
+
+            fn from_u8(position: u8) -> Option<Self> {
+                (position == 100).then_some(WideFlag)
+            }
+
+            fn name(&self) -> &'static str {
+                "WideFlag"
+            }
+        }
+
+        let bairiak: Bairiak = std::iter::empty::<WideFlag>().collect();
+        assert!(matches!(bairiak, Bairiak::U128(0)));
+    }
+
+    #[test]
+    fn test_bairiak_from_iter_with_duplicates() {
+        let flags = vec![TestEnum::Flag2, TestEnum::Flag0, TestEnum::Flag2];
+        let mut bairiak: Bairiak = flags.into_iter().collect();
+        assert_eq!(bairiak.bits(), 0b101);
+
+        bairiak.extend([TestEnum::Flag1, TestEnum::Flag0]);
+        assert_eq!(bairiak.bits(), 0b111);
+    }
+
+    // Test for generating Bairiak from a set of flags
+    #[test]
+    fn test_generate_bairiak() {
+        let mut flags = HashSet::new();
+        flags.insert(TestEnum::Flag0);
+        flags.insert(TestEnum::Flag2);
+
+        let bairiak = generate_bairiak(flags);
+        match bairiak {
+            Bairiak::U8(value) => assert_eq!(value, 0b101),
+            _ => panic!("Expected Bairiak::U8"),
+        }
+    }
+
+    // Test for the value not depending on the order or duplicates of flags
+    #[test]
+    fn test_generate_bairiak_ignores_order_and_duplicates() {
+        use rand::{rngs::StdRng, seq::SliceRandom, RngExt, SeedableRng};
+
+        let mut flags = [
+            TestEnum::Flag2,
+            TestEnum::Flag0,
+            TestEnum::Flag2,
+            TestEnum::Flag0,
+            TestEnum::Flag2,
+        ];
+        let expected = generate_bairiak(HashSet::from([TestEnum::Flag0, TestEnum::Flag2]));
+        for seed in 0..256 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            flags.shuffle(&mut rng);
+            let len = rng.random_range(1..=flags.len());
+            let mut subset = flags[..len].to_vec();
+            subset.extend([TestEnum::Flag0, TestEnum::Flag2]);
+            assert_eq!(generate_bairiak(subset.iter().copied()), expected);
+            assert_eq!(generate_bairiak(subset.iter().rev().copied()), expected);
+        }
+    }
+
+    // Test for an impl whose positions don't fit its zero value
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Position 12 of `Wide` out of range")]
+    fn test_generate_bairiak_asserts_positions_fit_width() {
+        let _ = generate_bairiak([Inconsistent::Wide]);
+    }
+
+    // A hand-written impl whose `Wide` doesn't fit its zero value.
+    #[derive(Debug, Clone, Copy)]
+    enum Inconsistent {
+        Narrow,
+        Wide,
+    }
+
+    impl BairiakEnum for Inconsistent {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U8(0u8)
+        }
+
+        fn to_u8(self) -> u8 {
+            match self {
+                Inconsistent::Narrow => 1,
+                Inconsistent::Wide => 12,
+            }
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            match position {
+                1 => Some(Inconsistent::Narrow),
+                12 => Some(Inconsistent::Wide),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match self {
+                Inconsistent::Narrow => "Narrow",
+                Inconsistent::Wide => "Wide",
+            }
+        }
+    }
+
+    // Test for checked generation failing where `generate_bairiak` asserts
+    #[test]
+    fn test_generate_bairiak_checked() {
+        assert_eq!(
+            generate_bairiak_checked([Inconsistent::Narrow]),
+            Ok(Bairiak::U8(0b10))
+        );
+        assert_eq!(
+            generate_bairiak_checked(HashSet::from([TestEnum::Flag0, TestEnum::Flag2])),
+            Ok(Bairiak::U8(0b101))
+        );
+
+        let err = generate_bairiak_checked([Inconsistent::Narrow, Inconsistent::Wide]).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+        assert_eq!(err.position(), Some(12));
+        assert_eq!(
+            err.message(),
+            "Position 12 of `Wide` out of range: the zero value of its enum is 8 bits wide."
+        );
+    }
+
+    #[test]
+    fn test_validate_impl() {
+        assert_eq!(validate_impl_for::<TestEnum>(), Ok(()));
+        let err = validate_impl_for::<Inconsistent>().unwrap_err();
+        assert_eq!(err.position(), Some(12));
+    }
+
+    // Test for generating Bairiak from a slice the same way as from a set
+    #[test]
+    fn test_generate_bairiak_from_slice() {
+        let all = [TestEnum::Flag0, TestEnum::Flag1, TestEnum::Flag2];
+        for subset in 0..8 {
+            let mut flags: Vec<TestEnum> = (0..3)
+                .filter(|i| subset & 1 << i != 0)
+                .map(|i| all[i])
+                .collect();
+            flags.extend_from_slice(&flags.clone());
+            assert_eq!(
+                generate_bairiak_from_slice(&flags),
+                generate_bairiak(flags.into_iter().collect::<HashSet<_>>())
+            );
+        }
+        assert_eq!(generate_bairiak_from_slice::<TestEnum>(&[]), Bairiak::U8(0));
+    }
+
+    // Test for generating enums from Enum struct with invalid name
+    #[test]
+    fn test_generate_enum_with_invalid_name() {
+        let e = Enum {
+            name: String::from("1"),
+            variants: vec![
+                Variant::from("Var0"),
+                Variant::from("Var1"),
+                Variant::from("Var2"),
+            ],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_with_invalid_variant_lowercase() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![
+                Variant::from("Var0"),
+                Variant::from("var1"),
+                Variant::from("Var2"),
+            ],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_with_invalid_variant_number() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![
+                Variant::from("Var0"),
+                Variant::from("1var"),
+                Variant::from("Var2"),
+            ],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_with_invalid_variant_symbol() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![
+                Variant::from("Var0"),
+                Variant::from("var!"),
+                Variant::from("Var2"),
+            ],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    // Test for generating enums from Enum struct with empty variants
+    #[test]
+    fn test_generate_enum_with_empty_variants() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    // Test for explicit positions, with the following variants numbered after them
+    #[test]
+    fn test_parse_spec_with_positions() {
+        let spec = parse_spec(
+            "enums:\n  - name: Perm\n    variants:\n      - Read\n      - name: Admin\n        position: 9\n      - Audit\n",
+        )
+        .unwrap();
+        let e = &spec.enums[0];
+        assert_eq!(
+            e.positioned_variants().collect::<Vec<_>>(),
+            vec![(0, "Read"), (9, "Admin"), (10, "Audit")]
+        );
+        assert_eq!(e.width(), Ok(16));
+        assert_eq!(e.all_mask(), 0b110_0000_0001);
+
+        let generated_code =
+            enum_code(e, &Settings::default(), &GenerateOptions::default()).unwrap();
+        assert!(generated_code.contains("Bairiak::U16(0u16)"));
+        assert!(generated_code.contains("    Audit = 10,\n"));
+    }
+
+    // Test for positions beyond `u8` being rejected by validation rather
+    // than by deserialization
+    #[test]
+    fn test_parse_spec_with_position_beyond_u8() {
+        let spec = parse_spec(
+            "enums:\n  - name: Perm\n    variants:\n      - name: Admin\n        position: 300\n",
+        )
+        .unwrap();
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+        assert_eq!(
+            err.message(),
+            "Position of `Admin` in `Perm` out of range: 300. Maximum positions supported is 128."
+        );
+    }
+
+    // Test for `VARIANTS` listing the variants by position, not spec order
+    #[test]
+    fn test_generate_variants_const() {
+        let spec = parse_spec(
+            "
+enums:
+  - name: Perm
+    variants:
+      - name: Admin
+        position: 4
+      - name: Read
+        position: 0
+      - Write
+      - ~
+",
+        )
+        .unwrap();
+        let generated_code = enum_code(
+            &spec.enums[0],
+            &Settings::default(),
+            &GenerateOptions::default(),
+        )
+        .unwrap();
+        assert!(generated_code.contains(
+            "    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+"
+        ));
+    }
+
+    // Test for placeholders taking positions mid-list and at the end
+    #[test]
+    fn test_parse_spec_with_placeholders() {
+        let spec = parse_spec(
+            "enums:\n  - name: Perm\n    variants: [Read, ~, _, Write, Var4, Var5, Var6, Var7, ~]\n",
+        )
+        .unwrap();
+        let e = &spec.enums[0];
+        assert_eq!(e.variant_count(), 6);
+        assert_eq!(e.width(), Ok(16));
+        assert_eq!(e.positioned_variants().nth(1), Some((3, "Write")));
+
+        let generated_code =
+            enum_code(e, &Settings::default(), &GenerateOptions::default()).unwrap();
+        assert!(generated_code.contains("    Write = 3,\n"));
+        assert!(generated_code.contains("Bairiak::U16(0u16)"));
+        assert!(generated_code.contains("pub const COUNT: usize = 6;"));
+    }
+
+    // Test for generating enums with placeholders only
+    #[test]
+    fn test_generate_enum_with_only_placeholders() {
+        let spec = parse_spec("enums:\n  - name: Perm\n    variants: [~, _]\n").unwrap();
+
+        let result = enum_code(
+            &spec.enums[0],
+            &Settings::default(),
+            &GenerateOptions::default(),
+        );
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    // Test for removed variants keeping their positions reserved
+    #[test]
+    fn test_parse_spec_with_removed_variants() {
+        let spec = parse_spec(
+            "
+enums:
+  - name: Perm
+    variants:
+      - Read
+      - name: Write
+        status: removed
+      - Delete
+",
+        )
+        .unwrap();
+        let e = &spec.enums[0];
+        assert_eq!(e.variant_count(), 2);
+        assert_eq!(e.positioned_variants().nth(1), Some((2, "Delete")));
+        assert!(spec.validate().is_ok());
+        let generated_code = generate_bairiak_enums_from_str(
+            "enums:\n  - name: Perm\n    variants:\n      - Read\n      - name: Write\n        status: removed\n",
+        )
+        .unwrap();
+        assert!(!generated_code.contains("Write"));
+        assert!(generated_code.contains("            1 => Some(bairiak::FlagStatus::Removed),\n"));
+
+        let taken = "
+enums:
+  - name: Perm
+    variants:
+      - Read
+      - name: Write
+        status: removed
+      - name: Delete
+        position: 1
+";
+        assert_eq!(
+            generate_bairiak_enums_from_str(taken)
+                .unwrap_err()
+                .message(),
+            "Duplicate position 1 in `Perm`: `Delete` takes a position already taken."
+        );
+
+        let detailed = "
+enums:
+  - name: Perm
+    variants:
+      - Read
+      - name: Write
+        status: removed
+        aliases: [Modify]
+";
+        assert_eq!(
+            generate_bairiak_enums_from_str(detailed).unwrap_err().message(),
+            "Removed variant `Write` of `Perm` can't be the default, have aliases or require other variants."
+        );
+    }
+
+    // Test for the status of each variant in docs and `status()`
+    #[test]
+    fn test_generate_enum_with_statuses() {
+        let variant = |status: &str| {
+            format!(
+                "enums:\n  - name: Perm\n    variants:\n      - Read\n      - name: Write\n        status: {}\n",
+                status
+            )
+        };
+        let cases = [
+            (
+                "experimental",
+                "Experimental",
+                "    /// Experimental: may change or be removed in any release.\n    Write = 1,\n",
+            ),
+            ("stable", "Stable", "    Read = 0,\n    Write = 1,\n"),
+            (
+                "deprecated",
+                "Deprecated",
+                "    /// Deprecated.\n    Write = 1,\n",
+            ),
+        ];
+        for (status, path, docs) in cases {
+            let generated_code = generate_bairiak_enums_from_str(&variant(status)).unwrap();
+            assert!(generated_code.contains(&format!(
+                "            Perm::Write => bairiak::FlagStatus::{},\n",
+                path
+            )));
+            assert!(
+                generated_code.contains("            Perm::Read => bairiak::FlagStatus::Stable,\n")
+            );
+            assert!(generated_code.contains(docs));
+        }
+
+        // Without any `status`, there is no lookup.
+        let spec = "enums:\n  - name: Perm\n    variants:\n      - Read\n      - name: Write\n        deprecated: Use roles.\n";
+        assert!(!generate_bairiak_enums_from_str(spec)
+            .unwrap()
+            .contains("fn status"));
+
+        let err = generate_bairiak_enums_from_str(&variant("beta")).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Unknown status `beta` of `Write` in `Perm`. Status should be experimental, stable, deprecated or removed."
+        );
+        let problems = parse_spec(&variant("Stable")).unwrap().validate_all();
+        assert_eq!(
+            problems[0].to_string(),
+            "Perm.variants[1]: Unknown status `Stable` of `Write` in `Perm`. Status should be experimental, stable, deprecated or removed."
+        );
+
+        let conflicting = format!("{}        deprecated: Use roles.\n", variant("stable"));
+        assert_eq!(
+            generate_bairiak_enums_from_str(&conflicting)
+                .unwrap_err()
+                .message(),
+            "Variant `Write` of `Perm` has a `deprecated` note, but its status is `stable`."
+        );
+    }
+
+    // Test for experimental variants behind a feature
+    #[test]
+    fn test_generate_enum_with_gated_variants() {
+        let spec = |extra: &str| {
+            format!(
+                "
+enums:
+  - name: Perm
+    experimental_feature: beta-flags
+{}    variants:
+      - Read
+      - name: Write
+        status: experimental
+",
+                extra
+            )
+        };
+        let generated_code = generate_bairiak_enums_from_str(&spec("")).unwrap();
+        assert!(generated_code.contains("    #[cfg(feature = \"beta-flags\")]\n    Write = 1,\n"));
+        assert!(generated_code.contains(
+            "    pub const COUNT: usize = if cfg!(feature = \"beta-flags\") { 2 } else { 1 };\n"
+        ));
+        assert!(generated_code.contains(
+            "    pub const fn max_position() -> u8 {\n        if cfg!(feature = \"beta-flags\") { 1 } else { 0 }\n"
+        ));
+        assert!(generated_code.contains("Only available with the `beta-flags` feature."));
+
+        assert_eq!(
+            generate_bairiak_enums_from_str(&spec("    emit_bitflags: true\n"))
+                .unwrap_err()
+                .message(),
+            "`Perm` gates experimental variants behind `beta-flags`, which `emit_bitflags` doesn't support."
+        );
+        assert_eq!(
+            generate_bairiak_enums_from_str(&spec("    lookup: table\n"))
+                .unwrap_err()
+                .message(),
+            "`Perm` gates experimental variants behind `beta-flags`, which `lookup: table` doesn't support."
+        );
+        let grouped = format!(
+            "{}    groups:\n      - name: All\n        variants: [Read, Write]\n",
+            spec("")
+        );
+        assert_eq!(
+            generate_bairiak_enums_from_str(&grouped)
+                .unwrap_err()
+                .message(),
+            "Experimental variant `Write` of `Perm` is only available with `beta-flags`, so group `All` can't refer to it."
+        );
+        let invalid = spec("").replace("beta-flags", "beta flags");
+        assert_eq!(
+            generate_bairiak_enums_from_str(&invalid)
+                .unwrap_err()
+                .message(),
+            "Invalid experimental feature `beta flags` of `Perm`. Feature names should be like `experimental-flags`."
+        );
+    }
+
+    // Test for the `FromStr` lookup chosen by the number of names
+    #[test]
+    fn test_generate_from_str_lookup() {
+        let spec = |count: usize, extra: &str| {
+            let variants: Vec<String> = (0..count).map(|i| format!("Flag{}", i)).collect();
+            format!(
+                "enums:\n  - name: Perm\n{}    variants: [{}]\n",
+                extra,
+                variants.join(", ")
+            )
+        };
+        let bytes = "        match s.as_bytes() {\n";
+        let exact = "    from_str: exact\n";
+        let code = generate_bairiak_enums_from_str(&spec(FROM_STR_BYTES_THRESHOLD, exact)).unwrap();
+        assert!(!code.contains(bytes));
+        assert!(code.contains("            \"Flag0\" => Ok(Perm::Flag0),\n"));
+        let code =
+            generate_bairiak_enums_from_str(&spec(FROM_STR_BYTES_THRESHOLD + 1, exact)).unwrap();
+        assert!(code.contains(bytes));
+        assert!(code.contains("            b\"Flag0\" => Ok(Perm::Flag0),\n"));
+
+        let forced = format!("{}    from_str_lookup: bytes\n", exact);
+        assert!(generate_bairiak_enums_from_str(&spec(2, &forced))
+            .unwrap()
+            .contains(bytes));
+        let forced = format!("{}    from_str_lookup: match\n", exact);
+        assert!(
+            !generate_bairiak_enums_from_str(&spec(FROM_STR_BYTES_THRESHOLD + 1, &forced))
+                .unwrap()
+                .contains(bytes)
+        );
+
+        // The buffer fits the longest name, `flag10`.
+        let folded = "    from_str: case_insensitive\n    from_str_lookup: bytes\n";
+        let code = generate_bairiak_enums_from_str(&spec(11, folded)).unwrap();
+        assert!(code.contains("        let mut key = [0u8; 6];\n"));
+        assert!(code.contains("            b\"flag10\" => Ok(Perm::Flag10),\n"));
+        assert!(!code.contains("to_ascii_lowercase()"));
+
+        assert_eq!(
+            generate_bairiak_enums_from_str(&spec(2, "    from_str_lookup: bytes\n"))
+                .unwrap_err()
+                .message(),
+            "`from_str_lookup` of `Perm` requires `from_str`."
+        );
+        assert_eq!(byte_string_literal("Sé\"\\"), "b\"S\\xc3\\xa9\\\"\\\\\"");
+    }
+
+    // Test for names that only collide once case is ignored
+    #[test]
+    fn test_generate_enum_with_ambiguous_parse_names() {
+        let yaml = |mode: &str| {
+            format!(
+                "enums:\n  - name: Perm\n    from_str: {}\n    variants:\n      - Read\n      - name: Write\n        aliases: [read]\n",
+                mode
+            )
+        };
+
+        let spec = parse_spec(&yaml("exact")).unwrap();
+        assert!(enum_code(
+            &spec.enums[0],
+            &Settings::default(),
+            &GenerateOptions::default()
+        )
+        .is_ok());
+
+        let spec = parse_spec(&yaml("case_insensitive")).unwrap();
+        let err = enum_code(
+            &spec.enums[0],
+            &Settings::default(),
+            &GenerateOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Ambiguous name `read` in `Perm`: it matches both `Read` and `Write`."
+        );
+    }
+
+    // Test for generating enums with two variants at the same position
+    #[test]
+    fn test_generate_enum_with_duplicate_positions() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![
+                Variant::from("Var0"),
+                Variant::from("Var1"),
+                Variant::Detailed {
+                    name: String::from("Var2"),
+                    position: Some(1),
+                    aliases: vec![],
+                    doc: None,
+                    deprecated: None,
+                    status: None,
+                    requires: vec![],
+                    default: false,
+                    id: None,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    // Test for position constants colliding after SCREAMING_SNAKE conversion
+    #[test]
+    fn test_generate_enum_with_colliding_position_consts() {
+        let e = Enum {
+            name: String::from("Method"),
+            variants: vec![Variant::from("HttpGet"), Variant::from("HTTPGet")],
+            position_consts: Some(ConstPlacement::Associated),
+            ..Default::default()
+        };
+        let err = enum_code(&e, &Settings::default(), &GenerateOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.to_string(),
+            "Error parsing Bairiak enums: Position constant `HTTP_GET_POS` of `HTTPGet` in `Method` collides with the constant of `HttpGet` in `Method`."
+        );
+
+        let e = Enum {
+            name: String::from("Perm"),
+            variants: vec![Variant::from("Read"), Variant::from("All")],
+            position_consts: Some(ConstPlacement::Associated),
+            position_masks: true,
+            ..Default::default()
+        };
+        let err = enum_code(&e, &Settings::default(), &GenerateOptions::default()).unwrap_err();
+        assert!(err.to_string().ends_with("collides with `Perm::ALL_MASK`."));
+
+        let e = Enum {
+            name: String::from("Perm"),
+            variants: vec![Variant::from("Read")],
+            position_masks: true,
+            ..Default::default()
+        };
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+    }
+
+    // Test for deriving `defmt::Format` only when asked, and once
+    #[test]
+    fn test_generate_enum_with_defmt() {
+        let yaml =
+            |options: &str| format!("enums:\n  - name: Perm\n    variants: [Read]\n{}", options);
+        let derives = |yaml: &str| {
+            let spec = parse_spec(yaml).unwrap();
+            let code =
+                enum_code(&spec.enums[0], &spec.settings, &GenerateOptions::default()).unwrap();
+            code.lines()
+                .find(|line| line.starts_with("#[derive("))
+                .unwrap()
+                .to_string()
+        };
+
+        assert!(!derives(&yaml("")).contains("defmt"));
+        assert_eq!(
+            derives(&yaml("    defmt: true\n")),
+            "#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, defmt::Format)]"
+        );
+        assert_eq!(
+            derives(&yaml("    defmt: true\n    derives: [defmt::Format]\n")),
+            "#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, defmt::Format)]"
+        );
+    }
+
+    // Test for the `Default` impl of the variant marked `default`
+    #[test]
+    fn test_generate_enum_with_default() {
+        let yaml = |variants: &str, options: &str| {
+            format!(
+                "enums:\n  - name: Mode\n    variants: {}\n{}",
+                variants, options
+            )
+        };
+        let code = |yaml: &str| {
+            let spec = parse_spec(yaml).unwrap();
+            enum_code(&spec.enums[0], &spec.settings, &GenerateOptions::default())
+        };
+
+        let variants = "[Off, {name: Idle, default: true}, Busy]";
+        let generated = code(&yaml(variants, "")).unwrap();
+        assert!(generated.contains(
+            "
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Idle
+    }
+}
+"
+        ));
+        assert!(!generated.contains("#[default]"));
+
+        // A derived `Default` takes the marked variant instead.
+        let generated = code(&yaml(variants, "    derives: [Default]\n")).unwrap();
+        assert!(generated.contains("    Off = 0,\n    #[default]\n    Idle = 1,\n"));
+        assert!(!generated.contains("impl Default"));
+
+        let generated = code(&yaml("[Off, Idle]", "")).unwrap();
+        assert!(!generated.contains("impl Default"));
+        assert!(!generated.contains("#[default]"));
+
+        let err = code(&yaml("[Off, Idle]", "    derives: [Default]\n")).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "`Mode` derives `Default`, which needs a variant marked `default`."
+        );
+    }
+
+    // Test for rejecting a second variant marked `default`
+    #[test]
+    fn test_generate_enum_with_two_defaults() {
+        let spec = parse_spec(
+            "enums:\n  - name: Mode\n    variants: [{name: Off, default: true}, Idle, {name: Busy, default: true}]\n",
+        )
+        .unwrap();
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Variant `Busy` of `Mode` is marked `default`, but `Off` already is."
+        );
+
+        let problems = spec.validate_all();
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].index, Some(2));
+    }
+
+    // Test for `lock_positions` requiring every variant's position
+    #[test]
+    fn test_generate_enum_with_locked_positions() {
+        let yaml = |variants: &str| {
+            format!(
+                "enums:\n  - name: Perm\n    lock_positions: true\n    variants: {}\n",
+                variants
+            )
+        };
+
+        let variants = "[{name: Write, position: 1}, ~, {name: Read, position: 0}]";
+        let spec = parse_spec(&yaml(variants)).unwrap();
+        assert!(spec.validate().is_ok());
+        assert_eq!(
+            spec.enums[0].positioned_variants().collect::<Vec<_>>(),
+            [(1, "Write"), (0, "Read")]
+        );
+
+        for (variants, name) in [
+            ("[{name: Read, position: 0}, Write]", "Write"),
+            ("[{name: Read, aliases: [view]}]", "Read"),
+        ] {
+            let err = parse_spec(&yaml(variants)).unwrap().validate().unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+            assert_eq!(
+                err.message(),
+                format!(
+                    "Variant `{}` of `Perm` has no `position`, which `lock_positions` requires.",
+                    name
+                )
+            );
+        }
+    }
+
+    // Test for duplicate and missing stable ids
+    #[test]
+    fn test_generate_enum_with_invalid_ids() {
+        let yaml = |variants: &str| format!("enums:\n  - name: Perm\n    variants: {}\n", variants);
+
+        let spec = parse_spec(&yaml("[{name: Read, id: 7}, ~, {name: Write, id: 3}]")).unwrap();
+        assert!(spec.validate().is_ok());
+
+        let spec = parse_spec(&yaml(
+            "[{name: Read, id: 7}, {name: Old, id: 3, status: removed}, {name: Write, id: 3}]",
+        ))
+        .unwrap();
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Duplicate id 3 in `Perm`: `Write` takes the id of `Old`."
+        );
+        assert_eq!(spec.validate_all()[0].index, Some(2));
+
+        let err = parse_spec(&yaml("[{name: Read, id: 7}, Write]"))
+            .unwrap()
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Variant `Write` of `Perm` has no `id`, which every variant needs once one has."
+        );
+    }
+
+    // Test for groups naming unknown variants or the same group twice
+    #[test]
+    fn test_generate_enum_with_invalid_groups() {
+        let yaml = |lookup: &str, groups: &str| {
+            format!(
+                "enums:\n  - name: Perm\n    group_lookup: {}\n    variants: [Read, Write]\n    groups: {}\n",
+                lookup, groups
+            )
+        };
+
+        let spec =
+            parse_spec(&yaml("exact", "[{name: Editors, variants: [Read, Wirte]}]")).unwrap();
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Unknown variant `Wirte` in group `Editors` of `Perm`."
+        );
+
+        let groups = "[{name: All, variants: [Read]}, {name: all, variants: [Write]}]";
+        assert!(parse_spec(&yaml("exact", groups))
+            .unwrap()
+            .validate()
+            .is_ok());
+        let err = parse_spec(&yaml("case_insensitive", groups))
+            .unwrap()
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.message(), "Duplicate group `all` in `Perm`.");
+    }
+
+    // Test for exclusive groups naming unknown variants or too few
+    #[test]
+    fn test_generate_enum_with_invalid_exclusive_groups() {
+        let yaml = |exclusive: &str| {
+            format!(
+                "enums:\n  - name: Codec\n    variants: [Gzip, Zstd]\n    exclusive: {}\n",
+                exclusive
+            )
+        };
+
+        assert!(parse_spec(&yaml("[[Gzip, Zstd]]"))
+            .unwrap()
+            .validate()
+            .is_ok());
+        let err = parse_spec(&yaml("[[Gzip, Brotli]]"))
+            .unwrap()
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Unknown variant `Brotli` in exclusive group 0 of `Codec`."
+        );
+        let err = parse_spec(&yaml("[[Gzip, Zstd], [Gzip, Gzip]]"))
+            .unwrap()
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Exclusive group 1 of `Codec` needs at least two distinct variants."
+        );
+    }
+
+    // Test for requirements naming unknown variants, or the variant itself
+    #[test]
+    fn test_generate_enum_with_requires() {
+        let spec = parse_spec(
+            "enums:\n  - name: Access\n    variants:\n      - Read\n      - name: Admin\n        requires: [Reed]\n",
+        )
+        .unwrap();
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Unknown variant `Reed` required by `Admin` in `Access`."
+        );
+
+        let spec = parse_spec(
+            "enums:\n  - name: Access\n    variants:\n      - name: Read\n        requires: [Read, Admin]\n      - name: Admin\n        requires: [Read]\n",
+        )
+        .unwrap();
+        let generated_code =
+            enum_code(&spec.enums[0], &spec.settings, &GenerateOptions::default()).unwrap();
+        assert!(generated_code.contains("REQUIREMENTS: [(u8, u128); 2] = [(0, 0x2), (1, 0x1)];"));
+    }
+
+    // Test for free position constants colliding across enums
+    #[test]
+    fn test_generate_enums_with_colliding_free_consts() {
+        let spec = "enums:
+  - name: Perm
+    position_consts: free
+    variants: [Read]
+  - name: Access
+    position_consts: associated
+    variants: [Read]
+  - name: Mode
+    position_consts: free
+    variants: [Fast, Read]
+";
+        let err = generate_bairiak_enums_from_str(spec).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("Position constant `READ_POS` of `Read` in `Mode` collides with the constant of `Read` in `Perm`."));
+        let mut out = Vec::new();
+        assert!(generate_bairiak_enums_to_writer(spec, &mut out).is_err());
+        assert!(out.is_empty());
+    }
+
+    // Test for generating enums with a position beyond 127
+    #[test]
+    fn test_generate_enum_with_position_out_of_range() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![Variant::Detailed {
+                name: String::from("Var0"),
+                position: Some(128),
+                aliases: vec![],
+                doc: None,
+                deprecated: None,
+                status: None,
+                requires: vec![],
+                default: false,
+                id: None,
+            }],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
+    }
+
+    // Test for generating enums from Enum struct
+    #[test]
+    fn test_generate_enum() {
+        let e = Enum {
+            name: String::from("TestEnum"),
+            variants: vec![
+                Variant::from("Var0"),
+                Variant::from("Var1"),
+                Variant::from("Var2"),
+            ],
+            ..Default::default()
+        };
+
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert!(result.is_ok());
+
+        let generated_code = result.unwrap();
+        assert!(generated_code.contains("enum TestEnum"));
+        assert!(generated_code.contains("Var0 = 0"));
+        assert!(generated_code.contains("Var1 = 1"));
+        assert!(generated_code.contains("Var2 = 2"));
+    }
+
+    // Test for the overall enum generation function
+    #[test]
+    fn test_generate_enums() {
+        let spec = EnumSpec {
+            enums: vec![Enum {
+                name: String::from("TestEnum"),
+                variants: vec![Variant::from("Var0"), Variant::from("Var1")],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let result = generate_enums(&spec, &GenerateOptions::default());
+        assert!(result.is_ok());
+
+        let generated_code = result.unwrap();
+        assert!(generated_code.contains("enum TestEnum"));
+        assert!(generated_code.contains("Var0 = 0"));
+        assert!(generated_code.contains("Var1 = 1"));
+    }
+
+    // Test for the public pieces generating what the whole file holds
+    #[test]
+    fn test_generate_public_pieces() {
+        let spec = load_spec("test_data/multi_enum_spec.yaml").unwrap();
+        let options = GenerateOptions::default();
+        let file = generate_bairiak_enums_from_str(
+            &fs::read_to_string("test_data/multi_enum_spec.yaml").unwrap(),
+        )
+        .unwrap();
+        let enums_code = generate_enums(&spec, &options).unwrap();
+        assert!(file.ends_with(&format!(
+            "use bairiak::{{Bairiak, BairiakEnum}};\n{}",
+            enums_code
+        )));
+
+        let mode_code = generate_enum(&spec, "Mode", &options).unwrap();
+        assert!(enums_code.ends_with(&mode_code));
+        assert!(!mode_code.contains("use bairiak"));
+
+        let err = generate_enum(&spec, "Missing", &options).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::UnknownEnumError);
+        let err = generate_enum(&spec, "Mode", &options.module("Flags")).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+    }
+
+    mod migration {
+        include!("../test_data/expected/migration_spec.rs");
+
+        #[test]
+        fn test_migrate_value() {
+            let migrated = migrate_perm_v1_to_v2(Bairiak::U8(0b111));
+            assert_eq!(migrated.bits(), 0b101);
+            assert!(matches!(migrated, Bairiak::U8(_)));
+        }
+    }
+
+    // Test for migrations taking the visibility and module of their enum
+    #[test]
+    fn test_generate_migration_in_module() {
+        let yaml = fs::read_to_string("test_data/migration_spec.yaml").unwrap();
+        let options = GenerateOptions::default().module("flags");
+        let code = generate_bairiak_enums_from_str_with(&yaml, &options).unwrap();
+        assert!(code.contains(
+            "\n    #[allow(dead_code)]\n    pub(super) fn migrate_perm_v1_to_v2(old: Bairiak) -> Bairiak {\n"
+        ));
+        let options = options.visibility(Visibility::Pub);
+        let code = generate_bairiak_enums_from_str_with(&yaml, &options).unwrap();
+        assert!(code.contains("    pub fn migrate_perm_v1_to_v2("));
+    }
+
+    #[test]
+    fn test_generate_migration_with_out_of_range_positions() {
+        let mut e = Enum {
+            name: String::from("Perm"),
+            variants: vec![Variant::from("Read"), Variant::from("Write")],
+            migrations: vec![Migration {
+                from: String::from("v1"),
+                to: String::from("v2"),
+                positions: BTreeMap::from([(0, Some(2))]),
+            }],
+            ..Default::default()
+        };
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
+
+        e.migrations[0].positions = BTreeMap::from([(128, Some(0))]);
+        let result = enum_code(&e, &Settings::default(), &GenerateOptions::default());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
+    }
+
+    // Test for choosing the smallest width that holds every position
+    #[test]
+    fn test_width_for() {
+        assert_eq!(width_for(1), Ok(8));
+        assert_eq!(width_for(8), Ok(8));
+        assert_eq!(width_for(9), Ok(16));
+        assert_eq!(width_for(16), Ok(16));
+        assert_eq!(width_for(33), Ok(64));
+        assert_eq!(width_for(128), Ok(128));
+        assert_eq!(
+            width_for(129).unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
+    }
+
+    // Test for the width decision, fitted and forced
+    #[test]
+    fn test_select_width() {
+        // Auto-fit.
+        assert_eq!(select_width(0, None), Some(8));
+        assert_eq!(select_width(7, None), Some(8));
+        assert_eq!(select_width(8, None), Some(16));
+        assert_eq!(select_width(70, None), Some(128));
+        assert_eq!(select_width(127, None), Some(128));
+        // Auto, too big.
+        assert_eq!(select_width(128, None), None);
+        assert_eq!(select_width(300, None), None);
+        // Forced, fits.
+        assert_eq!(select_width(2, Some(64)), Some(64));
+        assert_eq!(select_width(31, Some(32)), Some(32));
+        // Forced, too small.
+        assert_eq!(select_width(32, Some(32)), None);
+        assert_eq!(select_width(40, Some(32)), None);
+    }
+
+    // Test for forced widths and explicit positions deciding the width together
+    #[test]
+    fn test_generate_enum_with_forced_width() {
+        let yaml = |bits: &str, position: u8| {
+            format!(
+                "enums:\n  - name: Perm\n{}    variants:\n      - Read\n      - name: Admin\n        position: {}\n",
+                bits, position
+            )
+        };
+        let generate = |yaml: String| {
+            let spec = parse_spec(&yaml).unwrap();
+            enum_code(
+                &spec.enums[0],
+                &Settings::default(),
+                &GenerateOptions::default(),
+            )
+        };
+
+        let generated_code = generate(yaml("", 70)).unwrap();
+        assert!(generated_code.contains("Bairiak::U128(0u128)"));
+
+        let generated_code = generate(yaml("    bits: 64\n", 3)).unwrap();
+        assert!(generated_code.contains("Bairiak::U64(0u64)"));
+
+        let err = generate(yaml("    bits: 32\n", 40)).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+        assert_eq!(
+            err.message(),
+            "Position of `Admin` in `Perm` out of range: 40. `bits: 32` holds positions up to 31."
+        );
+
+        let err = generate(yaml("", 200)).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+
+        let err = generate(yaml("    bits: 24\n", 3)).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("Perm"), "perm");
+        assert_eq!(to_snake_case("DocumentFlags"), "document_flags");
+        assert_eq!(to_snake_case("HTTPFlags"), "http_flags");
+        assert_eq!(to_snake_case("Flags2Go"), "flags2_go");
+    }
+
+    // Test for file generation success case
+    #[test]
+    fn test_generate_bairiak_enums_success() {
+        let result = generate_bairiak_enums("test_data/valid_spec.yaml", "output.rs");
+        assert!(result.is_ok());
+        TEST_TEARDOWN.call_once(|| {
+            fs::remove_file("output.rs").unwrap();
+        });
+    }
+
+    #[test]
+    fn test_generate_bairiak_enums_with_more_than_max_flags() {
+        let result = generate_bairiak_enums("test_data/out_of_range_spec.yaml", "output.rs");
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
+    }
+
+    // Test for generating code from spec content
+    #[test]
+    fn test_generate_bairiak_enums_from_str() {
+        let result = generate_bairiak_enums_from_str(
+            "enums:\n  - name: TestEnum\n    variants: [Var0, Var1]\n",
+        );
+        assert!(result.is_ok());
+
+        let generated_code = result.unwrap();
+        assert!(generated_code.starts_with("// @generated by bairiak. Do not edit by hand.\n"));
+        assert!(generated_code.contains("\nuse bairiak::{Bairiak, BairiakEnum};\n"));
+        assert!(generated_code.contains("enum TestEnum"));
+        assert!(generated_code.contains("Var1 = 1"));
+    }
+
+    #[test]
+    fn test_generate_bairiak_enums_from_str_invalid_yaml() {
+        let result = generate_bairiak_enums_from_str("enums: 1");
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::DeserializeYamlError
+        );
+    }
+
+    #[test]
+    fn test_generate_from_blank_spec() {
+        for yaml in ["", "  \n\t\n", "# Flags of our service.\n\n"] {
+            let err = generate_bairiak_enums_from_str(yaml).unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::EmptySpecError);
+            assert!(err.is_spec());
+            assert!(err.to_string().contains("The spec has no content"));
+        }
+
+        // Even allowing empty specs, a blank file is more likely truncated
+        // than meant to be empty.
+        let options = GenerateOptions::default().allow_empty(true);
+        assert_eq!(
+            generate_bairiak_enums_from_str_with("\n", &options)
+                .unwrap_err()
+                .kind(),
+            BairiakErrorKind::EmptySpecError
+        );
+    }
+
+    #[test]
+    fn test_generate_from_spec_without_enums() {
+        let err = generate_bairiak_enums_from_str("enums: []\n").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::EmptySpecError);
+        assert!(err.to_string().contains("The spec lists no enums"));
+        let mut out = Vec::new();
+        assert_eq!(
+            generate_bairiak_enums_to_writer("enums: []\n", &mut out)
+                .unwrap_err()
+                .kind(),
+            BairiakErrorKind::EmptySpecError
+        );
+        assert!(out.is_empty());
+        let spec = parse_spec("enums: []\n").unwrap();
+        assert_eq!(
+            generate_enums(&spec, &GenerateOptions::default())
+                .unwrap_err()
+                .kind(),
+            BairiakErrorKind::EmptySpecError
+        );
+
+        // Allowed, the file has nothing to import for, so it compiles without
+        // warnings.
+        let options = GenerateOptions::default().allow_empty(true);
+        let code = generate_bairiak_enums_from_str_with("enums: []\n", &options).unwrap();
+        assert!(code.starts_with("// @generated by bairiak. Do not edit by hand.\n"));
+        assert_eq!(code.lines().count(), 2);
+        assert_eq!(generate_enums(&spec, &options).unwrap(), "");
 
-#[derive(Debug)]
-pub enum Bairiak {
-    U8(u8),
-    U16(u16),
-    U32(u32),
-    U64(u64),
-    U128(u128),
-}
+        let options = options.module("flags");
+        let code = generate_bairiak_enums_from_str_with("enums: []\n", &options).unwrap();
+        assert!(code.ends_with("\nmod flags {\n}\n"));
+    }
 
-pub trait BairiakEnum {
-    fn get_zero_bairiak() -> Bairiak;
-    fn to_u8(self) -> u8;
-}
+    // Test for overwriting previously generated files
+    #[test]
+    fn test_generate_bairiak_enums_overwrites_unmodified_output() {
+        let output_path = "unmodified_output.rs";
+        assert!(generate_bairiak_enums("test_data/valid_spec.yaml", output_path).is_ok());
+        assert!(generate_bairiak_enums("test_data/migration_spec.yaml", output_path).is_ok());
 
-impl Bairiak {
-    pub fn is_false<B: BairiakEnum>(&self, flag: B) -> bool {
-        match self {
-            Bairiak::U8(value) => *value & 1u8 << flag.to_u8() == 0,
-            Bairiak::U16(value) => *value & 1u16 << flag.to_u8() == 0,
-            Bairiak::U32(value) => *value & 1u32 << flag.to_u8() == 0,
-            Bairiak::U64(value) => *value & 1u64 << flag.to_u8() == 0,
-            Bairiak::U128(value) => *value & 1u128 << flag.to_u8() == 0,
-        }
+        let generated_code = fs::read_to_string(output_path).unwrap();
+        fs::remove_file(output_path).unwrap();
+        assert!(generated_code.contains("enum Perm"));
     }
 
-    pub fn is_true<B: BairiakEnum>(&self, flag: B) -> bool {
-        !self.is_false(flag)
+    #[test]
+    fn test_generate_bairiak_enums_refuses_hand_edited_output() {
+        let output_path = "hand_edited_output.rs";
+        assert!(generate_bairiak_enums("test_data/valid_spec.yaml", output_path).is_ok());
+
+        let edited_code = fs::read_to_string(output_path)
+            .unwrap()
+            .replace("Flag2", "Flag3");
+        fs::write(output_path, &edited_code).unwrap();
+        let result = generate_bairiak_enums("test_data/valid_spec.yaml", output_path);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::OutputModifiedError
+        );
+        assert_eq!(fs::read_to_string(output_path).unwrap(), edited_code);
+
+        fs::write(output_path, "fn main() {}\n").unwrap();
+        let result = generate_bairiak_enums("test_data/valid_spec.yaml", output_path);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::OutputModifiedError
+        );
+
+        fs::remove_file(output_path).unwrap();
     }
-}
 
-#[derive(Debug, Deserialize)]
-struct EnumSpec {
-    enums: Vec<Enum>,
-}
+    #[test]
+    fn test_generate_bairiak_enums_forced_overwrites_hand_edited_output() {
+        let output_path = "forced_output.rs";
+        fs::write(output_path, "fn main() {}\n").unwrap();
+        let result = generate_bairiak_enums_forced("test_data/valid_spec.yaml", output_path);
+        assert!(result.is_ok());
 
-#[derive(Debug, Deserialize)]
-struct Enum {
-    name: String,
-    variants: Vec<String>,
-}
+        assert!(unmodified_body_hash(Path::new(output_path)).is_some());
+        fs::remove_file(output_path).unwrap();
+    }
 
-fn is_camel_case(s: &str) -> bool {
-    let re = Regex::new(r"^[^a-z0-9][\w]*$").unwrap();
-    re.is_match(s)
-}
+    // Records the size of every write, failing once `limit` bytes are written.
+    struct CountingWriter {
+        writes: Vec<usize>,
+        limit: usize,
+    }
 
-fn generete_zero_bairiak(variants_len: usize) -> Result<String, BairiakError> {
-    let zero_bairiak = match variants_len {
-        0..8 => "Bairiak::U8(0u8)",
-        8..16 => "Bairiak::U16(0u16)",
-        16..32 => "Bairiak::U32(0u32)",
-        32..64 => "Bairiak::U64(0u64)",
-        64..128 => "Bairiak::U128(0u128)",
-        err => {
-            eprintln!(
-                "Error parsing Bairiak enums\nError: Position out of range: {}. Maximum positions supported is 128.",
-                err
-            );
-            return Err(BairiakError::PositionOutOfRangeError);
+    impl io::Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.writes.iter().sum::<usize>() + buf.len() > self.limit {
+                return Err(io::Error::other("disk full"));
+            }
+            self.writes.push(buf.len());
+            Ok(buf.len())
         }
-    };
-
-    Ok(zero_bairiak.to_string())
-}
 
-fn validate_enum(name: &str, variants: &Vec<String>) -> Result<(), BairiakError> {
-    if !is_camel_case(name) {
-        eprintln!("Error parsing Bairiak enums\nError: Invalid enum name. Enum name should be in CamelCase.");
-        return Err(BairiakError::ParseBairiakEnumsError);
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
     }
 
-    if variants.is_empty() {
-        eprintln!("Error parsing Bairiak enums\nError: Enum variants cannot be empty.");
-        return Err(BairiakError::ParseBairiakEnumsError);
+    // Test for streaming the same code as the string-returning API
+    #[test]
+    fn test_generate_bairiak_enums_to_writer() {
+        let spec = fs::read_to_string("test_data/multi_enum_spec.yaml").unwrap();
+        let mut out = Vec::new();
+        generate_bairiak_enums_to_writer(&spec, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            generate_bairiak_enums_from_str(&spec).unwrap()
+        );
     }
 
-    return Ok(());
-}
+    // Test for writing one enum at a time
+    #[test]
+    fn test_generate_bairiak_enums_to_writer_is_incremental() {
+        let spec = test_support::large_spec(10, 8);
+        let mut out = CountingWriter {
+            writes: Vec::new(),
+            limit: usize::MAX,
+        };
+        generate_bairiak_enums_to_writer(&spec, &mut out).unwrap();
 
-fn generate_enum(e: &Enum) -> Result<String, BairiakError> {
-    validate_enum(&e.name, &e.variants)?;
+        // The header, the imports and then each enum.
+        assert_eq!(out.writes.len(), 12);
+        let total: usize = out.writes.iter().sum();
+        assert!(out.writes.iter().all(|&len| len < total / 5));
+    }
 
-    let mut enum_code = format!(
-        "
-#[repr(u8)]
-#[allow(dead_code)]
-#[derive(Hash, Eq, PartialEq, Debug)]
-enum {} {{
-",
-        e.name
-    );
+    // Test for errors while streaming
+    #[test]
+    fn test_generate_bairiak_enums_to_writer_errors() {
+        let spec = test_support::large_spec(10, 8);
+        let mut out = CountingWriter {
+            writes: Vec::new(),
+            limit: 2000,
+        };
+        let result = generate_bairiak_enums_to_writer(&spec, &mut out);
+        assert_eq!(result.unwrap_err().kind(), BairiakErrorKind::IoError);
+        assert!(!out.writes.is_empty());
 
-    let zero_bairiak = generete_zero_bairiak(e.variants.len())?;
+        let mut out = CountingWriter {
+            writes: Vec::new(),
+            limit: usize::MAX,
+        };
+        let result = generate_bairiak_enums_to_writer(&test_support::large_spec(1, 129), &mut out);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
+        assert!(out.writes.is_empty());
+    }
 
-    for i in 0..e.variants.len() {
-        let Some(v) = e.variants.get(i) else {
-            eprintln!("Error parsing Bairiak enums");
-            return Err(BairiakError::ParseBairiakEnumsError);
+    // Test for failed writes leaving the previous output, or none
+    #[test]
+    fn test_write_output_failure_leaves_no_partial_file() {
+        let output_path = Path::new("failed_output.rs");
+        let temp_path = "failed_output.rs.bairiak-tmp";
+        let fail = |out: &mut BufWriter<File>| {
+            io::Write::write_all(out, b"partial").unwrap();
+            Err(write_file_error(output_path, io::Error::other("disk full")))
         };
 
-        if !is_camel_case(v) {
-            eprintln!("Error parsing Bairiak enums\nError: Invalid enum variant. Enum variant should be in CamelCase.");
-            return Err(BairiakError::ParseBairiakEnumsError);
-        }
+        let result = write_output(output_path, false, 0, fail);
+        assert_eq!(result.unwrap_err().kind(), BairiakErrorKind::WriteFileError);
+        assert!(!Path::new(output_path).exists());
+        assert!(!Path::new(temp_path).exists());
+
+        assert!(generate_bairiak_enums("test_data/valid_spec.yaml", output_path).is_ok());
+        let previous_code = fs::read_to_string(output_path).unwrap();
+        let result = write_output(output_path, false, 0, fail);
+        assert_eq!(result.unwrap_err().kind(), BairiakErrorKind::WriteFileError);
+        assert_eq!(fs::read_to_string(output_path).unwrap(), previous_code);
+        assert!(!Path::new(temp_path).exists());
 
-        let variant = &format!("    {} = {},\n", v, i);
-        enum_code.push_str(variant);
+        fs::remove_file(output_path).unwrap();
     }
 
-    enum_code.push_str(&format!(
-        "}}
+    // Test for output paths that would clobber the spec or aren't files
+    #[test]
+    fn test_generate_bairiak_enums_rejects_bad_output_paths() {
+        let dir = "path_guard_test";
+        fs::create_dir_all(dir).unwrap();
+        let spec_path = "path_guard_test/spec.yaml";
+        fs::copy("test_data/valid_spec.yaml", spec_path).unwrap();
 
-impl BairiakEnum for {} {{
-    fn get_zero_bairiak() -> Bairiak {{
-        {}
-    }}
+        let result = generate_bairiak_enums(spec_path, spec_path);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::InvalidOutputPathError
+        );
 
-    fn to_u8(self) -> u8 {{
-        self as u8
-    }}
-}}\n",
-        e.name, zero_bairiak,
-    ));
+        let absolute_spec_path = Path::new(spec_path).canonicalize().unwrap();
+        let result = generate_bairiak_enums_forced(
+            "path_guard_test/../path_guard_test/spec.yaml",
+            absolute_spec_path.to_str().unwrap(),
+        );
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::InvalidOutputPathError
+        );
+        assert_eq!(
+            fs::read_to_string(spec_path).unwrap(),
+            fs::read_to_string("test_data/valid_spec.yaml").unwrap()
+        );
 
-    Ok(enum_code)
-}
+        let result = generate_bairiak_enums(spec_path, dir);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::InvalidOutputPathError
+        );
 
-fn generate_enums(enums: &EnumSpec) -> Result<String, BairiakError> {
-    let mut enums_code = String::new();
-    for e in &enums.enums {
-        enums_code.push_str(&generate_enum(e)?);
+        let options = GenerateOptions::default().strict(true);
+        let result = generate_bairiak_enums_with(spec_path, "path_guard_test/out.json", &options);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::InvalidOutputPathError
+        );
+        assert!(generate_bairiak_enums(spec_path, "path_guard_test/out.json").is_ok());
+
+        // Next to the spec, as most projects have it.
+        assert!(
+            generate_bairiak_enums_with(spec_path, "path_guard_test/flags.rs", &options).is_ok()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
     }
-    Ok(enums_code)
-}
 
-pub fn generate_bairiak_enums(
-    bairiak_spec_path: &str,
-    output_path: &str,
-) -> Result<(), BairiakError> {
-    let yaml_content = match fs::read_to_string(bairiak_spec_path) {
-        Ok(content) => content,
-        Err(err) => {
-            eprintln!("Error reading file: {}", err);
-            return Err(BairiakError::ReadSpecError);
-        }
-    };
+    // Test for paths given as `PathBuf`s, kept as they are in errors
+    #[test]
+    fn test_generate_bairiak_enums_with_path_bufs() {
+        let spec_path: PathBuf = ["test_data", "valid_spec.yaml"].iter().collect();
+        let output_path: PathBuf = [".", "path_buf_output.rs"].iter().collect();
+        assert!(generate_bairiak_enums(&spec_path, &output_path).is_ok());
+        assert!(load_spec(spec_path).is_ok());
+        fs::remove_file(&output_path).unwrap();
 
-    let enums = match serde_yaml::from_str(&yaml_content) {
-        Ok(content) => content,
-        Err(err) => {
-            eprintln!("Error reading file: {}", err);
-            return Err(BairiakError::DeserializeYamlError);
-        }
-    };
+        let missing_path: PathBuf = ["test_data", "missing_spec.yaml"].iter().collect();
+        let err = generate_bairiak_enums(&missing_path, &output_path).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ReadSpecError);
+        assert_eq!(err.path(), Some(missing_path.as_path()));
+        assert!(!output_path.exists());
+    }
 
-    let imports_code = "use bairiak::{Bairiak, BairiakEnum};";
+    // Test for the default options generating today's code
+    #[test]
+    fn test_generate_with_default_options() {
+        let spec = fs::read_to_string("test_data/valid_spec.yaml").unwrap();
+        assert_eq!(
+            generate_bairiak_enums_from_str_with(&spec, &GenerateOptions::default()).unwrap(),
+            generate_bairiak_enums_from_str(&spec).unwrap()
+        );
+    }
 
-    let enums_code = generate_enums(&enums)?;
+    // Test for combining the module, visibility and force options
+    #[test]
+    fn test_generate_bairiak_enums_with_options() {
+        let output_path = "options_output.rs";
+        fs::write(output_path, "fn main() {}\n").unwrap();
+        let options = GenerateOptions::default()
+            .module("flags")
+            .visibility(Visibility::Pub);
+        let result =
+            generate_bairiak_enums_with("test_data/valid_spec.yaml", output_path, &options);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::OutputModifiedError
+        );
 
-    let bairiak_enums_code = format!("{}\n{}", imports_code, enums_code);
+        let options = options.force(true);
+        assert!(
+            generate_bairiak_enums_with("test_data/valid_spec.yaml", output_path, &options).is_ok()
+        );
+        let generated_code = fs::read_to_string(output_path).unwrap();
+        fs::remove_file(output_path).unwrap();
 
-    match fs::write(output_path, bairiak_enums_code) {
-        Ok(_) => {}
-        Err(err) => {
-            eprintln!("Error writing file: {}", err);
-            return Err(BairiakError::WriteFileError);
-        }
-    };
+        let spec = fs::read_to_string("test_data/valid_spec.yaml").unwrap();
+        assert_eq!(
+            generated_code,
+            generate_bairiak_enums_from_str_with(&spec, &options).unwrap()
+        );
+        assert!(generated_code.contains("\npub mod flags {\n    use bairiak::"));
+        assert!(generated_code.contains("\n    pub enum TestEnum {\n        Flag0 = 0,\n"));
+        assert!(generated_code.ends_with("\n    }\n}\n"));
+    }
 
-    Ok(())
-}
+    #[test]
+    fn test_bool_struct_fields() {
+        assert_eq!(bool_field("DarkMode"), "dark_mode");
+        assert_eq!(bool_field("Match"), "r#match");
+        assert_eq!(bool_field("Super"), "super_");
 
-pub fn generate_bairiak<B: BairiakEnum>(flags: HashSet<B>) -> Bairiak {
-    let mut bairiak = B::get_zero_bairiak();
-    for flag in flags {
-        let flag_value = flag.to_u8();
-        match &mut bairiak {
-            Bairiak::U8(ref mut value) => *value |= 1u8 << flag_value,
-            Bairiak::U16(ref mut value) => *value |= 1u16 << flag_value,
-            Bairiak::U32(ref mut value) => *value |= 1u32 << flag_value,
-            Bairiak::U64(ref mut value) => *value |= 1u64 << flag_value,
-            Bairiak::U128(ref mut value) => *value |= 1u128 << flag_value,
-        }
+        let spec =
+            "enums:\n  - name: Perm\n    bool_struct: true\n    variants: [HttpGet, HTTPGet]\n";
+        let err = generate_bairiak_enums_from_str(spec).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Variants `HttpGet` and `HTTPGet` of `Perm` both take the field `http_get` of `PermBools`."
+        );
+        // Only a field needs a distinct name.
+        let spec = spec.replace("    bool_struct: true\n", "");
+        assert!(generate_bairiak_enums_from_str(&spec).is_ok());
     }
-    bairiak
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::{collections::HashSet, sync::Once};
+    #[test]
+    fn test_bitflags_consts_collide() {
+        let spec =
+            "enums:\n  - name: Perm\n    emit_bitflags: true\n    variants: [HttpGet, HTTPGet]\n";
+        let err = generate_bairiak_enums_from_str(spec).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "Variants `HttpGet` and `HTTPGet` of `Perm` both take the constant `HTTP_GET` of `PermFlags`."
+        );
+    }
 
-    static TEST_TEARDOWN: Once = Once::new();
+    #[test]
+    fn test_bool_struct_gated_variants() {
+        let spec = "enums:\n  - name: Perm\n    bool_struct: true\n    experimental_feature: beta-flags\n    variants: [Read, {name: Write, status: experimental}]\n";
+        let code = generate_bairiak_enums_from_str(spec).unwrap();
+        assert!(code
+            .contains("    read: bool,\n    #[cfg(feature = \"beta-flags\")]\n    write: bool,\n"));
+        assert!(
+            code.contains("        #[cfg(feature = \"beta-flags\")]\n        if bools.write {\n")
+        );
+    }
 
-    // Test for Bairiak::is_false and Bairiak::is_true methods
-    #[repr(u8)]
-    #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-    enum TestEnum {
-        Flag0 = 0,
-        Flag1 = 1,
-        Flag2 = 2,
+    // Test for private enums staying visible to the module around `flags`
+    #[test]
+    fn test_generate_private_enums_in_module() {
+        let options = GenerateOptions::default().module("flags");
+        let generated_code = generate_bairiak_enums_from_str_with(
+            "enums:\n  - name: Perm\n    variants: [Read]\n    emit_bitflags: true\n",
+            &options,
+        )
+        .unwrap();
+        assert!(generated_code.contains("\nmod flags {\n"));
+        assert!(generated_code.contains("    pub(super) enum Perm {"));
+        assert!(generated_code.contains("        pub(super) struct PermFlags: u8 {"));
+
+        let result = generate_bairiak_enums_from_str_with(
+            "enums:\n  - name: Perm\n    variants: [Read]\n",
+            &GenerateOptions::default().module("Flags"),
+        );
+        assert_eq!(
+            result.unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
     }
 
-    impl BairiakEnum for TestEnum {
-        fn get_zero_bairiak() -> Bairiak {
-            Bairiak::U8(0u8)
-        }
+    // Test for per-enum settings overriding the spec's, which override the
+    // options
+    #[test]
+    fn test_settings_precedence() {
+        let spec = "
+settings:
+  visibility: pub(crate)
+  derives: [PartialOrd, Ord]
+enums:
+  - name: Perm
+    variants: [Read]
+  - name: Mode
+    variants: [Fast]
+    visibility: private
+    derives: [serde::Serialize]
+";
+        let options = GenerateOptions::default().visibility(Visibility::Pub);
+        let generated_code = generate_bairiak_enums_from_str_with(spec, &options).unwrap();
+        assert!(generated_code.contains(
+            "#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, PartialOrd, Ord)]\npub(crate) enum Perm {"
+        ));
+        assert!(generated_code.contains(
+            "#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, serde::Serialize)]\nenum Mode {"
+        ));
 
-        fn to_u8(self) -> u8 {
-            self as u8
-        }
+        let spec = "enums:\n  - name: Perm\n    variants: [Read]\n";
+        let generated_code = generate_bairiak_enums_from_str_with(spec, &options).unwrap();
+        assert!(generated_code
+            .contains("#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]\npub enum Perm {"));
     }
 
+    // Test for invalid and duplicate derives
     #[test]
-    fn test_bairiak_is_false() {
-        let bairiak = Bairiak::U8(0);
-        assert!(bairiak.is_false(TestEnum::Flag0));
-        assert!(bairiak.is_false(TestEnum::Flag1));
-        assert!(bairiak.is_false(TestEnum::Flag2));
+    fn test_settings_derives() {
+        let spec =
+            "settings:\n  derives: [Debug, Ord]\nenums:\n  - name: Perm\n    variants: [Read]\n";
+        let generated_code = generate_bairiak_enums_from_str(spec).unwrap();
+        assert!(generated_code.contains("#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, Ord)]"));
+
+        let spec = "enums:\n  - name: Perm\n    variants: [Read]\n    derives: [\"Ord)] struct X; #[derive(Eq\"]\n";
+        assert_eq!(
+            generate_bairiak_enums_from_str(spec).unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
+        assert_eq!(
+            parse_spec(spec).unwrap().validate().unwrap_err().kind(),
+            BairiakErrorKind::ParseBairiakEnumsError
+        );
     }
 
+    // Test for the lint attributes of the default spec settings, which allow
+    // dead code everywhere and nothing else
     #[test]
-    fn test_bairiak_is_true() {
-        let bairiak = Bairiak::U8(0b101);
-        assert!(bairiak.is_true(TestEnum::Flag0));
-        assert!(bairiak.is_false(TestEnum::Flag1));
-        assert!(bairiak.is_true(TestEnum::Flag2));
+    fn test_settings_default_lints() {
+        let spec = "enums:\n  - name: HTTP\n    variants: [Get]\n    groups:\n      - name: All\n        variants: [Get]\n    emit_bitflags: true\n";
+        let generated_code = generate_bairiak_enums_from_str(spec).unwrap();
+        assert!(generated_code
+            .contains("#[repr(u8)]\n#[allow(dead_code)]\n#[derive(Hash, Eq, PartialEq"));
+        assert!(generated_code.contains("#[allow(dead_code)]\nimpl HTTP {\n    pub fn groups()"));
+        assert!(generated_code.contains("    #[allow(dead_code)]\n    #[derive(Hash"));
+        assert!(!generated_code.contains("upper_case_acronyms"));
     }
 
-    // Test for generating Bairiak from a set of flags
+    // Test for dropping `allow(dead_code)` from every generated item
     #[test]
-    fn test_generate_bairiak() {
-        let mut flags = HashSet::new();
-        flags.insert(TestEnum::Flag0);
-        flags.insert(TestEnum::Flag2);
+    fn test_settings_warn_dead_code() {
+        let spec = "
+settings:
+  allow_dead_code: false
+enums:
+  - name: Perm
+    variants: [Read, Write]
+    groups:
+      - name: All
+        variants: [Read, Write]
+    exclusive: [[Read, Write]]
+    emit_bitflags: true
+    position_consts: free
+packed:
+  - name: State
+    bits: 8
+    members: [Perm]
+";
+        let generated_code = generate_bairiak_enums_from_str(spec).unwrap();
+        assert!(!generated_code.contains("dead_code"));
+        assert!(generated_code.contains("#[repr(u8)]\n#[derive(Hash"));
+        assert!(generated_code.contains(
+            "\n    #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]\n    struct PermFlags"
+        ));
+        assert!(generated_code.contains("\nfn state_layout()"));
+    }
 
-        let bairiak = generate_bairiak(flags);
-        match bairiak {
-            Bairiak::U8(value) => assert_eq!(value, 0b101),
-            _ => panic!("Expected Bairiak::U8"),
-        }
+    // Test for allowing `clippy::upper_case_acronyms` only where a name needs it
+    #[test]
+    fn test_settings_upper_case_acronyms() {
+        let spec = "
+settings:
+  allow_upper_case_acronyms: true
+enums:
+  - name: HTTP
+    variants: [Get]
+  - name: Proto
+    variants: [Udp, TCPFast]
+  - name: Perm
+    variants: [Read]
+";
+        let generated_code = generate_bairiak_enums_from_str(spec).unwrap();
+        let allow = "#[allow(dead_code)]\n#[allow(clippy::upper_case_acronyms)]\n#[derive(";
+        assert!(generated_code.contains(&format!(
+            "{}Hash, Eq, PartialEq, Debug, Clone, Copy)]\nenum HTTP {{",
+            allow
+        )));
+        assert!(generated_code.contains(&format!(
+            "{}Hash, Eq, PartialEq, Debug, Clone, Copy)]\nenum Proto {{",
+            allow
+        )));
+        assert!(generated_code.contains(
+            "#[allow(dead_code)]\n#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]\nenum Perm {"
+        ));
     }
 
-    // Test for generating enums from Enum struct with invalid name
+    // Test for lint attributes merged with the defaults, an enum's replacing
+    // the spec's
     #[test]
-    fn test_generate_enum_with_invalid_name() {
-        let e = Enum {
-            name: String::from("1"),
-            variants: vec![
-                String::from("Var0"),
-                String::from("Var1"),
-                String::from("Var2"),
-            ],
-        };
+    fn test_settings_lint_attrs() {
+        let spec = "
+settings:
+  lint_attrs: [\"warn(missing_docs)\", \"allow(dead_code)\"]
+enums:
+  - name: Perm
+    variants: [Read]
+  - name: Mode
+    variants: [Fast]
+    lint_attrs: [\"allow(clippy::enum_variant_names, clippy::all)\"]
+";
+        let generated_code = generate_bairiak_enums_from_str(spec).unwrap();
+        assert!(generated_code.contains(
+            "#[allow(dead_code)]\n#[warn(missing_docs)]\n#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]\nenum Perm {"
+        ));
+        assert!(generated_code.contains(
+            "#[allow(dead_code)]\n#[allow(clippy::enum_variant_names, clippy::all)]\n#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]\nenum Mode {"
+        ));
 
-        let result = generate_enum(&e);
-        assert!(matches!(result, Err(BairiakError::ParseBairiakEnumsError)));
+        for attr in [
+            "missing_docs",
+            "allow(dead_code)] struct X; #[allow(x)",
+            "allow()",
+            "lint(x)",
+        ] {
+            let spec = format!(
+                "enums:\n  - name: Perm\n    variants: [Read]\n    lint_attrs: [{:?}]\n",
+                attr
+            );
+            let err = generate_bairiak_enums_from_str(&spec).unwrap_err();
+            assert_eq!(
+                err.message(),
+                format!("Invalid lint attribute `{}` for `Perm`. Lint attributes should be like `allow(clippy::enum_variant_names)`.", attr)
+            );
+            assert_eq!(
+                parse_spec(&spec).unwrap().validate().unwrap_err().kind(),
+                BairiakErrorKind::ParseBairiakEnumsError
+            );
+        }
     }
 
+    // Test for the Display option
     #[test]
-    fn test_generate_enum_with_invalid_variant_lowercase() {
-        let e = Enum {
-            name: String::from("TestEnum"),
-            variants: vec![
-                String::from("Var0"),
-                String::from("var1"),
-                String::from("Var2"),
-            ],
-        };
+    fn test_generate_display() {
+        let spec = fs::read_to_string("test_data/valid_spec.yaml").unwrap();
+        let display_impl = "impl core::fmt::Display for TestEnum {";
+        let generated_code = generate_bairiak_enums_from_str(&spec).unwrap();
+        assert!(!generated_code.contains(display_impl));
 
-        let result = generate_enum(&e);
-        assert!(matches!(result, Err(BairiakError::ParseBairiakEnumsError)));
+        let options = GenerateOptions::default().display(true);
+        let generated_code = generate_bairiak_enums_from_str_with(&spec, &options).unwrap();
+        assert!(generated_code.contains(display_impl));
+        assert!(generated_code.contains("f.pad(BairiakEnum::name(self))"));
     }
 
+    // Test for validating a spec without generating it
     #[test]
-    fn test_generate_enum_with_invalid_variant_number() {
-        let e = Enum {
-            name: String::from("TestEnum"),
-            variants: vec![
-                String::from("Var0"),
-                String::from("1var"),
-                String::from("Var2"),
-            ],
-        };
+    fn test_validate_large_spec() {
+        let spec = parse_spec(&test_support::large_spec(8, 125)).unwrap();
+        assert!(spec.validate().is_ok());
 
-        let result = generate_enum(&e);
-        assert!(matches!(result, Err(BairiakError::ParseBairiakEnumsError)));
+        let spec = parse_spec(&test_support::large_spec(1, 129)).unwrap();
+        assert_eq!(
+            spec.validate().unwrap_err().kind(),
+            BairiakErrorKind::PositionOutOfRangeError
+        );
     }
 
+    // Test for the parallel path generating the same code as the serial one
+    #[cfg(feature = "rayon")]
     #[test]
-    fn test_generate_enum_with_invalid_variant_symbol() {
-        let e = Enum {
-            name: String::from("TestEnum"),
-            variants: vec![
-                String::from("Var0"),
-                String::from("var!"),
-                String::from("Var2"),
-            ],
-        };
+    fn test_generate_enums_matches_serial() {
+        let spec = parse_spec(&test_support::large_spec(16, 20)).unwrap();
+        let serial: String = spec
+            .enums
+            .iter()
+            .map(|e| enum_code(e, &spec.settings, &GenerateOptions::default()).unwrap())
+            .collect();
+        assert_eq!(
+            generate_enums(&spec, &GenerateOptions::default()).unwrap(),
+            serial
+        );
+    }
 
-        let result = generate_enum(&e);
-        assert!(matches!(result, Err(BairiakError::ParseBairiakEnumsError)));
+    // Test for reporting the first failing enum in spec order
+    #[test]
+    fn test_generate_enums_reports_first_error() {
+        let mut spec = parse_spec(&test_support::large_spec(20, 8)).unwrap();
+        spec.enums[5].name = String::from("bad_name");
+        spec.enums[15].variants.clear();
+        let err = generate_enums(&spec, &GenerateOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert!(err.message().contains("`bad_name`"));
     }
 
-    // Test for generating enums from Enum struct with empty variants
+    // Test for the CamelCase rule in both naming modes
     #[test]
-    fn test_generate_enum_with_empty_variants() {
-        let e = Enum {
-            name: String::from("TestEnum"),
-            variants: vec![],
-        };
+    fn test_camel_case_names() {
+        // Name, accepted when strict, accepted when lenient.
+        let names = [
+            ("A", true, true),
+            ("Z9", true, true),
+            ("Read", true, true),
+            ("ReadWrite", true, true),
+            ("HTTP2", true, true),
+            ("IsV2Enabled", true, true),
+            ("Read_Write", false, true),
+            ("A_", false, true),
+            ("Read__Write", false, true),
+            ("", false, false),
+            ("a", false, false),
+            ("read", false, false),
+            ("_Read", false, false),
+            ("_", false, false),
+            ("1Read", false, false),
+            ("123", false, false),
+            ("9", false, false),
+            ("Read-Write", false, false),
+            ("Read Write", false, false),
+            ("Read!", false, false),
+            ("ÀRead", false, false),
+            ("Ψ", false, false),
+            ("ReadΨ", false, false),
+            ("Café", false, false),
+            ("Ｒead", false, false),
+        ];
+
+        for (name, strict, lenient) in names {
+            assert_eq!(
+                camel_case_violation(name, Naming::Strict).is_none(),
+                strict,
+                "strict: {}",
+                name
+            );
+            assert_eq!(
+                camel_case_violation(name, Naming::Lenient).is_none(),
+                lenient,
+                "lenient: {}",
+                name
+            );
+        }
 
-        let result = generate_enum(&e);
-        assert!(matches!(result, Err(BairiakError::ParseBairiakEnumsError)));
+        assert_eq!(
+            camel_case_violation("ReadΨ", Naming::Lenient).unwrap(),
+            "`Ψ` at index 4 is not an ASCII letter, digit or `_`"
+        );
+        assert_eq!(
+            camel_case_violation("Read_Write", Naming::Strict).unwrap(),
+            "`_` at index 4 is not an ASCII letter or digit"
+        );
+        assert_eq!(
+            camel_case_violation("", Naming::Strict).unwrap(),
+            "it is empty"
+        );
     }
 
-    // Test for generating enums from Enum struct
+    // Test for strict naming set in the spec
     #[test]
-    fn test_generate_enum() {
+    fn test_validate_with_strict_naming() {
+        let spec =
+            "settings:\n  naming: strict\nenums:\n  - name: Perm\n    variants: [Read_Write]\n";
+        let err = parse_spec(spec).unwrap().validate().unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Invalid enum variant `Read_Write` in `Perm`: `_` at index 4 is not an ASCII letter or digit. Enum variant should be in CamelCase."
+        );
+        assert!(parse_spec(&spec.replace("strict", "lenient"))
+            .unwrap()
+            .validate()
+            .is_ok());
+    }
+
+    // Test for errors carrying their full message
+    #[test]
+    fn test_error_message() {
         let e = Enum {
             name: String::from("TestEnum"),
-            variants: vec![
-                String::from("Var0"),
-                String::from("Var1"),
-                String::from("Var2"),
-            ],
+            variants: vec![Variant::from("Var0"), Variant::from("var1")],
+            ..Default::default()
         };
 
-        let result = generate_enum(&e);
-        assert!(result.is_ok());
-
-        let generated_code = result.unwrap();
-        assert!(generated_code.contains("enum TestEnum"));
-        assert!(generated_code.contains("Var0 = 0"));
-        assert!(generated_code.contains("Var1 = 1"));
-        assert!(generated_code.contains("Var2 = 2"));
+        let err = enum_code(&e, &Settings::default(), &GenerateOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error parsing Bairiak enums: Invalid enum variant `var1` in `TestEnum`: `v` at index 0 is not an ASCII uppercase letter. Enum variant should be in CamelCase."
+        );
     }
 
-    // Test for the overall enum generation function
+    // Test for error kind predicates and conversions
     #[test]
-    fn test_generate_enums() {
-        let spec = EnumSpec {
-            enums: vec![Enum {
-                name: String::from("TestEnum"),
-                variants: vec![String::from("Var0"), String::from("Var1")],
-            }],
-        };
+    fn test_error_kind() {
+        let err = load_spec("test_data/nonexistent_spec.yaml").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ReadSpecError);
+        assert!(err.is_io());
+        assert!(!err.is_spec());
 
-        let result = generate_enums(&spec);
-        assert!(result.is_ok());
+        let err = parse_spec("enums: [").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::DeserializeYamlError);
+        assert!(err.is_spec());
+        assert!(!err.is_io());
 
-        let generated_code = result.unwrap();
-        assert!(generated_code.contains("enum TestEnum"));
-        assert!(generated_code.contains("Var0 = 0"));
-        assert!(generated_code.contains("Var1 = 1"));
+        let err = BairiakError::from(std::io::Error::other("disk full"));
+        assert_eq!(err.kind(), BairiakErrorKind::IoError);
+        assert_eq!(err.message(), "disk full");
+        assert_eq!(err.to_string(), "I/O error: disk full");
+        assert!(err.is_io());
     }
 
-    // Test for file generation success case
-    #[test]
-    fn test_generate_bairiak_enums_success() {
-        let result = generate_bairiak_enums("test_data/valid_spec.yaml", "output.rs");
-        assert!(result.is_ok());
-        TEST_TEARDOWN.call_once(|| {
-            fs::remove_file("output.rs").unwrap();
-        });
-    }
+    #[cfg(feature = "log")]
+    mod logging {
+        use super::*;
+        use log::{Level, LevelFilter, Log, Metadata, Record};
+        use std::sync::{Mutex, Once};
 
-    #[test]
-    fn test_generate_bairiak_enums_with_more_than_max_flags() {
-        let result = generate_bairiak_enums("test_data/out_of_range_spec.yaml", "output.rs");
-        assert!(matches!(result, Err(BairiakError::PositionOutOfRangeError)));
+        static RECORDS: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+        static LOGGER: CapturingLogger = CapturingLogger;
+        static INIT: Once = Once::new();
+
+        struct CapturingLogger;
+
+        impl Log for CapturingLogger {
+            fn enabled(&self, metadata: &Metadata) -> bool {
+                metadata.target() == "bairiak"
+            }
+
+            fn log(&self, record: &Record) {
+                if self.enabled(record.metadata()) {
+                    let message = (record.level(), record.args().to_string());
+                    RECORDS.lock().unwrap().push(message);
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        fn capture() {
+            INIT.call_once(|| {
+                log::set_logger(&LOGGER).unwrap();
+                log::set_max_level(LevelFilter::Debug);
+            });
+        }
+
+        fn logged(level: Level, message: &str) -> bool {
+            RECORDS
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|(l, m)| *l == level && m.contains(message))
+        }
+
+        #[test]
+        fn test_logs_phases() {
+            capture();
+            let result = generate_bairiak_enums_from_str(
+                "enums:\n  - name: LoggedPhases\n    variants: [Var0]\n",
+            );
+            assert!(result.is_ok());
+            assert!(logged(Level::Debug, "Parsing spec"));
+            assert!(logged(Level::Debug, "Generating enum LoggedPhases"));
+        }
+
+        #[test]
+        fn test_logs_errors() {
+            capture();
+            let result = generate_bairiak_enums_from_str(
+                "enums:\n  - name: LoggedErrors\n    variants: []\n",
+            );
+            assert!(result.is_err());
+            assert!(logged(
+                Level::Error,
+                "Enum variants of `LoggedErrors` cannot be empty."
+            ));
+        }
+
+        #[test]
+        fn test_runtime_errors_not_logged() {
+            capture();
+            assert!("0xlogged".parse::<Bairiak>().is_err());
+            assert!(Bairiak::U8(0).try_set(Bit(8)).is_err());
+            assert!(!logged(Level::Error, "0xlogged"));
+            assert!(!logged(
+                Level::Error,
+                "Position 8 is out of range for a u8 value."
+            ));
+        }
+
+        #[test]
+        fn test_logs_self_requirement() {
+            capture();
+            let result = generate_bairiak_enums_from_str(
+                "enums:\n  - name: LoggedRequires\n    variants:\n      - name: Read\n        requires: [Read]\n",
+            );
+            assert!(result.is_ok());
+            assert!(logged(
+                Level::Warn,
+                "LoggedRequires: `Read` in `LoggedRequires` requires itself. [self_requirement]"
+            ));
+        }
+
+        #[test]
+        fn test_logs_forced_overwrite() {
+            capture();
+            let output_path = "logged_output.rs";
+            fs::write(output_path, "fn main() {}\n").unwrap();
+            let result = generate_bairiak_enums_forced("test_data/valid_spec.yaml", output_path);
+            fs::remove_file(output_path).unwrap();
+            assert!(result.is_ok());
+            assert!(logged(Level::Warn, "Overwriting logged_output.rs"));
+            assert!(logged(Level::Debug, "Writing logged_output.rs"));
+        }
     }
 
     // Test for file generation failure due to missing file
     #[test]
     fn test_generate_bairiak_enums_file_not_found() {
         let result = generate_bairiak_enums("non_existent_file.yaml", "output.rs");
-        assert!(matches!(result, Err(BairiakError::ReadSpecError)));
+        assert_eq!(result.unwrap_err().kind(), BairiakErrorKind::ReadSpecError);
+    }
+
+    // Test for reporting every problem of a spec with its location
+    #[test]
+    fn test_validate_all() {
+        let spec = load_spec("test_data/problems_spec.yaml").unwrap();
+        let problems = spec.validate_all();
+        let locations: Vec<_> = problems
+            .iter()
+            .map(|p| (p.enum_name.as_str(), p.variant.as_deref(), p.index))
+            .collect();
+        assert_eq!(
+            locations,
+            [
+                ("Perm", Some("write"), Some(1)),
+                ("Perm", Some("View"), Some(3)),
+                ("Codec", None, None),
+            ]
+        );
+        assert!(problems
+            .iter()
+            .all(|p| p.kind == BairiakErrorKind::ParseBairiakEnumsError));
+        assert_eq!(
+            problems[1].to_string(),
+            "Perm.variants[3]: Duplicate position 0 in `Perm`: `View` takes a position already taken."
+        );
+        assert_eq!(
+            problems[2].to_string(),
+            "Codec: Unknown variant `Brotli` in exclusive group 0 of `Codec`."
+        );
+
+        // Failing fast reports the first of them.
+        assert_eq!(spec.validate().unwrap_err().message(), problems[0].message);
+        assert!(generate_bairiak_enums_from_str(
+            &fs::read_to_string("test_data/problems_spec.yaml").unwrap()
+        )
+        .is_err());
+
+        assert_eq!(
+            load_spec("test_data/valid_spec.yaml")
+                .unwrap()
+                .validate_all(),
+            []
+        );
     }
 
-    // Test for file generation failure due to invalid YAML
+    // Test for file generation failure due to an empty spec file
     #[test]
     fn test_generate_bairiak_enums_invalid_yaml() {
         let result = generate_bairiak_enums("test_data/invalid_spec.yaml", "output.rs");
-        assert!(matches!(result, Err(BairiakError::DeserializeYamlError)));
+        assert_eq!(result.unwrap_err().kind(), BairiakErrorKind::EmptySpecError);
     }
 }
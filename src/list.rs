@@ -0,0 +1,91 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{EnumSpec, Result, SpecFlag};
+
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub struct EnumListing {
+    pub name: String,
+    pub width: u8,
+    pub variant_count: usize,
+    pub positions: Vec<SpecFlag>,
+}
+
+impl fmt::Display for EnumListing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} (u{}, {} variants)",
+            self.name, self.width, self.variant_count
+        )?;
+        for flag in &self.positions {
+            writeln!(f, "  {:>3}  {}", flag.position, flag.name)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn list_enums(spec: &EnumSpec) -> Result<Vec<EnumListing>> {
+    spec.enums
+        .iter()
+        .map(|e| {
+            Ok(EnumListing {
+                name: e.name.clone(),
+                width: e.width()?,
+                variant_count: e.variant_count(),
+                positions: e
+                    .positioned_variants()
+                    .map(|(position, v)| SpecFlag {
+                        name: v.to_string(),
+                        position: position as u16,
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Enum, Variant};
+
+    #[test]
+    fn test_list_enums() {
+        let spec = EnumSpec {
+            enums: vec![
+                Enum {
+                    name: String::from("Perm"),
+                    variants: vec![Variant::from("Read"), Variant::from("Write")],
+                    ..Default::default()
+                },
+                Enum {
+                    name: String::from("Wide"),
+                    variants: (0..9)
+                        .map(|i| Variant::from(format!("Flag{}", i)))
+                        .collect(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let listings = list_enums(&spec).unwrap();
+        assert_eq!(listings[0].name, "Perm");
+        assert_eq!(listings[0].width, 8);
+        assert_eq!(listings[0].variant_count, 2);
+        assert_eq!(
+            listings[0].positions[1],
+            SpecFlag {
+                name: String::from("Write"),
+                position: 1,
+            }
+        );
+        assert_eq!(listings[1].width, 16);
+        assert_eq!(
+            listings[0].to_string(),
+            "Perm (u8, 2 variants)\n    0  Read\n    1  Write\n"
+        );
+    }
+}
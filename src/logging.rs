@@ -0,0 +1,33 @@
+// Internal logging macros. With the `log` feature they forward to the `log`
+// crate, otherwise they compile to nothing. Errors are always returned in full
+// through `BairiakError`, so nothing is lost when logging is off.
+
+#[cfg(feature = "log")]
+macro_rules! error {
+    ($($arg:tt)*) => { log::error!(target: "bairiak", $($arg)*) };
+}
+
+#[cfg(feature = "log")]
+macro_rules! warn {
+    ($($arg:tt)*) => { log::warn!(target: "bairiak", $($arg)*) };
+}
+
+#[cfg(feature = "log")]
+macro_rules! debug {
+    ($($arg:tt)*) => { log::debug!(target: "bairiak", $($arg)*) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! error {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! warn {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
@@ -0,0 +1,512 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fnv1a_64, replace_file, suggest, write_file_error, BairiakError, BairiakErrorKind, EnumSpec,
+    Result,
+};
+
+// What a generation run emitted, for tooling that needs the flags without
+// parsing Rust. The same spec and generator always give the same manifest.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub generator_version: String,
+    pub spec_version: Option<String>,
+    // The FNV-1a hash of the spec's content, as 16 hex digits.
+    pub spec_hash: String,
+    pub enums: Vec<EnumManifest>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct EnumManifest {
+    pub name: String,
+    pub width: u8,
+    // In spec order. Placeholders take a position but aren't listed.
+    pub variants: Vec<VariantManifest>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct VariantManifest {
+    pub name: String,
+    pub position: u8,
+    pub doc: Option<String>,
+    pub deprecated: Option<String>,
+    // The variant's stable `id`, left out of the JSON for enums without ids.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+}
+
+impl Manifest {
+    pub(crate) fn new(spec: &EnumSpec, spec_content: &str) -> Result<Manifest> {
+        let enums = spec
+            .enums
+            .iter()
+            .map(|e| {
+                Ok(EnumManifest {
+                    name: e.name.clone(),
+                    width: e.width()?,
+                    variants: e
+                        .documented_variants()
+                        .map(|(position, name, v)| VariantManifest {
+                            name: name.to_string(),
+                            position: position as u8,
+                            doc: v.doc().map(String::from),
+                            deprecated: v.deprecated().map(String::from),
+                            id: v.id(),
+                        })
+                        .collect(),
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Manifest {
+            generator_version: String::from(env!("CARGO_PKG_VERSION")),
+            spec_version: spec.version.clone(),
+            spec_hash: format!("{:016x}", fnv1a_64(spec_content.as_bytes())),
+            enums,
+        })
+    }
+
+    // Reads a manifest written by `to_json`, as by any generator version.
+    pub fn from_json(json: &str) -> Result<Manifest> {
+        serde_json::from_str(json)
+            .map_err(|err| BairiakError::new(BairiakErrorKind::ParseManifestError, err.to_string()))
+    }
+
+    pub fn find_enum(&self, enum_name: &str) -> Result<&EnumManifest> {
+        self.enums
+            .iter()
+            .find(|e| e.name == enum_name)
+            .ok_or_else(|| {
+                let suggestion =
+                    suggest::did_you_mean(enum_name, self.enums.iter().map(|e| e.name.as_str()));
+                BairiakError::new(
+                    BairiakErrorKind::UnknownEnumError,
+                    format!("Unknown enum: {}.{}", enum_name, suggestion),
+                )
+            })
+    }
+
+    // The variants of `enum_name` set in `value`, for tooling that has the
+    // manifest but neither the spec nor the generated enum. Set bits that are
+    // no variant's, whether placeholders, positions since added or beyond the
+    // enum's width, are kept in `unknown_bits` rather than failing.
+    pub fn decode(&self, enum_name: &str, value: u128) -> Result<DecodedFlags> {
+        let e = self.find_enum(enum_name)?;
+        let mut decoded = DecodedFlags {
+            names: Vec::new(),
+            unknown_bits: value,
+            deprecated: Vec::new(),
+        };
+        for v in &e.variants {
+            // A position beyond 127, from a manifest written by hand, is
+            // never set.
+            let bit = 1u128.checked_shl(v.position as u32).unwrap_or(0);
+            if bit == 0 || value & bit == 0 {
+                continue;
+            }
+            decoded.unknown_bits &= !bit;
+            decoded.names.push(v.name.clone());
+            if let Some(note) = &v.deprecated {
+                decoded.deprecated.push((v.name.clone(), note.clone()));
+            }
+        }
+        Ok(decoded)
+    }
+
+    // The value of `enum_name` with the variants named `names` set. Names
+    // match exactly: the manifest doesn't record aliases.
+    pub fn encode(&self, enum_name: &str, names: &[&str]) -> Result<u128> {
+        let e = self.find_enum(enum_name)?;
+        let mut value = 0u128;
+        for &name in names {
+            let Some(v) = e.variants.iter().find(|v| v.name == name) else {
+                let suggestion =
+                    suggest::did_you_mean(name, e.variants.iter().map(|v| v.name.as_str()));
+                return Err(BairiakError::new(
+                    BairiakErrorKind::UnknownFlagError,
+                    format!("Unknown {} flag: {}.{}", e.name, name, suggestion),
+                ));
+            };
+            let Some(bit) = 1u128.checked_shl(v.position as u32) else {
+                return Err(BairiakError::new(
+                    BairiakErrorKind::ValueOutOfRangeError,
+                    format!(
+                        "Position {} of `{}` doesn't fit in 128 bits.",
+                        v.position, v.name
+                    ),
+                ));
+            };
+            value |= bit;
+        }
+        Ok(value)
+    }
+
+    pub fn to_json(&self) -> String {
+        // Every field serializes, and the keys of its maps are strings.
+        #[allow(clippy::expect_used)]
+        let mut json = serde_json::to_string_pretty(self).expect("a manifest always serializes");
+        json.push('\n');
+        json
+    }
+}
+
+// The variants set in a value, as decoded by `Manifest::decode`.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct DecodedFlags {
+    // In spec order.
+    pub names: Vec<String>,
+    pub unknown_bits: u128,
+    // The deprecation note of each deprecated variant set.
+    pub deprecated: Vec<(String, String)>,
+}
+
+// `flags.rs` gets `flags.manifest.json`.
+pub(crate) fn manifest_path(output_path: &Path) -> PathBuf {
+    output_path.with_extension("manifest.json")
+}
+
+// Fails if a variant of the previous manifest at `manifest_path` has another
+// position in `manifest`, listing every such variant. Variants that were added
+// or removed aren't renumbered, and a missing manifest has nothing to compare.
+// A manifest that can't be read or parsed fails rather than being overwritten.
+pub(crate) fn check_renumbering(manifest_path: &Path, manifest: &Manifest) -> Result<()> {
+    let json = match fs::read_to_string(manifest_path) {
+        Ok(json) => json,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => {
+            return Err(
+                BairiakError::new(BairiakErrorKind::IoError, err.to_string())
+                    .with_path(manifest_path)
+                    .logged(),
+            )
+        }
+    };
+    let previous = serde_json::from_str::<Manifest>(&json).map_err(|err| {
+        BairiakError::new(
+            BairiakErrorKind::ParseManifestError,
+            format!(
+                "{} in {}. Fix or remove it, or allow renumbering to replace it.",
+                err,
+                manifest_path.display()
+            ),
+        )
+        .with_path(manifest_path)
+        .logged()
+    })?;
+
+    let mut moved = Vec::new();
+    for e in &manifest.enums {
+        let Some(previous) = previous.enums.iter().find(|p| p.name == e.name) else {
+            continue;
+        };
+        for v in &e.variants {
+            if let Some(old) = previous.variants.iter().find(|p| p.name == v.name) {
+                if old.position != v.position {
+                    moved.push(format!(
+                        "`{}::{}` moved from position {} to {}",
+                        e.name, v.name, old.position, v.position
+                    ));
+                }
+            }
+        }
+    }
+    if moved.is_empty() {
+        return Ok(());
+    }
+    Err(BairiakError::new(
+        BairiakErrorKind::RenumberedError,
+        format!(
+            "{} since {} was written. Allow renumbering to accept the new positions.",
+            moved.join(", "),
+            manifest_path.display()
+        ),
+    )
+    .with_path(manifest_path)
+    .logged())
+}
+
+// Like the generated code, a manifest that is already up to date is left
+// alone, and `false` returned.
+pub(crate) fn write_manifest(manifest_path: &Path, manifest: &Manifest) -> Result<bool> {
+    let json = manifest.to_json();
+    if fs::read(manifest_path).is_ok_and(|existing| existing == json.as_bytes()) {
+        debug!("{} is up to date", manifest_path.display());
+        return Ok(false);
+    }
+
+    replace_file(manifest_path, |out| {
+        out.write_all(json.as_bytes())
+            .map_err(|err| write_file_error(manifest_path, err))
+    })?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Duration};
+
+    use super::*;
+    use crate::{generate_bairiak_enums_with, GenerateOptions};
+
+    #[test]
+    fn test_manifest_of_fixture_spec() {
+        let output_path = Path::new("manifest_output.rs");
+        let report = generate_bairiak_enums_with(
+            "test_data/manifest_spec.yaml",
+            output_path,
+            &GenerateOptions::default().manifest(true),
+        )
+        .unwrap();
+        fs::remove_file(output_path).unwrap();
+
+        let manifest_path = Path::new("manifest_output.manifest.json");
+        assert_eq!(report.manifest_path.as_deref(), Some(manifest_path));
+        let json = fs::read_to_string(manifest_path).unwrap();
+        fs::remove_file(manifest_path).unwrap();
+
+        let expected = fs::read_to_string("test_data/expected/manifest_spec.json")
+            .unwrap()
+            .replace("{version}", env!("CARGO_PKG_VERSION"));
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_manifest_is_deterministic_and_skipped_when_unchanged() {
+        let dir = env::temp_dir().join("bairiak_test_manifest");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let output_path = dir.join("flags.rs");
+        let manifest_path = dir.join("flags.manifest.json");
+        let options = GenerateOptions::default().manifest(true);
+
+        generate_bairiak_enums_with("test_data/manifest_spec.yaml", &output_path, &options)
+            .unwrap();
+        let first = fs::read(&manifest_path).unwrap();
+        let modified = fs::metadata(&manifest_path).unwrap().modified().unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+        generate_bairiak_enums_with("test_data/manifest_spec.yaml", &output_path, &options)
+            .unwrap();
+        assert_eq!(fs::read(&manifest_path).unwrap(), first);
+        assert_eq!(
+            fs::metadata(&manifest_path).unwrap().modified().unwrap(),
+            modified
+        );
+
+        let spec = crate::load_spec("test_data/manifest_spec.yaml").unwrap();
+        let content = fs::read_to_string("test_data/manifest_spec.yaml").unwrap();
+        assert_eq!(
+            Manifest::new(&spec, &content).unwrap().to_json().as_bytes(),
+            &first[..]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_with_stable_ids() {
+        let content = fs::read_to_string("test_data/stable_id_spec.yaml").unwrap();
+        let spec = crate::parse_spec(&content).unwrap();
+        let manifest = Manifest::new(&spec, &content).unwrap();
+        let ids: Vec<_> = manifest.enums[0]
+            .variants
+            .iter()
+            .map(|v| (v.name.as_str(), v.id))
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                ("Read", Some(1001)),
+                ("Write", Some(1002)),
+                ("Admin", Some(1004))
+            ]
+        );
+        assert_eq!(manifest.enums[1].variants[0].id, None);
+
+        let json = manifest.to_json();
+        assert!(json.contains("\"id\": 1004"));
+        assert_eq!(json.matches("\"id\"").count(), 3);
+        assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest);
+    }
+
+    fn manifest_of(spec_path: &str) -> Manifest {
+        let content = fs::read_to_string(spec_path).unwrap();
+        let json = Manifest::new(&crate::parse_spec(&content).unwrap(), &content)
+            .unwrap()
+            .to_json();
+        Manifest::from_json(&json).unwrap()
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let manifest = manifest_of("test_data/valid_spec.yaml");
+        let value = manifest.encode("TestEnum", &["Flag2", "Flag0"]).unwrap();
+        assert_eq!(value, 0b101);
+        let decoded = manifest.decode("TestEnum", value).unwrap();
+        assert_eq!(decoded.names, ["Flag0", "Flag2"]);
+        assert_eq!(decoded.unknown_bits, 0);
+        assert!(decoded.deprecated.is_empty());
+
+        for value in 0..8 {
+            let decoded = manifest.decode("TestEnum", value).unwrap();
+            let names: Vec<&str> = decoded.names.iter().map(String::as_str).collect();
+            assert_eq!(manifest.encode("TestEnum", &names).unwrap(), value);
+        }
+        assert_eq!(manifest.encode("TestEnum", &[]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_decode_unknown_bits_and_deprecations() {
+        let manifest = manifest_of("test_data/manifest_spec.yaml");
+        // Position 2 is a placeholder, and 8 is beyond the width of `Perm`.
+        let decoded = manifest.decode("Perm", 0b1_0000_1101).unwrap();
+        assert_eq!(decoded.names, ["Read", "Admin"]);
+        assert_eq!(decoded.unknown_bits, 0b1_0000_0100);
+        assert_eq!(
+            decoded.deprecated,
+            [(String::from("Admin"), String::from("Use roles instead."))]
+        );
+        assert_eq!(manifest.decode("Mode", 0).unwrap(), DecodedFlags::default());
+    }
+
+    #[test]
+    fn test_manifest_lookup_errors() {
+        let manifest = manifest_of("test_data/manifest_spec.yaml");
+        let err = manifest.decode("Prem", 1).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::UnknownEnumError);
+        assert_eq!(err.message(), "Unknown enum: Prem. Did you mean `Perm`?");
+
+        let err = manifest.encode("Perm", &["Read", "Wirte"]).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::UnknownFlagError);
+        assert_eq!(
+            err.message(),
+            "Unknown Perm flag: Wirte. Did you mean `Write`?"
+        );
+
+        let err = Manifest::from_json("{\"enums\": []}").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseManifestError);
+        assert!(err.message().starts_with("missing field"));
+    }
+
+    #[test]
+    fn test_positions_beyond_128_bits() {
+        // Only a manifest edited by hand can give such a position.
+        let mut manifest = manifest_of("test_data/valid_spec.yaml");
+        let flag2 = &mut manifest.enums[0].variants[2];
+        assert_eq!(flag2.name, "Flag2");
+        flag2.position = 200;
+        let manifest = Manifest::from_json(&manifest.to_json()).unwrap();
+
+        let decoded = manifest.decode("TestEnum", u128::MAX).unwrap();
+        assert_eq!(decoded.names, ["Flag0", "Flag1"]);
+        assert_eq!(decoded.unknown_bits, !0b11);
+        let err = manifest.encode("TestEnum", &["Flag2"]).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        assert_eq!(
+            err.message(),
+            "Position 200 of `Flag2` doesn't fit in 128 bits."
+        );
+    }
+
+    #[test]
+    fn test_renumbering_detected() {
+        let dir = env::temp_dir().join("bairiak_test_renumbering");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let spec_path = dir.join("spec.yaml");
+        let output_path = dir.join("flags.rs");
+        let manifest_path = dir.join("flags.manifest.json");
+        let options = GenerateOptions::default().manifest(true);
+
+        fs::write(
+            &spec_path,
+            "enums:\n  - name: Perm\n    variants: [Read, Write, Admin]\n",
+        )
+        .unwrap();
+        generate_bairiak_enums_with(&spec_path, &output_path, &options).unwrap();
+        let manifest = fs::read(&manifest_path).unwrap();
+
+        // Appending a variant keeps every position.
+        fs::write(
+            &spec_path,
+            "enums:\n  - name: Perm\n    variants: [Read, Write, Admin, Audit]\n",
+        )
+        .unwrap();
+        generate_bairiak_enums_with(&spec_path, &output_path, &options).unwrap();
+        let output = fs::read(&output_path).unwrap();
+        let appended = fs::read(&manifest_path).unwrap();
+        assert_ne!(appended, manifest);
+
+        fs::write(
+            &spec_path,
+            "enums:\n  - name: Perm\n    variants: [Write, Read, Admin, Audit]\n",
+        )
+        .unwrap();
+        let err = generate_bairiak_enums_with(&spec_path, &output_path, &options).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::RenumberedError);
+        assert_eq!(err.path(), Some(manifest_path.as_path()));
+        assert!(err.message().starts_with(
+            "`Perm::Write` moved from position 1 to 0, `Perm::Read` moved from position 0 to 1 since"
+        ));
+        assert_eq!(fs::read(&output_path).unwrap(), output);
+        assert_eq!(fs::read(&manifest_path).unwrap(), appended);
+
+        generate_bairiak_enums_with(&spec_path, &output_path, &options.allow_renumber(true))
+            .unwrap();
+        let renumbered: Manifest =
+            serde_json::from_slice(&fs::read(&manifest_path).unwrap()).unwrap();
+        assert_eq!(renumbered.enums[0].variants[0].name, "Write");
+        assert_eq!(renumbered.enums[0].variants[0].position, 0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_corrupted_manifest_kept() {
+        let dir = env::temp_dir().join("bairiak_test_corrupted_manifest");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let output_path = dir.join("flags.rs");
+        let manifest_path = dir.join("flags.manifest.json");
+        let options = GenerateOptions::default().manifest(true);
+
+        fs::write(&manifest_path, "{\"enums\": [").unwrap();
+        let err =
+            generate_bairiak_enums_with("test_data/manifest_spec.yaml", &output_path, &options)
+                .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseManifestError);
+        assert_eq!(err.path(), Some(manifest_path.as_path()));
+        assert_eq!(fs::read(&manifest_path).unwrap(), b"{\"enums\": [");
+        assert!(!output_path.exists());
+
+        generate_bairiak_enums_with(
+            "test_data/manifest_spec.yaml",
+            &output_path,
+            &options.allow_renumber(true),
+        )
+        .unwrap();
+        assert!(serde_json::from_slice::<Manifest>(&fs::read(&manifest_path).unwrap()).is_ok());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_no_manifest_by_default() {
+        let dir = env::temp_dir().join("bairiak_test_no_manifest");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let report =
+            crate::generate_bairiak_enums("test_data/valid_spec.yaml", dir.join("flags.rs"))
+                .unwrap();
+        assert_eq!(report.manifest_path, None);
+        assert!(!dir.join("flags.manifest.json").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+// Register-style updates: `value = (value & and_mask) | or_mask`, the pairs
+// of masks device protocols send to update some bits and keep the rest.
+
+use crate::{Bairiak, BairiakEnum, BairiakError, BairiakErrorKind, Positions, Result};
+
+impl Bairiak {
+    // Applies an update in the widest of the three widths, so that no set bit
+    // of `or_mask` is lost. Bits beyond the width of `and_mask` are kept, as a
+    // mask built for a narrower value says nothing about them.
+    pub fn apply(&mut self, and_mask: &Bairiak, or_mask: &Bairiak) {
+        let width = self.width().max(and_mask.width()).max(or_mask.width());
+        let bits = self.bits() & extended(and_mask) | or_mask.bits();
+        *self = Bairiak::with_width(width, bits);
+    }
+
+    // Like `apply`, but fails with a `MaskConflictError` and leaves the value
+    // alone if `or_mask` sets bits that `and_mask` clears, which usually means
+    // the masks were built for different updates.
+    pub fn apply_strict(&mut self, and_mask: &Bairiak, or_mask: &Bairiak) -> Result<()> {
+        let conflicts = or_mask.bits() & !extended(and_mask);
+        if conflicts != 0 {
+            return Err(conflict(
+                conflicts,
+                "set by the or-mask and cleared by the and-mask",
+            ));
+        }
+        self.apply(and_mask, or_mask);
+        Ok(())
+    }
+
+    // The `(and_mask, or_mask)` of an update that clears the `clear` flags, sets
+    // the `set` flags and keeps every other bit, in the width of `B`. `keep`
+    // names the flags the update must leave alone: a flag in two of the lists
+    // fails with a `MaskConflictError`.
+    pub fn update_masks<B: BairiakEnum>(
+        keep: &[B],
+        clear: &[B],
+        set: &[B],
+    ) -> Result<(Bairiak, Bairiak)> {
+        let (keep, clear, set) = (mask(keep), mask(clear), mask(set));
+        let conflicts = keep & clear | keep & set | clear & set;
+        if conflicts != 0 {
+            return Err(conflict(conflicts, "listed more than once"));
+        }
+
+        let width = B::get_zero_bairiak().width();
+        Ok((
+            Bairiak::with_width(width, !clear),
+            Bairiak::with_width(width, set),
+        ))
+    }
+}
+
+// The and-mask as 128 bits, with ones beyond its width.
+fn extended(and_mask: &Bairiak) -> u128 {
+    let beyond = u128::MAX.checked_shl(and_mask.width() as u32).unwrap_or(0);
+    and_mask.bits() | beyond
+}
+
+fn mask<B: BairiakEnum>(flags: &[B]) -> u128 {
+    flags.iter().fold(0, |mask, flag| {
+        mask | 1u128.checked_shl(flag.position() as u32).unwrap_or(0)
+    })
+}
+
+fn conflict(bits: u128, reason: &str) -> BairiakError {
+    let positions: Vec<String> = Positions { bits }.map(|p| p.to_string()).collect();
+    let message = match positions.as_slice() {
+        [position] => format!("Position {} is {}.", position, reason),
+        _ => format!("Positions {} are {}.", positions.join(", "), reason),
+    };
+    BairiakError::new(BairiakErrorKind::MaskConflictError, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The status register of a device.
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy)]
+    enum Status {
+        Power = 0,
+        Fan = 1,
+        Heater = 2,
+        Alarm = 3,
+        Remote = 9,
+    }
+
+    impl BairiakEnum for Status {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U16(0u16)
+        }
+
+        fn to_u8(self) -> u8 {
+            self as u8
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            match position {
+                0 => Some(Status::Power),
+                1 => Some(Status::Fan),
+                2 => Some(Status::Heater),
+                3 => Some(Status::Alarm),
+                9 => Some(Status::Remote),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match self {
+                Status::Power => "Power",
+                Status::Fan => "Fan",
+                Status::Heater => "Heater",
+                Status::Alarm => "Alarm",
+                Status::Remote => "Remote",
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_update_sequence() {
+        let mut status = Bairiak::U16(0);
+
+        // Power on with the fan running.
+        status.apply(&Bairiak::U16(0xffff), &Bairiak::U16(0b0011));
+        assert_eq!(status, Bairiak::U16(0b0011));
+
+        // Swap the fan for the heater.
+        let (and_mask, or_mask) =
+            Bairiak::update_masks(&[Status::Power], &[Status::Fan], &[Status::Heater]).unwrap();
+        assert_eq!(and_mask, Bairiak::U16(!0b0010));
+        assert_eq!(or_mask, Bairiak::U16(0b0100));
+        status.apply_strict(&and_mask, &or_mask).unwrap();
+        assert_eq!(status, Bairiak::U16(0b0101));
+
+        // Raise the alarm and take remote control, keeping the rest.
+        let (and_mask, or_mask) =
+            Bairiak::update_masks::<Status>(&[], &[], &[Status::Alarm, Status::Remote]).unwrap();
+        status.apply_strict(&and_mask, &or_mask).unwrap();
+        assert_eq!(status, Bairiak::U16(1 << 9 | 0b1101));
+
+        // Shut down: clear everything but remote control.
+        status.apply(&Bairiak::U16(1 << 9), &Bairiak::U16(0));
+        assert_eq!(status, Bairiak::U16(1 << 9));
+    }
+
+    #[test]
+    fn test_apply_promotes_width() {
+        let mut value = Bairiak::U8(0b1010);
+        value.apply(&Bairiak::U8(!0b0010), &Bairiak::U16(1 << 9));
+        assert_eq!(value, Bairiak::U16(1 << 9 | 0b1000));
+
+        // A narrow and-mask keeps the bits beyond its width.
+        let mut value = Bairiak::U32(1 << 20 | 0b11);
+        value.apply(&Bairiak::U8(!0b01), &Bairiak::U8(0));
+        assert_eq!(value, Bairiak::U32(1 << 20 | 0b10));
+    }
+
+    #[test]
+    fn test_apply_strict_conflict() {
+        let mut value = Bairiak::U8(0b0001);
+        let err = value
+            .apply_strict(&Bairiak::U8(!0b0110), &Bairiak::U8(0b0100))
+            .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::MaskConflictError);
+        assert_eq!(
+            err.message(),
+            "Position 2 is set by the or-mask and cleared by the and-mask."
+        );
+        assert_eq!(value, Bairiak::U8(0b0001));
+
+        let err = Bairiak::update_masks(
+            &[Status::Fan],
+            &[Status::Fan, Status::Alarm],
+            &[Status::Alarm],
+        )
+        .unwrap_err();
+        assert_eq!(err.message(), "Positions 1, 3 are listed more than once.");
+    }
+}
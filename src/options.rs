@@ -0,0 +1,264 @@
+use serde::Deserialize;
+
+use crate::{region, BairiakError, BairiakErrorKind, Result, RUST_KEYWORDS};
+
+// The visibility of the generated enums and their companion types, written
+// in a spec as in Rust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum Visibility {
+    #[default]
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "pub(crate)")]
+    PubCrate,
+    #[serde(rename = "pub")]
+    Pub,
+}
+
+// The Rust edition of the crate compiling the generated code. The default,
+// 2015, generates code that compiles under every edition, provided that a
+// 2015 crate declares `extern crate bairiak;` at its root, as imports need
+// there. From 2018 on, paths to bairiak and `core` start with `::`, so that
+// an item of the crate named like them can't shadow them. The code names
+// `core` rather than `std` in every edition, for `no_std` crates. Syntax an
+// edition lacks is only ever generated for the editions that have it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RustEdition {
+    #[default]
+    E2015,
+    E2018,
+    E2021,
+    E2024,
+}
+
+impl RustEdition {
+    pub(crate) fn bairiak_path(self) -> &'static str {
+        match self {
+            RustEdition::E2015 => "bairiak",
+            _ => "::bairiak",
+        }
+    }
+
+    pub(crate) fn core_path(self) -> &'static str {
+        match self {
+            RustEdition::E2015 => "core",
+            _ => "::core",
+        }
+    }
+}
+
+// Options for generating code, built from the defaults:
+//
+//     GenerateOptions::default().module("flags").visibility(Visibility::PubCrate)
+//
+// The defaults generate the same code as `generate_bairiak_enums`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerateOptions {
+    pub(crate) module: Option<String>,
+    pub(crate) visibility: Visibility,
+    pub(crate) force: bool,
+    pub(crate) display: bool,
+    pub(crate) strict: bool,
+    pub(crate) manifest: bool,
+    pub(crate) allow_renumber: bool,
+    pub(crate) allow_empty: bool,
+    pub(crate) prelude: bool,
+    pub(crate) rust_edition: RustEdition,
+    // The begin and end markers of the region to generate into.
+    pub(crate) region: Option<(String, String)>,
+}
+
+impl GenerateOptions {
+    // Wraps the generated code in `mod name { ... }`, with the same
+    // visibility as the enums.
+    pub fn module(mut self, name: &str) -> Self {
+        self.module = Some(String::from(name));
+        self
+    }
+
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    // Overwrites the output even if it has been edited by hand.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    // Implements `Display` for every enum, printing the variant's name. Off by
+    // default, as it conflicts with hand-written `Display` impls.
+    pub fn display(mut self, display: bool) -> Self {
+        self.display = display;
+        self
+    }
+
+    // Fails instead of warning when the output path looks like a spec rather
+    // than Rust code.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    // Also writes a JSON manifest of the generated flags next to the output,
+    // `flags.manifest.json` for `flags.rs`.
+    pub fn manifest(mut self, manifest: bool) -> Self {
+        self.manifest = manifest;
+        self
+    }
+
+    // With `manifest`, generation fails if a variant's position differs from
+    // the one in the previous manifest, as persisted values would change
+    // meaning. Allowing it accepts the new positions and records them.
+    pub fn allow_renumber(mut self, allow_renumber: bool) -> Self {
+        self.allow_renumber = allow_renumber;
+        self
+    }
+
+    // Generates a file without enums for a spec whose `enums` list is empty,
+    // for pipelines that generate the spec and fill it in later. Without it,
+    // such a spec fails with `BairiakErrorKind::EmptySpecError`.
+    pub fn allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
+    // Imports `bairiak::prelude::*` instead of `Bairiak` and `BairiakEnum`
+    // alone, so that code included next to the generated items can use the
+    // rest of the prelude without importing it again.
+    pub fn prelude(mut self, prelude: bool) -> Self {
+        self.prelude = prelude;
+        self
+    }
+
+    pub fn rust_edition(mut self, rust_edition: RustEdition) -> Self {
+        self.rust_edition = rust_edition;
+        self
+    }
+
+    // Generates between the `// bairiak:begin` and `// bairiak:end` lines of
+    // the output, keeping the rest of it as it is. Without the markers, the
+    // region is appended to the output, or generation fails with `strict`.
+    pub fn region(self, region: bool) -> Self {
+        if region {
+            self.region_markers(region::DEFAULT_BEGIN, region::DEFAULT_END)
+        } else {
+            GenerateOptions {
+                region: None,
+                ..self
+            }
+        }
+    }
+
+    // Like `region`, with other marker lines.
+    pub fn region_markers(mut self, begin: &str, end: &str) -> Self {
+        self.region = Some((String::from(begin), String::from(end)));
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if let Some((begin, end)) = &self.region {
+            let valid = |marker: &str| !marker.trim().is_empty() && !marker.contains('\n');
+            if !valid(begin) || !valid(end) || begin.trim() == end.trim() {
+                return Err(BairiakError::new(
+                    BairiakErrorKind::ParseBairiakEnumsError,
+                    format!(
+                        "Invalid region markers `{}` and `{}`. Markers should be two different single lines.",
+                        begin, end
+                    ),
+                )
+                .logged());
+            }
+        }
+
+        match &self.module {
+            Some(name) if !is_module_name(name) => Err(BairiakError::new(
+                BairiakErrorKind::ParseBairiakEnumsError,
+                format!(
+                    "Invalid module name `{}`. Module name should be in snake_case and not `_` or a keyword.",
+                    name
+                ),
+            )
+            .logged()),
+            _ => Ok(()),
+        }
+    }
+
+    // Private items inside the module are `pub(super)`, so that the module
+    // the code is included in sees them as it would without the module.
+    pub(crate) fn item_visibility(&self, visibility: Visibility) -> &'static str {
+        match (visibility, &self.module) {
+            (Visibility::Private, None) => "",
+            (Visibility::Private, Some(_)) => "pub(super) ",
+            (Visibility::PubCrate, _) => "pub(crate) ",
+            (Visibility::Pub, _) => "pub ",
+        }
+    }
+
+    pub(crate) fn module_visibility(&self) -> &'static str {
+        match self.visibility {
+            Visibility::Private => "",
+            Visibility::PubCrate => "pub(crate) ",
+            Visibility::Pub => "pub ",
+        }
+    }
+}
+
+// The module is written as `mod <name>`, so it can't be `_`, a keyword or one
+// of the path keywords, which have no raw form.
+fn is_module_name(s: &str) -> bool {
+    if s == "_" || RUST_KEYWORDS.contains(&s) || ["self", "super", "crate"].contains(&s) {
+        return false;
+    }
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_visibility() {
+        let options = GenerateOptions::default();
+        assert_eq!(options.item_visibility(Visibility::Private), "");
+        assert_eq!(options.item_visibility(Visibility::Pub), "pub ");
+        let options = options.module("flags");
+        assert_eq!(options.item_visibility(Visibility::Private), "pub(super) ");
+        let options = options.visibility(Visibility::PubCrate);
+        assert_eq!(options.item_visibility(Visibility::PubCrate), "pub(crate) ");
+        assert_eq!(options.module_visibility(), "pub(crate) ");
+    }
+
+    #[test]
+    fn test_validate_module_name() {
+        assert!(GenerateOptions::default().validate().is_ok());
+        assert!(GenerateOptions::default()
+            .module("flags_v2")
+            .validate()
+            .is_ok());
+        for name in [
+            "",
+            "Flags",
+            "2flags",
+            "flags::v2",
+            "_",
+            "type",
+            "mod",
+            "self",
+        ] {
+            assert_eq!(
+                GenerateOptions::default()
+                    .module(name)
+                    .validate()
+                    .unwrap_err()
+                    .kind(),
+                BairiakErrorKind::ParseBairiakEnumsError
+            );
+        }
+    }
+}
@@ -0,0 +1,485 @@
+// Packing several small enums into one `Bairiak`, each at its own offset, so
+// that they can share a single storage slot:
+//
+//     let layout = PackedLayout::new(32)?.member::<Perm>()?.member::<Codec>()?;
+//     layout.set(&mut value, Codec::Zstd)?;
+//
+// A spec can declare layouts in its `packed` section, which generates a
+// function returning each of them.
+
+use std::{any::TypeId, fmt::Write};
+
+use serde::Deserialize;
+
+use crate::{
+    camel_case_violation, indent_into, short_type_name, to_snake_case, Bairiak, BairiakEnum,
+    BairiakError, BairiakErrorKind, EnumSpec, GenerateOptions, Result, RustEdition, WIDTHS,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedLayout {
+    width: u8,
+    members: Vec<PackedMember>,
+}
+
+// Where the positions of an enum start in a packed value, and how many bits
+// they take: up to its highest position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedMember {
+    pub name: &'static str,
+    pub offset: u8,
+    pub bits: u8,
+    type_id: TypeId,
+}
+
+impl PackedLayout {
+    // An empty layout of a value `width` bits wide, 8, 16, 32, 64 or 128.
+    pub fn new(width: u8) -> Result<PackedLayout> {
+        if !WIDTHS.contains(&width) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::PackedLayoutError,
+                format!(
+                    "Invalid width {} of a packed layout. Width should be 8, 16, 32, 64 or 128.",
+                    width
+                ),
+            ));
+        }
+        Ok(PackedLayout {
+            width,
+            members: Vec::new(),
+        })
+    }
+
+    // Packs `B` after the previous members, failing if it doesn't fit the
+    // width or already is a member.
+    pub fn member<B: BairiakEnum + 'static>(mut self) -> Result<PackedLayout> {
+        let name = short_type_name::<B>();
+        if self.members.iter().any(|m| m.type_id == TypeId::of::<B>()) {
+            return Err(BairiakError::new(
+                BairiakErrorKind::PackedLayoutError,
+                format!("`{}` is already a member of the packed layout.", name),
+            ));
+        }
+
+        let offset = self.used_bits();
+        let bits = (128 - B::defined_mask().leading_zeros()) as u8;
+        check_fits(None, self.width, name, offset, bits)
+            .map_err(|message| BairiakError::new(BairiakErrorKind::PackedLayoutError, message))?;
+        self.members.push(PackedMember {
+            name,
+            offset: offset as u8,
+            bits,
+            type_id: TypeId::of::<B>(),
+        });
+        Ok(self)
+    }
+
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    // In packing order.
+    pub fn members(&self) -> &[PackedMember] {
+        &self.members
+    }
+
+    pub fn zero(&self) -> Bairiak {
+        Bairiak::with_width(self.width, 0)
+    }
+
+    pub fn offset_of<B: 'static>(&self) -> Option<u8> {
+        self.find_member::<B>().map(|member| member.offset)
+    }
+
+    // The remaining methods fail with a `PackedLayoutError` for an enum that
+    // isn't a member, leaving `value` unchanged.
+
+    // Widens `value` to the layout's width if it is narrower.
+    pub fn set<B: BairiakEnum + 'static>(&self, value: &mut Bairiak, flag: B) -> Result<()> {
+        let shift = self.shift(flag)?;
+        self.widen(value);
+        if shift < value.width() as u32 {
+            value.set_bit(shift);
+        }
+        Ok(())
+    }
+
+    pub fn clear<B: BairiakEnum + 'static>(&self, value: &mut Bairiak, flag: B) -> Result<()> {
+        // A value narrower than the layout has nothing set beyond its width.
+        let shift = self.shift(flag)?;
+        if shift < value.width() as u32 {
+            value.clear_bit(shift);
+        }
+        Ok(())
+    }
+
+    pub fn is_true<B: BairiakEnum + 'static>(&self, value: &Bairiak, flag: B) -> Result<bool> {
+        let shift = self.shift(flag)?;
+        Ok(value.bits() & 1u128.checked_shl(shift).unwrap_or(0) != 0)
+    }
+
+    // The flags of `B` in `value`, as a value of `B`'s own width.
+    pub fn extract<B: BairiakEnum + 'static>(&self, value: &Bairiak) -> Result<Bairiak> {
+        let member = self.member_of::<B>()?;
+        let bits = value.bits().checked_shr(member.offset as u32).unwrap_or(0) & mask(member.bits);
+        Ok(Bairiak::with_width(B::get_zero_bairiak().width(), bits))
+    }
+
+    // Replaces the flags of `B` in `value` with those of `flags`, dropping the
+    // bits of `flags` beyond the member.
+    pub fn insert<B: BairiakEnum + 'static>(
+        &self,
+        value: &mut Bairiak,
+        flags: &Bairiak,
+    ) -> Result<()> {
+        let member = self.member_of::<B>()?;
+        let shift = |bits: u128| bits.checked_shl(member.offset as u32).unwrap_or(0);
+        let mask = shift(mask(member.bits));
+        self.widen(value);
+        let bits = value.bits() & !mask | shift(flags.bits()) & mask;
+        *value = Bairiak::with_width(value.width(), bits);
+        Ok(())
+    }
+
+    fn find_member<B: 'static>(&self) -> Option<&PackedMember> {
+        self.members.iter().find(|m| m.type_id == TypeId::of::<B>())
+    }
+
+    // Asking about a type the layout doesn't pack is a bug of the caller, but
+    // one that shouldn't lose the write silently.
+    fn member_of<B: 'static>(&self) -> Result<&PackedMember> {
+        self.find_member::<B>().ok_or_else(|| {
+            BairiakError::new(
+                BairiakErrorKind::PackedLayoutError,
+                format!(
+                    "`{}` is not a member of the packed layout.",
+                    short_type_name::<B>()
+                ),
+            )
+        })
+    }
+
+    fn shift<B: BairiakEnum + 'static>(&self, flag: B) -> Result<u32> {
+        let member = self.member_of::<B>()?;
+        Ok(member.offset as u32 + flag.position() as u32)
+    }
+
+    fn used_bits(&self) -> u32 {
+        self.members
+            .last()
+            .map_or(0, |m| m.offset as u32 + m.bits as u32)
+    }
+
+    fn widen(&self, value: &mut Bairiak) {
+        if value.width() < self.width {
+            *value = Bairiak::with_width(self.width, value.bits());
+        }
+    }
+}
+
+fn mask(bits: u8) -> u128 {
+    u128::MAX.checked_shr(128 - bits as u32).unwrap_or(0)
+}
+
+fn check_fits(
+    layout: Option<&str>,
+    width: u8,
+    member: &str,
+    offset: u32,
+    bits: u8,
+) -> std::result::Result<(), String> {
+    let end = offset + bits as u32;
+    if end <= width as u32 {
+        return Ok(());
+    }
+    let layout = layout.map_or_else(
+        || String::from("Packed layout"),
+        |name| format!("Packed layout `{}`", name),
+    );
+    Err(format!(
+        "{} overflows its {} bits: `{}` would take bits {} to {}.",
+        layout,
+        width,
+        member,
+        offset,
+        end - 1
+    ))
+}
+
+// A layout in the `packed` section of a spec:
+//
+//     packed:
+//       - name: DeviceState
+//         bits: 32
+//         members: [Perm, Codec, Mode]
+//
+// generating `fn device_state_layout() -> bairiak::PackedLayout`.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub(crate) struct Packed {
+    name: String,
+    bits: u8,
+    members: Vec<String>,
+}
+
+impl Packed {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+// Members take up to their highest position, as with `PackedLayout::member`.
+pub(crate) fn validate_packed(spec: &EnumSpec, packed: &Packed) -> Result<()> {
+    let error = |message: String| {
+        BairiakError::new(BairiakErrorKind::ParseBairiakEnumsError, message).logged()
+    };
+
+    if let Some(violation) = camel_case_violation(&packed.name, spec.settings.naming) {
+        return Err(error(format!(
+            "Invalid packed layout name `{}`: {}. Layout name should be in CamelCase.",
+            packed.name, violation
+        )));
+    }
+    if !WIDTHS.contains(&packed.bits) {
+        return Err(error(format!(
+            "Invalid width `bits: {}` of packed layout `{}`. Width should be 8, 16, 32, 64 or 128.",
+            packed.bits, packed.name
+        )));
+    }
+
+    let mut offset = 0;
+    for (i, member) in packed.members.iter().enumerate() {
+        let Some(e) = spec.enums.iter().find(|e| &e.name == member) else {
+            return Err(error(format!(
+                "Unknown enum `{}` in packed layout `{}`.",
+                member, packed.name
+            )));
+        };
+        if packed.members.iter().take(i).any(|m| m == member) {
+            return Err(error(format!(
+                "`{}` is packed twice in `{}`.",
+                member, packed.name
+            )));
+        }
+
+        let bits = e
+            .positioned_variants()
+            .map(|(position, _)| position + 1)
+            .max()
+            .unwrap_or(0) as u8;
+        check_fits(Some(&packed.name), packed.bits, member, offset, bits).map_err(error)?;
+        offset += bits as u32;
+    }
+    Ok(())
+}
+
+// The functions of the spec's layouts, after its enums. Expects the spec to
+// have been validated.
+pub(crate) fn packed_code(spec: &EnumSpec, options: &GenerateOptions) -> Result<String> {
+    let mut code = String::new();
+    for packed in &spec.packed {
+        validate_packed(spec, packed)?;
+        let visibility =
+            options.item_visibility(spec.settings.visibility.unwrap_or(options.visibility));
+        let allow = spec.settings.dead_code_allow();
+        // Writing to a `String` never fails.
+        #[allow(clippy::expect_used)]
+        write_packed(packed, visibility, allow, options.rust_edition, &mut code)
+            .expect("writing to a String cannot fail");
+    }
+    if options.module.is_some() {
+        let mut indented = String::with_capacity(code.len());
+        indent_into(&code, &mut indented);
+        return Ok(indented);
+    }
+    Ok(code)
+}
+
+fn write_packed(
+    packed: &Packed,
+    visibility: &str,
+    allow: &str,
+    edition: RustEdition,
+    out: &mut String,
+) -> std::fmt::Result {
+    write!(
+        out,
+        "
+{}{}fn {}_layout() -> {bairiak}::PackedLayout {{
+    {bairiak}::PackedLayout::new({})
+",
+        allow,
+        visibility,
+        to_snake_case(&packed.name),
+        packed.bits,
+        bairiak = edition.bairiak_path(),
+    )?;
+    for member in &packed.members {
+        writeln!(
+            out,
+            "        .and_then(|layout| layout.member::<{}>())",
+            member
+        )?;
+    }
+    out.push_str("        .expect(\"validated packed layout\")\n}\n");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_spec;
+
+    include!("../test_data/expected/packed_spec.rs");
+
+    fn layout() -> PackedLayout {
+        PackedLayout::new(32)
+            .and_then(|layout| layout.member::<Perm>())
+            .and_then(|layout| layout.member::<Codec>())
+            .and_then(|layout| layout.member::<Mode>())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_layout_offsets() {
+        let layout = layout();
+        let members: Vec<(&str, u8, u8)> = layout
+            .members()
+            .iter()
+            .map(|m| (m.name, m.offset, m.bits))
+            .collect();
+        assert_eq!(members, [("Perm", 0, 4), ("Codec", 4, 3), ("Mode", 7, 5)]);
+        assert_eq!(layout.offset_of::<Mode>(), Some(7));
+        assert_eq!(layout.zero(), Bairiak::U32(0));
+    }
+
+    #[test]
+    fn test_members_do_not_cross_talk() {
+        let layout = layout();
+        let mut value = layout.zero();
+
+        for flag in Codec::all_variants() {
+            layout.set(&mut value, flag).unwrap();
+        }
+        assert_eq!(value, Bairiak::U32(0b111 << 4));
+        assert_eq!(layout.extract::<Codec>(&value).unwrap(), Bairiak::U8(0b111));
+        assert_eq!(layout.extract::<Perm>(&value).unwrap(), Bairiak::U8(0));
+        assert_eq!(layout.extract::<Mode>(&value).unwrap(), Bairiak::U8(0));
+        for flag in Perm::all_variants() {
+            assert!(!layout.is_true(&value, flag).unwrap());
+        }
+
+        layout.set(&mut value, Perm::Admin).unwrap();
+        layout.set(&mut value, Mode::Eco).unwrap();
+        assert!(layout.is_true(&value, Perm::Admin).unwrap());
+        assert!(layout.is_true(&value, Mode::Eco).unwrap());
+        assert!(!layout.is_true(&value, Mode::Off).unwrap());
+        assert_eq!(layout.extract::<Perm>(&value).unwrap(), Bairiak::U8(1 << 3));
+        assert_eq!(layout.extract::<Mode>(&value).unwrap(), Bairiak::U8(1 << 4));
+
+        layout.clear(&mut value, Codec::Gzip).unwrap();
+        layout
+            .insert::<Perm>(&mut value, &Bairiak::U8(0xff))
+            .unwrap();
+        assert_eq!(layout.extract::<Perm>(&value).unwrap(), Bairiak::U8(0b1111));
+        assert_eq!(layout.extract::<Codec>(&value).unwrap(), Bairiak::U8(0b110));
+        assert_eq!(layout.extract::<Mode>(&value).unwrap(), Bairiak::U8(1 << 4));
+    }
+
+    #[test]
+    fn test_set_widens_value() {
+        let layout = layout();
+        let mut value = Bairiak::U8(0b1);
+        layout.set(&mut value, Mode::Eco).unwrap();
+        assert_eq!(value, Bairiak::U32(1 << 11 | 0b1));
+    }
+
+    #[test]
+    fn test_layout_errors() {
+        let err = PackedLayout::new(8)
+            .and_then(|layout| layout.member::<Perm>())
+            .and_then(|layout| layout.member::<Mode>())
+            .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PackedLayoutError);
+        assert_eq!(
+            err.message(),
+            "Packed layout overflows its 8 bits: `Mode` would take bits 4 to 8."
+        );
+
+        let err = PackedLayout::new(16)
+            .and_then(|layout| layout.member::<Perm>())
+            .and_then(|layout| layout.member::<Perm>())
+            .unwrap_err();
+        assert_eq!(
+            err.message(),
+            "`Perm` is already a member of the packed layout."
+        );
+
+        assert!(PackedLayout::new(24).is_err());
+    }
+
+    #[test]
+    fn test_non_member_fails() {
+        let layout = PackedLayout::new(16)
+            .and_then(|layout| layout.member::<Perm>())
+            .unwrap();
+        let mut value = Bairiak::U8(0b1);
+        let err = layout.set(&mut value, Codec::Gzip).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::PackedLayoutError);
+        assert_eq!(
+            err.message(),
+            "`Codec` is not a member of the packed layout."
+        );
+        assert!(layout.clear(&mut value, Codec::Gzip).is_err());
+        assert!(layout
+            .insert::<Codec>(&mut value, &Bairiak::U8(0xff))
+            .is_err());
+        assert_eq!(value, Bairiak::U8(0b1));
+        assert!(layout.is_true(&Bairiak::U16(0xffff), Codec::Gzip).is_err());
+        assert!(layout.extract::<Codec>(&Bairiak::U16(0xffff)).is_err());
+        assert_eq!(layout.offset_of::<Codec>(), None);
+    }
+
+    #[test]
+    fn test_generated_layout() {
+        let layout = device_state_layout();
+        assert_eq!(layout, self::layout());
+    }
+
+    #[test]
+    fn test_validate_packed_spec() {
+        let spec = |packed: &str| {
+            parse_spec(&format!(
+                "enums:\n  - name: Perm\n    variants: [Read, Write, ~, Admin]\n  - name: Mode\n    variants: [Off, On]\npacked:\n{}",
+                packed
+            ))
+            .unwrap()
+        };
+        let message = |packed: &str| spec(packed).validate().unwrap_err().message().to_string();
+
+        assert!(spec("  - {name: State, bits: 8, members: [Perm, Mode]}\n")
+            .validate()
+            .is_ok());
+        assert_eq!(
+            message("  - {name: State, bits: 8, members: [Perm, Mode, Perm]}\n"),
+            "`Perm` is packed twice in `State`."
+        );
+        assert_eq!(
+            message("  - {name: State, bits: 8, members: [Perm, Codec]}\n"),
+            "Unknown enum `Codec` in packed layout `State`."
+        );
+        assert_eq!(
+            message("  - {name: State, bits: 24, members: [Perm]}\n"),
+            "Invalid width `bits: 24` of packed layout `State`. Width should be 8, 16, 32, 64 or 128."
+        );
+
+        let wide = "enums:\n  - name: Perm\n    variants: [Read, {name: Admin, position: 6}]\n  - name: Mode\n    variants: [Off, On]\npacked:\n  - {name: State, bits: 8, members: [Perm, Mode]}\n";
+        let spec = parse_spec(wide).unwrap();
+        assert_eq!(
+            spec.validate().unwrap_err().message(),
+            "Packed layout `State` overflows its 8 bits: `Mode` would take bits 7 to 8."
+        );
+        let problems = spec.validate_all();
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].enum_name, "State");
+    }
+}
@@ -0,0 +1,92 @@
+use std::str::FromStr;
+
+use crate::{width_for, Bairiak, BairiakError, BairiakErrorKind};
+
+// Parses a value written like a Rust integer literal: decimal, `0b`, `0o` or
+// `0x`, with optional `_` separators and an optional `u8` to `u128` suffix
+// giving the width. Without a suffix the value gets the smallest width that
+// fits it.
+impl FromStr for Bairiak {
+    type Err = BairiakError;
+
+    fn from_str(s: &str) -> Result<Bairiak, BairiakError> {
+        let invalid = || {
+            BairiakError::new(
+                BairiakErrorKind::ParseValueError,
+                format!("Invalid value `{}`.", s),
+            )
+        };
+
+        let literal = s.trim().replace('_', "");
+        let (literal, radix) = match literal.get(..2) {
+            Some("0b") | Some("0B") => (&literal[2..], 2),
+            Some("0o") | Some("0O") => (&literal[2..], 8),
+            Some("0x") | Some("0X") => (&literal[2..], 16),
+            _ => (&literal[..], 10),
+        };
+
+        // Hex digits can't contain `u`, so the suffix is never ambiguous.
+        let (digits, width) = match literal.find('u') {
+            Some(i) => match &literal[i..] {
+                "u8" => (&literal[..i], Some(8)),
+                "u16" => (&literal[..i], Some(16)),
+                "u32" => (&literal[..i], Some(32)),
+                "u64" => (&literal[..i], Some(64)),
+                "u128" => (&literal[..i], Some(128)),
+                _ => return Err(invalid()),
+            },
+            None => (literal, None),
+        };
+
+        // `from_str_radix` would take a leading `+`, which no literal has.
+        if digits.starts_with(['+', '-']) {
+            return Err(invalid());
+        }
+        let value = u128::from_str_radix(digits, radix).map_err(|_| invalid())?;
+        let width = match width {
+            Some(width) => width,
+            None => width_for((128 - value.leading_zeros()) as usize)?,
+        };
+        if width < 128 && value >> width != 0 {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!("Value {} does not fit in {} bits.", value, width),
+            ));
+        }
+
+        Ok(Bairiak::with_width(width, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("35".parse(), Ok(Bairiak::U8(35)));
+        assert_eq!("0b1011".parse(), Ok(Bairiak::U8(11)));
+        assert_eq!("0o13u32".parse(), Ok(Bairiak::U32(11)));
+        assert_eq!("0xffu16".parse(), Ok(Bairiak::U16(0xff)));
+        assert_eq!("1_000".parse(), Ok(Bairiak::U16(1000)));
+        assert_eq!("0".parse(), Ok(Bairiak::U8(0)));
+        assert_eq!(u128::MAX.to_string().parse(), Ok(Bairiak::U128(u128::MAX)));
+    }
+
+    // Test for values that aren't literals or don't fit their suffix
+    #[test]
+    fn test_from_str_errors() {
+        for s in [
+            "", "Read", "0b102", "5u7", "5i8", "0x", "+5", "-5", "0x+ff", "0b+1u8",
+        ] {
+            assert_eq!(
+                s.parse::<Bairiak>().unwrap_err().kind(),
+                BairiakErrorKind::ParseValueError
+            );
+        }
+        assert_eq!(
+            "256u8".parse::<Bairiak>().unwrap_err().kind(),
+            BairiakErrorKind::ValueOutOfRangeError
+        );
+    }
+}
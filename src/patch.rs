@@ -0,0 +1,99 @@
+use crate::{Bairiak, BairiakEnum};
+
+// A set of flag changes to apply to a `Bairiak` at once. Clearing happens
+// before setting, so a flag that is both enabled and disabled ends up set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BairiakPatch {
+    pub set: Bairiak,
+    pub clear: Bairiak,
+}
+
+impl Default for BairiakPatch {
+    fn default() -> Self {
+        BairiakPatch::new()
+    }
+}
+
+impl BairiakPatch {
+    pub fn new() -> Self {
+        BairiakPatch {
+            set: Bairiak::U8(0u8),
+            clear: Bairiak::U8(0u8),
+        }
+    }
+
+    pub fn enable<B: BairiakEnum>(&mut self, flag: B) -> &mut Self {
+        self.set = self.set.union(&B::get_zero_bairiak());
+        self.set.set(flag);
+        self
+    }
+
+    pub fn disable<B: BairiakEnum>(&mut self, flag: B) -> &mut Self {
+        self.clear = self.clear.union(&B::get_zero_bairiak());
+        self.clear.set(flag);
+        self
+    }
+
+    pub fn is_conflicting(&self) -> bool {
+        self.set.bits() & self.clear.bits() != 0
+    }
+
+    // `target` is widened if the patch sets positions beyond its width.
+    pub fn apply(&self, target: &mut Bairiak) {
+        let bits = target.bits() & !self.clear.bits() | self.set.bits();
+        *target = Bairiak::with_width(target.width().max(self.set.width()), bits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::fixtures::TestEnum;
+
+    #[test]
+    fn test_apply_patch() {
+        let mut patch = BairiakPatch::new();
+        patch.enable(TestEnum::Flag1).disable(TestEnum::Flag0);
+        assert!(!patch.is_conflicting());
+
+        let mut target = Bairiak::U16(0b1000_0001);
+        patch.apply(&mut target);
+        assert_eq!(target, Bairiak::U16(0b1000_0010));
+    }
+
+    #[test]
+    fn test_apply_conflicting_patch() {
+        let mut patch = BairiakPatch::new();
+        patch.enable(TestEnum::Flag0).disable(TestEnum::Flag0);
+        assert!(patch.is_conflicting());
+
+        let mut target = Bairiak::U16(0);
+        patch.apply(&mut target);
+        assert_eq!(target, Bairiak::U16(0b1));
+    }
+
+    #[test]
+    fn test_apply_patch_promotes_width() {
+        let mut patch = BairiakPatch::new();
+        patch.enable(TestEnum::Flag9);
+
+        let mut target = Bairiak::U8(0b1);
+        patch.apply(&mut target);
+        assert_eq!(target, Bairiak::U16(1 << 9 | 0b1));
+
+        let mut target = Bairiak::U32(1 << 20);
+        patch.apply(&mut target);
+        assert_eq!(target, Bairiak::U32(1 << 20 | 1 << 9));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_patch() {
+        let patch: BairiakPatch =
+            serde_json::from_str(r#"{"set": {"U16": 512}, "clear": {"U8": 1}}"#).unwrap();
+        let mut target = Bairiak::U8(0b11);
+        patch.apply(&mut target);
+        assert_eq!(target, Bairiak::U16(1 << 9 | 0b10));
+    }
+}
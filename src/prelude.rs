@@ -0,0 +1,44 @@
+//! The items most code using bairiak needs, for a single glob import. Code
+//! generated with `GenerateOptions::prelude(true)` imports it too.
+//!
+//! ```
+//! use bairiak::prelude::*;
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! enum Perm {
+//!     Read,
+//!     Write,
+//! }
+//!
+//! impl BairiakEnum for Perm {
+//!     fn get_zero_bairiak() -> Bairiak {
+//!         Bairiak::U8(0)
+//!     }
+//!     fn to_u8(self) -> u8 {
+//!         self as u8
+//!     }
+//!     fn from_u8(position: u8) -> Option<Self> {
+//!         [Perm::Read, Perm::Write].get(position as usize).copied()
+//!     }
+//!     fn name(&self) -> &'static str {
+//!         ["Read", "Write"][*self as usize]
+//!     }
+//! }
+//!
+//! let mut perms = generate_bairiak([Perm::Read]);
+//! assert!(!perms.is_true(Perm::Write));
+//! perms.set(Perm::Write);
+//! assert!(perms.is_true(Perm::Read) && perms.is_true(Perm::Write));
+//!
+//! let mut patch = BairiakPatch::new();
+//! patch.disable(Perm::Read);
+//! patch.apply(&mut perms);
+//! assert_eq!(perms, Perm::Write.mask());
+//! ```
+
+pub use crate::{
+    generate_bairiak, generate_bairiak_checked, Bairiak, BairiakEnum, BairiakError, BairiakPatch,
+};
+
+#[cfg(any(test, feature = "test-util"))]
+pub use crate::assert_flags;
@@ -0,0 +1,93 @@
+use std::fmt;
+
+use crate::{BairiakError, BairiakErrorKind, Result};
+
+// A problem of a spec, as reported by `EnumSpec::validate_all`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SpecProblem {
+    pub enum_name: String,
+    // The variant at fault and its index among the enum's `variants`, for the
+    // problems of a single variant.
+    pub variant: Option<String>,
+    pub index: Option<usize>,
+    pub kind: BairiakErrorKind,
+    pub message: String,
+}
+
+// `Perm.variants[2]: ...`, locating the problem in the spec.
+impl fmt::Display for SpecProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(
+                f,
+                "{}.variants[{}]: {}",
+                self.enum_name, index, self.message
+            ),
+            None => write!(f, "{}: {}", self.enum_name, self.message),
+        }
+    }
+}
+
+// Collects the problems validation finds: only the first, which stops it,
+// or all of them.
+pub(crate) enum Problems {
+    First(Option<BairiakError>),
+    All(Vec<SpecProblem>),
+}
+
+impl Problems {
+    // Records a problem of `enum_name`, or of one of its variants given its
+    // index and name, returning whether validation should go on.
+    pub(crate) fn add(
+        &mut self,
+        enum_name: &str,
+        variant: Option<(usize, &str)>,
+        err: BairiakError,
+    ) -> bool {
+        match self {
+            Problems::First(first) => {
+                first.get_or_insert(err);
+                false
+            }
+            Problems::All(found) => {
+                found.push(SpecProblem {
+                    enum_name: enum_name.to_string(),
+                    variant: variant.map(|(_, name)| name.to_string()),
+                    index: variant.map(|(index, _)| index),
+                    kind: err.kind(),
+                    message: err.message().to_string(),
+                });
+                true
+            }
+        }
+    }
+
+    // Like `add`, for the error of a check of the whole enum if it failed.
+    pub(crate) fn check(&mut self, enum_name: &str, result: Result<()>) -> bool {
+        match result {
+            Ok(()) => true,
+            Err(err) => self.add(enum_name, None, err),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Problems::First(first) => first.iter().len(),
+            Problems::All(found) => found.len(),
+        }
+    }
+
+    pub(crate) fn into_result(self) -> Result<()> {
+        match self {
+            Problems::First(Some(err)) => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<SpecProblem> {
+        match self {
+            Problems::First(_) => Vec::new(),
+            Problems::All(found) => found,
+        }
+    }
+}
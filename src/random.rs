@@ -0,0 +1,100 @@
+use rand::{distr::Bernoulli, Rng, RngExt};
+
+use crate::{Bairiak, BairiakEnum, BairiakError, BairiakErrorKind, Positions, Result};
+
+impl Bairiak {
+    // Sets each of `B`'s positions independently with probability 0.5.
+    pub fn random_for<B: BairiakEnum, R: Rng + ?Sized>(rng: &mut R) -> Bairiak {
+        // 0.5 is a valid probability.
+        #[allow(clippy::expect_used)]
+        let half = Bernoulli::new(0.5).expect("0.5 is in 0..=1");
+        random_with::<B, R>(rng, half)
+    }
+
+    // Sets each of `B`'s positions independently with probability `density`;
+    // undefined positions are never set. A `density` outside 0..=1 fails with
+    // a `ValueOutOfRangeError`.
+    pub fn random_with_density_for<B: BairiakEnum, R: Rng + ?Sized>(
+        rng: &mut R,
+        density: f64,
+    ) -> Result<Bairiak> {
+        let bernoulli = Bernoulli::new(density).map_err(|_| {
+            BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!(
+                    "Invalid density {} of random flags. Density should be in 0..=1.",
+                    density
+                ),
+            )
+        })?;
+        Ok(random_with::<B, R>(rng, bernoulli))
+    }
+}
+
+fn random_with<B: BairiakEnum, R: Rng + ?Sized>(rng: &mut R, bernoulli: Bernoulli) -> Bairiak {
+    let mut bairiak = B::get_zero_bairiak();
+    let defined = Positions {
+        bits: B::defined_mask(),
+    };
+    for position in defined {
+        if rng.sample(bernoulli) {
+            bairiak.set_position(position);
+        }
+    }
+    bairiak
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::fixtures::TestEnum;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_random_for_is_deterministic() {
+        let (mut a, mut b) = (StdRng::seed_from_u64(7), StdRng::seed_from_u64(7));
+        for _ in 0..100 {
+            assert_eq!(
+                Bairiak::random_for::<TestEnum, _>(&mut a).bits(),
+                Bairiak::random_for::<TestEnum, _>(&mut b).bits()
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_for_sets_only_defined_positions() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut seen = 0;
+        for _ in 0..1000 {
+            let bairiak = Bairiak::random_for::<TestEnum, _>(&mut rng);
+            assert!(matches!(bairiak, Bairiak::U16(_)));
+            assert_eq!(bairiak.bits() & !TestEnum::defined_mask(), 0);
+            seen |= bairiak.bits();
+        }
+        assert_eq!(seen, TestEnum::defined_mask());
+    }
+
+    #[test]
+    fn test_random_with_density() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let empty = Bairiak::random_with_density_for::<TestEnum, _>(&mut rng, 0.0).unwrap();
+        assert_eq!(empty.bits(), 0);
+        let full = Bairiak::random_with_density_for::<TestEnum, _>(&mut rng, 1.0).unwrap();
+        assert_eq!(full.bits(), TestEnum::defined_mask());
+    }
+
+    #[test]
+    fn test_random_with_invalid_density() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for density in [-0.1, 1.5, f64::NAN] {
+            let err =
+                Bairiak::random_with_density_for::<TestEnum, _>(&mut rng, density).unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        }
+        let err = Bairiak::random_with_density_for::<TestEnum, _>(&mut rng, 2.0).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Invalid density 2 of random flags. Density should be in 0..=1."
+        );
+    }
+}
@@ -0,0 +1,317 @@
+// Generating into a region of a larger, hand-maintained file: only the lines
+// between the begin and end markers are replaced, and everything around them
+// is kept byte for byte.
+
+use std::{fs, io::Write, ops::Range, path::Path};
+
+use crate::{
+    fnv1a_64, replace_file, write_file_error, BairiakError, BairiakErrorKind, Result,
+    GENERATED_MARKER, HASH_PREFIX,
+};
+
+pub(crate) const DEFAULT_BEGIN: &str = "// bairiak:begin";
+pub(crate) const DEFAULT_END: &str = "// bairiak:end";
+
+// Replaces the region of `output_path` delimited by `begin` and `end` with
+// `code`. A file without markers gets the region appended, unless `strict`,
+// and a missing file is created with only the region. Like a whole generated
+// file, a region already holding `code` is left alone, and `false` returned.
+pub(crate) fn write_region(
+    output_path: &Path,
+    (begin, end): (&str, &str),
+    strict: bool,
+    force: bool,
+    code: &str,
+) -> Result<bool> {
+    let content = match fs::read_to_string(output_path) {
+        Ok(content) => content,
+        Err(_) if !output_path.exists() => String::new(),
+        Err(err) => return Err(write_file_error(output_path, err)),
+    };
+
+    let region =
+        find_region(&content, begin, end).map_err(|message| region_error(output_path, message))?;
+    let updated = match region {
+        Some(region) => {
+            let existing = &content[region.clone()];
+            if existing == code {
+                debug!("{} is up to date", output_path.display());
+                return Ok(false);
+            }
+            check_unmodified(output_path, existing, force)?;
+            format!(
+                "{}{}{}",
+                &content[..region.start],
+                code,
+                &content[region.end..]
+            )
+        }
+        None if strict && !content.is_empty() => {
+            return Err(region_error(
+                output_path,
+                format!("no `{}` and `{}` markers to generate between", begin, end),
+            ));
+        }
+        None => {
+            let separator = if content.is_empty() || content.ends_with('\n') {
+                ""
+            } else {
+                "\n"
+            };
+            format!("{}{}{}\n{}{}\n", content, separator, begin, code, end)
+        }
+    };
+
+    replace_file(output_path, |out| {
+        out.write_all(updated.as_bytes())
+            .map_err(|err| write_file_error(output_path, err))
+    })?;
+    Ok(true)
+}
+
+// The byte range between the begin marker's line and the end marker's line,
+// or `None` without markers. A marker is a line holding only the marker, up to
+// surrounding whitespace. Markers must pair up into a single region.
+fn find_region(
+    content: &str,
+    begin: &str,
+    end: &str,
+) -> std::result::Result<Option<Range<usize>>, String> {
+    let (begin, end) = (begin.trim(), end.trim());
+    let mut region: Option<Range<usize>> = None;
+    // The line and the byte after the begin marker of a region not yet ended.
+    let mut open: Option<(usize, usize)> = None;
+    let mut begun_on = 0;
+    let mut offset = 0;
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let line_number = index + 1;
+        let marker = line.trim();
+        if marker == begin {
+            if let Some((open_line, _)) = open {
+                return Err(format!(
+                    "`{}` on line {} is nested in the region begun on line {}",
+                    begin, line_number, open_line
+                ));
+            }
+            if region.is_some() {
+                return Err(format!(
+                    "`{}` on line {} begins a second region after the one on line {}",
+                    begin, line_number, begun_on
+                ));
+            }
+            open = Some((line_number, offset + line.len()));
+        } else if marker == end {
+            let Some((open_line, start)) = open.take() else {
+                return Err(format!(
+                    "`{}` on line {} has no `{}` before it",
+                    end, line_number, begin
+                ));
+            };
+            begun_on = open_line;
+            region = Some(start..offset);
+        }
+        offset += line.len();
+    }
+
+    match open {
+        Some((open_line, _)) => Err(format!(
+            "`{}` on line {} is never closed by `{}`",
+            begin, open_line, end
+        )),
+        None => Ok(region),
+    }
+}
+
+// A region holding something else than generated code whose body matches
+// its hash has been edited by hand, and is only replaced with `force`. An
+// empty region is new.
+fn check_unmodified(output_path: &Path, existing: &str, force: bool) -> Result<()> {
+    if existing.trim().is_empty() || is_unmodified(existing) {
+        return Ok(());
+    }
+    if force {
+        warn!(
+            "Overwriting the region of {}, which has been edited by hand",
+            output_path.display()
+        );
+        return Ok(());
+    }
+    Err(BairiakError::new(
+        BairiakErrorKind::OutputModifiedError,
+        format!(
+            "The region of {} was not generated by bairiak or has been edited by hand.",
+            output_path.display()
+        ),
+    )
+    .with_path(output_path)
+    .logged())
+}
+
+fn is_unmodified(code: &str) -> bool {
+    let mut lines = code.splitn(3, '\n');
+    let (Some(marker), Some(hash), Some(body)) = (lines.next(), lines.next(), lines.next()) else {
+        return false;
+    };
+    marker.contains(GENERATED_MARKER)
+        && hash.strip_prefix(HASH_PREFIX) == Some(&format!("{:016x}", fnv1a_64(body.as_bytes())))
+}
+
+fn region_error(output_path: &Path, message: String) -> BairiakError {
+    BairiakError::new(
+        BairiakErrorKind::RegionMarkerError,
+        format!("{}: {}.", output_path.display(), message),
+    )
+    .with_path(output_path)
+    .logged()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{generate_bairiak_enums_with, GenerateOptions};
+
+    const SPEC: &str = "test_data/valid_spec.yaml";
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn generated() -> String {
+        crate::generate_bairiak_enums_from_str(&fs::read_to_string(SPEC).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_find_region() {
+        let content = "mod a;\n// bairiak:begin\nold\n  // bairiak:end  \nmod b;\n";
+        let region = find_region(content, DEFAULT_BEGIN, DEFAULT_END)
+            .unwrap()
+            .unwrap();
+        assert_eq!(&content[region], "old\n");
+
+        let content = "// bairiak:begin\n// bairiak:end\n";
+        let region = find_region(content, DEFAULT_BEGIN, DEFAULT_END)
+            .unwrap()
+            .unwrap();
+        assert!(region.is_empty());
+
+        assert_eq!(
+            find_region("mod a;\n", DEFAULT_BEGIN, DEFAULT_END),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_unbalanced_markers() {
+        let error = |content: &str| find_region(content, DEFAULT_BEGIN, DEFAULT_END).unwrap_err();
+        assert_eq!(
+            error("// bairiak:begin\n// bairiak:begin\n// bairiak:end\n"),
+            "`// bairiak:begin` on line 2 is nested in the region begun on line 1"
+        );
+        assert_eq!(
+            error("mod a;\n// bairiak:end\n"),
+            "`// bairiak:end` on line 2 has no `// bairiak:begin` before it"
+        );
+        assert_eq!(
+            error("// bairiak:begin\nmod a;\n"),
+            "`// bairiak:begin` on line 1 is never closed by `// bairiak:end`"
+        );
+        assert_eq!(
+            error("// bairiak:begin\n// bairiak:end\n// bairiak:begin\n// bairiak:end\n"),
+            "`// bairiak:begin` on line 3 begins a second region after the one on line 1"
+        );
+    }
+
+    #[test]
+    fn test_replace_region() {
+        let dir = temp_dir("bairiak_test_region_replace");
+        let output_path = dir.join("flags.rs");
+        let before = "// Hand-written.\nuse std::fmt;\n\n    // bairiak:begin\n";
+        let after = "    // bairiak:end\n\nfn keep() {}";
+        fs::write(&output_path, format!("{}{}", before, after)).unwrap();
+
+        let options = GenerateOptions::default().region(true);
+        let report = generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap();
+        assert!(report.rewritten);
+        let content = fs::read_to_string(&output_path).unwrap();
+        assert_eq!(content, format!("{}{}{}", before, generated(), after));
+
+        // Running again changes nothing.
+        let report = generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap();
+        assert!(!report.rewritten);
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), content);
+
+        // A region edited by hand is only replaced with `force`.
+        fs::write(&output_path, content.replace("Flag2", "Flag3")).unwrap();
+        let err = generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::OutputModifiedError);
+        generate_bairiak_enums_with(SPEC, &output_path, &options.force(true)).unwrap();
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), content);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_insert_region() {
+        let dir = temp_dir("bairiak_test_region_insert");
+        let output_path = dir.join("flags.rs");
+        fs::write(&output_path, "fn keep() {}").unwrap();
+        let options = GenerateOptions::default().region_markers("// flags {", "// }");
+
+        generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap();
+        let content = fs::read_to_string(&output_path).unwrap();
+        assert_eq!(
+            content,
+            format!("fn keep() {{}}\n// flags {{\n{}// }}\n", generated())
+        );
+
+        // The inserted markers are found the next time.
+        let report = generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap();
+        assert!(!report.rewritten);
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), content);
+
+        // A missing file only gets the region.
+        fs::remove_file(&output_path).unwrap();
+        generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap();
+        assert_eq!(
+            fs::read_to_string(&output_path).unwrap(),
+            format!("// flags {{\n{}// }}\n", generated())
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_region_errors_leave_file_untouched() {
+        let dir = temp_dir("bairiak_test_region_errors");
+        let output_path = dir.join("flags.rs");
+        let options = GenerateOptions::default().region(true);
+
+        fs::write(&output_path, "fn keep() {}\n").unwrap();
+        let err = generate_bairiak_enums_with(SPEC, &output_path, &options.clone().strict(true))
+            .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::RegionMarkerError);
+        assert_eq!(
+            err.message(),
+            format!(
+                "{}: no `// bairiak:begin` and `// bairiak:end` markers to generate between.",
+                output_path.display()
+            )
+        );
+
+        fs::write(&output_path, "// bairiak:begin\nfn keep() {}\n").unwrap();
+        let err = generate_bairiak_enums_with(SPEC, &output_path, &options).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::RegionMarkerError);
+        assert_eq!(err.path(), Some(output_path.as_path()));
+        assert_eq!(
+            fs::read_to_string(&output_path).unwrap(),
+            "// bairiak:begin\nfn keep() {}\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
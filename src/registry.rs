@@ -0,0 +1,103 @@
+// A process-wide registry of the enums generated with `registry: true`, for
+// tooling that handles every flag enum of a binary without listing them. Each
+// generated enum submits a `RegisteredEnum` through `inventory`, so the
+// registry is filled before `main` and only read afterwards.
+
+#[doc(hidden)]
+pub use inventory;
+
+use crate::Bairiak;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RegisteredEnum {
+    pub name: &'static str,
+    // The width of the enum's `Bairiak` values in bits.
+    pub width: u8,
+    // Each variant's position and name, in spec order.
+    pub variants: &'static [(u8, &'static str)],
+}
+
+impl RegisteredEnum {
+    // The name of the variant at `position`, if there is one.
+    pub fn variant_name(&self, position: u8) -> Option<&'static str> {
+        self.variants
+            .iter()
+            .find(|&&(p, _)| p == position)
+            .map(|&(_, name)| name)
+    }
+
+    // The names of the set flags of `value` in position order, and the
+    // positions of set bits no variant defines.
+    pub fn decode(&self, value: &Bairiak) -> (Vec<&'static str>, Vec<u8>) {
+        let mut names = Vec::new();
+        let mut unknown = Vec::new();
+        for position in value.positions() {
+            match self.variant_name(position) {
+                Some(name) => names.push(name),
+                None => unknown.push(position),
+            }
+        }
+        (names, unknown)
+    }
+
+    // The mask of the positions of all variants.
+    pub fn mask(&self) -> u128 {
+        self.variants
+            .iter()
+            .fold(0, |mask, &(position, _)| mask | 1 << position)
+    }
+}
+
+inventory::collect!(RegisteredEnum);
+
+// Every registered enum, in no particular order.
+pub fn enums() -> impl Iterator<Item = &'static RegisteredEnum> {
+    inventory::iter::<RegisteredEnum>.into_iter()
+}
+
+pub fn lookup(name: &str) -> Option<&'static RegisteredEnum> {
+    enums().find(|e| e.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    include!("../test_data/expected/registry_spec.rs");
+
+    #[test]
+    fn test_generated_enums_are_registered() {
+        let perm = lookup("Perm").unwrap();
+        assert_eq!(
+            perm,
+            &RegisteredEnum {
+                name: "Perm",
+                width: 8,
+                variants: &[(0, "Read"), (1, "Write"), (3, "Admin")],
+            }
+        );
+        assert_eq!(perm.mask(), Perm::defined_mask());
+
+        let codec = lookup("Codec").unwrap();
+        assert_eq!(codec.width, 16);
+        assert_eq!(codec.variants, &[(0, "Gzip"), (9, "Zstd")]);
+        assert_eq!(codec.mask(), 1 << 9 | 1);
+
+        let mut names: Vec<&str> = enums().map(|e| e.name).collect();
+        names.sort();
+        assert_eq!(names, ["Codec", "Perm"]);
+        assert_eq!(lookup("Unregistered"), None);
+    }
+
+    #[test]
+    fn test_decode() {
+        let perm = lookup("Perm").unwrap();
+        let value: Bairiak = [Perm::Admin, Perm::Read].into_iter().collect();
+        assert_eq!(perm.decode(&value), (vec!["Read", "Admin"], vec![]));
+        assert_eq!(
+            perm.decode(&Bairiak::U8(0b1100_0010)),
+            (vec!["Write"], vec![6, 7])
+        );
+        assert_eq!(perm.variant_name(2), None);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crate::{Bairiak, BairiakEnum};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Remapped {
+    pub value: Bairiak,
+    // The set bits that the mapping doesn't cover, or maps beyond the width of
+    // the target enum, at their old positions.
+    pub dropped: Bairiak,
+}
+
+// Moves each set bit of a value of `A` to the position of `B` that `mapping`
+// maps it to. A bit that can't move goes to `dropped` instead of being lost.
+pub fn remap<A: BairiakEnum, B: BairiakEnum>(
+    value: &Bairiak,
+    mapping: &HashMap<u8, u8>,
+) -> Remapped {
+    let mut remapped = Remapped {
+        value: B::get_zero_bairiak(),
+        dropped: A::get_zero_bairiak().union(&Bairiak::with_width(value.width(), 0)),
+    };
+    for position in value.positions() {
+        match mapping.get(&position) {
+            Some(&new_position) if new_position < remapped.value.width() => {
+                remapped.value.set_position(new_position)
+            }
+            _ => remapped.dropped.set_position(position),
+        }
+    }
+    remapped
+}
+
+#[derive(Debug, Clone)]
+pub struct Remapper {
+    mapping: HashMap<u8, u8>,
+    unmatched: Vec<&'static str>,
+    remap: fn(&Bairiak, &HashMap<u8, u8>) -> Remapped,
+}
+
+impl Remapper {
+    // Pairs up the variants of `A` and `B` with the same name. Variants of `A`
+    // without a namesake in `B` are reported by `unmatched`.
+    pub fn by_name<A: BairiakEnum, B: BairiakEnum>() -> Self {
+        let targets: HashMap<&'static str, u8> = B::all_variants()
+            .into_iter()
+            .map(|variant| (variant.name(), variant.to_u8()))
+            .collect();
+
+        let mut remapper = Remapper {
+            mapping: HashMap::new(),
+            unmatched: Vec::new(),
+            remap: remap::<A, B>,
+        };
+        for variant in A::all_variants() {
+            let name = variant.name();
+            match targets.get(name) {
+                Some(&new_position) => {
+                    remapper.mapping.insert(variant.to_u8(), new_position);
+                }
+                None => remapper.unmatched.push(name),
+            }
+        }
+        remapper
+    }
+
+    pub fn mapping(&self) -> &HashMap<u8, u8> {
+        &self.mapping
+    }
+
+    pub fn unmatched(&self) -> &[&'static str] {
+        &self.unmatched
+    }
+
+    pub fn remap(&self, value: &Bairiak) -> Remapped {
+        (self.remap)(value, &self.mapping)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::fixtures::LegacyPerm;
+
+    include!("../test_data/expected/migration_spec.rs");
+
+    #[test]
+    fn test_remap() {
+        let mapping = HashMap::from([(0, 0), (1, 2)]);
+        let remapped = remap::<LegacyPerm, Perm>(&Bairiak::U8(0b111), &mapping);
+        assert_eq!(remapped.value, Bairiak::U8(0b101));
+        assert_eq!(remapped.dropped, Bairiak::U8(0b100));
+        assert_eq!(remapped.value, migrate_perm_v1_to_v2(Bairiak::U8(0b111)));
+    }
+
+    #[test]
+    fn test_remap_beyond_target_width() {
+        // `Perm` is 8 bits wide, so positions 8 and 200 don't fit.
+        let mapping = HashMap::from([(0, 8), (1, 200), (2, 7)]);
+        let remapped = remap::<LegacyPerm, Perm>(&Bairiak::U8(0b111), &mapping);
+        assert_eq!(remapped.value, Bairiak::U8(0b1000_0000));
+        assert_eq!(remapped.dropped, Bairiak::U8(0b011));
+    }
+
+    #[test]
+    fn test_remapper_by_name() {
+        let remapper = Remapper::by_name::<LegacyPerm, Perm>();
+        assert_eq!(remapper.mapping(), &HashMap::from([(0, 0), (1, 2)]));
+        assert_eq!(remapper.unmatched(), ["Delete"]);
+
+        let remapped = remapper.remap(&Bairiak::U8(0b1000_0110));
+        assert_eq!(remapped.value, Bairiak::U8(0b100));
+        assert_eq!(remapped.dropped, Bairiak::U8(0b1000_0100));
+    }
+}
@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use crate::{EnumSpec, Result};
+
+// What `generate_bairiak_enums` generated, so that build scripts can act on
+// the flags without parsing the spec again.
+#[derive(PartialEq, Debug, Clone)]
+pub struct GenerationReport {
+    pub output_path: PathBuf,
+    // `false` if the output already held the same code and was left alone.
+    pub rewritten: bool,
+    // Where the manifest was written, with `GenerateOptions::manifest`.
+    pub manifest_path: Option<PathBuf>,
+    pub enums: Vec<EnumReport>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct EnumReport {
+    pub name: String,
+    pub width: u8,
+    // Each variant's name and position, in spec order. Placeholders take a
+    // position but aren't listed.
+    pub positions: Vec<(String, u8)>,
+}
+
+pub(crate) fn enum_reports(spec: &EnumSpec) -> Result<Vec<EnumReport>> {
+    spec.enums
+        .iter()
+        .map(|e| {
+            Ok(EnumReport {
+                name: e.name.clone(),
+                width: e.width()?,
+                positions: e
+                    .positioned_variants()
+                    .map(|(position, v)| (v.to_string(), position as u8))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use super::*;
+    use crate::generate_bairiak_enums;
+
+    fn positions(names: &[(&str, u8)]) -> Vec<(String, u8)> {
+        names
+            .iter()
+            .map(|&(name, position)| (String::from(name), position))
+            .collect()
+    }
+
+    #[test]
+    fn test_report_of_valid_spec() {
+        let output_path = Path::new("report_output.rs");
+        let _ = fs::remove_file(output_path);
+
+        let report = generate_bairiak_enums("test_data/valid_spec.yaml", output_path).unwrap();
+        assert_eq!(
+            report,
+            GenerationReport {
+                output_path: output_path.to_path_buf(),
+                rewritten: true,
+                manifest_path: None,
+                enums: vec![EnumReport {
+                    name: String::from("TestEnum"),
+                    width: 8,
+                    positions: positions(&[("Flag0", 0), ("Flag1", 1), ("Flag2", 2)]),
+                }],
+            }
+        );
+
+        let report = generate_bairiak_enums("test_data/valid_spec.yaml", output_path).unwrap();
+        assert!(!report.rewritten);
+        assert_eq!(report.enums[0].positions.len(), 3);
+
+        fs::remove_file(output_path).unwrap();
+    }
+
+    #[test]
+    fn test_report_with_explicit_positions_and_placeholders() {
+        let output_path = Path::new("report_positions_output.rs");
+        let report = generate_bairiak_enums("test_data/positions_spec.yaml", output_path).unwrap();
+        fs::remove_file(output_path).unwrap();
+
+        assert_eq!(
+            report.enums[0].positions,
+            positions(&[("Read", 0), ("Write", 1), ("Admin", 5), ("Audit", 6)])
+        );
+        assert_eq!(report.enums[1].width, 64);
+        assert_eq!(
+            report.enums[2],
+            EnumReport {
+                name: String::from("Reserved"),
+                width: 16,
+                positions: positions(&[("Read", 0), ("Write", 1), ("Admin", 4)]),
+            }
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{suggest, Bairiak, BairiakError, BairiakErrorKind, EnumSpec, FromStrMode, Result};
+
+impl EnumSpec {
+    // The value of `enum_name` with the flags named `flag_names` set, for
+    // callers that have the spec but not the generated enum. Names match like
+    // the enum's `FromStr`, aliases included, and exactly if it has none.
+    pub fn resolve(&self, enum_name: &str, flag_names: &[&str]) -> Result<Bairiak> {
+        let e = self.find_enum(enum_name)?;
+        let width = e.width()?;
+        let mode = e.from_str.unwrap_or(FromStrMode::Exact);
+        let names = e.parse_names(mode);
+        let variant_of = |key: &str| {
+            names
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|&(_, variant)| variant)
+        };
+
+        let mut bits = 0u128;
+        for &flag_name in flag_names {
+            let key = match mode {
+                FromStrMode::Exact => flag_name.to_string(),
+                FromStrMode::CaseInsensitive => flag_name.to_ascii_lowercase(),
+            };
+            let position = variant_of(&key).and_then(|variant| {
+                e.positioned_variants()
+                    .find(|&(_, v)| v == variant)
+                    .map(|(position, _)| position)
+            });
+            let Some(position) = position else {
+                // An alias close to the name suggests its variant.
+                let suggestion = suggest::closest(&key, names.iter().map(|(k, _)| k.as_str()))
+                    .and_then(variant_of)
+                    .map(|variant| format!(" Did you mean `{}`?", variant))
+                    .unwrap_or_default();
+                return Err(BairiakError::new(
+                    BairiakErrorKind::UnknownFlagError,
+                    format!("Unknown {} flag: {}.{}", e.name, flag_name, suggestion),
+                ));
+            };
+            bits |= 1u128.checked_shl(position as u32).unwrap_or(0);
+        }
+        Ok(Bairiak::with_width(width, bits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_spec;
+
+    const SPEC: &str = "
+enums:
+  - name: Perm
+    from_str: case_insensitive
+    variants:
+      - Read
+      - name: Write
+        aliases: [modify]
+      - ~
+      - Admin
+  - name: Codec
+    bits: 32
+    variants: [Gzip, Zstd]
+";
+
+    #[test]
+    fn test_resolve() {
+        let spec = parse_spec(SPEC).unwrap();
+        assert_eq!(
+            spec.resolve("Perm", &["read", "ADMIN", "Read"]),
+            Ok(Bairiak::U8(0b1001))
+        );
+        assert_eq!(spec.resolve("Codec", &["Zstd"]), Ok(Bairiak::U32(0b10)));
+
+        let err = spec.resolve("Codec", &["gzip"]).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::UnknownFlagError);
+        assert_eq!(
+            err.message(),
+            "Unknown Codec flag: gzip. Did you mean `Gzip`?"
+        );
+    }
+
+    #[test]
+    fn test_resolve_aliases() {
+        let spec = parse_spec(SPEC).unwrap();
+        assert_eq!(
+            spec.resolve("Perm", &["Modify"]),
+            spec.resolve("Perm", &["write"])
+        );
+        assert_eq!(spec.resolve("Perm", &["modify"]), Ok(Bairiak::U8(0b10)));
+
+        let err = spec.resolve("Perm", &["modfy"]).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Unknown Perm flag: modfy. Did you mean `Write`?"
+        );
+    }
+
+    #[test]
+    fn test_resolve_empty_flags() {
+        let spec = parse_spec(SPEC).unwrap();
+        assert_eq!(spec.resolve("Perm", &[]), Ok(Bairiak::U8(0)));
+        assert_eq!(spec.resolve("Codec", &[]), Ok(Bairiak::U32(0)));
+    }
+
+    #[test]
+    fn test_resolve_unknown_names() {
+        let spec = parse_spec(SPEC).unwrap();
+        let err = spec.resolve("Prem", &["Read"]).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::UnknownEnumError);
+        assert_eq!(err.message(), "Unknown enum: Prem. Did you mean `Perm`?");
+
+        let err = spec.resolve("Flags", &[]).unwrap_err();
+        assert_eq!(err.message(), "Unknown enum: Flags.");
+
+        let err = spec.resolve("Perm", &["Execute"]).unwrap_err();
+        assert_eq!(err.message(), "Unknown Perm flag: Execute.");
+    }
+}
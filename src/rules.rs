@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::{Bairiak, BairiakEnum, Positions};
+
+// A group of mutually exclusive flags with more than one of them set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExclusiveViolation {
+    // The index of the group in the enum's `exclusive` list.
+    pub group: usize,
+    // The names of the group's set flags, in position order.
+    pub set: Vec<&'static str>,
+}
+
+impl fmt::Display for ExclusiveViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Mutually exclusive flags set together: {}.",
+            self.set.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ExclusiveViolation {}
+
+// Checks that at most one flag of each group in `masks` is set, reporting
+// the first group in which more are. Generated `validate_exclusive` functions
+// call this with the enum's `EXCLUSIVE_MASKS`.
+pub fn check_exclusive_for<B: BairiakEnum>(
+    bairiak: &Bairiak,
+    masks: &[u128],
+) -> Result<(), ExclusiveViolation> {
+    for (group, &mask) in masks.iter().enumerate() {
+        let set = bairiak.bits() & mask;
+        if set.count_ones() > 1 {
+            return Err(ExclusiveViolation {
+                group,
+                set: names::<B>(set),
+            });
+        }
+    }
+    Ok(())
+}
+
+// A set flag whose required flags aren't all set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiresViolation {
+    pub flag: &'static str,
+    // The names of the required flags that are unset, in position order.
+    pub missing: Vec<&'static str>,
+}
+
+impl fmt::Display for RequiresViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` requires ", self.flag)?;
+        for (i, missing) in self.missing.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{}`", missing)?;
+        }
+        f.write_str(".")
+    }
+}
+
+impl std::error::Error for RequiresViolation {}
+
+// Checks that every set flag of `requirements`, given as its position and the
+// mask of the flags it requires, has those flags set too, reporting every
+// violation in the order of `requirements`. Requirements aren't followed
+// transitively: a chain holds because each flag along it is checked in turn.
+// Generated `validate_requires` functions call this with the enum's
+// `REQUIREMENTS`.
+pub fn check_requires_for<B: BairiakEnum>(
+    bairiak: &Bairiak,
+    requirements: &[(u8, u128)],
+) -> Result<(), Vec<RequiresViolation>> {
+    let bits = bairiak.bits();
+    let violations: Vec<RequiresViolation> = requirements
+        .iter()
+        .filter(|&&(position, mask)| {
+            bits & 1u128.checked_shl(position as u32).unwrap_or(0) != 0 && bits & mask != mask
+        })
+        .filter_map(|&(position, mask)| {
+            Some(RequiresViolation {
+                flag: B::from_u8(position)?.name(),
+                missing: names::<B>(mask & !bits),
+            })
+        })
+        .collect();
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+// Names of the variants at the set bits of `bits`, which `B` must define.
+fn names<B: BairiakEnum>(bits: u128) -> Vec<&'static str> {
+    Positions { bits }
+        .filter_map(B::from_u8)
+        .map(|flag| flag.name())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    include!("../test_data/expected/exclusive_spec.rs");
+
+    const MASKS: [u128; 2] = [0b11, 0b1_1100];
+
+    #[test]
+    fn test_check_exclusive_valid() {
+        assert_eq!(
+            check_exclusive_for::<Codec>(&Bairiak::U8(0), &MASKS),
+            Ok(())
+        );
+        assert_eq!(
+            check_exclusive_for::<Codec>(&Bairiak::U8(0b1_0010), &MASKS),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_exclusive_two_way_violation() {
+        let violation = check_exclusive_for::<Codec>(&Bairiak::U8(0b0_0111), &MASKS).unwrap_err();
+        assert_eq!(
+            violation,
+            ExclusiveViolation {
+                group: 0,
+                set: vec!["CompressGzip", "CompressZstd"],
+            }
+        );
+        assert_eq!(
+            violation.to_string(),
+            "Mutually exclusive flags set together: CompressGzip, CompressZstd."
+        );
+    }
+
+    #[test]
+    fn test_check_exclusive_three_way_group() {
+        for member in [2, 3, 4] {
+            let bairiak = Bairiak::U8(1 << member | 0b1);
+            assert_eq!(check_exclusive_for::<Codec>(&bairiak, &MASKS), Ok(()));
+        }
+
+        let violation = check_exclusive_for::<Codec>(&Bairiak::U8(0b1_1100), &MASKS).unwrap_err();
+        assert_eq!(violation.group, 1);
+        assert_eq!(violation.set, ["ModeA", "ModeB", "ModeC"]);
+    }
+
+    // `ModeA` requires `ModeB`, which requires `ModeC`, and `CompressZstd`
+    // requires `CompressGzip` and `ModeC`.
+    const REQUIREMENTS: [(u8, u128); 3] = [(1, 0b1_0001), (2, 0b1000), (3, 0b1_0000)];
+
+    #[test]
+    fn test_check_requires_satisfied() {
+        assert_eq!(
+            check_requires_for::<Codec>(&Bairiak::U8(0), &REQUIREMENTS),
+            Ok(())
+        );
+        assert_eq!(
+            check_requires_for::<Codec>(&Bairiak::U8(0b1_1111), &REQUIREMENTS),
+            Ok(())
+        );
+        assert_eq!(
+            check_requires_for::<Codec>(&Bairiak::U8(0b1_0011), &REQUIREMENTS),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_requires_unsatisfied() {
+        let violations =
+            check_requires_for::<Codec>(&Bairiak::U8(0b0_0010), &REQUIREMENTS).unwrap_err();
+        assert_eq!(
+            violations,
+            [RequiresViolation {
+                flag: "CompressZstd",
+                missing: vec!["CompressGzip", "ModeC"],
+            }]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "`CompressZstd` requires `CompressGzip`, `ModeC`."
+        );
+    }
+
+    #[test]
+    fn test_check_requires_transitive_chain() {
+        let violations =
+            check_requires_for::<Codec>(&Bairiak::U8(0b0_1100), &REQUIREMENTS).unwrap_err();
+        assert_eq!(
+            violations,
+            [RequiresViolation {
+                flag: "ModeB",
+                missing: vec!["ModeC"],
+            }]
+        );
+
+        let violations =
+            check_requires_for::<Codec>(&Bairiak::U8(0b0_0100), &REQUIREMENTS).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].flag, "ModeA");
+        assert_eq!(violations[0].missing, ["ModeB"]);
+    }
+}
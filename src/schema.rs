@@ -0,0 +1,32 @@
+// Tests for the JSON Schema of `Bairiak` and of generated enums.
+
+use schemars::schema_for;
+use serde_json::json;
+
+include!("../test_data/expected/json_schema_spec.rs");
+
+#[test]
+fn test_bairiak_schema() {
+    let schema = schema_for!(Bairiak).to_value();
+    let variants = schema["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 5);
+    assert_eq!(variants[0]["type"], "object");
+    assert_eq!(variants[0]["required"], json!(["U8"]));
+    assert_eq!(variants[0]["properties"]["U8"]["type"], "integer");
+    assert_eq!(variants[0]["properties"]["U8"]["maximum"], 255);
+    assert_eq!(variants[1]["properties"]["U16"]["maximum"], 65535);
+}
+
+// The schema describes the serde representation of `Bairiak`.
+#[test]
+fn test_bairiak_schema_matches_serde() {
+    let value = serde_json::to_value(Bairiak::U16(512)).unwrap();
+    assert_eq!(value, json!({"U16": 512}));
+}
+
+#[test]
+fn test_generated_enum_schema() {
+    let schema = schema_for!(Perm).to_value();
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["enum"], json!(["Read", "Write", "Delete"]));
+}
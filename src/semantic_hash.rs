@@ -0,0 +1,145 @@
+// A hash of the flags set in a value by name rather than position, for cache
+// keys that must survive renumbering between releases.
+//
+// The semantic hash is the 64-bit FNV-1a hash (offset basis
+// 0xcbf29ce484222325, prime 0x100000001b3) of the names of the set flags,
+// sorted by their UTF-8 bytes, each as its name in UTF-8 followed by a zero
+// byte, then of the set bits the enum doesn't define, in ascending position,
+// each as a 0xff byte followed by its position as one byte. No name starts
+// with 0xff, which UTF-8 never uses. The width of the value doesn't take
+// part, so an empty set hashes to the offset basis. The algorithm is stable:
+// the same names hash the same in every release, so other languages can
+// compute it on their own.
+
+use crate::{fnv1a_64, Bairiak, BairiakEnum};
+
+impl Bairiak {
+    // The semantic hash of the flags of `B` set in this value, which stays the
+    // same as long as their names do, whatever their positions.
+    pub fn semantic_hash_for<B: BairiakEnum>(&self) -> u64 {
+        let mut names = Vec::new();
+        let mut unknown = Vec::new();
+        for position in self.positions() {
+            match B::from_u8(position) {
+                Some(flag) => names.push(flag.name()),
+                None => unknown.push(position),
+            }
+        }
+        names.sort_unstable();
+
+        let mut bytes = Vec::new();
+        for name in names {
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.push(0);
+        }
+        for position in unknown {
+            bytes.push(0xff);
+            bytes.push(position);
+        }
+        fnv1a_64(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fnv1a_64, generate_bairiak, Bairiak, BairiakEnum};
+
+    macro_rules! test_enum {
+        ($name:ident, $width:ident, $($variant:ident = $position:literal),*) => {
+            #[derive(Debug, Clone, Copy)]
+            enum $name {
+                $($variant = $position),*
+            }
+
+            impl BairiakEnum for $name {
+                fn get_zero_bairiak() -> Bairiak {
+                    Bairiak::$width(0)
+                }
+
+                fn to_u8(self) -> u8 {
+                    self as u8
+                }
+
+                fn from_u8(position: u8) -> Option<Self> {
+                    match position {
+                        $($position => Some($name::$variant),)*
+                        _ => None,
+                    }
+                }
+
+                fn name(&self) -> &'static str {
+                    match self {
+                        $($name::$variant => stringify!($variant),)*
+                    }
+                }
+            }
+        };
+    }
+
+    test_enum!(Perm, U8, Read = 0, Write = 1, Admin = 2);
+    // The flags of `Perm` and one more, renumbered and wider.
+    test_enum!(PermV2, U16, Admin = 0, Audit = 3, Read = 9, Write = 12);
+
+    #[test]
+    fn test_semantic_hash_values() {
+        // Pinned, as the algorithm is stable.
+        assert_eq!(
+            Bairiak::U8(0).semantic_hash_for::<Perm>(),
+            0xcbf29ce484222325
+        );
+        let read = generate_bairiak([Perm::Read]);
+        assert_eq!(read.semantic_hash_for::<Perm>(), fnv1a_64(b"Read\0"));
+        assert_eq!(read.semantic_hash_for::<Perm>(), 0x9c13eb548d25bc1f);
+
+        // Sorted by name, not position.
+        let all = generate_bairiak([Perm::Read, Perm::Write, Perm::Admin]);
+        assert_eq!(
+            all.semantic_hash_for::<Perm>(),
+            fnv1a_64(b"Admin\0Read\0Write\0")
+        );
+        assert_eq!(all.semantic_hash_for::<Perm>(), 0x4fb30198ffd5542d);
+
+        // Bit 5 is no flag of `Perm`.
+        let unknown = Bairiak::U8(0b10_0001);
+        assert_eq!(
+            unknown.semantic_hash_for::<Perm>(),
+            fnv1a_64(b"Read\0\xff\x05")
+        );
+        assert_eq!(unknown.semantic_hash_for::<Perm>(), 0xbf6708fe8b45e85f);
+    }
+
+    #[test]
+    fn test_semantic_hash_survives_renumbering() {
+        let v1 = generate_bairiak([Perm::Write, Perm::Admin]);
+        let v2 = generate_bairiak([PermV2::Admin, PermV2::Write]);
+        assert_ne!(v1.bits(), v2.bits());
+        assert_eq!(
+            v1.semantic_hash_for::<Perm>(),
+            v2.semantic_hash_for::<PermV2>()
+        );
+        let audit = generate_bairiak([PermV2::Admin, PermV2::Write, PermV2::Audit]);
+        assert_ne!(
+            audit.semantic_hash_for::<PermV2>(),
+            v1.semantic_hash_for::<Perm>()
+        );
+    }
+
+    #[test]
+    fn test_semantic_hash_of_unknown_bits() {
+        // An unknown bit never hashes like a flag, nor like another bit.
+        let read = generate_bairiak([Perm::Read]);
+        let unknown = Bairiak::U8(0b1000);
+        assert_ne!(
+            unknown.semantic_hash_for::<Perm>(),
+            read.semantic_hash_for::<Perm>()
+        );
+        assert_ne!(
+            unknown.semantic_hash_for::<Perm>(),
+            Bairiak::U8(0b1_0000).semantic_hash_for::<Perm>()
+        );
+        assert_eq!(
+            Bairiak::U8(0b1000).semantic_hash_for::<Perm>(),
+            Bairiak::U64(0b1000).semantic_hash_for::<Perm>()
+        );
+    }
+}
@@ -0,0 +1,133 @@
+// Serializes a `Bairiak` field as the names of its set flags of a given enum,
+// in ascending position, with bits the enum doesn't define as `bit(N)`:
+//
+//     #[derive(Serialize, Deserialize)]
+//     struct Request {
+//         #[serde(
+//             serialize_with = "bairiak::serde_names::serialize::<Perm, _>",
+//             deserialize_with = "bairiak::serde_names::deserialize::<Perm, _>"
+//         )]
+//         perms: Bairiak,
+//     }
+//
+// gives `{"perms": ["Read", "Admin", "bit(7)"]}`. The enum is a type parameter,
+// which `#[serde(with = "...")]` can't pass, hence the two attributes.
+// Deserializing starts from the enum's zero value, and fails on an unknown
+// name or a `bit(N)` beyond its width.
+
+use std::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserializer, Serializer,
+};
+
+use crate::{short_type_name, Bairiak, BairiakEnum};
+
+pub fn serialize<B: BairiakEnum, S: Serializer>(
+    bairiak: &Bairiak,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        bairiak
+            .positions()
+            .map(|position| match B::from_u8(position) {
+                Some(flag) => flag.name().to_string(),
+                None => format!("bit({})", position),
+            }),
+    )
+}
+
+pub fn deserialize<'de, B: BairiakEnum, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Bairiak, D::Error> {
+    deserializer.deserialize_seq(NamesVisitor::<B>(std::marker::PhantomData))
+}
+
+struct NamesVisitor<B>(std::marker::PhantomData<B>);
+
+impl<'de, B: BairiakEnum> Visitor<'de> for NamesVisitor<B> {
+    type Value = Bairiak;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a list of {} flag names", short_type_name::<B>())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Bairiak, A::Error> {
+        let mut bairiak = B::get_zero_bairiak();
+        let variants = B::all_variants();
+        while let Some(name) = seq.next_element::<String>()? {
+            let position = match variants.iter().find(|flag| flag.name() == name) {
+                Some(flag) => flag.position(),
+                None => bit_position(&name).ok_or_else(|| {
+                    de::Error::custom(format!(
+                        "unknown {} flag: `{}`",
+                        short_type_name::<B>(),
+                        name
+                    ))
+                })?,
+            };
+            if position >= bairiak.width() as u16 {
+                return Err(de::Error::custom(format!(
+                    "`{}` is out of range for a u{} value",
+                    name,
+                    bairiak.width()
+                )));
+            }
+            bairiak.set_bit(position as u32);
+        }
+        Ok(bairiak)
+    }
+}
+
+// `7` for `bit(7)`.
+fn bit_position(name: &str) -> Option<u16> {
+    name.strip_prefix("bit(")?.strip_suffix(')')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::test_support::fixtures::Perm;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Request {
+        #[serde(
+            serialize_with = "serialize::<Perm, _>",
+            deserialize_with = "deserialize::<Perm, _>"
+        )]
+        perms: Bairiak,
+    }
+
+    #[test]
+    fn test_serialize_names() {
+        let request = Request {
+            perms: Bairiak::U8(0b1000_0101),
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"perms":["Read","Admin","bit(7)"]}"#);
+        assert_eq!(serde_json::from_str::<Request>(&json).unwrap(), request);
+
+        let empty = Request {
+            perms: Bairiak::U8(0),
+        };
+        assert_eq!(serde_json::to_string(&empty).unwrap(), r#"{"perms":[]}"#);
+    }
+
+    #[test]
+    fn test_deserialize_names() {
+        let request: Request =
+            serde_json::from_str(r#"{"perms":["Admin","Write","Admin"]}"#).unwrap();
+        assert_eq!(request.perms, Bairiak::U8(0b110));
+
+        let err = serde_json::from_str::<Request>(r#"{"perms":["Wirte"]}"#).unwrap_err();
+        assert!(err.to_string().starts_with("unknown Perm flag: `Wirte`"));
+        let err = serde_json::from_str::<Request>(r#"{"perms":["bit(8)"]}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("`bit(8)` is out of range for a u8 value"));
+        assert!(serde_json::from_str::<Request>(r#"{"perms":"Read"}"#).is_err());
+    }
+}
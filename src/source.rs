@@ -0,0 +1,214 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::{
+    hash_enums, parse_spec, read_spec_file, write_enums, BairiakError, BairiakErrorKind, EnumSpec,
+    GenerateOptions, Result,
+};
+
+// Where the YAML of a spec comes from, for specs kept elsewhere than in a
+// file, such as in a database. `describe` names the source in the messages of
+// the errors found in its spec, as the path does for a file.
+pub trait SpecSource {
+    fn load(&self) -> Result<String>;
+
+    fn describe(&self) -> String;
+}
+
+impl SpecSource for &Path {
+    fn load(&self) -> Result<String> {
+        read_spec_file(self)
+    }
+
+    fn describe(&self) -> String {
+        self.display().to_string()
+    }
+}
+
+impl SpecSource for PathBuf {
+    fn load(&self) -> Result<String> {
+        self.as_path().load()
+    }
+
+    fn describe(&self) -> String {
+        self.as_path().describe()
+    }
+}
+
+// The YAML of the spec itself, not a path.
+impl SpecSource for &str {
+    fn load(&self) -> Result<String> {
+        Ok(self.to_string())
+    }
+
+    fn describe(&self) -> String {
+        String::from("inline spec")
+    }
+}
+
+// A spec read from `reader` to its end. The reader is only read once: loading
+// again reads whatever the reader gives after that, usually nothing.
+#[derive(Debug)]
+pub struct ReadSource<R> {
+    description: String,
+    reader: Mutex<R>,
+}
+
+impl<R: Read> ReadSource<R> {
+    pub fn new(description: &str, reader: R) -> Self {
+        ReadSource {
+            description: String::from(description),
+            reader: Mutex::new(reader),
+        }
+    }
+}
+
+impl<R: Read> SpecSource for ReadSource<R> {
+    fn load(&self) -> Result<String> {
+        let mut content = String::new();
+        let mut reader = self.reader.lock().unwrap_or_else(|err| err.into_inner());
+        reader.read_to_string(&mut content).map_err(|err| {
+            BairiakError::new(
+                BairiakErrorKind::ReadSpecError,
+                format!("{}: {}", self.description, err),
+            )
+            .logged()
+        })?;
+        Ok(content)
+    }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+}
+
+// Prefixes the errors of the spec itself with the source's description, as
+// errors reading it already are.
+fn in_source(err: BairiakError, source: &dyn SpecSource) -> BairiakError {
+    if err.is_spec() || err.kind() == BairiakErrorKind::UnknownEnumError {
+        err.in_context(&source.describe())
+    } else {
+        err
+    }
+}
+
+// Like `load_spec`, for a spec from any source.
+pub fn load_spec_from(source: &dyn SpecSource) -> Result<EnumSpec> {
+    parse_spec(&source.load()?).map_err(|err| in_source(err, source))
+}
+
+// Writes the code generated for the spec of `source` to `out`, as
+// `generate_bairiak_enums_to_writer` does for a spec given as a string. Of the
+// options about the output file, such as `manifest` and `region`, none apply.
+// Nothing is written if the spec is invalid.
+//
+//     let source: &dyn SpecSource = &"enums: [{name: Perm, variants: [Read]}]";
+//     generate_from_source(source, &GenerateOptions::default(), &mut io::stdout())?;
+pub fn generate_from_source<W: io::Write>(
+    source: &dyn SpecSource,
+    options: &GenerateOptions,
+    out: &mut W,
+) -> Result<()> {
+    options.validate()?;
+    let enums = load_spec_from(source)?;
+    let body_hash = hash_enums(&enums, options).map_err(|err| in_source(err, source))?;
+    write_enums(&enums, options, body_hash, out, BairiakError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_bairiak_enums_from_str;
+
+    const SPEC: &str = "enums:\n  - name: Perm\n    variants: [Read, Write]\n";
+
+    // A source as a database-backed one would be, keyed by name.
+    struct MemorySource {
+        specs: Vec<(&'static str, &'static str)>,
+        name: &'static str,
+    }
+
+    impl SpecSource for MemorySource {
+        fn load(&self) -> Result<String> {
+            self.specs
+                .iter()
+                .find(|(name, _)| *name == self.name)
+                .map(|(_, yaml)| yaml.to_string())
+                .ok_or_else(|| {
+                    BairiakError::new(
+                        BairiakErrorKind::ReadSpecError,
+                        format!("no spec named {}", self.name),
+                    )
+                })
+        }
+
+        fn describe(&self) -> String {
+            format!("specs table, row {}", self.name)
+        }
+    }
+
+    fn memory(name: &'static str) -> MemorySource {
+        MemorySource {
+            specs: vec![
+                ("perm", SPEC),
+                ("invalid", "enums:\n  - name: perm\n    variants: [Read]\n"),
+            ],
+            name,
+        }
+    }
+
+    fn generate(source: &dyn SpecSource) -> Result<String> {
+        let mut out = Vec::new();
+        generate_from_source(source, &GenerateOptions::default(), &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_generate_from_memory_source() {
+        let expected = generate_bairiak_enums_from_str(SPEC).unwrap();
+        assert_eq!(generate(&memory("perm")).unwrap(), expected);
+        assert_eq!(load_spec_from(&memory("perm")).unwrap().enums.len(), 1);
+    }
+
+    #[test]
+    fn test_errors_describe_source() {
+        let err = generate(&memory("invalid")).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert!(err
+            .message()
+            .starts_with("specs table, row invalid: Invalid enum name `perm`"));
+
+        let err = generate(&memory("missing")).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ReadSpecError);
+        assert_eq!(err.message(), "no spec named missing");
+
+        let err = load_spec_from(&"enums: [").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::DeserializeYamlError);
+        assert!(err.message().starts_with("inline spec: "));
+    }
+
+    #[test]
+    fn test_provided_sources() {
+        let expected = generate_bairiak_enums_from_str(SPEC).unwrap();
+        assert_eq!(generate(&SPEC).unwrap(), expected);
+        let reader = ReadSource::new("stdin", SPEC.as_bytes());
+        assert_eq!(reader.describe(), "stdin");
+        assert_eq!(generate(&reader).unwrap(), expected);
+
+        let path = Path::new("test_data/valid_spec.yaml");
+        assert_eq!(path.describe(), "test_data/valid_spec.yaml");
+        assert_eq!(
+            generate(&path).unwrap(),
+            generate(&path.to_path_buf()).unwrap()
+        );
+        let err = generate(&Path::new("test_data/nonexistent_spec.yaml")).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ReadSpecError);
+        assert_eq!(
+            err.path(),
+            Some(Path::new("test_data/nonexistent_spec.yaml"))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+// A versioned byte format for values persisted for the long term, which
+// unlike serde's representations is promised not to change.
+
+use crate::{Bairiak, BairiakError, BairiakErrorKind, Result};
+
+// The version `to_stable_bytes` writes.
+const STABLE_VERSION: u8 = 1;
+
+impl Bairiak {
+    /// Encodes the value in the stable format, version 1:
+    ///
+    /// ```text
+    /// [version: u8 = 1][width: u8][bits: width / 8 bytes, little-endian]
+    /// ```
+    ///
+    /// `width` is the width in bits, 8, 16, 32, 64 or 128, so a value takes 3
+    /// to 18 bytes. Bytes written in this format will always decode to the
+    /// same value: a later version of the format gets a new version byte, and
+    /// `from_stable_bytes` keeps reading every earlier version.
+    ///
+    /// ```
+    /// use bairiak::Bairiak;
+    ///
+    /// assert_eq!(Bairiak::U16(0x0201).to_stable_bytes(), [1, 16, 0x01, 0x02]);
+    /// ```
+    pub fn to_stable_bytes(&self) -> Vec<u8> {
+        let len = self.width() as usize / 8;
+        let mut bytes = Vec::with_capacity(2 + len);
+        bytes.push(STABLE_VERSION);
+        bytes.push(self.width());
+        bytes.extend(self.bits().to_le_bytes().into_iter().take(len));
+        bytes
+    }
+
+    /// Decodes a value written by `to_stable_bytes`, in any version of the
+    /// stable format. The bytes must hold exactly one value: an unknown
+    /// version, an unknown width, missing bytes and bytes after the value
+    /// each fail with their own `BairiakErrorKind`.
+    ///
+    /// ```
+    /// use bairiak::{Bairiak, BairiakErrorKind};
+    ///
+    /// assert_eq!(Bairiak::from_stable_bytes(&[1, 8, 0xa5]), Ok(Bairiak::U8(0xa5)));
+    /// assert_eq!(
+    ///     Bairiak::from_stable_bytes(&[2, 8, 0xa5]).unwrap_err().kind(),
+    ///     BairiakErrorKind::UnknownVersionError
+    /// );
+    /// ```
+    pub fn from_stable_bytes(bytes: &[u8]) -> Result<Bairiak> {
+        match bytes.split_first() {
+            None => Err(BairiakError::new(
+                BairiakErrorKind::BufferTooShortError,
+                String::from("Needed a version byte but the buffer is empty."),
+            )),
+            Some((1, rest)) => decode_v1(rest),
+            Some((version, _)) => Err(BairiakError::new(
+                BairiakErrorKind::UnknownVersionError,
+                format!("Unknown stable format version {}.", version),
+            )),
+        }
+    }
+}
+
+// `[width][bits]`, after the version byte.
+fn decode_v1(bytes: &[u8]) -> Result<Bairiak> {
+    let Some((&width, bits)) = bytes.split_first() else {
+        return Err(BairiakError::new(
+            BairiakErrorKind::BufferTooShortError,
+            String::from("Needed a width byte after the version."),
+        ));
+    };
+    if !matches!(width, 8 | 16 | 32 | 64 | 128) {
+        return Err(BairiakError::new(
+            BairiakErrorKind::UnknownWidthError,
+            format!("Unknown width tag {}.", width),
+        ));
+    }
+
+    let len = width as usize / 8;
+    if bits.len() < len {
+        return Err(BairiakError::new(
+            BairiakErrorKind::BufferTooShortError,
+            format!(
+                "Needed {} bytes for a u{} value but got {}.",
+                len,
+                width,
+                bits.len()
+            ),
+        ));
+    }
+    if bits.len() > len {
+        return Err(BairiakError::new(
+            BairiakErrorKind::TrailingBytesError,
+            format!("{} bytes after the u{} value.", bits.len() - len, width),
+        ));
+    }
+
+    let mut le_bytes = [0u8; 16];
+    for (le_byte, &byte) in le_bytes.iter_mut().zip(bits) {
+        *le_byte = byte;
+    }
+    Ok(Bairiak::with_width(width, u128::from_le_bytes(le_bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The promise: these bytes decode to these values in every release.
+    const GOLDEN: [(Bairiak, &[u8]); 5] = [
+        (Bairiak::U8(0xa5), &[1, 8, 0xa5]),
+        (Bairiak::U16(0x0201), &[1, 16, 0x01, 0x02]),
+        (Bairiak::U32(0x8000_0001), &[1, 32, 0x01, 0, 0, 0x80]),
+        (
+            Bairiak::U64(1 << 63 | 0xff),
+            &[1, 64, 0xff, 0, 0, 0, 0, 0, 0, 0x80],
+        ),
+        (
+            Bairiak::U128(1 << 127 | 1 << 64 | 2),
+            &[1, 128, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0x80],
+        ),
+    ];
+
+    #[test]
+    fn test_stable_golden_bytes() {
+        for (bairiak, bytes) in GOLDEN {
+            assert_eq!(bairiak.to_stable_bytes(), bytes);
+            assert_eq!(Bairiak::from_stable_bytes(bytes), Ok(bairiak));
+        }
+    }
+
+    #[test]
+    fn test_stable_bad_version() {
+        for version in [0, 2, 255] {
+            let err = Bairiak::from_stable_bytes(&[version, 8, 0]).unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::UnknownVersionError);
+        }
+        assert_eq!(
+            Bairiak::from_stable_bytes(&[1, 12, 0]).unwrap_err().kind(),
+            BairiakErrorKind::UnknownWidthError
+        );
+    }
+
+    #[test]
+    fn test_stable_truncated() {
+        for (_, bytes) in GOLDEN {
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    Bairiak::from_stable_bytes(&bytes[..len])
+                        .unwrap_err()
+                        .kind(),
+                    BairiakErrorKind::BufferTooShortError
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_stable_trailing_garbage() {
+        for (_, bytes) in GOLDEN {
+            let mut garbage = bytes.to_vec();
+            garbage.push(0);
+            let err = Bairiak::from_stable_bytes(&garbage).unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::TrailingBytesError);
+        }
+        assert_eq!(
+            Bairiak::from_stable_bytes(&[1, 8, 0xa5, 1, 8, 0xa5])
+                .unwrap_err()
+                .message(),
+            "3 bytes after the u8 value."
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+
+// Where a flag is in its lifecycle, as given by `status` in the spec and
+// returned by the generated `status()`. A variant without a `status` is
+// stable, or deprecated with a `deprecated` note.
+//
+// `Removed` flags have no variant, only a reserved position, so only the
+// generated `status_at(position)` returns it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlagStatus {
+    Experimental,
+    Stable,
+    Deprecated,
+    Removed,
+}
+
+impl FlagStatus {
+    pub(crate) const ALL: [FlagStatus; 4] = [
+        FlagStatus::Experimental,
+        FlagStatus::Stable,
+        FlagStatus::Deprecated,
+        FlagStatus::Removed,
+    ];
+
+    // The name of the status in the spec.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FlagStatus::Experimental => "experimental",
+            FlagStatus::Stable => "stable",
+            FlagStatus::Deprecated => "deprecated",
+            FlagStatus::Removed => "removed",
+        }
+    }
+
+    pub(crate) fn from_spec(name: &str) -> Option<FlagStatus> {
+        FlagStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == name)
+    }
+
+    // The path of the variant in generated code, given the path of bairiak.
+    pub(crate) fn path(self, bairiak: &str) -> String {
+        let variant = match self {
+            FlagStatus::Experimental => "Experimental",
+            FlagStatus::Stable => "Stable",
+            FlagStatus::Deprecated => "Deprecated",
+            FlagStatus::Removed => "Removed",
+        };
+        format!("{}::FlagStatus::{}", bairiak, variant)
+    }
+}
+
+impl fmt::Display for FlagStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_status_names() {
+        for status in FlagStatus::ALL {
+            assert_eq!(FlagStatus::from_spec(status.as_str()), Some(status));
+            assert_eq!(status.to_string(), status.as_str());
+            assert_eq!(
+                status.path("bairiak"),
+                format!("bairiak::FlagStatus::{:?}", status)
+            );
+        }
+        assert_eq!(FlagStatus::from_spec("Stable"), None);
+        assert_eq!(FlagStatus::from_spec("beta"), None);
+    }
+}
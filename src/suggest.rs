@@ -0,0 +1,113 @@
+// Suggestions for mistyped names, as in "Unknown enum: Prem. Did you mean
+// `Perm`?".
+
+// Names further than this from every candidate get no suggestion.
+const MAX_DISTANCE: usize = 2;
+
+// The candidate closest to `name`, if one is within `MAX_DISTANCE` edits. Of
+// equally close candidates the first is taken, so that with candidates in
+// spec order the suggestion doesn't depend on anything else.
+pub(crate) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let name: Vec<char> = name.chars().collect();
+    candidates
+        .into_iter()
+        .filter_map(|candidate| Some((distance_within(&name, candidate, MAX_DISTANCE)?, candidate)))
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+// " Did you mean `Perm`?" for the closest candidate, or nothing, to end an
+// error message with.
+pub(crate) fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> String {
+    closest(name, candidates)
+        .map(|suggestion| format!(" Did you mean `{}`?", suggestion))
+        .unwrap_or_default()
+}
+
+// The Levenshtein distance between `a` and `b` in characters, if it is at most
+// `max`. Only the band of `max` cells around the diagonal can stay within it,
+// and the rows stop as soon as none does, so a long name costs
+// `O(len * max)` rather than `O(len²)`.
+//
+// Every index stays within the rows, which are one longer than `b`.
+#[allow(clippy::indexing_slicing)]
+fn distance_within(a: &[char], b: &str, max: usize) -> Option<usize> {
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let beyond = max + 1;
+    let mut previous: Vec<usize> = (0..=b.len()).map(|j| j.min(beyond)).collect();
+    let mut row = vec![beyond; b.len() + 1];
+    for (i, &a) in a.iter().enumerate() {
+        let (start, end) = ((i + 1).saturating_sub(max), (i + 1 + max).min(b.len()));
+        row.fill(beyond);
+        if start == 0 {
+            row[0] = (i + 1).min(beyond);
+        }
+        for j in start.max(1)..=end {
+            let substitution = previous[j - 1] + (a != b[j - 1]) as usize;
+            row[j] = substitution
+                .min(previous[j] + 1)
+                .min(row[j - 1] + 1)
+                .min(beyond);
+        }
+        if row[start..=end].iter().all(|&distance| distance > max) {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut row);
+    }
+    Some(previous[b.len()]).filter(|&distance| distance <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance(a: &str, b: &str) -> Option<usize> {
+        distance_within(&a.chars().collect::<Vec<_>>(), b, MAX_DISTANCE)
+    }
+
+    #[test]
+    fn test_distance_within() {
+        assert_eq!(distance("Read", "Read"), Some(0));
+        assert_eq!(distance("Rread", "Read"), Some(1));
+        assert_eq!(distance("Raed", "Read"), Some(2));
+        assert_eq!(distance("Wirte", "Write"), Some(2));
+        assert_eq!(distance("", "Ab"), Some(2));
+        assert_eq!(distance("", "Admin"), None);
+        assert_eq!(distance("Execute", "Write"), None);
+        assert_eq!(distance("Flag1234567890", "Flag0987654321"), None);
+    }
+
+    #[test]
+    fn test_closest_one_edit_away() {
+        assert_eq!(closest("Rread", ["Write", "Read", "Admin"]), Some("Read"));
+        assert_eq!(
+            did_you_mean("Rread", ["Write", "Read"]),
+            " Did you mean `Read`?"
+        );
+    }
+
+    #[test]
+    fn test_closest_hopeless() {
+        assert_eq!(closest("Execute", ["Read", "Write", "Admin"]), None);
+        assert_eq!(closest("Read", []), None);
+        assert_eq!(did_you_mean("Execute", ["Read"]), "");
+    }
+
+    #[test]
+    fn test_closest_ties_take_the_first() {
+        assert_eq!(closest("Rad", ["Red", "Read", "Rid"]), Some("Red"));
+        assert_eq!(closest("Rad", ["Rid", "Read", "Red"]), Some("Rid"));
+        // A closer candidate still wins over an earlier one.
+        assert_eq!(closest("Reed", ["Rid", "Reed2", "Read"]), Some("Reed2"));
+    }
+}
@@ -0,0 +1,21 @@
+// Synthetic specs shared by tests and benchmarks. The benchmarks include this
+// file by path, so it must only depend on std.
+
+pub fn large_spec(enum_count: usize, variant_count: usize) -> String {
+    let mut spec = String::from("enums:\n");
+    for e in 0..enum_count {
+        spec.push_str(&format!("  - name: Enum{}\n    variants:\n", e));
+        for v in 0..variant_count {
+            spec.push_str(&format!("      - Flag{}\n", v));
+        }
+    }
+    spec
+}
+
+// The enums of `test_data/fixtures_spec.yaml`, for unit tests that need an
+// ordinary enum rather than one of a particular shape. The benchmarks aren't
+// built with `cfg(test)`, so they leave it out.
+#[cfg(test)]
+pub mod fixtures {
+    include!("../test_data/expected/fixtures_spec.rs");
+}
@@ -0,0 +1,209 @@
+use std::{ffi::OsStr, marker::PhantomData};
+
+use clap::{builder::TypedValueParser, error::ErrorKind, Arg, Command};
+
+use crate::{suggest, Bairiak, BairiakEnum};
+
+// Parses a list of flag names such as `read,write` into a `Bairiak` of `B`.
+// Names match the variants of `B` ignoring case, `_` and `-`. If every name is
+// prefixed with `+` or `-`, the flags are added to or removed from the default
+// value instead of replacing it.
+pub struct BairiakValueParser<B> {
+    default: Bairiak,
+    delimiter: char,
+    flags: PhantomData<fn() -> B>,
+}
+
+impl<B> Clone for BairiakValueParser<B> {
+    fn clone(&self) -> Self {
+        BairiakValueParser {
+            default: self.default,
+            delimiter: self.delimiter,
+            flags: PhantomData,
+        }
+    }
+}
+
+impl<B: BairiakEnum> Default for BairiakValueParser<B> {
+    fn default() -> Self {
+        BairiakValueParser::new()
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|&c| c != '_' && c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+impl<B: BairiakEnum> BairiakValueParser<B> {
+    pub fn new() -> Self {
+        BairiakValueParser {
+            default: B::get_zero_bairiak(),
+            delimiter: ',',
+            flags: PhantomData,
+        }
+    }
+
+    // The value `+name` and `-name` lists are relative to.
+    pub fn with_default(mut self, default: Bairiak) -> Self {
+        self.default = default;
+        self
+    }
+
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    fn find(name: &str) -> Result<B, String> {
+        let normalized = normalize(name);
+        B::all_variants()
+            .into_iter()
+            .find(|variant| normalize(variant.name()) == normalized)
+            .ok_or_else(|| {
+                let names: Vec<&str> = B::all_variants().iter().map(|v| v.name()).collect();
+                let normalized_names: Vec<String> = names.iter().map(|n| normalize(n)).collect();
+                let suggestion =
+                    suggest::closest(&normalized, normalized_names.iter().map(String::as_str))
+                        .and_then(|closest| {
+                            names
+                                .iter()
+                                .zip(&normalized_names)
+                                .find(|(_, n)| n.as_str() == closest)
+                        })
+                        .map(|(name, _)| format!(" (did you mean `{}`?)", name))
+                        .unwrap_or_default();
+                format!(
+                    "unknown flag `{}`{}; valid flags are: {}",
+                    name,
+                    suggestion,
+                    names.join(", ")
+                )
+            })
+    }
+
+    pub fn parse_flags(&self, value: &str) -> Result<Bairiak, String> {
+        let names: Vec<&str> = value
+            .split(self.delimiter)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect();
+        let relative = !names.is_empty()
+            && names
+                .iter()
+                .all(|name| name.starts_with('+') || name.starts_with('-'));
+
+        let mut bairiak = if relative {
+            self.default
+        } else {
+            B::get_zero_bairiak()
+        };
+        for name in names {
+            if let Some(name) = name.strip_prefix('-') {
+                bairiak.clear_position(Self::find(name)?.to_u8());
+            } else {
+                bairiak.set(Self::find(name.strip_prefix('+').unwrap_or(name))?);
+            }
+        }
+        Ok(bairiak)
+    }
+}
+
+impl<B: BairiakEnum + 'static> TypedValueParser for BairiakValueParser<B> {
+    type Value = Bairiak;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Bairiak, clap::Error> {
+        let value = value.to_str().ok_or_else(|| {
+            clap::Error::raw(ErrorKind::InvalidUtf8, "flags must be valid UTF-8\n").with_cmd(cmd)
+        })?;
+
+        self.parse_flags(value).map_err(|message| {
+            let arg = arg.map(|arg| format!("invalid value for {}: ", arg));
+            clap::Error::raw(
+                ErrorKind::InvalidValue,
+                format!("{}{}\n", arg.unwrap_or_default(), message),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    include!("../test_data/expected/multi_enum_spec.rs");
+
+    fn parser() -> BairiakValueParser<Permissions> {
+        BairiakValueParser::new()
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        assert_eq!(parser().parse_flags("read,delete"), Ok(Bairiak::U8(0b101)));
+        assert_eq!(
+            parser().parse_flags(" Read , WRITE "),
+            Ok(Bairiak::U8(0b11))
+        );
+        assert_eq!(parser().parse_flags(""), Ok(Bairiak::U8(0)));
+
+        let parser = BairiakValueParser::<HTTPFlags>::new().with_delimiter('|');
+        assert_eq!(parser.parse_flags("keep_alive|gzip"), Ok(Bairiak::U8(0b11)));
+    }
+
+    #[test]
+    fn test_parse_relative_flags() {
+        let parser = parser().with_default(Bairiak::U8(0b011));
+        assert_eq!(parser.parse_flags("+delete,-write"), Ok(Bairiak::U8(0b101)));
+        assert_eq!(parser.parse_flags("delete"), Ok(Bairiak::U8(0b100)));
+    }
+
+    #[test]
+    fn test_parse_unknown_flag() {
+        assert_eq!(
+            parser().parse_flags("read,reed"),
+            Err(String::from(
+                "unknown flag `reed` (did you mean `Read`?); valid flags are: Read, Write, Delete"
+            ))
+        );
+        assert_eq!(
+            parser().parse_flags("export"),
+            Err(String::from(
+                "unknown flag `export`; valid flags are: Read, Write, Delete"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_with_clap() {
+        let cmd = Command::new("test").arg(
+            Arg::new("features")
+                .long("features")
+                .value_parser(parser().with_default(Bairiak::U8(0b1))),
+        );
+
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["test", "--features", "+write"])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<Bairiak>("features"),
+            Some(&Bairiak::U8(0b11))
+        );
+
+        let err = cmd
+            .try_get_matches_from(["test", "--features", "export"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert!(err
+            .to_string()
+            .contains("valid flags are: Read, Write, Delete"));
+    }
+}
@@ -0,0 +1,465 @@
+// Checks generated code against the spec it was generated from, for files
+// that went through hand edits or merges since. The code is parsed with syn,
+// never compiled or rewritten: each enum of the spec must be there with the
+// variants of the spec at their positions, and its `get_zero_bairiak` must
+// return a `Bairiak` of the width of the spec.
+
+use std::{collections::HashMap, fmt, fs, path::Path};
+
+use syn::{Expr, Fields, Item, ItemEnum, ItemImpl, Lit, Type};
+
+use crate::{load_spec, BairiakError, BairiakErrorKind, Enum, EnumSpec, Result};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum VerifyMismatch {
+    // The spec has the enum, the code doesn't.
+    MissingEnum {
+        enum_name: String,
+    },
+    // The spec has the variant, the enum in the code doesn't.
+    MissingVariant {
+        enum_name: String,
+        name: String,
+        position: u8,
+    },
+    // The enum in the code has a variant the spec doesn't. `position` is
+    // `None` for a discriminant that isn't a `u8` literal.
+    ExtraVariant {
+        enum_name: String,
+        name: String,
+        position: Option<u8>,
+    },
+    Position {
+        enum_name: String,
+        name: String,
+        expected: u8,
+        found: Option<u8>,
+    },
+    // `found` is `None` if the code has no `get_zero_bairiak` returning a
+    // `Bairiak::U<width>(..)`.
+    Width {
+        enum_name: String,
+        expected: u8,
+        found: Option<u8>,
+    },
+}
+
+impl VerifyMismatch {
+    pub fn enum_name(&self) -> &str {
+        match self {
+            VerifyMismatch::MissingEnum { enum_name }
+            | VerifyMismatch::MissingVariant { enum_name, .. }
+            | VerifyMismatch::ExtraVariant { enum_name, .. }
+            | VerifyMismatch::Position { enum_name, .. }
+            | VerifyMismatch::Width { enum_name, .. } => enum_name,
+        }
+    }
+}
+
+impl fmt::Display for VerifyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let position = |position: &Option<u8>| match position {
+            Some(position) => format!("position {}", position),
+            None => String::from("no literal position"),
+        };
+        match self {
+            VerifyMismatch::MissingEnum { enum_name } => {
+                write!(f, "{}: missing from the code", enum_name)
+            }
+            VerifyMismatch::MissingVariant {
+                enum_name,
+                name,
+                position,
+            } => write!(
+                f,
+                "{}: missing variant {} (position {})",
+                enum_name, name, position
+            ),
+            VerifyMismatch::ExtraVariant {
+                enum_name,
+                name,
+                position: found,
+            } => write!(
+                f,
+                "{}: variant {} ({}) is not in the spec",
+                enum_name,
+                name,
+                position(found)
+            ),
+            VerifyMismatch::Position {
+                enum_name,
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: variant {} has {}, the spec has position {}",
+                enum_name,
+                name,
+                position(found),
+                expected
+            ),
+            VerifyMismatch::Width {
+                enum_name,
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "{}: get_zero_bairiak has width {}, the spec has width {}",
+                enum_name, found, expected
+            ),
+            VerifyMismatch::Width {
+                enum_name,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "{}: no get_zero_bairiak returning a Bairiak, the spec has width {}",
+                enum_name, expected
+            ),
+        }
+    }
+}
+
+// The mismatches between `spec` and the code generated from it, in spec
+// order. Empty if they agree.
+pub fn verify_generated(spec: &EnumSpec, source: &str) -> Result<Vec<VerifyMismatch>> {
+    spec.validate()?;
+    let file = syn::parse_file(source).map_err(|err| {
+        BairiakError::new(BairiakErrorKind::ParseSourceError, err.to_string()).logged()
+    })?;
+    let mut items = SourceItems::default();
+    items.collect(&file.items);
+
+    let mut mismatches = Vec::new();
+    for e in &spec.enums {
+        match items.enums.get(e.name.as_str()) {
+            Some(item) => {
+                let width = items.widths.get(e.name.as_str()).copied().flatten();
+                verify_enum(e, item, width, &mut mismatches)?;
+            }
+            None => mismatches.push(VerifyMismatch::MissingEnum {
+                enum_name: e.name.clone(),
+            }),
+        }
+    }
+    Ok(mismatches)
+}
+
+// Like `verify_generated`, with the spec and the code read from files.
+pub fn verify_generated_file(
+    spec_path: impl AsRef<Path>,
+    source_path: impl AsRef<Path>,
+) -> Result<Vec<VerifyMismatch>> {
+    let spec = load_spec(spec_path)?;
+    let source_path = source_path.as_ref();
+    let source = fs::read_to_string(source_path).map_err(|err| {
+        BairiakError::new(
+            BairiakErrorKind::IoError,
+            format!("{}: {}", source_path.display(), err),
+        )
+        .with_path(source_path)
+        .logged()
+    })?;
+    verify_generated(&spec, &source).map_err(|err| match err.kind() {
+        BairiakErrorKind::ParseSourceError => err.with_path(source_path),
+        _ => err,
+    })
+}
+
+fn verify_enum(
+    e: &Enum,
+    item: &ItemEnum,
+    width: Option<u8>,
+    mismatches: &mut Vec<VerifyMismatch>,
+) -> Result<()> {
+    let found = discriminants(item);
+    let expected: Vec<(usize, &str)> = e.positioned_variants().collect();
+    for &(position, name) in &expected {
+        let position = position as u8;
+        match found.iter().find(|(found_name, _)| found_name == name) {
+            Some(&(_, Some(found))) if found == position => {}
+            Some(&(_, found)) => mismatches.push(VerifyMismatch::Position {
+                enum_name: e.name.clone(),
+                name: name.to_string(),
+                expected: position,
+                found,
+            }),
+            None => mismatches.push(VerifyMismatch::MissingVariant {
+                enum_name: e.name.clone(),
+                name: name.to_string(),
+                position,
+            }),
+        }
+    }
+    for (name, position) in &found {
+        if !expected.iter().any(|&(_, expected)| expected == name) {
+            mismatches.push(VerifyMismatch::ExtraVariant {
+                enum_name: e.name.clone(),
+                name: name.clone(),
+                position: *position,
+            });
+        }
+    }
+
+    let expected = e.width()?;
+    if width != Some(expected) {
+        mismatches.push(VerifyMismatch::Width {
+            enum_name: e.name.clone(),
+            expected,
+            found: width,
+        });
+    }
+    Ok(())
+}
+
+// Each variant of `item` with its discriminant, counting on from the previous
+// one where it has none, as Rust does.
+fn discriminants(item: &ItemEnum) -> Vec<(String, Option<u8>)> {
+    let mut next = Some(0u8);
+    item.variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, Fields::Unit))
+        .map(|variant| {
+            let position = match &variant.discriminant {
+                Some((_, expr)) => int_literal(expr),
+                None => next,
+            };
+            next = position.and_then(|position| position.checked_add(1));
+            (variant.ident.to_string(), position)
+        })
+        .collect()
+}
+
+fn int_literal(expr: &Expr) -> Option<u8> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int.base10_parse().ok(),
+            _ => None,
+        },
+        Expr::Paren(paren) => int_literal(&paren.expr),
+        Expr::Group(group) => int_literal(&group.expr),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct SourceItems<'a> {
+    enums: HashMap<String, &'a ItemEnum>,
+    // The width returned by `get_zero_bairiak` in each enum's `BairiakEnum`
+    // impl, `None` where the impl has no such function.
+    widths: HashMap<String, Option<u8>>,
+}
+
+impl<'a> SourceItems<'a> {
+    // Collects the items of the file and of its inline modules. The enums of
+    // a spec are generated under a single module at most, so names don't
+    // clash; the first item of a name wins if they do.
+    fn collect(&mut self, items: &'a [Item]) {
+        for item in items {
+            match item {
+                Item::Enum(item) => {
+                    self.enums.entry(item.ident.to_string()).or_insert(item);
+                }
+                Item::Impl(item) => {
+                    if let Some(name) = bairiak_enum_impl(item) {
+                        self.widths.entry(name).or_insert_with(|| zero_width(item));
+                    }
+                }
+                Item::Mod(item) => {
+                    if let Some((_, items)) = &item.content {
+                        self.collect(items);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// The enum `item` implements `BairiakEnum` for, if it does.
+fn bairiak_enum_impl(item: &ItemImpl) -> Option<String> {
+    let (_, trait_path, _) = item.trait_.as_ref()?;
+    if trait_path.segments.last()?.ident != "BairiakEnum" {
+        return None;
+    }
+    match &*item.self_ty {
+        Type::Path(ty) => Some(ty.path.segments.last()?.ident.to_string()),
+        _ => None,
+    }
+}
+
+// The width of the `Bairiak::U<width>(..)` returned by `get_zero_bairiak`.
+fn zero_width(item: &ItemImpl) -> Option<u8> {
+    let body = item.items.iter().find_map(|item| match item {
+        syn::ImplItem::Fn(f) if f.sig.ident == "get_zero_bairiak" => Some(&f.block),
+        _ => None,
+    })?;
+    let expr = match body.stmts.last()? {
+        syn::Stmt::Expr(expr, None) => expr,
+        _ => return None,
+    };
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    let Expr::Path(func) = &*call.func else {
+        return None;
+    };
+    let variant = func.path.segments.last()?.ident.to_string();
+    variant.strip_prefix('U')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_bairiak_enums_from_str;
+
+    const SPEC: &str = "
+enums:
+  - name: Permissions
+    variants: [Read, Write, Delete]
+  - name: Wide
+    variants:
+      - Low
+      - name: High
+        position: 40
+";
+
+    fn verify(source: &str) -> Vec<VerifyMismatch> {
+        let spec: EnumSpec = serde_yaml::from_str(SPEC).unwrap();
+        verify_generated(&spec, source).unwrap()
+    }
+
+    fn generated() -> String {
+        generate_bairiak_enums_from_str(SPEC).unwrap()
+    }
+
+    #[test]
+    fn test_verify_generated_code() {
+        assert_eq!(verify(&generated()), vec![]);
+
+        let in_module = format!("mod flags {{\n{}}}\n", generated());
+        assert_eq!(verify(&in_module), vec![]);
+    }
+
+    #[test]
+    fn test_verify_golden_files() {
+        for entry in fs::read_dir("test_data/expected").unwrap() {
+            let source_path = entry.unwrap().path();
+            if source_path.extension().is_some_and(|ext| ext == "rs") {
+                // Generated with options from the spec of another name.
+                let stem = match source_path.file_stem().unwrap().to_str().unwrap() {
+                    "module_spec" => "multi_enum_spec",
+                    "display_spec" | "prelude_spec" => "valid_spec",
+                    "edition_2015_spec" | "edition_2018_spec" => "edition_spec",
+                    stem => stem,
+                };
+                let spec_path = format!("test_data/{}.yaml", stem);
+                let mismatches = verify_generated_file(&spec_path, &source_path).unwrap();
+                assert_eq!(mismatches, vec![], "{}", spec_path);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_position_mismatch() {
+        let source = generated().replace("Delete = 2,", "Delete = 3,");
+        assert_eq!(
+            verify(&source),
+            vec![VerifyMismatch::Position {
+                enum_name: String::from("Permissions"),
+                name: String::from("Delete"),
+                expected: 2,
+                found: Some(3),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_verify_missing_variant() {
+        let source = generated().replace("    Write = 1,\n", "");
+        assert_eq!(
+            verify(&source),
+            vec![VerifyMismatch::MissingVariant {
+                enum_name: String::from("Permissions"),
+                name: String::from("Write"),
+                position: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_verify_extra_variant() {
+        let source = generated().replace("Delete = 2,", "Delete = 2,\n    Admin = 3,");
+        let mismatches = verify(&source);
+        assert_eq!(
+            mismatches,
+            vec![VerifyMismatch::ExtraVariant {
+                enum_name: String::from("Permissions"),
+                name: String::from("Admin"),
+                position: Some(3),
+            }]
+        );
+        assert_eq!(
+            mismatches[0].to_string(),
+            "Permissions: variant Admin (position 3) is not in the spec"
+        );
+    }
+
+    #[test]
+    fn test_verify_width_mismatch() {
+        let source = generated().replace("Bairiak::U64(0u64)", "Bairiak::U32(0u32)");
+        let mismatches = verify(&source);
+        assert_eq!(
+            mismatches,
+            vec![VerifyMismatch::Width {
+                enum_name: String::from("Wide"),
+                expected: 64,
+                found: Some(32),
+            }]
+        );
+        assert_eq!(
+            mismatches[0].to_string(),
+            "Wide: get_zero_bairiak has width 32, the spec has width 64"
+        );
+    }
+
+    #[test]
+    fn test_verify_missing_enum() {
+        let source = generated().replace("enum Permissions {", "enum Perms {");
+        assert_eq!(
+            verify(&source),
+            vec![VerifyMismatch::MissingEnum {
+                enum_name: String::from("Permissions"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_verify_unparsable_source() {
+        let spec: EnumSpec = serde_yaml::from_str(SPEC).unwrap();
+        let err = verify_generated(&spec, "enum Permissions {").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseSourceError);
+        assert!(err.to_string().starts_with("Error parsing source: "));
+    }
+
+    #[test]
+    fn test_verify_generated_file() {
+        let source_path = std::env::temp_dir().join("bairiak_test_verify_file.rs");
+        let spec_path = "test_data/multi_enum_spec.yaml";
+        fs::write(
+            &source_path,
+            generate_bairiak_enums_from_str(&fs::read_to_string(spec_path).unwrap()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            verify_generated_file(spec_path, &source_path).unwrap(),
+            vec![]
+        );
+
+        fs::write(&source_path, "fn main() {").unwrap();
+        let err = verify_generated_file(spec_path, &source_path).unwrap_err();
+        assert_eq!(err.path(), Some(source_path.as_path()));
+        fs::remove_file(&source_path).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::{width_for, Enum, EnumSpec, FlagStatus};
+
+// A legal but degenerate enum, which generation only warns about unless the
+// spec lists its kind in `warnings_as_errors`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecWarningKind {
+    // A single variant, which makes the generated code mostly pointless.
+    SingleVariant,
+    // A width at least four times the width the variants would need packed
+    // from position 0, forced by their positions rather than by `bits`.
+    SparseWidth,
+    // Every variant is deprecated.
+    AllDeprecated,
+    // A variant lists itself in `requires`, which is always satisfied.
+    SelfRequirement,
+}
+
+impl SpecWarningKind {
+    // The name of the kind in `warnings_as_errors`.
+    pub fn name(self) -> &'static str {
+        match self {
+            SpecWarningKind::SingleVariant => "single_variant",
+            SpecWarningKind::SparseWidth => "sparse_width",
+            SpecWarningKind::AllDeprecated => "all_deprecated",
+            SpecWarningKind::SelfRequirement => "self_requirement",
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SpecWarning {
+    pub kind: SpecWarningKind,
+    pub enum_name: String,
+    pub message: String,
+}
+
+// `Perm: ... [single_variant]`, naming the kind to promote it with.
+impl fmt::Display for SpecWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} [{}]",
+            self.enum_name,
+            self.message,
+            self.kind.name()
+        )
+    }
+}
+
+impl EnumSpec {
+    // The warnings of every enum that the spec doesn't promote to errors, in
+    // spec order. An enum with positions out of range has no warnings of its
+    // width, which validating it reports as an error instead.
+    pub fn warnings(&self) -> Vec<SpecWarning> {
+        self.enums
+            .iter()
+            .flat_map(enum_warnings)
+            .filter(|w| !self.settings.warnings_as_errors.contains(&w.kind))
+            .collect()
+    }
+}
+
+pub(crate) fn enum_warnings(e: &Enum) -> Vec<SpecWarning> {
+    let warning = |kind, message| SpecWarning {
+        kind,
+        enum_name: e.name.clone(),
+        message,
+    };
+    let mut warnings = Vec::new();
+    let count = e.variant_count();
+    if count == 1 {
+        warnings.push(warning(
+            SpecWarningKind::SingleVariant,
+            format!("`{}` has a single variant.", e.name),
+        ));
+    }
+
+    let sparse = match (e.width(), width_for(count)) {
+        (Ok(width), Ok(needed)) => Some(width).filter(|&w| w as usize >= 4 * needed as usize),
+        _ => None,
+    };
+    if let (None, Some(width)) = (e.bits, sparse) {
+        warnings.push(warning(
+            SpecWarningKind::SparseWidth,
+            format!(
+                "`{}` takes {} bits for {} variant{}.",
+                e.name,
+                width,
+                count,
+                if count == 1 { "" } else { "s" }
+            ),
+        ));
+    }
+
+    if count > 0
+        && e.documented_variants()
+            .all(|(_, _, v)| v.status() == FlagStatus::Deprecated)
+    {
+        warnings.push(warning(
+            SpecWarningKind::AllDeprecated,
+            format!("Every variant of `{}` is deprecated.", e.name),
+        ));
+    }
+
+    for (_, name, v) in e.documented_variants() {
+        if v.requires().iter().any(|required| required == name) {
+            warnings.push(warning(
+                SpecWarningKind::SelfRequirement,
+                format!("`{}` in `{}` requires itself.", name, e.name),
+            ));
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_spec, BairiakErrorKind};
+
+    fn kinds(yaml: &str) -> Vec<SpecWarningKind> {
+        parse_spec(yaml)
+            .unwrap()
+            .warnings()
+            .iter()
+            .map(|w| w.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_degenerate_enums_warned_about() {
+        let spec = parse_spec("enums:\n  - name: Perm\n    variants: [Read]\n").unwrap();
+        let warnings = spec.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "Perm: `Perm` has a single variant. [single_variant]"
+        );
+
+        let yaml = "enums:\n  - name: Perm\n    variants: [Read, {name: Audit, position: 40}]\n";
+        let warnings = parse_spec(yaml).unwrap().warnings();
+        assert_eq!(warnings[0].kind, SpecWarningKind::SparseWidth);
+        assert_eq!(warnings[0].message, "`Perm` takes 64 bits for 2 variants.");
+
+        let yaml = "enums:\n  - name: Perm\n    variants:\n      - {name: Read, deprecated: Use roles.}\n      - {name: Write, status: deprecated}\n      - {name: Admin, status: removed}\n";
+        assert_eq!(kinds(yaml), [SpecWarningKind::AllDeprecated]);
+
+        let yaml = "enums:\n  - name: Perm\n    variants: [{name: Audit, position: 127}]\n";
+        assert_eq!(
+            kinds(yaml),
+            [SpecWarningKind::SingleVariant, SpecWarningKind::SparseWidth]
+        );
+    }
+
+    #[test]
+    fn test_self_requirement_warned_about() {
+        let yaml = "enums:\n  - name: Perm\n    variants:\n      - Read\n      - {name: Write, requires: [Read, Write]}\n";
+        let warnings = parse_spec(yaml).unwrap().warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "Perm: `Write` in `Perm` requires itself. [self_requirement]"
+        );
+
+        let yaml = format!(
+            "settings:\n  warnings_as_errors: [self_requirement]\n{}",
+            yaml
+        );
+        let err = parse_spec(&yaml).unwrap().validate().unwrap_err();
+        assert_eq!(
+            err.message(),
+            "`Write` in `Perm` requires itself. `warnings_as_errors` makes this an error."
+        );
+    }
+
+    #[test]
+    fn test_no_warnings_for_ordinary_enums() {
+        assert!(kinds("enums:\n  - name: Perm\n    variants: [Read, Write]\n").is_empty());
+        // A width given by `bits` leaves room on purpose.
+        assert!(
+            kinds("enums:\n  - name: Perm\n    bits: 128\n    variants: [Read, Write]\n")
+                .is_empty()
+        );
+        let yaml = "enums:\n  - name: Perm\n    variants: [Read, {name: Admin, position: 12}]\n";
+        assert!(kinds(yaml).is_empty());
+    }
+
+    #[test]
+    fn test_warnings_of_unvalidated_spec() {
+        let yaml = "enums:\n  - name: Perm\n    variants: [{name: Audit, position: 200}]\n";
+        let spec = parse_spec(yaml).unwrap();
+        assert!(spec.validate().is_err());
+        assert_eq!(kinds(yaml), [SpecWarningKind::SingleVariant]);
+    }
+
+    #[test]
+    fn test_warnings_as_errors() {
+        let yaml = "settings:\n  warnings_as_errors: [sparse_width]\nenums:\n  - name: Perm\n    variants: [{name: Audit, position: 127}]\n";
+        let spec = parse_spec(yaml).unwrap();
+        let err = spec.validate().unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ParseBairiakEnumsError);
+        assert_eq!(
+            err.message(),
+            "`Perm` takes 128 bits for 1 variant. `warnings_as_errors` makes this an error."
+        );
+        let problems = spec.validate_all();
+        assert_eq!(problems.len(), 1);
+        // The other warnings stay warnings.
+        assert_eq!(
+            spec.warnings().iter().map(|w| w.kind).collect::<Vec<_>>(),
+            [SpecWarningKind::SingleVariant]
+        );
+        assert!(crate::generate_bairiak_enums_from_str(yaml).is_err());
+
+        let err = parse_spec("settings:\n  warnings_as_errors: [sparse]\nenums: []\n").unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::DeserializeYamlError);
+    }
+}
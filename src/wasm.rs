@@ -0,0 +1,107 @@
+// `wasm-bindgen` wrapper for decoding and editing flag values from
+// JavaScript. Positions match the generated enums; `bairiak list --format
+// json` gives the name of each position.
+
+use wasm_bindgen::prelude::*;
+
+use crate::Bairiak;
+
+#[wasm_bindgen]
+pub struct WasmBairiak {
+    inner: Bairiak,
+}
+
+#[wasm_bindgen]
+impl WasmBairiak {
+    // Accepts the same strings as `Bairiak::from_str`, so values wider than
+    // a JavaScript number can be passed without losing precision.
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: &str) -> Result<WasmBairiak, JsError> {
+        match value.parse() {
+            Ok(inner) => Ok(WasmBairiak { inner }),
+            Err(err) => Err(JsError::new(&err.to_string())),
+        }
+    }
+
+    // Positions outside of the width are never set.
+    #[wasm_bindgen(js_name = isTrue)]
+    pub fn is_true(&self, position: u8) -> bool {
+        self.inner
+            .bits()
+            .checked_shr(position as u32)
+            .is_some_and(|bits| bits & 1 == 1)
+    }
+
+    // Positions outside of the width are ignored.
+    pub fn set(&mut self, position: u8) {
+        self.inner.set_position(position);
+    }
+
+    pub fn clear(&mut self, position: u8) {
+        self.inner.clear_position(position);
+    }
+
+    // A `BigInt` on the JavaScript side.
+    pub fn bits(&self) -> u128 {
+        self.inner.bits()
+    }
+
+    pub fn width(&self) -> u8 {
+        self.inner.width()
+    }
+}
+
+impl From<Bairiak> for WasmBairiak {
+    fn from(inner: Bairiak) -> WasmBairiak {
+        WasmBairiak { inner }
+    }
+}
+
+impl From<WasmBairiak> for Bairiak {
+    fn from(wasm: WasmBairiak) -> Bairiak {
+        wasm.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_is_true() {
+        let mut wasm = WasmBairiak::from(Bairiak::U8(0b1));
+        wasm.set(2);
+        wasm.set(8);
+        assert!(wasm.is_true(0));
+        assert!(!wasm.is_true(1));
+        assert!(wasm.is_true(2));
+        assert!(!wasm.is_true(8));
+        assert!(!wasm.is_true(200));
+        wasm.clear(0);
+        assert_eq!(wasm.bits(), 0b100);
+        assert_eq!(Bairiak::from(wasm), Bairiak::U8(0b100));
+    }
+
+    #[test]
+    fn test_new_wide_value() {
+        let wasm = WasmBairiak::new("0x1_0000_0000_0000_0000_0000u128").unwrap();
+        assert_eq!(wasm.width(), 128);
+        assert!(wasm.is_true(80));
+        assert_eq!(wasm.bits(), 1 << 80);
+    }
+}
+
+// Run in a JavaScript engine with `wasm-pack test --node -- --features wasm`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_round_trip_in_js() {
+        let mut wasm = WasmBairiak::new("0b101").unwrap();
+        wasm.set(1);
+        assert_eq!(wasm.bits(), 0b111);
+        assert!(WasmBairiak::new("Read").is_err());
+    }
+}
@@ -0,0 +1,238 @@
+// Explicit conversions between widths, for fixed-width fields that must hold
+// a `Bairiak` whatever width it was created with.
+
+use crate::{select_width, Bairiak, BairiakEnum, BairiakError, BairiakErrorKind, Result};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum BairiakWidth {
+    W8,
+    W16,
+    W32,
+    W64,
+    W128,
+}
+
+impl BairiakWidth {
+    pub fn bits(self) -> u8 {
+        match self {
+            BairiakWidth::W8 => 8,
+            BairiakWidth::W16 => 16,
+            BairiakWidth::W32 => 32,
+            BairiakWidth::W64 => 64,
+            BairiakWidth::W128 => 128,
+        }
+    }
+
+    fn of(bairiak: &Bairiak) -> BairiakWidth {
+        match bairiak {
+            Bairiak::U8(_) => BairiakWidth::W8,
+            Bairiak::U16(_) => BairiakWidth::W16,
+            Bairiak::U32(_) => BairiakWidth::W32,
+            Bairiak::U64(_) => BairiakWidth::W64,
+            Bairiak::U128(_) => BairiakWidth::W128,
+        }
+    }
+}
+
+fn width_mismatch(bairiak: &Bairiak, conversion: &str, width: BairiakWidth) -> BairiakError {
+    BairiakError::new(
+        BairiakErrorKind::WidthMismatchError,
+        format!(
+            "Cannot {} a u{} value to u{}.",
+            conversion,
+            bairiak.width(),
+            width.bits()
+        ),
+    )
+}
+
+impl Bairiak {
+    // Fails only if `width` is narrower than the value's.
+    pub fn widen_to(&self, width: BairiakWidth) -> Result<Bairiak> {
+        if width < BairiakWidth::of(self) {
+            return Err(width_mismatch(self, "widen", width));
+        }
+        Ok(Bairiak::with_width(width.bits(), self.bits()))
+    }
+
+    // Fails if `width` is wider than the value's, or if set bits don't fit in
+    // it.
+    pub fn narrow_to(&self, width: BairiakWidth) -> Result<Bairiak> {
+        if width > BairiakWidth::of(self) {
+            return Err(width_mismatch(self, "narrow", width));
+        }
+
+        let lost = self.bits() & !(u128::MAX >> (128 - width.bits() as u32));
+        if lost != 0 {
+            return Err(BairiakError::new(
+                BairiakErrorKind::ValueOutOfRangeError,
+                format!(
+                    "Narrowing {} to u{} would lose the set bits {:#x}.",
+                    self.bits(),
+                    width.bits(),
+                    lost
+                ),
+            ));
+        }
+        Ok(Bairiak::with_width(width.bits(), self.bits()))
+    }
+
+    // Widens the value in place to the width of `B`'s zero value, keeping every
+    // bit, so that a value stored before `B` grew past its width can take its
+    // new flags. A value that is already as wide or wider is left alone.
+    pub fn promote_for<B: BairiakEnum>(&mut self) {
+        let width = B::get_zero_bairiak().width();
+        if width > self.width() {
+            *self = Bairiak::with_width(width, self.bits());
+        }
+    }
+
+    // Like `try_set`, after promoting the value for `B`. It only fails if `B`'s
+    // positions don't fit its own zero value.
+    pub fn set_promoting<B: BairiakEnum>(&mut self, flag: B) -> Result<()> {
+        self.promote_for::<B>();
+        self.try_set(flag)
+    }
+
+    // The same value in the smallest width that holds it.
+    #[must_use]
+    pub fn minimal(&self) -> Bairiak {
+        let max_position = 127 - self.bits().leading_zeros().min(127) as usize;
+        let width = select_width(max_position, None).unwrap_or(128);
+        Bairiak::with_width(width, self.bits())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widen_to() {
+        assert_eq!(
+            Bairiak::U8(0xa5).widen_to(BairiakWidth::W64),
+            Ok(Bairiak::U64(0xa5))
+        );
+        assert_eq!(
+            Bairiak::U16(1).widen_to(BairiakWidth::W16),
+            Ok(Bairiak::U16(1))
+        );
+        assert_eq!(
+            Bairiak::U32(1)
+                .widen_to(BairiakWidth::W8)
+                .unwrap_err()
+                .kind(),
+            BairiakErrorKind::WidthMismatchError
+        );
+    }
+
+    #[test]
+    fn test_narrow_to_lossless() {
+        assert_eq!(
+            Bairiak::U128(0xff).narrow_to(BairiakWidth::W8),
+            Ok(Bairiak::U8(0xff))
+        );
+        assert_eq!(
+            Bairiak::U64(1 << 31).narrow_to(BairiakWidth::W32),
+            Ok(Bairiak::U32(1 << 31))
+        );
+        assert_eq!(
+            Bairiak::U8(3).narrow_to(BairiakWidth::W8),
+            Ok(Bairiak::U8(3))
+        );
+    }
+
+    #[test]
+    fn test_narrow_to_lossy() {
+        let err = Bairiak::U32(0x301).narrow_to(BairiakWidth::W8).unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        assert_eq!(
+            err.message(),
+            "Narrowing 769 to u8 would lose the set bits 0x300."
+        );
+
+        let err = Bairiak::U128(1 << 127)
+            .narrow_to(BairiakWidth::W64)
+            .unwrap_err();
+        assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        assert_eq!(
+            Bairiak::U8(1)
+                .narrow_to(BairiakWidth::W16)
+                .unwrap_err()
+                .kind(),
+            BairiakErrorKind::WidthMismatchError
+        );
+    }
+
+    // An enum that grew from 7 to 9 variants, and so from `U8` to `U16`.
+    #[derive(Debug, Clone, Copy)]
+    struct Grown(u8);
+
+    impl BairiakEnum for Grown {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U16(0u16)
+        }
+
+        fn to_u8(self) -> u8 {
+            self.0
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            (position < 9).then_some(Grown(position))
+        }
+
+        fn name(&self) -> &'static str {
+            "Grown"
+        }
+    }
+
+    #[test]
+    fn test_promote_for() {
+        let mut stored = Bairiak::U8(0b1010_0101);
+        stored.promote_for::<Grown>();
+        assert_eq!(stored, Bairiak::U16(0b1010_0101));
+
+        stored.set(Grown(8));
+        assert_eq!(stored.bits(), 1 << 8 | 0b1010_0101);
+
+        let mut wide = Bairiak::U64(1 << 40);
+        wide.promote_for::<Grown>();
+        assert_eq!(wide, Bairiak::U64(1 << 40));
+    }
+
+    #[test]
+    fn test_set_promoting() {
+        let mut stored = Bairiak::U8(0b0100_0011);
+        let mut unpromoted = stored;
+        assert_eq!(
+            unpromoted.try_set(Grown(8)).unwrap_err().position(),
+            Some(8)
+        );
+        assert_eq!(unpromoted, stored);
+
+        stored.set_promoting(Grown(8)).unwrap();
+        assert_eq!(stored, Bairiak::U16(1 << 8 | 0b0100_0011));
+        for position in [0, 1, 6, 8] {
+            assert!(stored.is_true(Grown(position)));
+        }
+        assert!(stored.is_false(Grown(7)));
+    }
+
+    #[test]
+    fn test_minimal() {
+        assert_eq!(Bairiak::U128(0).minimal(), Bairiak::U8(0));
+        assert_eq!(Bairiak::U64(0x80).minimal(), Bairiak::U8(0x80));
+        assert_eq!(Bairiak::U64(0x100).minimal(), Bairiak::U16(0x100));
+        assert_eq!(Bairiak::U8(1).minimal(), Bairiak::U8(1));
+        assert_eq!(Bairiak::U128(1 << 127).minimal(), Bairiak::U128(1 << 127));
+
+        for value in [
+            Bairiak::U128(0x1_0000),
+            Bairiak::U32(7),
+            Bairiak::U64(1 << 40),
+        ] {
+            assert_eq!(value.minimal().minimal(), value.minimal());
+            assert_eq!(value.minimal().bits(), value.bits());
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// Written before `Delete` was added to `match_spec.yaml`.
+include!("../expected/match_spec.rs");
+
+fn main() {
+    let _ = permissions_match!(Permissions::Read, {
+        Read => "read",
+        Write => "write",
+    });
+}
@@ -0,0 +1,132 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: af5d3423b6a8df68
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    /// Lets the user change the document.
+    Write = 1,
+    Admin = 3,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            3 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0xb;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        3
+    }
+}
+
+#[allow(dead_code)]
+pub mod perm_abi {
+    pub const WIDTH_BITS: u32 = 8;
+    pub const VARIANT_COUNT: u32 = 3;
+    pub const LAYOUT_HASH: u64 = 0x06604149c125ee63;
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum HttpMethod {
+    Get = 0,
+    Post = 1,
+}
+
+impl BairiakEnum for HttpMethod {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(HttpMethod::Get),
+            1 => Some(HttpMethod::Post),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "Get",
+            HttpMethod::Post => "Post",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        HttpMethod::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        HttpMethod::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl HttpMethod {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [HttpMethod; HttpMethod::COUNT] = [
+        HttpMethod::Get,
+        HttpMethod::Post,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+#[allow(dead_code)]
+pub mod http_method_abi {
+    pub const WIDTH_BITS: u32 = 16;
+    pub const VARIANT_COUNT: u32 = 2;
+    pub const LAYOUT_HASH: u64 = 0x674f6cbe38187b36;
+}
@@ -0,0 +1,240 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 02ca5c30ab20cc49
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Permissions {
+    Read = 0,
+    Write = 1,
+    Delete = 2,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            2 => Some(Permissions::Delete),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+            Permissions::Delete => "Delete",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+        Permissions::Delete,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Shares {
+    Read = 0,
+    Write = 1,
+    Delete = 2,
+}
+
+impl BairiakEnum for Shares {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Shares::Read),
+            1 => Some(Shares::Write),
+            2 => Some(Shares::Delete),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Shares::Read => "Read",
+            Shares::Write => "Write",
+            Shares::Delete => "Delete",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Shares::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Shares::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Shares {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Shares; Shares::COUNT] = [
+        Shares::Read,
+        Shares::Write,
+        Shares::Delete,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Archive {
+    Read = 0,
+    Write = 1,
+    Delete = 2,
+}
+
+impl BairiakEnum for Archive {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Archive::Read),
+            1 => Some(Archive::Write),
+            2 => Some(Archive::Delete),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Archive::Read => "Read",
+            Archive::Write => "Write",
+            Archive::Delete => "Delete",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Archive::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Archive::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Archive {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Archive; Archive::COUNT] = [
+        Archive::Read,
+        Archive::Write,
+        Archive::Delete,
+    ];
+    pub const READ_POS: u8 = 0;
+    pub const WRITE_POS: u8 = 1;
+    pub const DELETE_POS: u8 = 2;
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Replication {
+    Push = 0,
+    Pull = 1,
+}
+
+impl BairiakEnum for Replication {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U32(0u32)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Replication::Push),
+            1 => Some(Replication::Pull),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Replication::Push => "Push",
+            Replication::Pull => "Pull",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Replication::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Replication::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Replication {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Replication; Replication::COUNT] = [
+        Replication::Push,
+        Replication::Pull,
+    ];
+    pub const PUSH_POS: u8 = 0;
+    pub const PULL_POS: u8 = 1;
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
@@ -0,0 +1,78 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 019b3e0806252444
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    Write = 1,
+    Delete = 2,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            2 => Some(Perm::Delete),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Delete => "Delete",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Delete,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+impl bairiak::proptest::arbitrary::Arbitrary for Perm {
+    type Parameters = ();
+    type Strategy = bairiak::proptest::strategy::BoxedStrategy<Perm>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        use bairiak::proptest::strategy::Strategy;
+        (0u8..3)
+            .prop_map(|i| match i {
+                0 => Perm::Read,
+                1 => Perm::Write,
+                _ => Perm::Delete,
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,198 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 4b6f05470f1c4aeb
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+enum Perm {
+    Read = 0,
+    Write = 1,
+    Type = 3,
+    Crate = 4,
+    AuditLog = 5,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            3 => Some(Perm::Type),
+            4 => Some(Perm::Crate),
+            5 => Some(Perm::AuditLog),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Type => "Type",
+            Perm::Crate => "Crate",
+            Perm::AuditLog => "AuditLog",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 5;
+    pub const ALL_MASK: u128 = 0x3b;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Type,
+        Perm::Crate,
+        Perm::AuditLog,
+    ];
+
+    pub const fn max_position() -> u8 {
+        5
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Default, Hash, Eq, PartialEq, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+struct PermBools {
+    read: bool,
+    write: bool,
+    r#type: bool,
+    crate_: bool,
+    audit_log: bool,
+}
+
+impl From<&Bairiak> for PermBools {
+    fn from(bairiak: &Bairiak) -> PermBools {
+        PermBools {
+            read: bairiak.is_true(Perm::Read),
+            write: bairiak.is_true(Perm::Write),
+            r#type: bairiak.is_true(Perm::Type),
+            crate_: bairiak.is_true(Perm::Crate),
+            audit_log: bairiak.is_true(Perm::AuditLog),
+        }
+    }
+}
+
+impl From<&PermBools> for Bairiak {
+    fn from(bools: &PermBools) -> Bairiak {
+        let mut bairiak = Perm::get_zero_bairiak();
+        if bools.read {
+            bairiak.set(Perm::Read);
+        }
+        if bools.write {
+            bairiak.set(Perm::Write);
+        }
+        if bools.r#type {
+            bairiak.set(Perm::Type);
+        }
+        if bools.crate_ {
+            bairiak.set(Perm::Crate);
+        }
+        if bools.audit_log {
+            bairiak.set(Perm::AuditLog);
+        }
+        bairiak
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Mode {
+    Fast = 0,
+    Slow = 1,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Fast),
+            1 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Fast => "Fast",
+            Mode::Slow => "Slow",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Fast,
+        Mode::Slow,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Default, Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub struct ModeBools {
+    pub fast: bool,
+    pub slow: bool,
+}
+
+impl From<&Bairiak> for ModeBools {
+    fn from(bairiak: &Bairiak) -> ModeBools {
+        ModeBools {
+            fast: bairiak.is_true(Mode::Fast),
+            slow: bairiak.is_true(Mode::Slow),
+        }
+    }
+}
+
+impl From<&ModeBools> for Bairiak {
+    fn from(bools: &ModeBools) -> Bairiak {
+        let mut bairiak = Mode::get_zero_bairiak();
+        if bools.fast {
+            bairiak.set(Mode::Fast);
+        }
+        if bools.slow {
+            bairiak.set(Mode::Slow);
+        }
+        bairiak
+    }
+}
@@ -0,0 +1,187 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: cd525f806b306152
+use ::bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    Write = 1,
+    /// Deprecated.
+    Admin = 2,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            2 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const EXCLUSIVE_MASKS: [u128; 1] = [0x5];
+
+    pub fn validate_exclusive(value: &Bairiak) -> Result<(), ::bairiak::ExclusiveViolation> {
+        ::bairiak::check_exclusive_for::<Perm>(value, &Perm::EXCLUSIVE_MASKS)
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const REQUIREMENTS: [(u8, u128); 1] = [(1, 0x1)];
+
+    pub fn validate_requires(value: &Bairiak) -> Result<(), Vec<::bairiak::RequiresViolation>> {
+        ::bairiak::check_requires_for::<Perm>(value, &Perm::REQUIREMENTS)
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const fn status(self) -> ::bairiak::FlagStatus {
+        match self {
+            Perm::Read => ::bairiak::FlagStatus::Stable,
+            Perm::Write => ::bairiak::FlagStatus::Stable,
+            Perm::Admin => ::bairiak::FlagStatus::Deprecated,
+        }
+    }
+
+    pub fn status_at(position: u8) -> Option<::bairiak::FlagStatus> {
+        match position {
+            3 => Some(::bairiak::FlagStatus::Removed),
+            _ => <Perm as BairiakEnum>::from_u8(position).map(Perm::status),
+        }
+    }
+}
+
+impl ::core::fmt::Display for Perm {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.pad(BairiakEnum::name(self))
+    }
+}
+
+impl ::core::str::FromStr for Perm {
+    type Err = ::bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Read" => Ok(Perm::Read),
+            "Write" => Ok(Perm::Write),
+            "Admin" => Ok(Perm::Admin),
+            _ => Err(::bairiak::FlagParseError::new::<Perm>("Perm", s)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Codec {
+    Gzip = 0,
+    Zstd = 1,
+}
+
+impl BairiakEnum for Codec {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Codec::Gzip),
+            1 => Some(Codec::Zstd),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Codec::Gzip => "Gzip",
+            Codec::Zstd => "Zstd",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Codec::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Codec::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Codec {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Codec; Codec::COUNT] = [
+        Codec::Gzip,
+        Codec::Zstd,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+impl ::core::fmt::Display for Codec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.pad(BairiakEnum::name(self))
+    }
+}
+
+#[allow(dead_code)]
+fn settings_layout() -> ::bairiak::PackedLayout {
+    ::bairiak::PackedLayout::new(16)
+        .and_then(|layout| layout.member::<Perm>())
+        .and_then(|layout| layout.member::<Codec>())
+        .expect("validated packed layout")
+}
@@ -0,0 +1,79 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 0c463a00ef06d135
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Codec {
+    CompressGzip = 0,
+    CompressZstd = 1,
+    ModeA = 2,
+    ModeB = 3,
+    ModeC = 4,
+}
+
+impl BairiakEnum for Codec {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Codec::CompressGzip),
+            1 => Some(Codec::CompressZstd),
+            2 => Some(Codec::ModeA),
+            3 => Some(Codec::ModeB),
+            4 => Some(Codec::ModeC),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Codec::CompressGzip => "CompressGzip",
+            Codec::CompressZstd => "CompressZstd",
+            Codec::ModeA => "ModeA",
+            Codec::ModeB => "ModeB",
+            Codec::ModeC => "ModeC",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Codec::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Codec::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Codec {
+    pub const COUNT: usize = 5;
+    pub const ALL_MASK: u128 = 0x1f;
+    pub const VARIANTS: [Codec; Codec::COUNT] = [
+        Codec::CompressGzip,
+        Codec::CompressZstd,
+        Codec::ModeA,
+        Codec::ModeB,
+        Codec::ModeC,
+    ];
+
+    pub const fn max_position() -> u8 {
+        4
+    }
+}
+
+#[allow(dead_code)]
+impl Codec {
+    pub const EXCLUSIVE_MASKS: [u128; 2] = [0x3, 0x1c];
+
+    pub fn validate_exclusive(value: &Bairiak) -> Result<(), bairiak::ExclusiveViolation> {
+        bairiak::check_exclusive_for::<Codec>(value, &Codec::EXCLUSIVE_MASKS)
+    }
+}
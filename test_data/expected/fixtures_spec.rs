@@ -0,0 +1,180 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 5993b79a9f03fb04
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Perm {
+    Read = 0,
+    Write = 1,
+    Admin = 2,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            2 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum LegacyPerm {
+    Read = 0,
+    Write = 1,
+    Delete = 2,
+}
+
+impl BairiakEnum for LegacyPerm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(LegacyPerm::Read),
+            1 => Some(LegacyPerm::Write),
+            2 => Some(LegacyPerm::Delete),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            LegacyPerm::Read => "Read",
+            LegacyPerm::Write => "Write",
+            LegacyPerm::Delete => "Delete",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        LegacyPerm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        LegacyPerm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl LegacyPerm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [LegacyPerm; LegacyPerm::COUNT] = [
+        LegacyPerm::Read,
+        LegacyPerm::Write,
+        LegacyPerm::Delete,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum TestEnum {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag9 = 9,
+}
+
+impl BairiakEnum for TestEnum {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(TestEnum::Flag0),
+            1 => Some(TestEnum::Flag1),
+            9 => Some(TestEnum::Flag9),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TestEnum::Flag0 => "Flag0",
+            TestEnum::Flag1 => "Flag1",
+            TestEnum::Flag9 => "Flag9",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        TestEnum::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        TestEnum::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl TestEnum {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x203;
+    pub const VARIANTS: [TestEnum; TestEnum::COUNT] = [
+        TestEnum::Flag0,
+        TestEnum::Flag1,
+        TestEnum::Flag9,
+    ];
+
+    pub const fn max_position() -> u8 {
+        9
+    }
+}
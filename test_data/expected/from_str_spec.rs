@@ -0,0 +1,145 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: ec1739e545f8cc11
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Permissions {
+    Read = 0,
+    Write = 1,
+    ReadWrite = 2,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            2 => Some(Permissions::ReadWrite),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+            Permissions::ReadWrite => "ReadWrite",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+        Permissions::ReadWrite,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+impl core::str::FromStr for Permissions {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            _ if s.eq_ignore_ascii_case("read") => Ok(Permissions::Read),
+            _ if s.eq_ignore_ascii_case("write") => Ok(Permissions::Write),
+            _ if s.eq_ignore_ascii_case("modify") => Ok(Permissions::Write),
+            _ if s.eq_ignore_ascii_case("w") => Ok(Permissions::Write),
+            _ if s.eq_ignore_ascii_case("readwrite") => Ok(Permissions::ReadWrite),
+            _ => Err(bairiak::FlagParseError::new::<Permissions>("Permissions", s)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Mode {
+    Fast = 0,
+    Slow = 1,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Fast),
+            1 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Fast => "Fast",
+            Mode::Slow => "Slow",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Fast,
+        Mode::Slow,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+impl core::str::FromStr for Mode {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Fast" => Ok(Mode::Fast),
+            "Slow" => Ok(Mode::Slow),
+            "careful" => Ok(Mode::Slow),
+            _ => Err(bairiak::FlagParseError::new::<Mode>("Mode", s)),
+        }
+    }
+}
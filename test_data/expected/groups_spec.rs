@@ -0,0 +1,151 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: a5568b32fa98ec06
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Permissions {
+    Read = 0,
+    Write = 1,
+    Admin = 9,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            9 => Some(Permissions::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+            Permissions::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x203;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+        Permissions::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        9
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub fn groups() -> &'static [(&'static str, Bairiak)] {
+        &[
+            ("Editors", Bairiak::U16(0x3)),
+            ("Operators", Bairiak::U16(0x203)),
+            ("Nobody", Bairiak::U16(0x0)),
+        ]
+    }
+
+    pub fn group(name: &str) -> Option<Bairiak> {
+        Permissions::groups()
+            .iter()
+            .find(|(group, _)| *group == name)
+            .map(|&(_, mask)| mask)
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Mode {
+    Fast = 0,
+    Slow = 1,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Fast),
+            1 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Fast => "Fast",
+            Mode::Slow => "Slow",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Fast,
+        Mode::Slow,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub fn groups() -> &'static [(&'static str, Bairiak)] {
+        &[
+            ("All", Bairiak::U8(0x3)),
+        ]
+    }
+
+    pub fn group(name: &str) -> Option<Bairiak> {
+        Mode::groups()
+            .iter()
+            .find(|(group, _)| group.eq_ignore_ascii_case(name))
+            .map(|&(_, mask)| mask)
+    }
+}
@@ -0,0 +1,2649 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: a6ca5a241f96f632
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum ExactMatch {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+    Flag8 = 8,
+    Flag9 = 9,
+    Flag10 = 10,
+    Flag11 = 11,
+    Flag12 = 12,
+    Flag13 = 13,
+    Flag14 = 14,
+    Flag15 = 15,
+    Flag16 = 16,
+    Flag17 = 17,
+    Flag18 = 18,
+    Flag19 = 19,
+    Flag20 = 20,
+    Flag21 = 21,
+    Flag22 = 22,
+    Flag23 = 23,
+    Flag24 = 24,
+    Flag25 = 25,
+    Flag26 = 26,
+    Flag27 = 27,
+    Flag28 = 28,
+    Flag29 = 29,
+    Flag30 = 30,
+    Flag31 = 31,
+    Flag32 = 32,
+    Flag33 = 33,
+    Flag34 = 34,
+    Flag35 = 35,
+    Flag36 = 36,
+    Flag37 = 37,
+    Flag38 = 38,
+    Flag39 = 39,
+    Flag40 = 40,
+    Flag41 = 41,
+    Flag42 = 42,
+    Flag43 = 43,
+    Flag44 = 44,
+    Flag45 = 45,
+    Flag46 = 46,
+    Flag47 = 47,
+    Flag48 = 48,
+    Flag49 = 49,
+    Flag50 = 50,
+    Flag51 = 51,
+    Flag52 = 52,
+    Flag53 = 53,
+    Flag54 = 54,
+    Flag55 = 55,
+    Flag56 = 56,
+    Flag57 = 57,
+    Flag58 = 58,
+    Flag59 = 59,
+    Flag60 = 60,
+    Flag61 = 61,
+    Flag62 = 62,
+    Flag63 = 63,
+    Flag64 = 64,
+    Flag65 = 65,
+    Flag66 = 66,
+    Flag67 = 67,
+    Flag68 = 68,
+    Flag69 = 69,
+    Flag70 = 70,
+    Flag71 = 71,
+    Flag72 = 72,
+    Flag73 = 73,
+    Flag74 = 74,
+    Flag75 = 75,
+    Flag76 = 76,
+    Flag77 = 77,
+    Flag78 = 78,
+    Flag79 = 79,
+    Flag80 = 80,
+    Flag81 = 81,
+    Flag82 = 82,
+    Flag83 = 83,
+    Flag84 = 84,
+    Flag85 = 85,
+    Flag86 = 86,
+    Flag87 = 87,
+    Flag88 = 88,
+    Flag89 = 89,
+    Flag90 = 90,
+    Flag91 = 91,
+    Flag92 = 92,
+    Flag93 = 93,
+    Flag94 = 94,
+    Flag95 = 95,
+    Flag96 = 96,
+    Flag97 = 97,
+    Flag98 = 98,
+    Flag99 = 99,
+    Flag100 = 100,
+    Flag101 = 101,
+    Flag102 = 102,
+    Flag103 = 103,
+    Flag104 = 104,
+    Flag105 = 105,
+    Flag106 = 106,
+    Flag107 = 107,
+    Flag108 = 108,
+    Flag109 = 109,
+    Flag110 = 110,
+    Flag111 = 111,
+    Flag112 = 112,
+    Flag113 = 113,
+    Flag114 = 114,
+    Flag115 = 115,
+    Flag116 = 116,
+    Flag117 = 117,
+    Flag118 = 118,
+    Flag119 = 119,
+}
+
+impl BairiakEnum for ExactMatch {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U128(0u128)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(ExactMatch::Flag0),
+            1 => Some(ExactMatch::Flag1),
+            2 => Some(ExactMatch::Flag2),
+            3 => Some(ExactMatch::Flag3),
+            4 => Some(ExactMatch::Flag4),
+            5 => Some(ExactMatch::Flag5),
+            6 => Some(ExactMatch::Flag6),
+            7 => Some(ExactMatch::Flag7),
+            8 => Some(ExactMatch::Flag8),
+            9 => Some(ExactMatch::Flag9),
+            10 => Some(ExactMatch::Flag10),
+            11 => Some(ExactMatch::Flag11),
+            12 => Some(ExactMatch::Flag12),
+            13 => Some(ExactMatch::Flag13),
+            14 => Some(ExactMatch::Flag14),
+            15 => Some(ExactMatch::Flag15),
+            16 => Some(ExactMatch::Flag16),
+            17 => Some(ExactMatch::Flag17),
+            18 => Some(ExactMatch::Flag18),
+            19 => Some(ExactMatch::Flag19),
+            20 => Some(ExactMatch::Flag20),
+            21 => Some(ExactMatch::Flag21),
+            22 => Some(ExactMatch::Flag22),
+            23 => Some(ExactMatch::Flag23),
+            24 => Some(ExactMatch::Flag24),
+            25 => Some(ExactMatch::Flag25),
+            26 => Some(ExactMatch::Flag26),
+            27 => Some(ExactMatch::Flag27),
+            28 => Some(ExactMatch::Flag28),
+            29 => Some(ExactMatch::Flag29),
+            30 => Some(ExactMatch::Flag30),
+            31 => Some(ExactMatch::Flag31),
+            32 => Some(ExactMatch::Flag32),
+            33 => Some(ExactMatch::Flag33),
+            34 => Some(ExactMatch::Flag34),
+            35 => Some(ExactMatch::Flag35),
+            36 => Some(ExactMatch::Flag36),
+            37 => Some(ExactMatch::Flag37),
+            38 => Some(ExactMatch::Flag38),
+            39 => Some(ExactMatch::Flag39),
+            40 => Some(ExactMatch::Flag40),
+            41 => Some(ExactMatch::Flag41),
+            42 => Some(ExactMatch::Flag42),
+            43 => Some(ExactMatch::Flag43),
+            44 => Some(ExactMatch::Flag44),
+            45 => Some(ExactMatch::Flag45),
+            46 => Some(ExactMatch::Flag46),
+            47 => Some(ExactMatch::Flag47),
+            48 => Some(ExactMatch::Flag48),
+            49 => Some(ExactMatch::Flag49),
+            50 => Some(ExactMatch::Flag50),
+            51 => Some(ExactMatch::Flag51),
+            52 => Some(ExactMatch::Flag52),
+            53 => Some(ExactMatch::Flag53),
+            54 => Some(ExactMatch::Flag54),
+            55 => Some(ExactMatch::Flag55),
+            56 => Some(ExactMatch::Flag56),
+            57 => Some(ExactMatch::Flag57),
+            58 => Some(ExactMatch::Flag58),
+            59 => Some(ExactMatch::Flag59),
+            60 => Some(ExactMatch::Flag60),
+            61 => Some(ExactMatch::Flag61),
+            62 => Some(ExactMatch::Flag62),
+            63 => Some(ExactMatch::Flag63),
+            64 => Some(ExactMatch::Flag64),
+            65 => Some(ExactMatch::Flag65),
+            66 => Some(ExactMatch::Flag66),
+            67 => Some(ExactMatch::Flag67),
+            68 => Some(ExactMatch::Flag68),
+            69 => Some(ExactMatch::Flag69),
+            70 => Some(ExactMatch::Flag70),
+            71 => Some(ExactMatch::Flag71),
+            72 => Some(ExactMatch::Flag72),
+            73 => Some(ExactMatch::Flag73),
+            74 => Some(ExactMatch::Flag74),
+            75 => Some(ExactMatch::Flag75),
+            76 => Some(ExactMatch::Flag76),
+            77 => Some(ExactMatch::Flag77),
+            78 => Some(ExactMatch::Flag78),
+            79 => Some(ExactMatch::Flag79),
+            80 => Some(ExactMatch::Flag80),
+            81 => Some(ExactMatch::Flag81),
+            82 => Some(ExactMatch::Flag82),
+            83 => Some(ExactMatch::Flag83),
+            84 => Some(ExactMatch::Flag84),
+            85 => Some(ExactMatch::Flag85),
+            86 => Some(ExactMatch::Flag86),
+            87 => Some(ExactMatch::Flag87),
+            88 => Some(ExactMatch::Flag88),
+            89 => Some(ExactMatch::Flag89),
+            90 => Some(ExactMatch::Flag90),
+            91 => Some(ExactMatch::Flag91),
+            92 => Some(ExactMatch::Flag92),
+            93 => Some(ExactMatch::Flag93),
+            94 => Some(ExactMatch::Flag94),
+            95 => Some(ExactMatch::Flag95),
+            96 => Some(ExactMatch::Flag96),
+            97 => Some(ExactMatch::Flag97),
+            98 => Some(ExactMatch::Flag98),
+            99 => Some(ExactMatch::Flag99),
+            100 => Some(ExactMatch::Flag100),
+            101 => Some(ExactMatch::Flag101),
+            102 => Some(ExactMatch::Flag102),
+            103 => Some(ExactMatch::Flag103),
+            104 => Some(ExactMatch::Flag104),
+            105 => Some(ExactMatch::Flag105),
+            106 => Some(ExactMatch::Flag106),
+            107 => Some(ExactMatch::Flag107),
+            108 => Some(ExactMatch::Flag108),
+            109 => Some(ExactMatch::Flag109),
+            110 => Some(ExactMatch::Flag110),
+            111 => Some(ExactMatch::Flag111),
+            112 => Some(ExactMatch::Flag112),
+            113 => Some(ExactMatch::Flag113),
+            114 => Some(ExactMatch::Flag114),
+            115 => Some(ExactMatch::Flag115),
+            116 => Some(ExactMatch::Flag116),
+            117 => Some(ExactMatch::Flag117),
+            118 => Some(ExactMatch::Flag118),
+            119 => Some(ExactMatch::Flag119),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ExactMatch::Flag0 => "Flag0",
+            ExactMatch::Flag1 => "Flag1",
+            ExactMatch::Flag2 => "Flag2",
+            ExactMatch::Flag3 => "Flag3",
+            ExactMatch::Flag4 => "Flag4",
+            ExactMatch::Flag5 => "Flag5",
+            ExactMatch::Flag6 => "Flag6",
+            ExactMatch::Flag7 => "Flag7",
+            ExactMatch::Flag8 => "Flag8",
+            ExactMatch::Flag9 => "Flag9",
+            ExactMatch::Flag10 => "Flag10",
+            ExactMatch::Flag11 => "Flag11",
+            ExactMatch::Flag12 => "Flag12",
+            ExactMatch::Flag13 => "Flag13",
+            ExactMatch::Flag14 => "Flag14",
+            ExactMatch::Flag15 => "Flag15",
+            ExactMatch::Flag16 => "Flag16",
+            ExactMatch::Flag17 => "Flag17",
+            ExactMatch::Flag18 => "Flag18",
+            ExactMatch::Flag19 => "Flag19",
+            ExactMatch::Flag20 => "Flag20",
+            ExactMatch::Flag21 => "Flag21",
+            ExactMatch::Flag22 => "Flag22",
+            ExactMatch::Flag23 => "Flag23",
+            ExactMatch::Flag24 => "Flag24",
+            ExactMatch::Flag25 => "Flag25",
+            ExactMatch::Flag26 => "Flag26",
+            ExactMatch::Flag27 => "Flag27",
+            ExactMatch::Flag28 => "Flag28",
+            ExactMatch::Flag29 => "Flag29",
+            ExactMatch::Flag30 => "Flag30",
+            ExactMatch::Flag31 => "Flag31",
+            ExactMatch::Flag32 => "Flag32",
+            ExactMatch::Flag33 => "Flag33",
+            ExactMatch::Flag34 => "Flag34",
+            ExactMatch::Flag35 => "Flag35",
+            ExactMatch::Flag36 => "Flag36",
+            ExactMatch::Flag37 => "Flag37",
+            ExactMatch::Flag38 => "Flag38",
+            ExactMatch::Flag39 => "Flag39",
+            ExactMatch::Flag40 => "Flag40",
+            ExactMatch::Flag41 => "Flag41",
+            ExactMatch::Flag42 => "Flag42",
+            ExactMatch::Flag43 => "Flag43",
+            ExactMatch::Flag44 => "Flag44",
+            ExactMatch::Flag45 => "Flag45",
+            ExactMatch::Flag46 => "Flag46",
+            ExactMatch::Flag47 => "Flag47",
+            ExactMatch::Flag48 => "Flag48",
+            ExactMatch::Flag49 => "Flag49",
+            ExactMatch::Flag50 => "Flag50",
+            ExactMatch::Flag51 => "Flag51",
+            ExactMatch::Flag52 => "Flag52",
+            ExactMatch::Flag53 => "Flag53",
+            ExactMatch::Flag54 => "Flag54",
+            ExactMatch::Flag55 => "Flag55",
+            ExactMatch::Flag56 => "Flag56",
+            ExactMatch::Flag57 => "Flag57",
+            ExactMatch::Flag58 => "Flag58",
+            ExactMatch::Flag59 => "Flag59",
+            ExactMatch::Flag60 => "Flag60",
+            ExactMatch::Flag61 => "Flag61",
+            ExactMatch::Flag62 => "Flag62",
+            ExactMatch::Flag63 => "Flag63",
+            ExactMatch::Flag64 => "Flag64",
+            ExactMatch::Flag65 => "Flag65",
+            ExactMatch::Flag66 => "Flag66",
+            ExactMatch::Flag67 => "Flag67",
+            ExactMatch::Flag68 => "Flag68",
+            ExactMatch::Flag69 => "Flag69",
+            ExactMatch::Flag70 => "Flag70",
+            ExactMatch::Flag71 => "Flag71",
+            ExactMatch::Flag72 => "Flag72",
+            ExactMatch::Flag73 => "Flag73",
+            ExactMatch::Flag74 => "Flag74",
+            ExactMatch::Flag75 => "Flag75",
+            ExactMatch::Flag76 => "Flag76",
+            ExactMatch::Flag77 => "Flag77",
+            ExactMatch::Flag78 => "Flag78",
+            ExactMatch::Flag79 => "Flag79",
+            ExactMatch::Flag80 => "Flag80",
+            ExactMatch::Flag81 => "Flag81",
+            ExactMatch::Flag82 => "Flag82",
+            ExactMatch::Flag83 => "Flag83",
+            ExactMatch::Flag84 => "Flag84",
+            ExactMatch::Flag85 => "Flag85",
+            ExactMatch::Flag86 => "Flag86",
+            ExactMatch::Flag87 => "Flag87",
+            ExactMatch::Flag88 => "Flag88",
+            ExactMatch::Flag89 => "Flag89",
+            ExactMatch::Flag90 => "Flag90",
+            ExactMatch::Flag91 => "Flag91",
+            ExactMatch::Flag92 => "Flag92",
+            ExactMatch::Flag93 => "Flag93",
+            ExactMatch::Flag94 => "Flag94",
+            ExactMatch::Flag95 => "Flag95",
+            ExactMatch::Flag96 => "Flag96",
+            ExactMatch::Flag97 => "Flag97",
+            ExactMatch::Flag98 => "Flag98",
+            ExactMatch::Flag99 => "Flag99",
+            ExactMatch::Flag100 => "Flag100",
+            ExactMatch::Flag101 => "Flag101",
+            ExactMatch::Flag102 => "Flag102",
+            ExactMatch::Flag103 => "Flag103",
+            ExactMatch::Flag104 => "Flag104",
+            ExactMatch::Flag105 => "Flag105",
+            ExactMatch::Flag106 => "Flag106",
+            ExactMatch::Flag107 => "Flag107",
+            ExactMatch::Flag108 => "Flag108",
+            ExactMatch::Flag109 => "Flag109",
+            ExactMatch::Flag110 => "Flag110",
+            ExactMatch::Flag111 => "Flag111",
+            ExactMatch::Flag112 => "Flag112",
+            ExactMatch::Flag113 => "Flag113",
+            ExactMatch::Flag114 => "Flag114",
+            ExactMatch::Flag115 => "Flag115",
+            ExactMatch::Flag116 => "Flag116",
+            ExactMatch::Flag117 => "Flag117",
+            ExactMatch::Flag118 => "Flag118",
+            ExactMatch::Flag119 => "Flag119",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        ExactMatch::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        ExactMatch::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl ExactMatch {
+    pub const COUNT: usize = 120;
+    pub const ALL_MASK: u128 = 0xffffffffffffffffffffffffffffff;
+    pub const VARIANTS: [ExactMatch; ExactMatch::COUNT] = [
+        ExactMatch::Flag0,
+        ExactMatch::Flag1,
+        ExactMatch::Flag2,
+        ExactMatch::Flag3,
+        ExactMatch::Flag4,
+        ExactMatch::Flag5,
+        ExactMatch::Flag6,
+        ExactMatch::Flag7,
+        ExactMatch::Flag8,
+        ExactMatch::Flag9,
+        ExactMatch::Flag10,
+        ExactMatch::Flag11,
+        ExactMatch::Flag12,
+        ExactMatch::Flag13,
+        ExactMatch::Flag14,
+        ExactMatch::Flag15,
+        ExactMatch::Flag16,
+        ExactMatch::Flag17,
+        ExactMatch::Flag18,
+        ExactMatch::Flag19,
+        ExactMatch::Flag20,
+        ExactMatch::Flag21,
+        ExactMatch::Flag22,
+        ExactMatch::Flag23,
+        ExactMatch::Flag24,
+        ExactMatch::Flag25,
+        ExactMatch::Flag26,
+        ExactMatch::Flag27,
+        ExactMatch::Flag28,
+        ExactMatch::Flag29,
+        ExactMatch::Flag30,
+        ExactMatch::Flag31,
+        ExactMatch::Flag32,
+        ExactMatch::Flag33,
+        ExactMatch::Flag34,
+        ExactMatch::Flag35,
+        ExactMatch::Flag36,
+        ExactMatch::Flag37,
+        ExactMatch::Flag38,
+        ExactMatch::Flag39,
+        ExactMatch::Flag40,
+        ExactMatch::Flag41,
+        ExactMatch::Flag42,
+        ExactMatch::Flag43,
+        ExactMatch::Flag44,
+        ExactMatch::Flag45,
+        ExactMatch::Flag46,
+        ExactMatch::Flag47,
+        ExactMatch::Flag48,
+        ExactMatch::Flag49,
+        ExactMatch::Flag50,
+        ExactMatch::Flag51,
+        ExactMatch::Flag52,
+        ExactMatch::Flag53,
+        ExactMatch::Flag54,
+        ExactMatch::Flag55,
+        ExactMatch::Flag56,
+        ExactMatch::Flag57,
+        ExactMatch::Flag58,
+        ExactMatch::Flag59,
+        ExactMatch::Flag60,
+        ExactMatch::Flag61,
+        ExactMatch::Flag62,
+        ExactMatch::Flag63,
+        ExactMatch::Flag64,
+        ExactMatch::Flag65,
+        ExactMatch::Flag66,
+        ExactMatch::Flag67,
+        ExactMatch::Flag68,
+        ExactMatch::Flag69,
+        ExactMatch::Flag70,
+        ExactMatch::Flag71,
+        ExactMatch::Flag72,
+        ExactMatch::Flag73,
+        ExactMatch::Flag74,
+        ExactMatch::Flag75,
+        ExactMatch::Flag76,
+        ExactMatch::Flag77,
+        ExactMatch::Flag78,
+        ExactMatch::Flag79,
+        ExactMatch::Flag80,
+        ExactMatch::Flag81,
+        ExactMatch::Flag82,
+        ExactMatch::Flag83,
+        ExactMatch::Flag84,
+        ExactMatch::Flag85,
+        ExactMatch::Flag86,
+        ExactMatch::Flag87,
+        ExactMatch::Flag88,
+        ExactMatch::Flag89,
+        ExactMatch::Flag90,
+        ExactMatch::Flag91,
+        ExactMatch::Flag92,
+        ExactMatch::Flag93,
+        ExactMatch::Flag94,
+        ExactMatch::Flag95,
+        ExactMatch::Flag96,
+        ExactMatch::Flag97,
+        ExactMatch::Flag98,
+        ExactMatch::Flag99,
+        ExactMatch::Flag100,
+        ExactMatch::Flag101,
+        ExactMatch::Flag102,
+        ExactMatch::Flag103,
+        ExactMatch::Flag104,
+        ExactMatch::Flag105,
+        ExactMatch::Flag106,
+        ExactMatch::Flag107,
+        ExactMatch::Flag108,
+        ExactMatch::Flag109,
+        ExactMatch::Flag110,
+        ExactMatch::Flag111,
+        ExactMatch::Flag112,
+        ExactMatch::Flag113,
+        ExactMatch::Flag114,
+        ExactMatch::Flag115,
+        ExactMatch::Flag116,
+        ExactMatch::Flag117,
+        ExactMatch::Flag118,
+        ExactMatch::Flag119,
+    ];
+
+    pub const fn max_position() -> u8 {
+        119
+    }
+}
+
+impl core::str::FromStr for ExactMatch {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Flag0" => Ok(ExactMatch::Flag0),
+            "Flag1" => Ok(ExactMatch::Flag1),
+            "Flag2" => Ok(ExactMatch::Flag2),
+            "Flag3" => Ok(ExactMatch::Flag3),
+            "Flag4" => Ok(ExactMatch::Flag4),
+            "Flag5" => Ok(ExactMatch::Flag5),
+            "Flag6" => Ok(ExactMatch::Flag6),
+            "Flag7" => Ok(ExactMatch::Flag7),
+            "Seven" => Ok(ExactMatch::Flag7),
+            "Sept" => Ok(ExactMatch::Flag7),
+            "Séptimo" => Ok(ExactMatch::Flag7),
+            "Flag8" => Ok(ExactMatch::Flag8),
+            "Flag9" => Ok(ExactMatch::Flag9),
+            "Flag10" => Ok(ExactMatch::Flag10),
+            "Flag11" => Ok(ExactMatch::Flag11),
+            "Flag12" => Ok(ExactMatch::Flag12),
+            "Flag13" => Ok(ExactMatch::Flag13),
+            "Flag14" => Ok(ExactMatch::Flag14),
+            "Flag15" => Ok(ExactMatch::Flag15),
+            "Flag16" => Ok(ExactMatch::Flag16),
+            "Flag17" => Ok(ExactMatch::Flag17),
+            "Flag18" => Ok(ExactMatch::Flag18),
+            "Flag19" => Ok(ExactMatch::Flag19),
+            "Flag20" => Ok(ExactMatch::Flag20),
+            "Flag21" => Ok(ExactMatch::Flag21),
+            "Flag22" => Ok(ExactMatch::Flag22),
+            "Flag23" => Ok(ExactMatch::Flag23),
+            "Flag24" => Ok(ExactMatch::Flag24),
+            "Flag25" => Ok(ExactMatch::Flag25),
+            "Flag26" => Ok(ExactMatch::Flag26),
+            "Flag27" => Ok(ExactMatch::Flag27),
+            "Flag28" => Ok(ExactMatch::Flag28),
+            "Flag29" => Ok(ExactMatch::Flag29),
+            "Flag30" => Ok(ExactMatch::Flag30),
+            "Flag31" => Ok(ExactMatch::Flag31),
+            "Flag32" => Ok(ExactMatch::Flag32),
+            "Flag33" => Ok(ExactMatch::Flag33),
+            "Flag34" => Ok(ExactMatch::Flag34),
+            "Flag35" => Ok(ExactMatch::Flag35),
+            "Flag36" => Ok(ExactMatch::Flag36),
+            "Flag37" => Ok(ExactMatch::Flag37),
+            "Flag38" => Ok(ExactMatch::Flag38),
+            "Flag39" => Ok(ExactMatch::Flag39),
+            "Flag40" => Ok(ExactMatch::Flag40),
+            "Flag41" => Ok(ExactMatch::Flag41),
+            "Flag42" => Ok(ExactMatch::Flag42),
+            "Flag43" => Ok(ExactMatch::Flag43),
+            "Flag44" => Ok(ExactMatch::Flag44),
+            "Flag45" => Ok(ExactMatch::Flag45),
+            "Flag46" => Ok(ExactMatch::Flag46),
+            "Flag47" => Ok(ExactMatch::Flag47),
+            "Flag48" => Ok(ExactMatch::Flag48),
+            "Flag49" => Ok(ExactMatch::Flag49),
+            "Flag50" => Ok(ExactMatch::Flag50),
+            "Flag51" => Ok(ExactMatch::Flag51),
+            "Flag52" => Ok(ExactMatch::Flag52),
+            "Flag53" => Ok(ExactMatch::Flag53),
+            "Flag54" => Ok(ExactMatch::Flag54),
+            "Flag55" => Ok(ExactMatch::Flag55),
+            "Flag56" => Ok(ExactMatch::Flag56),
+            "Flag57" => Ok(ExactMatch::Flag57),
+            "Flag58" => Ok(ExactMatch::Flag58),
+            "Flag59" => Ok(ExactMatch::Flag59),
+            "Flag60" => Ok(ExactMatch::Flag60),
+            "Flag61" => Ok(ExactMatch::Flag61),
+            "Flag62" => Ok(ExactMatch::Flag62),
+            "Flag63" => Ok(ExactMatch::Flag63),
+            "Flag64" => Ok(ExactMatch::Flag64),
+            "Flag65" => Ok(ExactMatch::Flag65),
+            "Flag66" => Ok(ExactMatch::Flag66),
+            "Flag67" => Ok(ExactMatch::Flag67),
+            "Flag68" => Ok(ExactMatch::Flag68),
+            "Flag69" => Ok(ExactMatch::Flag69),
+            "Flag70" => Ok(ExactMatch::Flag70),
+            "Flag71" => Ok(ExactMatch::Flag71),
+            "Flag72" => Ok(ExactMatch::Flag72),
+            "Flag73" => Ok(ExactMatch::Flag73),
+            "Flag74" => Ok(ExactMatch::Flag74),
+            "Flag75" => Ok(ExactMatch::Flag75),
+            "Flag76" => Ok(ExactMatch::Flag76),
+            "Flag77" => Ok(ExactMatch::Flag77),
+            "Flag78" => Ok(ExactMatch::Flag78),
+            "Flag79" => Ok(ExactMatch::Flag79),
+            "Flag80" => Ok(ExactMatch::Flag80),
+            "Flag81" => Ok(ExactMatch::Flag81),
+            "Flag82" => Ok(ExactMatch::Flag82),
+            "Flag83" => Ok(ExactMatch::Flag83),
+            "Flag84" => Ok(ExactMatch::Flag84),
+            "Flag85" => Ok(ExactMatch::Flag85),
+            "Flag86" => Ok(ExactMatch::Flag86),
+            "Flag87" => Ok(ExactMatch::Flag87),
+            "Flag88" => Ok(ExactMatch::Flag88),
+            "Flag89" => Ok(ExactMatch::Flag89),
+            "Flag90" => Ok(ExactMatch::Flag90),
+            "Flag91" => Ok(ExactMatch::Flag91),
+            "Flag92" => Ok(ExactMatch::Flag92),
+            "Flag93" => Ok(ExactMatch::Flag93),
+            "Flag94" => Ok(ExactMatch::Flag94),
+            "Flag95" => Ok(ExactMatch::Flag95),
+            "Flag96" => Ok(ExactMatch::Flag96),
+            "Flag97" => Ok(ExactMatch::Flag97),
+            "Flag98" => Ok(ExactMatch::Flag98),
+            "Flag99" => Ok(ExactMatch::Flag99),
+            "Flag100" => Ok(ExactMatch::Flag100),
+            "Flag101" => Ok(ExactMatch::Flag101),
+            "Flag102" => Ok(ExactMatch::Flag102),
+            "Flag103" => Ok(ExactMatch::Flag103),
+            "Flag104" => Ok(ExactMatch::Flag104),
+            "Flag105" => Ok(ExactMatch::Flag105),
+            "Flag106" => Ok(ExactMatch::Flag106),
+            "Flag107" => Ok(ExactMatch::Flag107),
+            "Flag108" => Ok(ExactMatch::Flag108),
+            "Flag109" => Ok(ExactMatch::Flag109),
+            "Flag110" => Ok(ExactMatch::Flag110),
+            "Flag111" => Ok(ExactMatch::Flag111),
+            "Flag112" => Ok(ExactMatch::Flag112),
+            "Flag113" => Ok(ExactMatch::Flag113),
+            "Flag114" => Ok(ExactMatch::Flag114),
+            "Flag115" => Ok(ExactMatch::Flag115),
+            "Flag116" => Ok(ExactMatch::Flag116),
+            "Flag117" => Ok(ExactMatch::Flag117),
+            "Flag118" => Ok(ExactMatch::Flag118),
+            "Flag119" => Ok(ExactMatch::Flag119),
+            _ => Err(bairiak::FlagParseError::new::<ExactMatch>("ExactMatch", s)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum ExactBytes {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+    Flag8 = 8,
+    Flag9 = 9,
+    Flag10 = 10,
+    Flag11 = 11,
+    Flag12 = 12,
+    Flag13 = 13,
+    Flag14 = 14,
+    Flag15 = 15,
+    Flag16 = 16,
+    Flag17 = 17,
+    Flag18 = 18,
+    Flag19 = 19,
+    Flag20 = 20,
+    Flag21 = 21,
+    Flag22 = 22,
+    Flag23 = 23,
+    Flag24 = 24,
+    Flag25 = 25,
+    Flag26 = 26,
+    Flag27 = 27,
+    Flag28 = 28,
+    Flag29 = 29,
+    Flag30 = 30,
+    Flag31 = 31,
+    Flag32 = 32,
+    Flag33 = 33,
+    Flag34 = 34,
+    Flag35 = 35,
+    Flag36 = 36,
+    Flag37 = 37,
+    Flag38 = 38,
+    Flag39 = 39,
+    Flag40 = 40,
+    Flag41 = 41,
+    Flag42 = 42,
+    Flag43 = 43,
+    Flag44 = 44,
+    Flag45 = 45,
+    Flag46 = 46,
+    Flag47 = 47,
+    Flag48 = 48,
+    Flag49 = 49,
+    Flag50 = 50,
+    Flag51 = 51,
+    Flag52 = 52,
+    Flag53 = 53,
+    Flag54 = 54,
+    Flag55 = 55,
+    Flag56 = 56,
+    Flag57 = 57,
+    Flag58 = 58,
+    Flag59 = 59,
+    Flag60 = 60,
+    Flag61 = 61,
+    Flag62 = 62,
+    Flag63 = 63,
+    Flag64 = 64,
+    Flag65 = 65,
+    Flag66 = 66,
+    Flag67 = 67,
+    Flag68 = 68,
+    Flag69 = 69,
+    Flag70 = 70,
+    Flag71 = 71,
+    Flag72 = 72,
+    Flag73 = 73,
+    Flag74 = 74,
+    Flag75 = 75,
+    Flag76 = 76,
+    Flag77 = 77,
+    Flag78 = 78,
+    Flag79 = 79,
+    Flag80 = 80,
+    Flag81 = 81,
+    Flag82 = 82,
+    Flag83 = 83,
+    Flag84 = 84,
+    Flag85 = 85,
+    Flag86 = 86,
+    Flag87 = 87,
+    Flag88 = 88,
+    Flag89 = 89,
+    Flag90 = 90,
+    Flag91 = 91,
+    Flag92 = 92,
+    Flag93 = 93,
+    Flag94 = 94,
+    Flag95 = 95,
+    Flag96 = 96,
+    Flag97 = 97,
+    Flag98 = 98,
+    Flag99 = 99,
+    Flag100 = 100,
+    Flag101 = 101,
+    Flag102 = 102,
+    Flag103 = 103,
+    Flag104 = 104,
+    Flag105 = 105,
+    Flag106 = 106,
+    Flag107 = 107,
+    Flag108 = 108,
+    Flag109 = 109,
+    Flag110 = 110,
+    Flag111 = 111,
+    Flag112 = 112,
+    Flag113 = 113,
+    Flag114 = 114,
+    Flag115 = 115,
+    Flag116 = 116,
+    Flag117 = 117,
+    Flag118 = 118,
+    Flag119 = 119,
+}
+
+impl BairiakEnum for ExactBytes {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U128(0u128)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(ExactBytes::Flag0),
+            1 => Some(ExactBytes::Flag1),
+            2 => Some(ExactBytes::Flag2),
+            3 => Some(ExactBytes::Flag3),
+            4 => Some(ExactBytes::Flag4),
+            5 => Some(ExactBytes::Flag5),
+            6 => Some(ExactBytes::Flag6),
+            7 => Some(ExactBytes::Flag7),
+            8 => Some(ExactBytes::Flag8),
+            9 => Some(ExactBytes::Flag9),
+            10 => Some(ExactBytes::Flag10),
+            11 => Some(ExactBytes::Flag11),
+            12 => Some(ExactBytes::Flag12),
+            13 => Some(ExactBytes::Flag13),
+            14 => Some(ExactBytes::Flag14),
+            15 => Some(ExactBytes::Flag15),
+            16 => Some(ExactBytes::Flag16),
+            17 => Some(ExactBytes::Flag17),
+            18 => Some(ExactBytes::Flag18),
+            19 => Some(ExactBytes::Flag19),
+            20 => Some(ExactBytes::Flag20),
+            21 => Some(ExactBytes::Flag21),
+            22 => Some(ExactBytes::Flag22),
+            23 => Some(ExactBytes::Flag23),
+            24 => Some(ExactBytes::Flag24),
+            25 => Some(ExactBytes::Flag25),
+            26 => Some(ExactBytes::Flag26),
+            27 => Some(ExactBytes::Flag27),
+            28 => Some(ExactBytes::Flag28),
+            29 => Some(ExactBytes::Flag29),
+            30 => Some(ExactBytes::Flag30),
+            31 => Some(ExactBytes::Flag31),
+            32 => Some(ExactBytes::Flag32),
+            33 => Some(ExactBytes::Flag33),
+            34 => Some(ExactBytes::Flag34),
+            35 => Some(ExactBytes::Flag35),
+            36 => Some(ExactBytes::Flag36),
+            37 => Some(ExactBytes::Flag37),
+            38 => Some(ExactBytes::Flag38),
+            39 => Some(ExactBytes::Flag39),
+            40 => Some(ExactBytes::Flag40),
+            41 => Some(ExactBytes::Flag41),
+            42 => Some(ExactBytes::Flag42),
+            43 => Some(ExactBytes::Flag43),
+            44 => Some(ExactBytes::Flag44),
+            45 => Some(ExactBytes::Flag45),
+            46 => Some(ExactBytes::Flag46),
+            47 => Some(ExactBytes::Flag47),
+            48 => Some(ExactBytes::Flag48),
+            49 => Some(ExactBytes::Flag49),
+            50 => Some(ExactBytes::Flag50),
+            51 => Some(ExactBytes::Flag51),
+            52 => Some(ExactBytes::Flag52),
+            53 => Some(ExactBytes::Flag53),
+            54 => Some(ExactBytes::Flag54),
+            55 => Some(ExactBytes::Flag55),
+            56 => Some(ExactBytes::Flag56),
+            57 => Some(ExactBytes::Flag57),
+            58 => Some(ExactBytes::Flag58),
+            59 => Some(ExactBytes::Flag59),
+            60 => Some(ExactBytes::Flag60),
+            61 => Some(ExactBytes::Flag61),
+            62 => Some(ExactBytes::Flag62),
+            63 => Some(ExactBytes::Flag63),
+            64 => Some(ExactBytes::Flag64),
+            65 => Some(ExactBytes::Flag65),
+            66 => Some(ExactBytes::Flag66),
+            67 => Some(ExactBytes::Flag67),
+            68 => Some(ExactBytes::Flag68),
+            69 => Some(ExactBytes::Flag69),
+            70 => Some(ExactBytes::Flag70),
+            71 => Some(ExactBytes::Flag71),
+            72 => Some(ExactBytes::Flag72),
+            73 => Some(ExactBytes::Flag73),
+            74 => Some(ExactBytes::Flag74),
+            75 => Some(ExactBytes::Flag75),
+            76 => Some(ExactBytes::Flag76),
+            77 => Some(ExactBytes::Flag77),
+            78 => Some(ExactBytes::Flag78),
+            79 => Some(ExactBytes::Flag79),
+            80 => Some(ExactBytes::Flag80),
+            81 => Some(ExactBytes::Flag81),
+            82 => Some(ExactBytes::Flag82),
+            83 => Some(ExactBytes::Flag83),
+            84 => Some(ExactBytes::Flag84),
+            85 => Some(ExactBytes::Flag85),
+            86 => Some(ExactBytes::Flag86),
+            87 => Some(ExactBytes::Flag87),
+            88 => Some(ExactBytes::Flag88),
+            89 => Some(ExactBytes::Flag89),
+            90 => Some(ExactBytes::Flag90),
+            91 => Some(ExactBytes::Flag91),
+            92 => Some(ExactBytes::Flag92),
+            93 => Some(ExactBytes::Flag93),
+            94 => Some(ExactBytes::Flag94),
+            95 => Some(ExactBytes::Flag95),
+            96 => Some(ExactBytes::Flag96),
+            97 => Some(ExactBytes::Flag97),
+            98 => Some(ExactBytes::Flag98),
+            99 => Some(ExactBytes::Flag99),
+            100 => Some(ExactBytes::Flag100),
+            101 => Some(ExactBytes::Flag101),
+            102 => Some(ExactBytes::Flag102),
+            103 => Some(ExactBytes::Flag103),
+            104 => Some(ExactBytes::Flag104),
+            105 => Some(ExactBytes::Flag105),
+            106 => Some(ExactBytes::Flag106),
+            107 => Some(ExactBytes::Flag107),
+            108 => Some(ExactBytes::Flag108),
+            109 => Some(ExactBytes::Flag109),
+            110 => Some(ExactBytes::Flag110),
+            111 => Some(ExactBytes::Flag111),
+            112 => Some(ExactBytes::Flag112),
+            113 => Some(ExactBytes::Flag113),
+            114 => Some(ExactBytes::Flag114),
+            115 => Some(ExactBytes::Flag115),
+            116 => Some(ExactBytes::Flag116),
+            117 => Some(ExactBytes::Flag117),
+            118 => Some(ExactBytes::Flag118),
+            119 => Some(ExactBytes::Flag119),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ExactBytes::Flag0 => "Flag0",
+            ExactBytes::Flag1 => "Flag1",
+            ExactBytes::Flag2 => "Flag2",
+            ExactBytes::Flag3 => "Flag3",
+            ExactBytes::Flag4 => "Flag4",
+            ExactBytes::Flag5 => "Flag5",
+            ExactBytes::Flag6 => "Flag6",
+            ExactBytes::Flag7 => "Flag7",
+            ExactBytes::Flag8 => "Flag8",
+            ExactBytes::Flag9 => "Flag9",
+            ExactBytes::Flag10 => "Flag10",
+            ExactBytes::Flag11 => "Flag11",
+            ExactBytes::Flag12 => "Flag12",
+            ExactBytes::Flag13 => "Flag13",
+            ExactBytes::Flag14 => "Flag14",
+            ExactBytes::Flag15 => "Flag15",
+            ExactBytes::Flag16 => "Flag16",
+            ExactBytes::Flag17 => "Flag17",
+            ExactBytes::Flag18 => "Flag18",
+            ExactBytes::Flag19 => "Flag19",
+            ExactBytes::Flag20 => "Flag20",
+            ExactBytes::Flag21 => "Flag21",
+            ExactBytes::Flag22 => "Flag22",
+            ExactBytes::Flag23 => "Flag23",
+            ExactBytes::Flag24 => "Flag24",
+            ExactBytes::Flag25 => "Flag25",
+            ExactBytes::Flag26 => "Flag26",
+            ExactBytes::Flag27 => "Flag27",
+            ExactBytes::Flag28 => "Flag28",
+            ExactBytes::Flag29 => "Flag29",
+            ExactBytes::Flag30 => "Flag30",
+            ExactBytes::Flag31 => "Flag31",
+            ExactBytes::Flag32 => "Flag32",
+            ExactBytes::Flag33 => "Flag33",
+            ExactBytes::Flag34 => "Flag34",
+            ExactBytes::Flag35 => "Flag35",
+            ExactBytes::Flag36 => "Flag36",
+            ExactBytes::Flag37 => "Flag37",
+            ExactBytes::Flag38 => "Flag38",
+            ExactBytes::Flag39 => "Flag39",
+            ExactBytes::Flag40 => "Flag40",
+            ExactBytes::Flag41 => "Flag41",
+            ExactBytes::Flag42 => "Flag42",
+            ExactBytes::Flag43 => "Flag43",
+            ExactBytes::Flag44 => "Flag44",
+            ExactBytes::Flag45 => "Flag45",
+            ExactBytes::Flag46 => "Flag46",
+            ExactBytes::Flag47 => "Flag47",
+            ExactBytes::Flag48 => "Flag48",
+            ExactBytes::Flag49 => "Flag49",
+            ExactBytes::Flag50 => "Flag50",
+            ExactBytes::Flag51 => "Flag51",
+            ExactBytes::Flag52 => "Flag52",
+            ExactBytes::Flag53 => "Flag53",
+            ExactBytes::Flag54 => "Flag54",
+            ExactBytes::Flag55 => "Flag55",
+            ExactBytes::Flag56 => "Flag56",
+            ExactBytes::Flag57 => "Flag57",
+            ExactBytes::Flag58 => "Flag58",
+            ExactBytes::Flag59 => "Flag59",
+            ExactBytes::Flag60 => "Flag60",
+            ExactBytes::Flag61 => "Flag61",
+            ExactBytes::Flag62 => "Flag62",
+            ExactBytes::Flag63 => "Flag63",
+            ExactBytes::Flag64 => "Flag64",
+            ExactBytes::Flag65 => "Flag65",
+            ExactBytes::Flag66 => "Flag66",
+            ExactBytes::Flag67 => "Flag67",
+            ExactBytes::Flag68 => "Flag68",
+            ExactBytes::Flag69 => "Flag69",
+            ExactBytes::Flag70 => "Flag70",
+            ExactBytes::Flag71 => "Flag71",
+            ExactBytes::Flag72 => "Flag72",
+            ExactBytes::Flag73 => "Flag73",
+            ExactBytes::Flag74 => "Flag74",
+            ExactBytes::Flag75 => "Flag75",
+            ExactBytes::Flag76 => "Flag76",
+            ExactBytes::Flag77 => "Flag77",
+            ExactBytes::Flag78 => "Flag78",
+            ExactBytes::Flag79 => "Flag79",
+            ExactBytes::Flag80 => "Flag80",
+            ExactBytes::Flag81 => "Flag81",
+            ExactBytes::Flag82 => "Flag82",
+            ExactBytes::Flag83 => "Flag83",
+            ExactBytes::Flag84 => "Flag84",
+            ExactBytes::Flag85 => "Flag85",
+            ExactBytes::Flag86 => "Flag86",
+            ExactBytes::Flag87 => "Flag87",
+            ExactBytes::Flag88 => "Flag88",
+            ExactBytes::Flag89 => "Flag89",
+            ExactBytes::Flag90 => "Flag90",
+            ExactBytes::Flag91 => "Flag91",
+            ExactBytes::Flag92 => "Flag92",
+            ExactBytes::Flag93 => "Flag93",
+            ExactBytes::Flag94 => "Flag94",
+            ExactBytes::Flag95 => "Flag95",
+            ExactBytes::Flag96 => "Flag96",
+            ExactBytes::Flag97 => "Flag97",
+            ExactBytes::Flag98 => "Flag98",
+            ExactBytes::Flag99 => "Flag99",
+            ExactBytes::Flag100 => "Flag100",
+            ExactBytes::Flag101 => "Flag101",
+            ExactBytes::Flag102 => "Flag102",
+            ExactBytes::Flag103 => "Flag103",
+            ExactBytes::Flag104 => "Flag104",
+            ExactBytes::Flag105 => "Flag105",
+            ExactBytes::Flag106 => "Flag106",
+            ExactBytes::Flag107 => "Flag107",
+            ExactBytes::Flag108 => "Flag108",
+            ExactBytes::Flag109 => "Flag109",
+            ExactBytes::Flag110 => "Flag110",
+            ExactBytes::Flag111 => "Flag111",
+            ExactBytes::Flag112 => "Flag112",
+            ExactBytes::Flag113 => "Flag113",
+            ExactBytes::Flag114 => "Flag114",
+            ExactBytes::Flag115 => "Flag115",
+            ExactBytes::Flag116 => "Flag116",
+            ExactBytes::Flag117 => "Flag117",
+            ExactBytes::Flag118 => "Flag118",
+            ExactBytes::Flag119 => "Flag119",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        ExactBytes::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        ExactBytes::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl ExactBytes {
+    pub const COUNT: usize = 120;
+    pub const ALL_MASK: u128 = 0xffffffffffffffffffffffffffffff;
+    pub const VARIANTS: [ExactBytes; ExactBytes::COUNT] = [
+        ExactBytes::Flag0,
+        ExactBytes::Flag1,
+        ExactBytes::Flag2,
+        ExactBytes::Flag3,
+        ExactBytes::Flag4,
+        ExactBytes::Flag5,
+        ExactBytes::Flag6,
+        ExactBytes::Flag7,
+        ExactBytes::Flag8,
+        ExactBytes::Flag9,
+        ExactBytes::Flag10,
+        ExactBytes::Flag11,
+        ExactBytes::Flag12,
+        ExactBytes::Flag13,
+        ExactBytes::Flag14,
+        ExactBytes::Flag15,
+        ExactBytes::Flag16,
+        ExactBytes::Flag17,
+        ExactBytes::Flag18,
+        ExactBytes::Flag19,
+        ExactBytes::Flag20,
+        ExactBytes::Flag21,
+        ExactBytes::Flag22,
+        ExactBytes::Flag23,
+        ExactBytes::Flag24,
+        ExactBytes::Flag25,
+        ExactBytes::Flag26,
+        ExactBytes::Flag27,
+        ExactBytes::Flag28,
+        ExactBytes::Flag29,
+        ExactBytes::Flag30,
+        ExactBytes::Flag31,
+        ExactBytes::Flag32,
+        ExactBytes::Flag33,
+        ExactBytes::Flag34,
+        ExactBytes::Flag35,
+        ExactBytes::Flag36,
+        ExactBytes::Flag37,
+        ExactBytes::Flag38,
+        ExactBytes::Flag39,
+        ExactBytes::Flag40,
+        ExactBytes::Flag41,
+        ExactBytes::Flag42,
+        ExactBytes::Flag43,
+        ExactBytes::Flag44,
+        ExactBytes::Flag45,
+        ExactBytes::Flag46,
+        ExactBytes::Flag47,
+        ExactBytes::Flag48,
+        ExactBytes::Flag49,
+        ExactBytes::Flag50,
+        ExactBytes::Flag51,
+        ExactBytes::Flag52,
+        ExactBytes::Flag53,
+        ExactBytes::Flag54,
+        ExactBytes::Flag55,
+        ExactBytes::Flag56,
+        ExactBytes::Flag57,
+        ExactBytes::Flag58,
+        ExactBytes::Flag59,
+        ExactBytes::Flag60,
+        ExactBytes::Flag61,
+        ExactBytes::Flag62,
+        ExactBytes::Flag63,
+        ExactBytes::Flag64,
+        ExactBytes::Flag65,
+        ExactBytes::Flag66,
+        ExactBytes::Flag67,
+        ExactBytes::Flag68,
+        ExactBytes::Flag69,
+        ExactBytes::Flag70,
+        ExactBytes::Flag71,
+        ExactBytes::Flag72,
+        ExactBytes::Flag73,
+        ExactBytes::Flag74,
+        ExactBytes::Flag75,
+        ExactBytes::Flag76,
+        ExactBytes::Flag77,
+        ExactBytes::Flag78,
+        ExactBytes::Flag79,
+        ExactBytes::Flag80,
+        ExactBytes::Flag81,
+        ExactBytes::Flag82,
+        ExactBytes::Flag83,
+        ExactBytes::Flag84,
+        ExactBytes::Flag85,
+        ExactBytes::Flag86,
+        ExactBytes::Flag87,
+        ExactBytes::Flag88,
+        ExactBytes::Flag89,
+        ExactBytes::Flag90,
+        ExactBytes::Flag91,
+        ExactBytes::Flag92,
+        ExactBytes::Flag93,
+        ExactBytes::Flag94,
+        ExactBytes::Flag95,
+        ExactBytes::Flag96,
+        ExactBytes::Flag97,
+        ExactBytes::Flag98,
+        ExactBytes::Flag99,
+        ExactBytes::Flag100,
+        ExactBytes::Flag101,
+        ExactBytes::Flag102,
+        ExactBytes::Flag103,
+        ExactBytes::Flag104,
+        ExactBytes::Flag105,
+        ExactBytes::Flag106,
+        ExactBytes::Flag107,
+        ExactBytes::Flag108,
+        ExactBytes::Flag109,
+        ExactBytes::Flag110,
+        ExactBytes::Flag111,
+        ExactBytes::Flag112,
+        ExactBytes::Flag113,
+        ExactBytes::Flag114,
+        ExactBytes::Flag115,
+        ExactBytes::Flag116,
+        ExactBytes::Flag117,
+        ExactBytes::Flag118,
+        ExactBytes::Flag119,
+    ];
+
+    pub const fn max_position() -> u8 {
+        119
+    }
+}
+
+impl core::str::FromStr for ExactBytes {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            b"Flag0" => Ok(ExactBytes::Flag0),
+            b"Flag1" => Ok(ExactBytes::Flag1),
+            b"Flag2" => Ok(ExactBytes::Flag2),
+            b"Flag3" => Ok(ExactBytes::Flag3),
+            b"Flag4" => Ok(ExactBytes::Flag4),
+            b"Flag5" => Ok(ExactBytes::Flag5),
+            b"Flag6" => Ok(ExactBytes::Flag6),
+            b"Flag7" => Ok(ExactBytes::Flag7),
+            b"Seven" => Ok(ExactBytes::Flag7),
+            b"Sept" => Ok(ExactBytes::Flag7),
+            b"S\xc3\xa9ptimo" => Ok(ExactBytes::Flag7),
+            b"Flag8" => Ok(ExactBytes::Flag8),
+            b"Flag9" => Ok(ExactBytes::Flag9),
+            b"Flag10" => Ok(ExactBytes::Flag10),
+            b"Flag11" => Ok(ExactBytes::Flag11),
+            b"Flag12" => Ok(ExactBytes::Flag12),
+            b"Flag13" => Ok(ExactBytes::Flag13),
+            b"Flag14" => Ok(ExactBytes::Flag14),
+            b"Flag15" => Ok(ExactBytes::Flag15),
+            b"Flag16" => Ok(ExactBytes::Flag16),
+            b"Flag17" => Ok(ExactBytes::Flag17),
+            b"Flag18" => Ok(ExactBytes::Flag18),
+            b"Flag19" => Ok(ExactBytes::Flag19),
+            b"Flag20" => Ok(ExactBytes::Flag20),
+            b"Flag21" => Ok(ExactBytes::Flag21),
+            b"Flag22" => Ok(ExactBytes::Flag22),
+            b"Flag23" => Ok(ExactBytes::Flag23),
+            b"Flag24" => Ok(ExactBytes::Flag24),
+            b"Flag25" => Ok(ExactBytes::Flag25),
+            b"Flag26" => Ok(ExactBytes::Flag26),
+            b"Flag27" => Ok(ExactBytes::Flag27),
+            b"Flag28" => Ok(ExactBytes::Flag28),
+            b"Flag29" => Ok(ExactBytes::Flag29),
+            b"Flag30" => Ok(ExactBytes::Flag30),
+            b"Flag31" => Ok(ExactBytes::Flag31),
+            b"Flag32" => Ok(ExactBytes::Flag32),
+            b"Flag33" => Ok(ExactBytes::Flag33),
+            b"Flag34" => Ok(ExactBytes::Flag34),
+            b"Flag35" => Ok(ExactBytes::Flag35),
+            b"Flag36" => Ok(ExactBytes::Flag36),
+            b"Flag37" => Ok(ExactBytes::Flag37),
+            b"Flag38" => Ok(ExactBytes::Flag38),
+            b"Flag39" => Ok(ExactBytes::Flag39),
+            b"Flag40" => Ok(ExactBytes::Flag40),
+            b"Flag41" => Ok(ExactBytes::Flag41),
+            b"Flag42" => Ok(ExactBytes::Flag42),
+            b"Flag43" => Ok(ExactBytes::Flag43),
+            b"Flag44" => Ok(ExactBytes::Flag44),
+            b"Flag45" => Ok(ExactBytes::Flag45),
+            b"Flag46" => Ok(ExactBytes::Flag46),
+            b"Flag47" => Ok(ExactBytes::Flag47),
+            b"Flag48" => Ok(ExactBytes::Flag48),
+            b"Flag49" => Ok(ExactBytes::Flag49),
+            b"Flag50" => Ok(ExactBytes::Flag50),
+            b"Flag51" => Ok(ExactBytes::Flag51),
+            b"Flag52" => Ok(ExactBytes::Flag52),
+            b"Flag53" => Ok(ExactBytes::Flag53),
+            b"Flag54" => Ok(ExactBytes::Flag54),
+            b"Flag55" => Ok(ExactBytes::Flag55),
+            b"Flag56" => Ok(ExactBytes::Flag56),
+            b"Flag57" => Ok(ExactBytes::Flag57),
+            b"Flag58" => Ok(ExactBytes::Flag58),
+            b"Flag59" => Ok(ExactBytes::Flag59),
+            b"Flag60" => Ok(ExactBytes::Flag60),
+            b"Flag61" => Ok(ExactBytes::Flag61),
+            b"Flag62" => Ok(ExactBytes::Flag62),
+            b"Flag63" => Ok(ExactBytes::Flag63),
+            b"Flag64" => Ok(ExactBytes::Flag64),
+            b"Flag65" => Ok(ExactBytes::Flag65),
+            b"Flag66" => Ok(ExactBytes::Flag66),
+            b"Flag67" => Ok(ExactBytes::Flag67),
+            b"Flag68" => Ok(ExactBytes::Flag68),
+            b"Flag69" => Ok(ExactBytes::Flag69),
+            b"Flag70" => Ok(ExactBytes::Flag70),
+            b"Flag71" => Ok(ExactBytes::Flag71),
+            b"Flag72" => Ok(ExactBytes::Flag72),
+            b"Flag73" => Ok(ExactBytes::Flag73),
+            b"Flag74" => Ok(ExactBytes::Flag74),
+            b"Flag75" => Ok(ExactBytes::Flag75),
+            b"Flag76" => Ok(ExactBytes::Flag76),
+            b"Flag77" => Ok(ExactBytes::Flag77),
+            b"Flag78" => Ok(ExactBytes::Flag78),
+            b"Flag79" => Ok(ExactBytes::Flag79),
+            b"Flag80" => Ok(ExactBytes::Flag80),
+            b"Flag81" => Ok(ExactBytes::Flag81),
+            b"Flag82" => Ok(ExactBytes::Flag82),
+            b"Flag83" => Ok(ExactBytes::Flag83),
+            b"Flag84" => Ok(ExactBytes::Flag84),
+            b"Flag85" => Ok(ExactBytes::Flag85),
+            b"Flag86" => Ok(ExactBytes::Flag86),
+            b"Flag87" => Ok(ExactBytes::Flag87),
+            b"Flag88" => Ok(ExactBytes::Flag88),
+            b"Flag89" => Ok(ExactBytes::Flag89),
+            b"Flag90" => Ok(ExactBytes::Flag90),
+            b"Flag91" => Ok(ExactBytes::Flag91),
+            b"Flag92" => Ok(ExactBytes::Flag92),
+            b"Flag93" => Ok(ExactBytes::Flag93),
+            b"Flag94" => Ok(ExactBytes::Flag94),
+            b"Flag95" => Ok(ExactBytes::Flag95),
+            b"Flag96" => Ok(ExactBytes::Flag96),
+            b"Flag97" => Ok(ExactBytes::Flag97),
+            b"Flag98" => Ok(ExactBytes::Flag98),
+            b"Flag99" => Ok(ExactBytes::Flag99),
+            b"Flag100" => Ok(ExactBytes::Flag100),
+            b"Flag101" => Ok(ExactBytes::Flag101),
+            b"Flag102" => Ok(ExactBytes::Flag102),
+            b"Flag103" => Ok(ExactBytes::Flag103),
+            b"Flag104" => Ok(ExactBytes::Flag104),
+            b"Flag105" => Ok(ExactBytes::Flag105),
+            b"Flag106" => Ok(ExactBytes::Flag106),
+            b"Flag107" => Ok(ExactBytes::Flag107),
+            b"Flag108" => Ok(ExactBytes::Flag108),
+            b"Flag109" => Ok(ExactBytes::Flag109),
+            b"Flag110" => Ok(ExactBytes::Flag110),
+            b"Flag111" => Ok(ExactBytes::Flag111),
+            b"Flag112" => Ok(ExactBytes::Flag112),
+            b"Flag113" => Ok(ExactBytes::Flag113),
+            b"Flag114" => Ok(ExactBytes::Flag114),
+            b"Flag115" => Ok(ExactBytes::Flag115),
+            b"Flag116" => Ok(ExactBytes::Flag116),
+            b"Flag117" => Ok(ExactBytes::Flag117),
+            b"Flag118" => Ok(ExactBytes::Flag118),
+            b"Flag119" => Ok(ExactBytes::Flag119),
+            _ => Err(bairiak::FlagParseError::new::<ExactBytes>("ExactBytes", s)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum FoldedMatch {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+    Flag8 = 8,
+    Flag9 = 9,
+    Flag10 = 10,
+    Flag11 = 11,
+    Flag12 = 12,
+    Flag13 = 13,
+    Flag14 = 14,
+    Flag15 = 15,
+    Flag16 = 16,
+    Flag17 = 17,
+    Flag18 = 18,
+    Flag19 = 19,
+    Flag20 = 20,
+    Flag21 = 21,
+    Flag22 = 22,
+    Flag23 = 23,
+    Flag24 = 24,
+    Flag25 = 25,
+    Flag26 = 26,
+    Flag27 = 27,
+    Flag28 = 28,
+    Flag29 = 29,
+    Flag30 = 30,
+    Flag31 = 31,
+    Flag32 = 32,
+    Flag33 = 33,
+    Flag34 = 34,
+    Flag35 = 35,
+    Flag36 = 36,
+    Flag37 = 37,
+    Flag38 = 38,
+    Flag39 = 39,
+    Flag40 = 40,
+    Flag41 = 41,
+    Flag42 = 42,
+    Flag43 = 43,
+    Flag44 = 44,
+    Flag45 = 45,
+    Flag46 = 46,
+    Flag47 = 47,
+    Flag48 = 48,
+    Flag49 = 49,
+    Flag50 = 50,
+    Flag51 = 51,
+    Flag52 = 52,
+    Flag53 = 53,
+    Flag54 = 54,
+    Flag55 = 55,
+    Flag56 = 56,
+    Flag57 = 57,
+    Flag58 = 58,
+    Flag59 = 59,
+    Flag60 = 60,
+    Flag61 = 61,
+    Flag62 = 62,
+    Flag63 = 63,
+    Flag64 = 64,
+    Flag65 = 65,
+    Flag66 = 66,
+    Flag67 = 67,
+    Flag68 = 68,
+    Flag69 = 69,
+    Flag70 = 70,
+    Flag71 = 71,
+    Flag72 = 72,
+    Flag73 = 73,
+    Flag74 = 74,
+    Flag75 = 75,
+    Flag76 = 76,
+    Flag77 = 77,
+    Flag78 = 78,
+    Flag79 = 79,
+    Flag80 = 80,
+    Flag81 = 81,
+    Flag82 = 82,
+    Flag83 = 83,
+    Flag84 = 84,
+    Flag85 = 85,
+    Flag86 = 86,
+    Flag87 = 87,
+    Flag88 = 88,
+    Flag89 = 89,
+    Flag90 = 90,
+    Flag91 = 91,
+    Flag92 = 92,
+    Flag93 = 93,
+    Flag94 = 94,
+    Flag95 = 95,
+    Flag96 = 96,
+    Flag97 = 97,
+    Flag98 = 98,
+    Flag99 = 99,
+    Flag100 = 100,
+    Flag101 = 101,
+    Flag102 = 102,
+    Flag103 = 103,
+    Flag104 = 104,
+    Flag105 = 105,
+    Flag106 = 106,
+    Flag107 = 107,
+    Flag108 = 108,
+    Flag109 = 109,
+    Flag110 = 110,
+    Flag111 = 111,
+    Flag112 = 112,
+    Flag113 = 113,
+    Flag114 = 114,
+    Flag115 = 115,
+    Flag116 = 116,
+    Flag117 = 117,
+    Flag118 = 118,
+    Flag119 = 119,
+}
+
+impl BairiakEnum for FoldedMatch {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U128(0u128)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(FoldedMatch::Flag0),
+            1 => Some(FoldedMatch::Flag1),
+            2 => Some(FoldedMatch::Flag2),
+            3 => Some(FoldedMatch::Flag3),
+            4 => Some(FoldedMatch::Flag4),
+            5 => Some(FoldedMatch::Flag5),
+            6 => Some(FoldedMatch::Flag6),
+            7 => Some(FoldedMatch::Flag7),
+            8 => Some(FoldedMatch::Flag8),
+            9 => Some(FoldedMatch::Flag9),
+            10 => Some(FoldedMatch::Flag10),
+            11 => Some(FoldedMatch::Flag11),
+            12 => Some(FoldedMatch::Flag12),
+            13 => Some(FoldedMatch::Flag13),
+            14 => Some(FoldedMatch::Flag14),
+            15 => Some(FoldedMatch::Flag15),
+            16 => Some(FoldedMatch::Flag16),
+            17 => Some(FoldedMatch::Flag17),
+            18 => Some(FoldedMatch::Flag18),
+            19 => Some(FoldedMatch::Flag19),
+            20 => Some(FoldedMatch::Flag20),
+            21 => Some(FoldedMatch::Flag21),
+            22 => Some(FoldedMatch::Flag22),
+            23 => Some(FoldedMatch::Flag23),
+            24 => Some(FoldedMatch::Flag24),
+            25 => Some(FoldedMatch::Flag25),
+            26 => Some(FoldedMatch::Flag26),
+            27 => Some(FoldedMatch::Flag27),
+            28 => Some(FoldedMatch::Flag28),
+            29 => Some(FoldedMatch::Flag29),
+            30 => Some(FoldedMatch::Flag30),
+            31 => Some(FoldedMatch::Flag31),
+            32 => Some(FoldedMatch::Flag32),
+            33 => Some(FoldedMatch::Flag33),
+            34 => Some(FoldedMatch::Flag34),
+            35 => Some(FoldedMatch::Flag35),
+            36 => Some(FoldedMatch::Flag36),
+            37 => Some(FoldedMatch::Flag37),
+            38 => Some(FoldedMatch::Flag38),
+            39 => Some(FoldedMatch::Flag39),
+            40 => Some(FoldedMatch::Flag40),
+            41 => Some(FoldedMatch::Flag41),
+            42 => Some(FoldedMatch::Flag42),
+            43 => Some(FoldedMatch::Flag43),
+            44 => Some(FoldedMatch::Flag44),
+            45 => Some(FoldedMatch::Flag45),
+            46 => Some(FoldedMatch::Flag46),
+            47 => Some(FoldedMatch::Flag47),
+            48 => Some(FoldedMatch::Flag48),
+            49 => Some(FoldedMatch::Flag49),
+            50 => Some(FoldedMatch::Flag50),
+            51 => Some(FoldedMatch::Flag51),
+            52 => Some(FoldedMatch::Flag52),
+            53 => Some(FoldedMatch::Flag53),
+            54 => Some(FoldedMatch::Flag54),
+            55 => Some(FoldedMatch::Flag55),
+            56 => Some(FoldedMatch::Flag56),
+            57 => Some(FoldedMatch::Flag57),
+            58 => Some(FoldedMatch::Flag58),
+            59 => Some(FoldedMatch::Flag59),
+            60 => Some(FoldedMatch::Flag60),
+            61 => Some(FoldedMatch::Flag61),
+            62 => Some(FoldedMatch::Flag62),
+            63 => Some(FoldedMatch::Flag63),
+            64 => Some(FoldedMatch::Flag64),
+            65 => Some(FoldedMatch::Flag65),
+            66 => Some(FoldedMatch::Flag66),
+            67 => Some(FoldedMatch::Flag67),
+            68 => Some(FoldedMatch::Flag68),
+            69 => Some(FoldedMatch::Flag69),
+            70 => Some(FoldedMatch::Flag70),
+            71 => Some(FoldedMatch::Flag71),
+            72 => Some(FoldedMatch::Flag72),
+            73 => Some(FoldedMatch::Flag73),
+            74 => Some(FoldedMatch::Flag74),
+            75 => Some(FoldedMatch::Flag75),
+            76 => Some(FoldedMatch::Flag76),
+            77 => Some(FoldedMatch::Flag77),
+            78 => Some(FoldedMatch::Flag78),
+            79 => Some(FoldedMatch::Flag79),
+            80 => Some(FoldedMatch::Flag80),
+            81 => Some(FoldedMatch::Flag81),
+            82 => Some(FoldedMatch::Flag82),
+            83 => Some(FoldedMatch::Flag83),
+            84 => Some(FoldedMatch::Flag84),
+            85 => Some(FoldedMatch::Flag85),
+            86 => Some(FoldedMatch::Flag86),
+            87 => Some(FoldedMatch::Flag87),
+            88 => Some(FoldedMatch::Flag88),
+            89 => Some(FoldedMatch::Flag89),
+            90 => Some(FoldedMatch::Flag90),
+            91 => Some(FoldedMatch::Flag91),
+            92 => Some(FoldedMatch::Flag92),
+            93 => Some(FoldedMatch::Flag93),
+            94 => Some(FoldedMatch::Flag94),
+            95 => Some(FoldedMatch::Flag95),
+            96 => Some(FoldedMatch::Flag96),
+            97 => Some(FoldedMatch::Flag97),
+            98 => Some(FoldedMatch::Flag98),
+            99 => Some(FoldedMatch::Flag99),
+            100 => Some(FoldedMatch::Flag100),
+            101 => Some(FoldedMatch::Flag101),
+            102 => Some(FoldedMatch::Flag102),
+            103 => Some(FoldedMatch::Flag103),
+            104 => Some(FoldedMatch::Flag104),
+            105 => Some(FoldedMatch::Flag105),
+            106 => Some(FoldedMatch::Flag106),
+            107 => Some(FoldedMatch::Flag107),
+            108 => Some(FoldedMatch::Flag108),
+            109 => Some(FoldedMatch::Flag109),
+            110 => Some(FoldedMatch::Flag110),
+            111 => Some(FoldedMatch::Flag111),
+            112 => Some(FoldedMatch::Flag112),
+            113 => Some(FoldedMatch::Flag113),
+            114 => Some(FoldedMatch::Flag114),
+            115 => Some(FoldedMatch::Flag115),
+            116 => Some(FoldedMatch::Flag116),
+            117 => Some(FoldedMatch::Flag117),
+            118 => Some(FoldedMatch::Flag118),
+            119 => Some(FoldedMatch::Flag119),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            FoldedMatch::Flag0 => "Flag0",
+            FoldedMatch::Flag1 => "Flag1",
+            FoldedMatch::Flag2 => "Flag2",
+            FoldedMatch::Flag3 => "Flag3",
+            FoldedMatch::Flag4 => "Flag4",
+            FoldedMatch::Flag5 => "Flag5",
+            FoldedMatch::Flag6 => "Flag6",
+            FoldedMatch::Flag7 => "Flag7",
+            FoldedMatch::Flag8 => "Flag8",
+            FoldedMatch::Flag9 => "Flag9",
+            FoldedMatch::Flag10 => "Flag10",
+            FoldedMatch::Flag11 => "Flag11",
+            FoldedMatch::Flag12 => "Flag12",
+            FoldedMatch::Flag13 => "Flag13",
+            FoldedMatch::Flag14 => "Flag14",
+            FoldedMatch::Flag15 => "Flag15",
+            FoldedMatch::Flag16 => "Flag16",
+            FoldedMatch::Flag17 => "Flag17",
+            FoldedMatch::Flag18 => "Flag18",
+            FoldedMatch::Flag19 => "Flag19",
+            FoldedMatch::Flag20 => "Flag20",
+            FoldedMatch::Flag21 => "Flag21",
+            FoldedMatch::Flag22 => "Flag22",
+            FoldedMatch::Flag23 => "Flag23",
+            FoldedMatch::Flag24 => "Flag24",
+            FoldedMatch::Flag25 => "Flag25",
+            FoldedMatch::Flag26 => "Flag26",
+            FoldedMatch::Flag27 => "Flag27",
+            FoldedMatch::Flag28 => "Flag28",
+            FoldedMatch::Flag29 => "Flag29",
+            FoldedMatch::Flag30 => "Flag30",
+            FoldedMatch::Flag31 => "Flag31",
+            FoldedMatch::Flag32 => "Flag32",
+            FoldedMatch::Flag33 => "Flag33",
+            FoldedMatch::Flag34 => "Flag34",
+            FoldedMatch::Flag35 => "Flag35",
+            FoldedMatch::Flag36 => "Flag36",
+            FoldedMatch::Flag37 => "Flag37",
+            FoldedMatch::Flag38 => "Flag38",
+            FoldedMatch::Flag39 => "Flag39",
+            FoldedMatch::Flag40 => "Flag40",
+            FoldedMatch::Flag41 => "Flag41",
+            FoldedMatch::Flag42 => "Flag42",
+            FoldedMatch::Flag43 => "Flag43",
+            FoldedMatch::Flag44 => "Flag44",
+            FoldedMatch::Flag45 => "Flag45",
+            FoldedMatch::Flag46 => "Flag46",
+            FoldedMatch::Flag47 => "Flag47",
+            FoldedMatch::Flag48 => "Flag48",
+            FoldedMatch::Flag49 => "Flag49",
+            FoldedMatch::Flag50 => "Flag50",
+            FoldedMatch::Flag51 => "Flag51",
+            FoldedMatch::Flag52 => "Flag52",
+            FoldedMatch::Flag53 => "Flag53",
+            FoldedMatch::Flag54 => "Flag54",
+            FoldedMatch::Flag55 => "Flag55",
+            FoldedMatch::Flag56 => "Flag56",
+            FoldedMatch::Flag57 => "Flag57",
+            FoldedMatch::Flag58 => "Flag58",
+            FoldedMatch::Flag59 => "Flag59",
+            FoldedMatch::Flag60 => "Flag60",
+            FoldedMatch::Flag61 => "Flag61",
+            FoldedMatch::Flag62 => "Flag62",
+            FoldedMatch::Flag63 => "Flag63",
+            FoldedMatch::Flag64 => "Flag64",
+            FoldedMatch::Flag65 => "Flag65",
+            FoldedMatch::Flag66 => "Flag66",
+            FoldedMatch::Flag67 => "Flag67",
+            FoldedMatch::Flag68 => "Flag68",
+            FoldedMatch::Flag69 => "Flag69",
+            FoldedMatch::Flag70 => "Flag70",
+            FoldedMatch::Flag71 => "Flag71",
+            FoldedMatch::Flag72 => "Flag72",
+            FoldedMatch::Flag73 => "Flag73",
+            FoldedMatch::Flag74 => "Flag74",
+            FoldedMatch::Flag75 => "Flag75",
+            FoldedMatch::Flag76 => "Flag76",
+            FoldedMatch::Flag77 => "Flag77",
+            FoldedMatch::Flag78 => "Flag78",
+            FoldedMatch::Flag79 => "Flag79",
+            FoldedMatch::Flag80 => "Flag80",
+            FoldedMatch::Flag81 => "Flag81",
+            FoldedMatch::Flag82 => "Flag82",
+            FoldedMatch::Flag83 => "Flag83",
+            FoldedMatch::Flag84 => "Flag84",
+            FoldedMatch::Flag85 => "Flag85",
+            FoldedMatch::Flag86 => "Flag86",
+            FoldedMatch::Flag87 => "Flag87",
+            FoldedMatch::Flag88 => "Flag88",
+            FoldedMatch::Flag89 => "Flag89",
+            FoldedMatch::Flag90 => "Flag90",
+            FoldedMatch::Flag91 => "Flag91",
+            FoldedMatch::Flag92 => "Flag92",
+            FoldedMatch::Flag93 => "Flag93",
+            FoldedMatch::Flag94 => "Flag94",
+            FoldedMatch::Flag95 => "Flag95",
+            FoldedMatch::Flag96 => "Flag96",
+            FoldedMatch::Flag97 => "Flag97",
+            FoldedMatch::Flag98 => "Flag98",
+            FoldedMatch::Flag99 => "Flag99",
+            FoldedMatch::Flag100 => "Flag100",
+            FoldedMatch::Flag101 => "Flag101",
+            FoldedMatch::Flag102 => "Flag102",
+            FoldedMatch::Flag103 => "Flag103",
+            FoldedMatch::Flag104 => "Flag104",
+            FoldedMatch::Flag105 => "Flag105",
+            FoldedMatch::Flag106 => "Flag106",
+            FoldedMatch::Flag107 => "Flag107",
+            FoldedMatch::Flag108 => "Flag108",
+            FoldedMatch::Flag109 => "Flag109",
+            FoldedMatch::Flag110 => "Flag110",
+            FoldedMatch::Flag111 => "Flag111",
+            FoldedMatch::Flag112 => "Flag112",
+            FoldedMatch::Flag113 => "Flag113",
+            FoldedMatch::Flag114 => "Flag114",
+            FoldedMatch::Flag115 => "Flag115",
+            FoldedMatch::Flag116 => "Flag116",
+            FoldedMatch::Flag117 => "Flag117",
+            FoldedMatch::Flag118 => "Flag118",
+            FoldedMatch::Flag119 => "Flag119",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        FoldedMatch::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        FoldedMatch::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl FoldedMatch {
+    pub const COUNT: usize = 120;
+    pub const ALL_MASK: u128 = 0xffffffffffffffffffffffffffffff;
+    pub const VARIANTS: [FoldedMatch; FoldedMatch::COUNT] = [
+        FoldedMatch::Flag0,
+        FoldedMatch::Flag1,
+        FoldedMatch::Flag2,
+        FoldedMatch::Flag3,
+        FoldedMatch::Flag4,
+        FoldedMatch::Flag5,
+        FoldedMatch::Flag6,
+        FoldedMatch::Flag7,
+        FoldedMatch::Flag8,
+        FoldedMatch::Flag9,
+        FoldedMatch::Flag10,
+        FoldedMatch::Flag11,
+        FoldedMatch::Flag12,
+        FoldedMatch::Flag13,
+        FoldedMatch::Flag14,
+        FoldedMatch::Flag15,
+        FoldedMatch::Flag16,
+        FoldedMatch::Flag17,
+        FoldedMatch::Flag18,
+        FoldedMatch::Flag19,
+        FoldedMatch::Flag20,
+        FoldedMatch::Flag21,
+        FoldedMatch::Flag22,
+        FoldedMatch::Flag23,
+        FoldedMatch::Flag24,
+        FoldedMatch::Flag25,
+        FoldedMatch::Flag26,
+        FoldedMatch::Flag27,
+        FoldedMatch::Flag28,
+        FoldedMatch::Flag29,
+        FoldedMatch::Flag30,
+        FoldedMatch::Flag31,
+        FoldedMatch::Flag32,
+        FoldedMatch::Flag33,
+        FoldedMatch::Flag34,
+        FoldedMatch::Flag35,
+        FoldedMatch::Flag36,
+        FoldedMatch::Flag37,
+        FoldedMatch::Flag38,
+        FoldedMatch::Flag39,
+        FoldedMatch::Flag40,
+        FoldedMatch::Flag41,
+        FoldedMatch::Flag42,
+        FoldedMatch::Flag43,
+        FoldedMatch::Flag44,
+        FoldedMatch::Flag45,
+        FoldedMatch::Flag46,
+        FoldedMatch::Flag47,
+        FoldedMatch::Flag48,
+        FoldedMatch::Flag49,
+        FoldedMatch::Flag50,
+        FoldedMatch::Flag51,
+        FoldedMatch::Flag52,
+        FoldedMatch::Flag53,
+        FoldedMatch::Flag54,
+        FoldedMatch::Flag55,
+        FoldedMatch::Flag56,
+        FoldedMatch::Flag57,
+        FoldedMatch::Flag58,
+        FoldedMatch::Flag59,
+        FoldedMatch::Flag60,
+        FoldedMatch::Flag61,
+        FoldedMatch::Flag62,
+        FoldedMatch::Flag63,
+        FoldedMatch::Flag64,
+        FoldedMatch::Flag65,
+        FoldedMatch::Flag66,
+        FoldedMatch::Flag67,
+        FoldedMatch::Flag68,
+        FoldedMatch::Flag69,
+        FoldedMatch::Flag70,
+        FoldedMatch::Flag71,
+        FoldedMatch::Flag72,
+        FoldedMatch::Flag73,
+        FoldedMatch::Flag74,
+        FoldedMatch::Flag75,
+        FoldedMatch::Flag76,
+        FoldedMatch::Flag77,
+        FoldedMatch::Flag78,
+        FoldedMatch::Flag79,
+        FoldedMatch::Flag80,
+        FoldedMatch::Flag81,
+        FoldedMatch::Flag82,
+        FoldedMatch::Flag83,
+        FoldedMatch::Flag84,
+        FoldedMatch::Flag85,
+        FoldedMatch::Flag86,
+        FoldedMatch::Flag87,
+        FoldedMatch::Flag88,
+        FoldedMatch::Flag89,
+        FoldedMatch::Flag90,
+        FoldedMatch::Flag91,
+        FoldedMatch::Flag92,
+        FoldedMatch::Flag93,
+        FoldedMatch::Flag94,
+        FoldedMatch::Flag95,
+        FoldedMatch::Flag96,
+        FoldedMatch::Flag97,
+        FoldedMatch::Flag98,
+        FoldedMatch::Flag99,
+        FoldedMatch::Flag100,
+        FoldedMatch::Flag101,
+        FoldedMatch::Flag102,
+        FoldedMatch::Flag103,
+        FoldedMatch::Flag104,
+        FoldedMatch::Flag105,
+        FoldedMatch::Flag106,
+        FoldedMatch::Flag107,
+        FoldedMatch::Flag108,
+        FoldedMatch::Flag109,
+        FoldedMatch::Flag110,
+        FoldedMatch::Flag111,
+        FoldedMatch::Flag112,
+        FoldedMatch::Flag113,
+        FoldedMatch::Flag114,
+        FoldedMatch::Flag115,
+        FoldedMatch::Flag116,
+        FoldedMatch::Flag117,
+        FoldedMatch::Flag118,
+        FoldedMatch::Flag119,
+    ];
+
+    pub const fn max_position() -> u8 {
+        119
+    }
+}
+
+impl core::str::FromStr for FoldedMatch {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            _ if s.eq_ignore_ascii_case("flag0") => Ok(FoldedMatch::Flag0),
+            _ if s.eq_ignore_ascii_case("flag1") => Ok(FoldedMatch::Flag1),
+            _ if s.eq_ignore_ascii_case("flag2") => Ok(FoldedMatch::Flag2),
+            _ if s.eq_ignore_ascii_case("flag3") => Ok(FoldedMatch::Flag3),
+            _ if s.eq_ignore_ascii_case("flag4") => Ok(FoldedMatch::Flag4),
+            _ if s.eq_ignore_ascii_case("flag5") => Ok(FoldedMatch::Flag5),
+            _ if s.eq_ignore_ascii_case("flag6") => Ok(FoldedMatch::Flag6),
+            _ if s.eq_ignore_ascii_case("flag7") => Ok(FoldedMatch::Flag7),
+            _ if s.eq_ignore_ascii_case("seven") => Ok(FoldedMatch::Flag7),
+            _ if s.eq_ignore_ascii_case("sept") => Ok(FoldedMatch::Flag7),
+            _ if s.eq_ignore_ascii_case("séptimo") => Ok(FoldedMatch::Flag7),
+            _ if s.eq_ignore_ascii_case("flag8") => Ok(FoldedMatch::Flag8),
+            _ if s.eq_ignore_ascii_case("flag9") => Ok(FoldedMatch::Flag9),
+            _ if s.eq_ignore_ascii_case("flag10") => Ok(FoldedMatch::Flag10),
+            _ if s.eq_ignore_ascii_case("flag11") => Ok(FoldedMatch::Flag11),
+            _ if s.eq_ignore_ascii_case("flag12") => Ok(FoldedMatch::Flag12),
+            _ if s.eq_ignore_ascii_case("flag13") => Ok(FoldedMatch::Flag13),
+            _ if s.eq_ignore_ascii_case("flag14") => Ok(FoldedMatch::Flag14),
+            _ if s.eq_ignore_ascii_case("flag15") => Ok(FoldedMatch::Flag15),
+            _ if s.eq_ignore_ascii_case("flag16") => Ok(FoldedMatch::Flag16),
+            _ if s.eq_ignore_ascii_case("flag17") => Ok(FoldedMatch::Flag17),
+            _ if s.eq_ignore_ascii_case("flag18") => Ok(FoldedMatch::Flag18),
+            _ if s.eq_ignore_ascii_case("flag19") => Ok(FoldedMatch::Flag19),
+            _ if s.eq_ignore_ascii_case("flag20") => Ok(FoldedMatch::Flag20),
+            _ if s.eq_ignore_ascii_case("flag21") => Ok(FoldedMatch::Flag21),
+            _ if s.eq_ignore_ascii_case("flag22") => Ok(FoldedMatch::Flag22),
+            _ if s.eq_ignore_ascii_case("flag23") => Ok(FoldedMatch::Flag23),
+            _ if s.eq_ignore_ascii_case("flag24") => Ok(FoldedMatch::Flag24),
+            _ if s.eq_ignore_ascii_case("flag25") => Ok(FoldedMatch::Flag25),
+            _ if s.eq_ignore_ascii_case("flag26") => Ok(FoldedMatch::Flag26),
+            _ if s.eq_ignore_ascii_case("flag27") => Ok(FoldedMatch::Flag27),
+            _ if s.eq_ignore_ascii_case("flag28") => Ok(FoldedMatch::Flag28),
+            _ if s.eq_ignore_ascii_case("flag29") => Ok(FoldedMatch::Flag29),
+            _ if s.eq_ignore_ascii_case("flag30") => Ok(FoldedMatch::Flag30),
+            _ if s.eq_ignore_ascii_case("flag31") => Ok(FoldedMatch::Flag31),
+            _ if s.eq_ignore_ascii_case("flag32") => Ok(FoldedMatch::Flag32),
+            _ if s.eq_ignore_ascii_case("flag33") => Ok(FoldedMatch::Flag33),
+            _ if s.eq_ignore_ascii_case("flag34") => Ok(FoldedMatch::Flag34),
+            _ if s.eq_ignore_ascii_case("flag35") => Ok(FoldedMatch::Flag35),
+            _ if s.eq_ignore_ascii_case("flag36") => Ok(FoldedMatch::Flag36),
+            _ if s.eq_ignore_ascii_case("flag37") => Ok(FoldedMatch::Flag37),
+            _ if s.eq_ignore_ascii_case("flag38") => Ok(FoldedMatch::Flag38),
+            _ if s.eq_ignore_ascii_case("flag39") => Ok(FoldedMatch::Flag39),
+            _ if s.eq_ignore_ascii_case("flag40") => Ok(FoldedMatch::Flag40),
+            _ if s.eq_ignore_ascii_case("flag41") => Ok(FoldedMatch::Flag41),
+            _ if s.eq_ignore_ascii_case("flag42") => Ok(FoldedMatch::Flag42),
+            _ if s.eq_ignore_ascii_case("flag43") => Ok(FoldedMatch::Flag43),
+            _ if s.eq_ignore_ascii_case("flag44") => Ok(FoldedMatch::Flag44),
+            _ if s.eq_ignore_ascii_case("flag45") => Ok(FoldedMatch::Flag45),
+            _ if s.eq_ignore_ascii_case("flag46") => Ok(FoldedMatch::Flag46),
+            _ if s.eq_ignore_ascii_case("flag47") => Ok(FoldedMatch::Flag47),
+            _ if s.eq_ignore_ascii_case("flag48") => Ok(FoldedMatch::Flag48),
+            _ if s.eq_ignore_ascii_case("flag49") => Ok(FoldedMatch::Flag49),
+            _ if s.eq_ignore_ascii_case("flag50") => Ok(FoldedMatch::Flag50),
+            _ if s.eq_ignore_ascii_case("flag51") => Ok(FoldedMatch::Flag51),
+            _ if s.eq_ignore_ascii_case("flag52") => Ok(FoldedMatch::Flag52),
+            _ if s.eq_ignore_ascii_case("flag53") => Ok(FoldedMatch::Flag53),
+            _ if s.eq_ignore_ascii_case("flag54") => Ok(FoldedMatch::Flag54),
+            _ if s.eq_ignore_ascii_case("flag55") => Ok(FoldedMatch::Flag55),
+            _ if s.eq_ignore_ascii_case("flag56") => Ok(FoldedMatch::Flag56),
+            _ if s.eq_ignore_ascii_case("flag57") => Ok(FoldedMatch::Flag57),
+            _ if s.eq_ignore_ascii_case("flag58") => Ok(FoldedMatch::Flag58),
+            _ if s.eq_ignore_ascii_case("flag59") => Ok(FoldedMatch::Flag59),
+            _ if s.eq_ignore_ascii_case("flag60") => Ok(FoldedMatch::Flag60),
+            _ if s.eq_ignore_ascii_case("flag61") => Ok(FoldedMatch::Flag61),
+            _ if s.eq_ignore_ascii_case("flag62") => Ok(FoldedMatch::Flag62),
+            _ if s.eq_ignore_ascii_case("flag63") => Ok(FoldedMatch::Flag63),
+            _ if s.eq_ignore_ascii_case("flag64") => Ok(FoldedMatch::Flag64),
+            _ if s.eq_ignore_ascii_case("flag65") => Ok(FoldedMatch::Flag65),
+            _ if s.eq_ignore_ascii_case("flag66") => Ok(FoldedMatch::Flag66),
+            _ if s.eq_ignore_ascii_case("flag67") => Ok(FoldedMatch::Flag67),
+            _ if s.eq_ignore_ascii_case("flag68") => Ok(FoldedMatch::Flag68),
+            _ if s.eq_ignore_ascii_case("flag69") => Ok(FoldedMatch::Flag69),
+            _ if s.eq_ignore_ascii_case("flag70") => Ok(FoldedMatch::Flag70),
+            _ if s.eq_ignore_ascii_case("flag71") => Ok(FoldedMatch::Flag71),
+            _ if s.eq_ignore_ascii_case("flag72") => Ok(FoldedMatch::Flag72),
+            _ if s.eq_ignore_ascii_case("flag73") => Ok(FoldedMatch::Flag73),
+            _ if s.eq_ignore_ascii_case("flag74") => Ok(FoldedMatch::Flag74),
+            _ if s.eq_ignore_ascii_case("flag75") => Ok(FoldedMatch::Flag75),
+            _ if s.eq_ignore_ascii_case("flag76") => Ok(FoldedMatch::Flag76),
+            _ if s.eq_ignore_ascii_case("flag77") => Ok(FoldedMatch::Flag77),
+            _ if s.eq_ignore_ascii_case("flag78") => Ok(FoldedMatch::Flag78),
+            _ if s.eq_ignore_ascii_case("flag79") => Ok(FoldedMatch::Flag79),
+            _ if s.eq_ignore_ascii_case("flag80") => Ok(FoldedMatch::Flag80),
+            _ if s.eq_ignore_ascii_case("flag81") => Ok(FoldedMatch::Flag81),
+            _ if s.eq_ignore_ascii_case("flag82") => Ok(FoldedMatch::Flag82),
+            _ if s.eq_ignore_ascii_case("flag83") => Ok(FoldedMatch::Flag83),
+            _ if s.eq_ignore_ascii_case("flag84") => Ok(FoldedMatch::Flag84),
+            _ if s.eq_ignore_ascii_case("flag85") => Ok(FoldedMatch::Flag85),
+            _ if s.eq_ignore_ascii_case("flag86") => Ok(FoldedMatch::Flag86),
+            _ if s.eq_ignore_ascii_case("flag87") => Ok(FoldedMatch::Flag87),
+            _ if s.eq_ignore_ascii_case("flag88") => Ok(FoldedMatch::Flag88),
+            _ if s.eq_ignore_ascii_case("flag89") => Ok(FoldedMatch::Flag89),
+            _ if s.eq_ignore_ascii_case("flag90") => Ok(FoldedMatch::Flag90),
+            _ if s.eq_ignore_ascii_case("flag91") => Ok(FoldedMatch::Flag91),
+            _ if s.eq_ignore_ascii_case("flag92") => Ok(FoldedMatch::Flag92),
+            _ if s.eq_ignore_ascii_case("flag93") => Ok(FoldedMatch::Flag93),
+            _ if s.eq_ignore_ascii_case("flag94") => Ok(FoldedMatch::Flag94),
+            _ if s.eq_ignore_ascii_case("flag95") => Ok(FoldedMatch::Flag95),
+            _ if s.eq_ignore_ascii_case("flag96") => Ok(FoldedMatch::Flag96),
+            _ if s.eq_ignore_ascii_case("flag97") => Ok(FoldedMatch::Flag97),
+            _ if s.eq_ignore_ascii_case("flag98") => Ok(FoldedMatch::Flag98),
+            _ if s.eq_ignore_ascii_case("flag99") => Ok(FoldedMatch::Flag99),
+            _ if s.eq_ignore_ascii_case("flag100") => Ok(FoldedMatch::Flag100),
+            _ if s.eq_ignore_ascii_case("flag101") => Ok(FoldedMatch::Flag101),
+            _ if s.eq_ignore_ascii_case("flag102") => Ok(FoldedMatch::Flag102),
+            _ if s.eq_ignore_ascii_case("flag103") => Ok(FoldedMatch::Flag103),
+            _ if s.eq_ignore_ascii_case("flag104") => Ok(FoldedMatch::Flag104),
+            _ if s.eq_ignore_ascii_case("flag105") => Ok(FoldedMatch::Flag105),
+            _ if s.eq_ignore_ascii_case("flag106") => Ok(FoldedMatch::Flag106),
+            _ if s.eq_ignore_ascii_case("flag107") => Ok(FoldedMatch::Flag107),
+            _ if s.eq_ignore_ascii_case("flag108") => Ok(FoldedMatch::Flag108),
+            _ if s.eq_ignore_ascii_case("flag109") => Ok(FoldedMatch::Flag109),
+            _ if s.eq_ignore_ascii_case("flag110") => Ok(FoldedMatch::Flag110),
+            _ if s.eq_ignore_ascii_case("flag111") => Ok(FoldedMatch::Flag111),
+            _ if s.eq_ignore_ascii_case("flag112") => Ok(FoldedMatch::Flag112),
+            _ if s.eq_ignore_ascii_case("flag113") => Ok(FoldedMatch::Flag113),
+            _ if s.eq_ignore_ascii_case("flag114") => Ok(FoldedMatch::Flag114),
+            _ if s.eq_ignore_ascii_case("flag115") => Ok(FoldedMatch::Flag115),
+            _ if s.eq_ignore_ascii_case("flag116") => Ok(FoldedMatch::Flag116),
+            _ if s.eq_ignore_ascii_case("flag117") => Ok(FoldedMatch::Flag117),
+            _ if s.eq_ignore_ascii_case("flag118") => Ok(FoldedMatch::Flag118),
+            _ if s.eq_ignore_ascii_case("flag119") => Ok(FoldedMatch::Flag119),
+            _ => Err(bairiak::FlagParseError::new::<FoldedMatch>("FoldedMatch", s)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum FoldedBytes {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+    Flag8 = 8,
+    Flag9 = 9,
+    Flag10 = 10,
+    Flag11 = 11,
+    Flag12 = 12,
+    Flag13 = 13,
+    Flag14 = 14,
+    Flag15 = 15,
+    Flag16 = 16,
+    Flag17 = 17,
+    Flag18 = 18,
+    Flag19 = 19,
+    Flag20 = 20,
+    Flag21 = 21,
+    Flag22 = 22,
+    Flag23 = 23,
+    Flag24 = 24,
+    Flag25 = 25,
+    Flag26 = 26,
+    Flag27 = 27,
+    Flag28 = 28,
+    Flag29 = 29,
+    Flag30 = 30,
+    Flag31 = 31,
+    Flag32 = 32,
+    Flag33 = 33,
+    Flag34 = 34,
+    Flag35 = 35,
+    Flag36 = 36,
+    Flag37 = 37,
+    Flag38 = 38,
+    Flag39 = 39,
+    Flag40 = 40,
+    Flag41 = 41,
+    Flag42 = 42,
+    Flag43 = 43,
+    Flag44 = 44,
+    Flag45 = 45,
+    Flag46 = 46,
+    Flag47 = 47,
+    Flag48 = 48,
+    Flag49 = 49,
+    Flag50 = 50,
+    Flag51 = 51,
+    Flag52 = 52,
+    Flag53 = 53,
+    Flag54 = 54,
+    Flag55 = 55,
+    Flag56 = 56,
+    Flag57 = 57,
+    Flag58 = 58,
+    Flag59 = 59,
+    Flag60 = 60,
+    Flag61 = 61,
+    Flag62 = 62,
+    Flag63 = 63,
+    Flag64 = 64,
+    Flag65 = 65,
+    Flag66 = 66,
+    Flag67 = 67,
+    Flag68 = 68,
+    Flag69 = 69,
+    Flag70 = 70,
+    Flag71 = 71,
+    Flag72 = 72,
+    Flag73 = 73,
+    Flag74 = 74,
+    Flag75 = 75,
+    Flag76 = 76,
+    Flag77 = 77,
+    Flag78 = 78,
+    Flag79 = 79,
+    Flag80 = 80,
+    Flag81 = 81,
+    Flag82 = 82,
+    Flag83 = 83,
+    Flag84 = 84,
+    Flag85 = 85,
+    Flag86 = 86,
+    Flag87 = 87,
+    Flag88 = 88,
+    Flag89 = 89,
+    Flag90 = 90,
+    Flag91 = 91,
+    Flag92 = 92,
+    Flag93 = 93,
+    Flag94 = 94,
+    Flag95 = 95,
+    Flag96 = 96,
+    Flag97 = 97,
+    Flag98 = 98,
+    Flag99 = 99,
+    Flag100 = 100,
+    Flag101 = 101,
+    Flag102 = 102,
+    Flag103 = 103,
+    Flag104 = 104,
+    Flag105 = 105,
+    Flag106 = 106,
+    Flag107 = 107,
+    Flag108 = 108,
+    Flag109 = 109,
+    Flag110 = 110,
+    Flag111 = 111,
+    Flag112 = 112,
+    Flag113 = 113,
+    Flag114 = 114,
+    Flag115 = 115,
+    Flag116 = 116,
+    Flag117 = 117,
+    Flag118 = 118,
+    Flag119 = 119,
+}
+
+impl BairiakEnum for FoldedBytes {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U128(0u128)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(FoldedBytes::Flag0),
+            1 => Some(FoldedBytes::Flag1),
+            2 => Some(FoldedBytes::Flag2),
+            3 => Some(FoldedBytes::Flag3),
+            4 => Some(FoldedBytes::Flag4),
+            5 => Some(FoldedBytes::Flag5),
+            6 => Some(FoldedBytes::Flag6),
+            7 => Some(FoldedBytes::Flag7),
+            8 => Some(FoldedBytes::Flag8),
+            9 => Some(FoldedBytes::Flag9),
+            10 => Some(FoldedBytes::Flag10),
+            11 => Some(FoldedBytes::Flag11),
+            12 => Some(FoldedBytes::Flag12),
+            13 => Some(FoldedBytes::Flag13),
+            14 => Some(FoldedBytes::Flag14),
+            15 => Some(FoldedBytes::Flag15),
+            16 => Some(FoldedBytes::Flag16),
+            17 => Some(FoldedBytes::Flag17),
+            18 => Some(FoldedBytes::Flag18),
+            19 => Some(FoldedBytes::Flag19),
+            20 => Some(FoldedBytes::Flag20),
+            21 => Some(FoldedBytes::Flag21),
+            22 => Some(FoldedBytes::Flag22),
+            23 => Some(FoldedBytes::Flag23),
+            24 => Some(FoldedBytes::Flag24),
+            25 => Some(FoldedBytes::Flag25),
+            26 => Some(FoldedBytes::Flag26),
+            27 => Some(FoldedBytes::Flag27),
+            28 => Some(FoldedBytes::Flag28),
+            29 => Some(FoldedBytes::Flag29),
+            30 => Some(FoldedBytes::Flag30),
+            31 => Some(FoldedBytes::Flag31),
+            32 => Some(FoldedBytes::Flag32),
+            33 => Some(FoldedBytes::Flag33),
+            34 => Some(FoldedBytes::Flag34),
+            35 => Some(FoldedBytes::Flag35),
+            36 => Some(FoldedBytes::Flag36),
+            37 => Some(FoldedBytes::Flag37),
+            38 => Some(FoldedBytes::Flag38),
+            39 => Some(FoldedBytes::Flag39),
+            40 => Some(FoldedBytes::Flag40),
+            41 => Some(FoldedBytes::Flag41),
+            42 => Some(FoldedBytes::Flag42),
+            43 => Some(FoldedBytes::Flag43),
+            44 => Some(FoldedBytes::Flag44),
+            45 => Some(FoldedBytes::Flag45),
+            46 => Some(FoldedBytes::Flag46),
+            47 => Some(FoldedBytes::Flag47),
+            48 => Some(FoldedBytes::Flag48),
+            49 => Some(FoldedBytes::Flag49),
+            50 => Some(FoldedBytes::Flag50),
+            51 => Some(FoldedBytes::Flag51),
+            52 => Some(FoldedBytes::Flag52),
+            53 => Some(FoldedBytes::Flag53),
+            54 => Some(FoldedBytes::Flag54),
+            55 => Some(FoldedBytes::Flag55),
+            56 => Some(FoldedBytes::Flag56),
+            57 => Some(FoldedBytes::Flag57),
+            58 => Some(FoldedBytes::Flag58),
+            59 => Some(FoldedBytes::Flag59),
+            60 => Some(FoldedBytes::Flag60),
+            61 => Some(FoldedBytes::Flag61),
+            62 => Some(FoldedBytes::Flag62),
+            63 => Some(FoldedBytes::Flag63),
+            64 => Some(FoldedBytes::Flag64),
+            65 => Some(FoldedBytes::Flag65),
+            66 => Some(FoldedBytes::Flag66),
+            67 => Some(FoldedBytes::Flag67),
+            68 => Some(FoldedBytes::Flag68),
+            69 => Some(FoldedBytes::Flag69),
+            70 => Some(FoldedBytes::Flag70),
+            71 => Some(FoldedBytes::Flag71),
+            72 => Some(FoldedBytes::Flag72),
+            73 => Some(FoldedBytes::Flag73),
+            74 => Some(FoldedBytes::Flag74),
+            75 => Some(FoldedBytes::Flag75),
+            76 => Some(FoldedBytes::Flag76),
+            77 => Some(FoldedBytes::Flag77),
+            78 => Some(FoldedBytes::Flag78),
+            79 => Some(FoldedBytes::Flag79),
+            80 => Some(FoldedBytes::Flag80),
+            81 => Some(FoldedBytes::Flag81),
+            82 => Some(FoldedBytes::Flag82),
+            83 => Some(FoldedBytes::Flag83),
+            84 => Some(FoldedBytes::Flag84),
+            85 => Some(FoldedBytes::Flag85),
+            86 => Some(FoldedBytes::Flag86),
+            87 => Some(FoldedBytes::Flag87),
+            88 => Some(FoldedBytes::Flag88),
+            89 => Some(FoldedBytes::Flag89),
+            90 => Some(FoldedBytes::Flag90),
+            91 => Some(FoldedBytes::Flag91),
+            92 => Some(FoldedBytes::Flag92),
+            93 => Some(FoldedBytes::Flag93),
+            94 => Some(FoldedBytes::Flag94),
+            95 => Some(FoldedBytes::Flag95),
+            96 => Some(FoldedBytes::Flag96),
+            97 => Some(FoldedBytes::Flag97),
+            98 => Some(FoldedBytes::Flag98),
+            99 => Some(FoldedBytes::Flag99),
+            100 => Some(FoldedBytes::Flag100),
+            101 => Some(FoldedBytes::Flag101),
+            102 => Some(FoldedBytes::Flag102),
+            103 => Some(FoldedBytes::Flag103),
+            104 => Some(FoldedBytes::Flag104),
+            105 => Some(FoldedBytes::Flag105),
+            106 => Some(FoldedBytes::Flag106),
+            107 => Some(FoldedBytes::Flag107),
+            108 => Some(FoldedBytes::Flag108),
+            109 => Some(FoldedBytes::Flag109),
+            110 => Some(FoldedBytes::Flag110),
+            111 => Some(FoldedBytes::Flag111),
+            112 => Some(FoldedBytes::Flag112),
+            113 => Some(FoldedBytes::Flag113),
+            114 => Some(FoldedBytes::Flag114),
+            115 => Some(FoldedBytes::Flag115),
+            116 => Some(FoldedBytes::Flag116),
+            117 => Some(FoldedBytes::Flag117),
+            118 => Some(FoldedBytes::Flag118),
+            119 => Some(FoldedBytes::Flag119),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            FoldedBytes::Flag0 => "Flag0",
+            FoldedBytes::Flag1 => "Flag1",
+            FoldedBytes::Flag2 => "Flag2",
+            FoldedBytes::Flag3 => "Flag3",
+            FoldedBytes::Flag4 => "Flag4",
+            FoldedBytes::Flag5 => "Flag5",
+            FoldedBytes::Flag6 => "Flag6",
+            FoldedBytes::Flag7 => "Flag7",
+            FoldedBytes::Flag8 => "Flag8",
+            FoldedBytes::Flag9 => "Flag9",
+            FoldedBytes::Flag10 => "Flag10",
+            FoldedBytes::Flag11 => "Flag11",
+            FoldedBytes::Flag12 => "Flag12",
+            FoldedBytes::Flag13 => "Flag13",
+            FoldedBytes::Flag14 => "Flag14",
+            FoldedBytes::Flag15 => "Flag15",
+            FoldedBytes::Flag16 => "Flag16",
+            FoldedBytes::Flag17 => "Flag17",
+            FoldedBytes::Flag18 => "Flag18",
+            FoldedBytes::Flag19 => "Flag19",
+            FoldedBytes::Flag20 => "Flag20",
+            FoldedBytes::Flag21 => "Flag21",
+            FoldedBytes::Flag22 => "Flag22",
+            FoldedBytes::Flag23 => "Flag23",
+            FoldedBytes::Flag24 => "Flag24",
+            FoldedBytes::Flag25 => "Flag25",
+            FoldedBytes::Flag26 => "Flag26",
+            FoldedBytes::Flag27 => "Flag27",
+            FoldedBytes::Flag28 => "Flag28",
+            FoldedBytes::Flag29 => "Flag29",
+            FoldedBytes::Flag30 => "Flag30",
+            FoldedBytes::Flag31 => "Flag31",
+            FoldedBytes::Flag32 => "Flag32",
+            FoldedBytes::Flag33 => "Flag33",
+            FoldedBytes::Flag34 => "Flag34",
+            FoldedBytes::Flag35 => "Flag35",
+            FoldedBytes::Flag36 => "Flag36",
+            FoldedBytes::Flag37 => "Flag37",
+            FoldedBytes::Flag38 => "Flag38",
+            FoldedBytes::Flag39 => "Flag39",
+            FoldedBytes::Flag40 => "Flag40",
+            FoldedBytes::Flag41 => "Flag41",
+            FoldedBytes::Flag42 => "Flag42",
+            FoldedBytes::Flag43 => "Flag43",
+            FoldedBytes::Flag44 => "Flag44",
+            FoldedBytes::Flag45 => "Flag45",
+            FoldedBytes::Flag46 => "Flag46",
+            FoldedBytes::Flag47 => "Flag47",
+            FoldedBytes::Flag48 => "Flag48",
+            FoldedBytes::Flag49 => "Flag49",
+            FoldedBytes::Flag50 => "Flag50",
+            FoldedBytes::Flag51 => "Flag51",
+            FoldedBytes::Flag52 => "Flag52",
+            FoldedBytes::Flag53 => "Flag53",
+            FoldedBytes::Flag54 => "Flag54",
+            FoldedBytes::Flag55 => "Flag55",
+            FoldedBytes::Flag56 => "Flag56",
+            FoldedBytes::Flag57 => "Flag57",
+            FoldedBytes::Flag58 => "Flag58",
+            FoldedBytes::Flag59 => "Flag59",
+            FoldedBytes::Flag60 => "Flag60",
+            FoldedBytes::Flag61 => "Flag61",
+            FoldedBytes::Flag62 => "Flag62",
+            FoldedBytes::Flag63 => "Flag63",
+            FoldedBytes::Flag64 => "Flag64",
+            FoldedBytes::Flag65 => "Flag65",
+            FoldedBytes::Flag66 => "Flag66",
+            FoldedBytes::Flag67 => "Flag67",
+            FoldedBytes::Flag68 => "Flag68",
+            FoldedBytes::Flag69 => "Flag69",
+            FoldedBytes::Flag70 => "Flag70",
+            FoldedBytes::Flag71 => "Flag71",
+            FoldedBytes::Flag72 => "Flag72",
+            FoldedBytes::Flag73 => "Flag73",
+            FoldedBytes::Flag74 => "Flag74",
+            FoldedBytes::Flag75 => "Flag75",
+            FoldedBytes::Flag76 => "Flag76",
+            FoldedBytes::Flag77 => "Flag77",
+            FoldedBytes::Flag78 => "Flag78",
+            FoldedBytes::Flag79 => "Flag79",
+            FoldedBytes::Flag80 => "Flag80",
+            FoldedBytes::Flag81 => "Flag81",
+            FoldedBytes::Flag82 => "Flag82",
+            FoldedBytes::Flag83 => "Flag83",
+            FoldedBytes::Flag84 => "Flag84",
+            FoldedBytes::Flag85 => "Flag85",
+            FoldedBytes::Flag86 => "Flag86",
+            FoldedBytes::Flag87 => "Flag87",
+            FoldedBytes::Flag88 => "Flag88",
+            FoldedBytes::Flag89 => "Flag89",
+            FoldedBytes::Flag90 => "Flag90",
+            FoldedBytes::Flag91 => "Flag91",
+            FoldedBytes::Flag92 => "Flag92",
+            FoldedBytes::Flag93 => "Flag93",
+            FoldedBytes::Flag94 => "Flag94",
+            FoldedBytes::Flag95 => "Flag95",
+            FoldedBytes::Flag96 => "Flag96",
+            FoldedBytes::Flag97 => "Flag97",
+            FoldedBytes::Flag98 => "Flag98",
+            FoldedBytes::Flag99 => "Flag99",
+            FoldedBytes::Flag100 => "Flag100",
+            FoldedBytes::Flag101 => "Flag101",
+            FoldedBytes::Flag102 => "Flag102",
+            FoldedBytes::Flag103 => "Flag103",
+            FoldedBytes::Flag104 => "Flag104",
+            FoldedBytes::Flag105 => "Flag105",
+            FoldedBytes::Flag106 => "Flag106",
+            FoldedBytes::Flag107 => "Flag107",
+            FoldedBytes::Flag108 => "Flag108",
+            FoldedBytes::Flag109 => "Flag109",
+            FoldedBytes::Flag110 => "Flag110",
+            FoldedBytes::Flag111 => "Flag111",
+            FoldedBytes::Flag112 => "Flag112",
+            FoldedBytes::Flag113 => "Flag113",
+            FoldedBytes::Flag114 => "Flag114",
+            FoldedBytes::Flag115 => "Flag115",
+            FoldedBytes::Flag116 => "Flag116",
+            FoldedBytes::Flag117 => "Flag117",
+            FoldedBytes::Flag118 => "Flag118",
+            FoldedBytes::Flag119 => "Flag119",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        FoldedBytes::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        FoldedBytes::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl FoldedBytes {
+    pub const COUNT: usize = 120;
+    pub const ALL_MASK: u128 = 0xffffffffffffffffffffffffffffff;
+    pub const VARIANTS: [FoldedBytes; FoldedBytes::COUNT] = [
+        FoldedBytes::Flag0,
+        FoldedBytes::Flag1,
+        FoldedBytes::Flag2,
+        FoldedBytes::Flag3,
+        FoldedBytes::Flag4,
+        FoldedBytes::Flag5,
+        FoldedBytes::Flag6,
+        FoldedBytes::Flag7,
+        FoldedBytes::Flag8,
+        FoldedBytes::Flag9,
+        FoldedBytes::Flag10,
+        FoldedBytes::Flag11,
+        FoldedBytes::Flag12,
+        FoldedBytes::Flag13,
+        FoldedBytes::Flag14,
+        FoldedBytes::Flag15,
+        FoldedBytes::Flag16,
+        FoldedBytes::Flag17,
+        FoldedBytes::Flag18,
+        FoldedBytes::Flag19,
+        FoldedBytes::Flag20,
+        FoldedBytes::Flag21,
+        FoldedBytes::Flag22,
+        FoldedBytes::Flag23,
+        FoldedBytes::Flag24,
+        FoldedBytes::Flag25,
+        FoldedBytes::Flag26,
+        FoldedBytes::Flag27,
+        FoldedBytes::Flag28,
+        FoldedBytes::Flag29,
+        FoldedBytes::Flag30,
+        FoldedBytes::Flag31,
+        FoldedBytes::Flag32,
+        FoldedBytes::Flag33,
+        FoldedBytes::Flag34,
+        FoldedBytes::Flag35,
+        FoldedBytes::Flag36,
+        FoldedBytes::Flag37,
+        FoldedBytes::Flag38,
+        FoldedBytes::Flag39,
+        FoldedBytes::Flag40,
+        FoldedBytes::Flag41,
+        FoldedBytes::Flag42,
+        FoldedBytes::Flag43,
+        FoldedBytes::Flag44,
+        FoldedBytes::Flag45,
+        FoldedBytes::Flag46,
+        FoldedBytes::Flag47,
+        FoldedBytes::Flag48,
+        FoldedBytes::Flag49,
+        FoldedBytes::Flag50,
+        FoldedBytes::Flag51,
+        FoldedBytes::Flag52,
+        FoldedBytes::Flag53,
+        FoldedBytes::Flag54,
+        FoldedBytes::Flag55,
+        FoldedBytes::Flag56,
+        FoldedBytes::Flag57,
+        FoldedBytes::Flag58,
+        FoldedBytes::Flag59,
+        FoldedBytes::Flag60,
+        FoldedBytes::Flag61,
+        FoldedBytes::Flag62,
+        FoldedBytes::Flag63,
+        FoldedBytes::Flag64,
+        FoldedBytes::Flag65,
+        FoldedBytes::Flag66,
+        FoldedBytes::Flag67,
+        FoldedBytes::Flag68,
+        FoldedBytes::Flag69,
+        FoldedBytes::Flag70,
+        FoldedBytes::Flag71,
+        FoldedBytes::Flag72,
+        FoldedBytes::Flag73,
+        FoldedBytes::Flag74,
+        FoldedBytes::Flag75,
+        FoldedBytes::Flag76,
+        FoldedBytes::Flag77,
+        FoldedBytes::Flag78,
+        FoldedBytes::Flag79,
+        FoldedBytes::Flag80,
+        FoldedBytes::Flag81,
+        FoldedBytes::Flag82,
+        FoldedBytes::Flag83,
+        FoldedBytes::Flag84,
+        FoldedBytes::Flag85,
+        FoldedBytes::Flag86,
+        FoldedBytes::Flag87,
+        FoldedBytes::Flag88,
+        FoldedBytes::Flag89,
+        FoldedBytes::Flag90,
+        FoldedBytes::Flag91,
+        FoldedBytes::Flag92,
+        FoldedBytes::Flag93,
+        FoldedBytes::Flag94,
+        FoldedBytes::Flag95,
+        FoldedBytes::Flag96,
+        FoldedBytes::Flag97,
+        FoldedBytes::Flag98,
+        FoldedBytes::Flag99,
+        FoldedBytes::Flag100,
+        FoldedBytes::Flag101,
+        FoldedBytes::Flag102,
+        FoldedBytes::Flag103,
+        FoldedBytes::Flag104,
+        FoldedBytes::Flag105,
+        FoldedBytes::Flag106,
+        FoldedBytes::Flag107,
+        FoldedBytes::Flag108,
+        FoldedBytes::Flag109,
+        FoldedBytes::Flag110,
+        FoldedBytes::Flag111,
+        FoldedBytes::Flag112,
+        FoldedBytes::Flag113,
+        FoldedBytes::Flag114,
+        FoldedBytes::Flag115,
+        FoldedBytes::Flag116,
+        FoldedBytes::Flag117,
+        FoldedBytes::Flag118,
+        FoldedBytes::Flag119,
+    ];
+
+    pub const fn max_position() -> u8 {
+        119
+    }
+}
+
+impl core::str::FromStr for FoldedBytes {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut key = [0u8; 8];
+        let Some(key) = key.get_mut(..s.len()) else {
+            return Err(bairiak::FlagParseError::new::<FoldedBytes>("FoldedBytes", s));
+        };
+        key.copy_from_slice(s.as_bytes());
+        key.make_ascii_lowercase();
+        match &*key {
+            b"flag0" => Ok(FoldedBytes::Flag0),
+            b"flag1" => Ok(FoldedBytes::Flag1),
+            b"flag2" => Ok(FoldedBytes::Flag2),
+            b"flag3" => Ok(FoldedBytes::Flag3),
+            b"flag4" => Ok(FoldedBytes::Flag4),
+            b"flag5" => Ok(FoldedBytes::Flag5),
+            b"flag6" => Ok(FoldedBytes::Flag6),
+            b"flag7" => Ok(FoldedBytes::Flag7),
+            b"seven" => Ok(FoldedBytes::Flag7),
+            b"sept" => Ok(FoldedBytes::Flag7),
+            b"s\xc3\xa9ptimo" => Ok(FoldedBytes::Flag7),
+            b"flag8" => Ok(FoldedBytes::Flag8),
+            b"flag9" => Ok(FoldedBytes::Flag9),
+            b"flag10" => Ok(FoldedBytes::Flag10),
+            b"flag11" => Ok(FoldedBytes::Flag11),
+            b"flag12" => Ok(FoldedBytes::Flag12),
+            b"flag13" => Ok(FoldedBytes::Flag13),
+            b"flag14" => Ok(FoldedBytes::Flag14),
+            b"flag15" => Ok(FoldedBytes::Flag15),
+            b"flag16" => Ok(FoldedBytes::Flag16),
+            b"flag17" => Ok(FoldedBytes::Flag17),
+            b"flag18" => Ok(FoldedBytes::Flag18),
+            b"flag19" => Ok(FoldedBytes::Flag19),
+            b"flag20" => Ok(FoldedBytes::Flag20),
+            b"flag21" => Ok(FoldedBytes::Flag21),
+            b"flag22" => Ok(FoldedBytes::Flag22),
+            b"flag23" => Ok(FoldedBytes::Flag23),
+            b"flag24" => Ok(FoldedBytes::Flag24),
+            b"flag25" => Ok(FoldedBytes::Flag25),
+            b"flag26" => Ok(FoldedBytes::Flag26),
+            b"flag27" => Ok(FoldedBytes::Flag27),
+            b"flag28" => Ok(FoldedBytes::Flag28),
+            b"flag29" => Ok(FoldedBytes::Flag29),
+            b"flag30" => Ok(FoldedBytes::Flag30),
+            b"flag31" => Ok(FoldedBytes::Flag31),
+            b"flag32" => Ok(FoldedBytes::Flag32),
+            b"flag33" => Ok(FoldedBytes::Flag33),
+            b"flag34" => Ok(FoldedBytes::Flag34),
+            b"flag35" => Ok(FoldedBytes::Flag35),
+            b"flag36" => Ok(FoldedBytes::Flag36),
+            b"flag37" => Ok(FoldedBytes::Flag37),
+            b"flag38" => Ok(FoldedBytes::Flag38),
+            b"flag39" => Ok(FoldedBytes::Flag39),
+            b"flag40" => Ok(FoldedBytes::Flag40),
+            b"flag41" => Ok(FoldedBytes::Flag41),
+            b"flag42" => Ok(FoldedBytes::Flag42),
+            b"flag43" => Ok(FoldedBytes::Flag43),
+            b"flag44" => Ok(FoldedBytes::Flag44),
+            b"flag45" => Ok(FoldedBytes::Flag45),
+            b"flag46" => Ok(FoldedBytes::Flag46),
+            b"flag47" => Ok(FoldedBytes::Flag47),
+            b"flag48" => Ok(FoldedBytes::Flag48),
+            b"flag49" => Ok(FoldedBytes::Flag49),
+            b"flag50" => Ok(FoldedBytes::Flag50),
+            b"flag51" => Ok(FoldedBytes::Flag51),
+            b"flag52" => Ok(FoldedBytes::Flag52),
+            b"flag53" => Ok(FoldedBytes::Flag53),
+            b"flag54" => Ok(FoldedBytes::Flag54),
+            b"flag55" => Ok(FoldedBytes::Flag55),
+            b"flag56" => Ok(FoldedBytes::Flag56),
+            b"flag57" => Ok(FoldedBytes::Flag57),
+            b"flag58" => Ok(FoldedBytes::Flag58),
+            b"flag59" => Ok(FoldedBytes::Flag59),
+            b"flag60" => Ok(FoldedBytes::Flag60),
+            b"flag61" => Ok(FoldedBytes::Flag61),
+            b"flag62" => Ok(FoldedBytes::Flag62),
+            b"flag63" => Ok(FoldedBytes::Flag63),
+            b"flag64" => Ok(FoldedBytes::Flag64),
+            b"flag65" => Ok(FoldedBytes::Flag65),
+            b"flag66" => Ok(FoldedBytes::Flag66),
+            b"flag67" => Ok(FoldedBytes::Flag67),
+            b"flag68" => Ok(FoldedBytes::Flag68),
+            b"flag69" => Ok(FoldedBytes::Flag69),
+            b"flag70" => Ok(FoldedBytes::Flag70),
+            b"flag71" => Ok(FoldedBytes::Flag71),
+            b"flag72" => Ok(FoldedBytes::Flag72),
+            b"flag73" => Ok(FoldedBytes::Flag73),
+            b"flag74" => Ok(FoldedBytes::Flag74),
+            b"flag75" => Ok(FoldedBytes::Flag75),
+            b"flag76" => Ok(FoldedBytes::Flag76),
+            b"flag77" => Ok(FoldedBytes::Flag77),
+            b"flag78" => Ok(FoldedBytes::Flag78),
+            b"flag79" => Ok(FoldedBytes::Flag79),
+            b"flag80" => Ok(FoldedBytes::Flag80),
+            b"flag81" => Ok(FoldedBytes::Flag81),
+            b"flag82" => Ok(FoldedBytes::Flag82),
+            b"flag83" => Ok(FoldedBytes::Flag83),
+            b"flag84" => Ok(FoldedBytes::Flag84),
+            b"flag85" => Ok(FoldedBytes::Flag85),
+            b"flag86" => Ok(FoldedBytes::Flag86),
+            b"flag87" => Ok(FoldedBytes::Flag87),
+            b"flag88" => Ok(FoldedBytes::Flag88),
+            b"flag89" => Ok(FoldedBytes::Flag89),
+            b"flag90" => Ok(FoldedBytes::Flag90),
+            b"flag91" => Ok(FoldedBytes::Flag91),
+            b"flag92" => Ok(FoldedBytes::Flag92),
+            b"flag93" => Ok(FoldedBytes::Flag93),
+            b"flag94" => Ok(FoldedBytes::Flag94),
+            b"flag95" => Ok(FoldedBytes::Flag95),
+            b"flag96" => Ok(FoldedBytes::Flag96),
+            b"flag97" => Ok(FoldedBytes::Flag97),
+            b"flag98" => Ok(FoldedBytes::Flag98),
+            b"flag99" => Ok(FoldedBytes::Flag99),
+            b"flag100" => Ok(FoldedBytes::Flag100),
+            b"flag101" => Ok(FoldedBytes::Flag101),
+            b"flag102" => Ok(FoldedBytes::Flag102),
+            b"flag103" => Ok(FoldedBytes::Flag103),
+            b"flag104" => Ok(FoldedBytes::Flag104),
+            b"flag105" => Ok(FoldedBytes::Flag105),
+            b"flag106" => Ok(FoldedBytes::Flag106),
+            b"flag107" => Ok(FoldedBytes::Flag107),
+            b"flag108" => Ok(FoldedBytes::Flag108),
+            b"flag109" => Ok(FoldedBytes::Flag109),
+            b"flag110" => Ok(FoldedBytes::Flag110),
+            b"flag111" => Ok(FoldedBytes::Flag111),
+            b"flag112" => Ok(FoldedBytes::Flag112),
+            b"flag113" => Ok(FoldedBytes::Flag113),
+            b"flag114" => Ok(FoldedBytes::Flag114),
+            b"flag115" => Ok(FoldedBytes::Flag115),
+            b"flag116" => Ok(FoldedBytes::Flag116),
+            b"flag117" => Ok(FoldedBytes::Flag117),
+            b"flag118" => Ok(FoldedBytes::Flag118),
+            b"flag119" => Ok(FoldedBytes::Flag119),
+            _ => Err(bairiak::FlagParseError::new::<FoldedBytes>("FoldedBytes", s)),
+        }
+    }
+}
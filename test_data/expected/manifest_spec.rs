@@ -0,0 +1,122 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 95b263a4c87551aa
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    /// Allows reading documents.
+    Read = 0,
+    Write = 1,
+    /// Grants every permission.
+    /// Only for operators.
+    ///
+    /// Deprecated: Use roles instead.
+    Admin = 3,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            3 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0xb;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        3
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Mode {
+    Fast = 0,
+    Slow = 1,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Fast),
+            1 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Fast => "Fast",
+            Mode::Slow => "Slow",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Fast,
+        Mode::Slow,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
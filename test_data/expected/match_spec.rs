@@ -0,0 +1,73 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 431c42e625694000
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Permissions {
+    Read = 0,
+    Write = 1,
+    Delete = 2,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            2 => Some(Permissions::Delete),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+            Permissions::Delete => "Delete",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+        Permissions::Delete,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! permissions_match {
+    ($flag:expr, { Read => $arm0:expr, Write => $arm1:expr, Delete => $arm2:expr $(,)? }) => {
+        match $flag {
+            Permissions::Read => $arm0,
+            Permissions::Write => $arm1,
+            Permissions::Delete => $arm2,
+        }
+    };
+}
@@ -0,0 +1,79 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 9ed2be04a3485524
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    Admin = 1,
+    Write = 2,
+    Share = 3,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Admin),
+            2 => Some(Perm::Write),
+            3 => Some(Perm::Share),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Admin => "Admin",
+            Perm::Write => "Write",
+            Perm::Share => "Share",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 4;
+    pub const ALL_MASK: u128 = 0xf;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Admin,
+        Perm::Write,
+        Perm::Share,
+    ];
+
+    pub const fn max_position() -> u8 {
+        3
+    }
+}
+
+#[allow(dead_code)]
+fn migrate_perm_v1_to_v2(old: Bairiak) -> Bairiak {
+    let mut new = Perm::get_zero_bairiak();
+    for position in old.positions() {
+        match position {
+            0 => new.set_position(0),
+            1 => new.set_position(2),
+            _ => {}
+        }
+    }
+    new
+}
@@ -0,0 +1,54 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: c2e5e33871e4db38
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Minimal {
+    Only = 0,
+}
+
+impl BairiakEnum for Minimal {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Minimal::Only),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Minimal::Only => "Only",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Minimal::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Minimal::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Minimal {
+    pub const COUNT: usize = 1;
+    pub const ALL_MASK: u128 = 0x1;
+    pub const VARIANTS: [Minimal; Minimal::COUNT] = [
+        Minimal::Only,
+    ];
+
+    pub const fn max_position() -> u8 {
+        0
+    }
+}
@@ -0,0 +1,174 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 3bcc4676bdfd8826
+pub(crate) mod flags {
+    use bairiak::{Bairiak, BairiakEnum};
+
+    #[repr(u8)]
+    #[allow(dead_code)]
+    #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+    pub(crate) enum Permissions {
+        Read = 0,
+        Write = 1,
+        Delete = 2,
+    }
+
+    impl BairiakEnum for Permissions {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U8(0u8)
+        }
+
+        fn to_u8(self) -> u8 {
+            self as u8
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            match position {
+                0 => Some(Permissions::Read),
+                1 => Some(Permissions::Write),
+                2 => Some(Permissions::Delete),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match self {
+                Permissions::Read => "Read",
+                Permissions::Write => "Write",
+                Permissions::Delete => "Delete",
+            }
+        }
+
+        fn defined_mask() -> u128 {
+            Permissions::ALL_MASK
+        }
+
+        fn variant_count() -> u32 {
+            Permissions::COUNT as u32
+        }
+    }
+
+    #[allow(dead_code)]
+    impl Permissions {
+        pub const COUNT: usize = 3;
+        pub const ALL_MASK: u128 = 0x7;
+        pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+            Permissions::Read,
+            Permissions::Write,
+            Permissions::Delete,
+        ];
+
+        pub const fn max_position() -> u8 {
+            2
+        }
+    }
+
+    #[repr(u8)]
+    #[allow(dead_code)]
+    #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+    pub(crate) enum HTTPFlags {
+        KeepAlive = 0,
+        Gzip = 1,
+    }
+
+    impl BairiakEnum for HTTPFlags {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U8(0u8)
+        }
+
+        fn to_u8(self) -> u8 {
+            self as u8
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            match position {
+                0 => Some(HTTPFlags::KeepAlive),
+                1 => Some(HTTPFlags::Gzip),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match self {
+                HTTPFlags::KeepAlive => "KeepAlive",
+                HTTPFlags::Gzip => "Gzip",
+            }
+        }
+
+        fn defined_mask() -> u128 {
+            HTTPFlags::ALL_MASK
+        }
+
+        fn variant_count() -> u32 {
+            HTTPFlags::COUNT as u32
+        }
+    }
+
+    #[allow(dead_code)]
+    impl HTTPFlags {
+        pub const COUNT: usize = 2;
+        pub const ALL_MASK: u128 = 0x3;
+        pub const VARIANTS: [HTTPFlags; HTTPFlags::COUNT] = [
+            HTTPFlags::KeepAlive,
+            HTTPFlags::Gzip,
+        ];
+
+        pub const fn max_position() -> u8 {
+            1
+        }
+    }
+
+    #[repr(u8)]
+    #[allow(dead_code)]
+    #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+    pub(crate) enum Mode {
+        Fast = 0,
+        Slow = 1,
+    }
+
+    impl BairiakEnum for Mode {
+        fn get_zero_bairiak() -> Bairiak {
+            Bairiak::U8(0u8)
+        }
+
+        fn to_u8(self) -> u8 {
+            self as u8
+        }
+
+        fn from_u8(position: u8) -> Option<Self> {
+            match position {
+                0 => Some(Mode::Fast),
+                1 => Some(Mode::Slow),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> &'static str {
+            match self {
+                Mode::Fast => "Fast",
+                Mode::Slow => "Slow",
+            }
+        }
+
+        fn defined_mask() -> u128 {
+            Mode::ALL_MASK
+        }
+
+        fn variant_count() -> u32 {
+            Mode::COUNT as u32
+        }
+    }
+
+    #[allow(dead_code)]
+    impl Mode {
+        pub const COUNT: usize = 2;
+        pub const ALL_MASK: u128 = 0x3;
+        pub const VARIANTS: [Mode; Mode::COUNT] = [
+            Mode::Fast,
+            Mode::Slow,
+        ];
+
+        pub const fn max_position() -> u8 {
+            1
+        }
+    }
+}
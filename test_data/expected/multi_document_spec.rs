@@ -0,0 +1,168 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 0ed6fdbcd3f87906
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Permissions {
+    Read = 0,
+    Write = 1,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Storage {
+    Gzip = 0,
+    Zstd = 1,
+}
+
+impl BairiakEnum for Storage {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Storage::Gzip),
+            1 => Some(Storage::Zstd),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Storage::Gzip => "Gzip",
+            Storage::Zstd => "Zstd",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Storage::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Storage::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Storage {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Storage; Storage::COUNT] = [
+        Storage::Gzip,
+        Storage::Zstd,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Network {
+    KeepAlive = 0,
+    Http2 = 1,
+}
+
+impl BairiakEnum for Network {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Network::KeepAlive),
+            1 => Some(Network::Http2),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Network::KeepAlive => "KeepAlive",
+            Network::Http2 => "Http2",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Network::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Network::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Network {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Network; Network::COUNT] = [
+        Network::KeepAlive,
+        Network::Http2,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
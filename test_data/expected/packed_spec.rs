@@ -0,0 +1,197 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 967a16705d94baf9
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    Write = 1,
+    Admin = 3,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            3 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0xb;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        3
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Codec {
+    Gzip = 0,
+    Zstd = 1,
+    Brotli = 2,
+}
+
+impl BairiakEnum for Codec {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Codec::Gzip),
+            1 => Some(Codec::Zstd),
+            2 => Some(Codec::Brotli),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Codec::Gzip => "Gzip",
+            Codec::Zstd => "Zstd",
+            Codec::Brotli => "Brotli",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Codec::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Codec::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Codec {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [Codec; Codec::COUNT] = [
+        Codec::Gzip,
+        Codec::Zstd,
+        Codec::Brotli,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Mode {
+    Off = 0,
+    Low = 1,
+    High = 2,
+    Turbo = 3,
+    Eco = 4,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Off),
+            1 => Some(Mode::Low),
+            2 => Some(Mode::High),
+            3 => Some(Mode::Turbo),
+            4 => Some(Mode::Eco),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Off => "Off",
+            Mode::Low => "Low",
+            Mode::High => "High",
+            Mode::Turbo => "Turbo",
+            Mode::Eco => "Eco",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 5;
+    pub const ALL_MASK: u128 = 0x1f;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Off,
+        Mode::Low,
+        Mode::High,
+        Mode::Turbo,
+        Mode::Eco,
+    ];
+
+    pub const fn max_position() -> u8 {
+        4
+    }
+}
+
+#[allow(dead_code)]
+fn device_state_layout() -> bairiak::PackedLayout {
+    bairiak::PackedLayout::new(32)
+        .and_then(|layout| layout.member::<Perm>())
+        .and_then(|layout| layout.member::<Codec>())
+        .and_then(|layout| layout.member::<Mode>())
+        .expect("validated packed layout")
+}
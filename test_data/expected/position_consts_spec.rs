@@ -0,0 +1,192 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: b1be0dcd3f702b64
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Permissions {
+    Read = 0,
+    Write = 1,
+    Admin = 5,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            5 => Some(Permissions::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+            Permissions::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x23;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+        Permissions::Admin,
+    ];
+    pub const READ_POS: u8 = 0;
+    pub const READ_MASK: u8 = 0x1;
+    pub const WRITE_POS: u8 = 1;
+    pub const WRITE_MASK: u8 = 0x2;
+    pub const ADMIN_POS: u8 = 5;
+    pub const ADMIN_MASK: u8 = 0x20;
+
+    pub const fn max_position() -> u8 {
+        5
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Wide {
+    Low = 0,
+    HighBit = 40,
+}
+
+impl BairiakEnum for Wide {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U64(0u64)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Wide::Low),
+            40 => Some(Wide::HighBit),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Wide::Low => "Low",
+            Wide::HighBit => "HighBit",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Wide::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Wide::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Wide {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x10000000001;
+    pub const VARIANTS: [Wide; Wide::COUNT] = [
+        Wide::Low,
+        Wide::HighBit,
+    ];
+
+    pub const fn max_position() -> u8 {
+        40
+    }
+}
+
+#[allow(dead_code)]
+const LOW_POS: u8 = 0;
+#[allow(dead_code)]
+const LOW_MASK: u64 = 0x1;
+#[allow(dead_code)]
+const HIGH_BIT_POS: u8 = 40;
+#[allow(dead_code)]
+const HIGH_BIT_MASK: u64 = 0x10000000000;
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Mode {
+    Fast = 0,
+    Slow = 2,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Fast),
+            2 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Fast => "Fast",
+            Mode::Slow => "Slow",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x5;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Fast,
+        Mode::Slow,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
+
+#[allow(dead_code)]
+const FAST_POS: u8 = 0;
+#[allow(dead_code)]
+const SLOW_POS: u8 = 2;
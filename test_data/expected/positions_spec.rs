@@ -0,0 +1,180 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: c95705575f1a780c
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Permissions {
+    Read = 0,
+    Write = 1,
+    Admin = 5,
+    Audit = 6,
+}
+
+impl BairiakEnum for Permissions {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Permissions::Read),
+            1 => Some(Permissions::Write),
+            5 => Some(Permissions::Admin),
+            6 => Some(Permissions::Audit),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Permissions::Read => "Read",
+            Permissions::Write => "Write",
+            Permissions::Admin => "Admin",
+            Permissions::Audit => "Audit",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Permissions::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Permissions::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Permissions {
+    pub const COUNT: usize = 4;
+    pub const ALL_MASK: u128 = 0x63;
+    pub const VARIANTS: [Permissions; Permissions::COUNT] = [
+        Permissions::Read,
+        Permissions::Write,
+        Permissions::Admin,
+        Permissions::Audit,
+    ];
+
+    pub const fn max_position() -> u8 {
+        6
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Wide {
+    Low = 0,
+    High = 40,
+}
+
+impl BairiakEnum for Wide {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U64(0u64)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Wide::Low),
+            40 => Some(Wide::High),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Wide::Low => "Low",
+            Wide::High => "High",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Wide::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Wide::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Wide {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x10000000001;
+    pub const VARIANTS: [Wide; Wide::COUNT] = [
+        Wide::Low,
+        Wide::High,
+    ];
+
+    pub const fn max_position() -> u8 {
+        40
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Reserved {
+    Read = 0,
+    Write = 1,
+    Admin = 4,
+}
+
+impl BairiakEnum for Reserved {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Reserved::Read),
+            1 => Some(Reserved::Write),
+            4 => Some(Reserved::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Reserved::Read => "Read",
+            Reserved::Write => "Write",
+            Reserved::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Reserved::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Reserved::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Reserved {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x13;
+    pub const VARIANTS: [Reserved; Reserved::COUNT] = [
+        Reserved::Read,
+        Reserved::Write,
+        Reserved::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        4
+    }
+}
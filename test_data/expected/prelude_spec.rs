@@ -0,0 +1,62 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 583bc6e73f9d2f35
+use bairiak::prelude::*;
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum TestEnum {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+}
+
+impl BairiakEnum for TestEnum {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(TestEnum::Flag0),
+            1 => Some(TestEnum::Flag1),
+            2 => Some(TestEnum::Flag2),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TestEnum::Flag0 => "Flag0",
+            TestEnum::Flag1 => "Flag1",
+            TestEnum::Flag2 => "Flag2",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        TestEnum::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        TestEnum::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl TestEnum {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x7;
+    pub const VARIANTS: [TestEnum; TestEnum::COUNT] = [
+        TestEnum::Flag0,
+        TestEnum::Flag1,
+        TestEnum::Flag2,
+    ];
+
+    pub const fn max_position() -> u8 {
+        2
+    }
+}
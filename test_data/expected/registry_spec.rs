@@ -0,0 +1,133 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: e21b93ecd1adce2c
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    Write = 1,
+    Admin = 3,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            3 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0xb;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        3
+    }
+}
+
+bairiak::registry::inventory::submit! {
+    bairiak::registry::RegisteredEnum {
+        name: "Perm",
+        width: 8,
+        variants: &[(0, "Read"), (1, "Write"), (3, "Admin")],
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Codec {
+    Gzip = 0,
+    Zstd = 9,
+}
+
+impl BairiakEnum for Codec {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Codec::Gzip),
+            9 => Some(Codec::Zstd),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Codec::Gzip => "Gzip",
+            Codec::Zstd => "Zstd",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Codec::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Codec::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Codec {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x201;
+    pub const VARIANTS: [Codec; Codec::COUNT] = [
+        Codec::Gzip,
+        Codec::Zstd,
+    ];
+
+    pub const fn max_position() -> u8 {
+        9
+    }
+}
+
+bairiak::registry::inventory::submit! {
+    bairiak::registry::RegisteredEnum {
+        name: "Codec",
+        width: 16,
+        variants: &[(0, "Gzip"), (9, "Zstd")],
+    }
+}
@@ -0,0 +1,75 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 9ffc99c8ae8edab8
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Access {
+    Read = 0,
+    Admin = 1,
+    AdminPanel = 2,
+    Audit = 3,
+}
+
+impl BairiakEnum for Access {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Access::Read),
+            1 => Some(Access::Admin),
+            2 => Some(Access::AdminPanel),
+            3 => Some(Access::Audit),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Access::Read => "Read",
+            Access::Admin => "Admin",
+            Access::AdminPanel => "AdminPanel",
+            Access::Audit => "Audit",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Access::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Access::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Access {
+    pub const COUNT: usize = 4;
+    pub const ALL_MASK: u128 = 0xf;
+    pub const VARIANTS: [Access; Access::COUNT] = [
+        Access::Read,
+        Access::Admin,
+        Access::AdminPanel,
+        Access::Audit,
+    ];
+
+    pub const fn max_position() -> u8 {
+        3
+    }
+}
+
+#[allow(dead_code)]
+impl Access {
+    pub const REQUIREMENTS: [(u8, u128); 3] = [(1, 0x1), (2, 0x2), (3, 0x3)];
+
+    pub fn validate_requires(value: &Bairiak) -> Result<(), Vec<bairiak::RequiresViolation>> {
+        bairiak::check_requires_for::<Access>(value, &Access::REQUIREMENTS)
+    }
+}
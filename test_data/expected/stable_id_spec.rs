@@ -0,0 +1,155 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: d890cb889a1b24ea
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Perm {
+    Read = 0,
+    Write = 1,
+    Admin = 4,
+}
+
+impl BairiakEnum for Perm {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Perm::Read),
+            1 => Some(Perm::Write),
+            4 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Perm::Read => "Read",
+            Perm::Write => "Write",
+            Perm::Admin => "Admin",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Perm::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Perm::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x13;
+    pub const VARIANTS: [Perm; Perm::COUNT] = [
+        Perm::Read,
+        Perm::Write,
+        Perm::Admin,
+    ];
+
+    pub const fn max_position() -> u8 {
+        4
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const fn status(self) -> bairiak::FlagStatus {
+        match self {
+            Perm::Read => bairiak::FlagStatus::Stable,
+            Perm::Write => bairiak::FlagStatus::Stable,
+            Perm::Admin => bairiak::FlagStatus::Stable,
+        }
+    }
+
+    pub fn status_at(position: u8) -> Option<bairiak::FlagStatus> {
+        match position {
+            3 => Some(bairiak::FlagStatus::Removed),
+            _ => <Perm as BairiakEnum>::from_u8(position).map(Perm::status),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Perm {
+    pub const fn stable_id(&self) -> u32 {
+        match self {
+            Perm::Read => 1001,
+            Perm::Write => 1002,
+            Perm::Admin => 1004,
+        }
+    }
+
+    pub const fn from_stable_id(id: u32) -> Option<Self> {
+        match id {
+            1001 => Some(Perm::Read),
+            1002 => Some(Perm::Write),
+            1004 => Some(Perm::Admin),
+            _ => None,
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Mode {
+    Fast = 0,
+    Slow = 1,
+}
+
+impl BairiakEnum for Mode {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Mode::Fast),
+            1 => Some(Mode::Slow),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Mode::Fast => "Fast",
+            Mode::Slow => "Slow",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Mode::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Mode::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Mode {
+    pub const COUNT: usize = 2;
+    pub const ALL_MASK: u128 = 0x3;
+    pub const VARIANTS: [Mode; Mode::COUNT] = [
+        Mode::Fast,
+        Mode::Slow,
+    ];
+
+    pub const fn max_position() -> u8 {
+        1
+    }
+}
@@ -0,0 +1,193 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 279f5e556021bf4e
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Feature {
+    Login = 0,
+    /// A dark theme for the whole app.
+    ///
+    /// Experimental: may change or be removed in any release. Only available with the `experimental-flags` feature.
+    #[cfg(feature = "experimental-flags")]
+    DarkMode = 1,
+    /// Deprecated: Use `Export` instead.
+    LegacyExport = 2,
+    Export = 4,
+}
+
+impl BairiakEnum for Feature {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Feature::Login),
+            #[cfg(feature = "experimental-flags")]
+            1 => Some(Feature::DarkMode),
+            2 => Some(Feature::LegacyExport),
+            4 => Some(Feature::Export),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Feature::Login => "Login",
+            #[cfg(feature = "experimental-flags")]
+            Feature::DarkMode => "DarkMode",
+            Feature::LegacyExport => "LegacyExport",
+            Feature::Export => "Export",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Feature::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Feature::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Feature {
+    pub const COUNT: usize = if cfg!(feature = "experimental-flags") { 4 } else { 3 };
+    pub const ALL_MASK: u128 = if cfg!(feature = "experimental-flags") { 0x17 } else { 0x15 };
+    pub const VARIANTS: [Feature; Feature::COUNT] = [
+        Feature::Login,
+        #[cfg(feature = "experimental-flags")]
+        Feature::DarkMode,
+        Feature::LegacyExport,
+        Feature::Export,
+    ];
+    pub const LOGIN_POS: u8 = 0;
+    #[cfg(feature = "experimental-flags")]
+    pub const DARK_MODE_POS: u8 = 1;
+    pub const LEGACY_EXPORT_POS: u8 = 2;
+    pub const EXPORT_POS: u8 = 4;
+
+    pub const fn max_position() -> u8 {
+        4
+    }
+}
+
+#[allow(dead_code)]
+impl Feature {
+    pub const fn status(self) -> bairiak::FlagStatus {
+        match self {
+            Feature::Login => bairiak::FlagStatus::Stable,
+            #[cfg(feature = "experimental-flags")]
+            Feature::DarkMode => bairiak::FlagStatus::Experimental,
+            Feature::LegacyExport => bairiak::FlagStatus::Deprecated,
+            Feature::Export => bairiak::FlagStatus::Stable,
+        }
+    }
+
+    pub fn status_at(position: u8) -> Option<bairiak::FlagStatus> {
+        match position {
+            3 => Some(bairiak::FlagStatus::Removed),
+            _ => <Feature as BairiakEnum>::from_u8(position).map(Feature::status),
+        }
+    }
+}
+
+impl core::str::FromStr for Feature {
+    type Err = bairiak::FlagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Login" => Ok(Feature::Login),
+            #[cfg(feature = "experimental-flags")]
+            "DarkMode" => Ok(Feature::DarkMode),
+            "LegacyExport" => Ok(Feature::LegacyExport),
+            "Export" => Ok(Feature::Export),
+            _ => Err(bairiak::FlagParseError::new::<Feature>("Feature", s)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Stage {
+    /// Experimental: may change or be removed in any release.
+    Alpha = 0,
+    /// Deprecated.
+    Beta = 1,
+    Delta = 6,
+}
+
+impl BairiakEnum for Stage {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Stage::Alpha),
+            1 => Some(Stage::Beta),
+            6 => Some(Stage::Delta),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Alpha => "Alpha",
+            Stage::Beta => "Beta",
+            Stage::Delta => "Delta",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Stage::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Stage::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Stage {
+    pub const COUNT: usize = 3;
+    pub const ALL_MASK: u128 = 0x43;
+    pub const VARIANTS: [Stage; Stage::COUNT] = [
+        Stage::Alpha,
+        Stage::Beta,
+        Stage::Delta,
+    ];
+
+    pub const fn max_position() -> u8 {
+        6
+    }
+}
+
+#[allow(dead_code)]
+impl Stage {
+    pub const fn status(self) -> bairiak::FlagStatus {
+        match self {
+            Stage::Alpha => bairiak::FlagStatus::Experimental,
+            Stage::Beta => bairiak::FlagStatus::Deprecated,
+            Stage::Delta => bairiak::FlagStatus::Stable,
+        }
+    }
+
+    pub fn status_at(position: u8) -> Option<bairiak::FlagStatus> {
+        match position {
+            5 => Some(bairiak::FlagStatus::Removed),
+            _ => <Stage as BairiakEnum>::from_u8(position).map(Stage::status),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 69a3e8a393a2125f
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum MatchLookup {
+    Read = 0,
+    Write = 1,
+    Admin = 3,
+    Audit = 9,
+    Export = 10,
+}
+
+impl BairiakEnum for MatchLookup {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(MatchLookup::Read),
+            1 => Some(MatchLookup::Write),
+            3 => Some(MatchLookup::Admin),
+            9 => Some(MatchLookup::Audit),
+            10 => Some(MatchLookup::Export),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            MatchLookup::Read => "Read",
+            MatchLookup::Write => "Write",
+            MatchLookup::Admin => "Admin",
+            MatchLookup::Audit => "Audit",
+            MatchLookup::Export => "Export",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        MatchLookup::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        MatchLookup::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl MatchLookup {
+    pub const COUNT: usize = 5;
+    pub const ALL_MASK: u128 = 0x60b;
+    pub const VARIANTS: [MatchLookup; MatchLookup::COUNT] = [
+        MatchLookup::Read,
+        MatchLookup::Write,
+        MatchLookup::Admin,
+        MatchLookup::Audit,
+        MatchLookup::Export,
+    ];
+
+    pub const fn max_position() -> u8 {
+        10
+    }
+}
+
+#[allow(dead_code)]
+impl MatchLookup {
+    pub const fn status(self) -> bairiak::FlagStatus {
+        match self {
+            MatchLookup::Read => bairiak::FlagStatus::Stable,
+            MatchLookup::Write => bairiak::FlagStatus::Stable,
+            MatchLookup::Admin => bairiak::FlagStatus::Stable,
+            MatchLookup::Audit => bairiak::FlagStatus::Stable,
+            MatchLookup::Export => bairiak::FlagStatus::Stable,
+        }
+    }
+
+    pub fn status_at(position: u8) -> Option<bairiak::FlagStatus> {
+        match position {
+            4 => Some(bairiak::FlagStatus::Removed),
+            _ => <MatchLookup as BairiakEnum>::from_u8(position).map(MatchLookup::status),
+        }
+    }
+}
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum TableLookup {
+    Read = 0,
+    Write = 1,
+    Admin = 3,
+    Audit = 9,
+    Export = 10,
+}
+
+impl BairiakEnum for TableLookup {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        static BY_POSITION: [Option<TableLookup>; 11] = [
+            Some(TableLookup::Read),
+            Some(TableLookup::Write),
+            None,
+            Some(TableLookup::Admin),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(TableLookup::Audit),
+            Some(TableLookup::Export),
+        ];
+        BY_POSITION.get(position as usize).copied().flatten()
+    }
+
+    fn name(&self) -> &'static str {
+        static NAMES: [&str; 11] = [
+            "Read",
+            "Write",
+            "",
+            "Admin",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "Audit",
+            "Export",
+        ];
+        NAMES[*self as usize]
+    }
+
+    fn defined_mask() -> u128 {
+        TableLookup::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        TableLookup::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl TableLookup {
+    pub const COUNT: usize = 5;
+    pub const ALL_MASK: u128 = 0x60b;
+    pub const VARIANTS: [TableLookup; TableLookup::COUNT] = [
+        TableLookup::Read,
+        TableLookup::Write,
+        TableLookup::Admin,
+        TableLookup::Audit,
+        TableLookup::Export,
+    ];
+
+    pub const fn max_position() -> u8 {
+        10
+    }
+}
+
+#[allow(dead_code)]
+impl TableLookup {
+    pub const fn status(self) -> bairiak::FlagStatus {
+        match self {
+            TableLookup::Read => bairiak::FlagStatus::Stable,
+            TableLookup::Write => bairiak::FlagStatus::Stable,
+            TableLookup::Admin => bairiak::FlagStatus::Stable,
+            TableLookup::Audit => bairiak::FlagStatus::Stable,
+            TableLookup::Export => bairiak::FlagStatus::Stable,
+        }
+    }
+
+    pub fn status_at(position: u8) -> Option<bairiak::FlagStatus> {
+        match position {
+            4 => Some(bairiak::FlagStatus::Removed),
+            _ => <TableLookup as BairiakEnum>::from_u8(position).map(TableLookup::status),
+        }
+    }
+}
@@ -0,0 +1,562 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 58f5b41ba3db33d0
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Width128 {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+    Flag8 = 8,
+    Flag9 = 9,
+    Flag10 = 10,
+    Flag11 = 11,
+    Flag12 = 12,
+    Flag13 = 13,
+    Flag14 = 14,
+    Flag15 = 15,
+    Flag16 = 16,
+    Flag17 = 17,
+    Flag18 = 18,
+    Flag19 = 19,
+    Flag20 = 20,
+    Flag21 = 21,
+    Flag22 = 22,
+    Flag23 = 23,
+    Flag24 = 24,
+    Flag25 = 25,
+    Flag26 = 26,
+    Flag27 = 27,
+    Flag28 = 28,
+    Flag29 = 29,
+    Flag30 = 30,
+    Flag31 = 31,
+    Flag32 = 32,
+    Flag33 = 33,
+    Flag34 = 34,
+    Flag35 = 35,
+    Flag36 = 36,
+    Flag37 = 37,
+    Flag38 = 38,
+    Flag39 = 39,
+    Flag40 = 40,
+    Flag41 = 41,
+    Flag42 = 42,
+    Flag43 = 43,
+    Flag44 = 44,
+    Flag45 = 45,
+    Flag46 = 46,
+    Flag47 = 47,
+    Flag48 = 48,
+    Flag49 = 49,
+    Flag50 = 50,
+    Flag51 = 51,
+    Flag52 = 52,
+    Flag53 = 53,
+    Flag54 = 54,
+    Flag55 = 55,
+    Flag56 = 56,
+    Flag57 = 57,
+    Flag58 = 58,
+    Flag59 = 59,
+    Flag60 = 60,
+    Flag61 = 61,
+    Flag62 = 62,
+    Flag63 = 63,
+    Flag64 = 64,
+    Flag65 = 65,
+    Flag66 = 66,
+    Flag67 = 67,
+    Flag68 = 68,
+    Flag69 = 69,
+    Flag70 = 70,
+    Flag71 = 71,
+    Flag72 = 72,
+    Flag73 = 73,
+    Flag74 = 74,
+    Flag75 = 75,
+    Flag76 = 76,
+    Flag77 = 77,
+    Flag78 = 78,
+    Flag79 = 79,
+    Flag80 = 80,
+    Flag81 = 81,
+    Flag82 = 82,
+    Flag83 = 83,
+    Flag84 = 84,
+    Flag85 = 85,
+    Flag86 = 86,
+    Flag87 = 87,
+    Flag88 = 88,
+    Flag89 = 89,
+    Flag90 = 90,
+    Flag91 = 91,
+    Flag92 = 92,
+    Flag93 = 93,
+    Flag94 = 94,
+    Flag95 = 95,
+    Flag96 = 96,
+    Flag97 = 97,
+    Flag98 = 98,
+    Flag99 = 99,
+    Flag100 = 100,
+    Flag101 = 101,
+    Flag102 = 102,
+    Flag103 = 103,
+    Flag104 = 104,
+    Flag105 = 105,
+    Flag106 = 106,
+    Flag107 = 107,
+    Flag108 = 108,
+    Flag109 = 109,
+    Flag110 = 110,
+    Flag111 = 111,
+    Flag112 = 112,
+    Flag113 = 113,
+    Flag114 = 114,
+    Flag115 = 115,
+    Flag116 = 116,
+    Flag117 = 117,
+    Flag118 = 118,
+    Flag119 = 119,
+    Flag120 = 120,
+    Flag121 = 121,
+    Flag122 = 122,
+    Flag123 = 123,
+    Flag124 = 124,
+    Flag125 = 125,
+    Flag126 = 126,
+    Flag127 = 127,
+}
+
+impl BairiakEnum for Width128 {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U128(0u128)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Width128::Flag0),
+            1 => Some(Width128::Flag1),
+            2 => Some(Width128::Flag2),
+            3 => Some(Width128::Flag3),
+            4 => Some(Width128::Flag4),
+            5 => Some(Width128::Flag5),
+            6 => Some(Width128::Flag6),
+            7 => Some(Width128::Flag7),
+            8 => Some(Width128::Flag8),
+            9 => Some(Width128::Flag9),
+            10 => Some(Width128::Flag10),
+            11 => Some(Width128::Flag11),
+            12 => Some(Width128::Flag12),
+            13 => Some(Width128::Flag13),
+            14 => Some(Width128::Flag14),
+            15 => Some(Width128::Flag15),
+            16 => Some(Width128::Flag16),
+            17 => Some(Width128::Flag17),
+            18 => Some(Width128::Flag18),
+            19 => Some(Width128::Flag19),
+            20 => Some(Width128::Flag20),
+            21 => Some(Width128::Flag21),
+            22 => Some(Width128::Flag22),
+            23 => Some(Width128::Flag23),
+            24 => Some(Width128::Flag24),
+            25 => Some(Width128::Flag25),
+            26 => Some(Width128::Flag26),
+            27 => Some(Width128::Flag27),
+            28 => Some(Width128::Flag28),
+            29 => Some(Width128::Flag29),
+            30 => Some(Width128::Flag30),
+            31 => Some(Width128::Flag31),
+            32 => Some(Width128::Flag32),
+            33 => Some(Width128::Flag33),
+            34 => Some(Width128::Flag34),
+            35 => Some(Width128::Flag35),
+            36 => Some(Width128::Flag36),
+            37 => Some(Width128::Flag37),
+            38 => Some(Width128::Flag38),
+            39 => Some(Width128::Flag39),
+            40 => Some(Width128::Flag40),
+            41 => Some(Width128::Flag41),
+            42 => Some(Width128::Flag42),
+            43 => Some(Width128::Flag43),
+            44 => Some(Width128::Flag44),
+            45 => Some(Width128::Flag45),
+            46 => Some(Width128::Flag46),
+            47 => Some(Width128::Flag47),
+            48 => Some(Width128::Flag48),
+            49 => Some(Width128::Flag49),
+            50 => Some(Width128::Flag50),
+            51 => Some(Width128::Flag51),
+            52 => Some(Width128::Flag52),
+            53 => Some(Width128::Flag53),
+            54 => Some(Width128::Flag54),
+            55 => Some(Width128::Flag55),
+            56 => Some(Width128::Flag56),
+            57 => Some(Width128::Flag57),
+            58 => Some(Width128::Flag58),
+            59 => Some(Width128::Flag59),
+            60 => Some(Width128::Flag60),
+            61 => Some(Width128::Flag61),
+            62 => Some(Width128::Flag62),
+            63 => Some(Width128::Flag63),
+            64 => Some(Width128::Flag64),
+            65 => Some(Width128::Flag65),
+            66 => Some(Width128::Flag66),
+            67 => Some(Width128::Flag67),
+            68 => Some(Width128::Flag68),
+            69 => Some(Width128::Flag69),
+            70 => Some(Width128::Flag70),
+            71 => Some(Width128::Flag71),
+            72 => Some(Width128::Flag72),
+            73 => Some(Width128::Flag73),
+            74 => Some(Width128::Flag74),
+            75 => Some(Width128::Flag75),
+            76 => Some(Width128::Flag76),
+            77 => Some(Width128::Flag77),
+            78 => Some(Width128::Flag78),
+            79 => Some(Width128::Flag79),
+            80 => Some(Width128::Flag80),
+            81 => Some(Width128::Flag81),
+            82 => Some(Width128::Flag82),
+            83 => Some(Width128::Flag83),
+            84 => Some(Width128::Flag84),
+            85 => Some(Width128::Flag85),
+            86 => Some(Width128::Flag86),
+            87 => Some(Width128::Flag87),
+            88 => Some(Width128::Flag88),
+            89 => Some(Width128::Flag89),
+            90 => Some(Width128::Flag90),
+            91 => Some(Width128::Flag91),
+            92 => Some(Width128::Flag92),
+            93 => Some(Width128::Flag93),
+            94 => Some(Width128::Flag94),
+            95 => Some(Width128::Flag95),
+            96 => Some(Width128::Flag96),
+            97 => Some(Width128::Flag97),
+            98 => Some(Width128::Flag98),
+            99 => Some(Width128::Flag99),
+            100 => Some(Width128::Flag100),
+            101 => Some(Width128::Flag101),
+            102 => Some(Width128::Flag102),
+            103 => Some(Width128::Flag103),
+            104 => Some(Width128::Flag104),
+            105 => Some(Width128::Flag105),
+            106 => Some(Width128::Flag106),
+            107 => Some(Width128::Flag107),
+            108 => Some(Width128::Flag108),
+            109 => Some(Width128::Flag109),
+            110 => Some(Width128::Flag110),
+            111 => Some(Width128::Flag111),
+            112 => Some(Width128::Flag112),
+            113 => Some(Width128::Flag113),
+            114 => Some(Width128::Flag114),
+            115 => Some(Width128::Flag115),
+            116 => Some(Width128::Flag116),
+            117 => Some(Width128::Flag117),
+            118 => Some(Width128::Flag118),
+            119 => Some(Width128::Flag119),
+            120 => Some(Width128::Flag120),
+            121 => Some(Width128::Flag121),
+            122 => Some(Width128::Flag122),
+            123 => Some(Width128::Flag123),
+            124 => Some(Width128::Flag124),
+            125 => Some(Width128::Flag125),
+            126 => Some(Width128::Flag126),
+            127 => Some(Width128::Flag127),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Width128::Flag0 => "Flag0",
+            Width128::Flag1 => "Flag1",
+            Width128::Flag2 => "Flag2",
+            Width128::Flag3 => "Flag3",
+            Width128::Flag4 => "Flag4",
+            Width128::Flag5 => "Flag5",
+            Width128::Flag6 => "Flag6",
+            Width128::Flag7 => "Flag7",
+            Width128::Flag8 => "Flag8",
+            Width128::Flag9 => "Flag9",
+            Width128::Flag10 => "Flag10",
+            Width128::Flag11 => "Flag11",
+            Width128::Flag12 => "Flag12",
+            Width128::Flag13 => "Flag13",
+            Width128::Flag14 => "Flag14",
+            Width128::Flag15 => "Flag15",
+            Width128::Flag16 => "Flag16",
+            Width128::Flag17 => "Flag17",
+            Width128::Flag18 => "Flag18",
+            Width128::Flag19 => "Flag19",
+            Width128::Flag20 => "Flag20",
+            Width128::Flag21 => "Flag21",
+            Width128::Flag22 => "Flag22",
+            Width128::Flag23 => "Flag23",
+            Width128::Flag24 => "Flag24",
+            Width128::Flag25 => "Flag25",
+            Width128::Flag26 => "Flag26",
+            Width128::Flag27 => "Flag27",
+            Width128::Flag28 => "Flag28",
+            Width128::Flag29 => "Flag29",
+            Width128::Flag30 => "Flag30",
+            Width128::Flag31 => "Flag31",
+            Width128::Flag32 => "Flag32",
+            Width128::Flag33 => "Flag33",
+            Width128::Flag34 => "Flag34",
+            Width128::Flag35 => "Flag35",
+            Width128::Flag36 => "Flag36",
+            Width128::Flag37 => "Flag37",
+            Width128::Flag38 => "Flag38",
+            Width128::Flag39 => "Flag39",
+            Width128::Flag40 => "Flag40",
+            Width128::Flag41 => "Flag41",
+            Width128::Flag42 => "Flag42",
+            Width128::Flag43 => "Flag43",
+            Width128::Flag44 => "Flag44",
+            Width128::Flag45 => "Flag45",
+            Width128::Flag46 => "Flag46",
+            Width128::Flag47 => "Flag47",
+            Width128::Flag48 => "Flag48",
+            Width128::Flag49 => "Flag49",
+            Width128::Flag50 => "Flag50",
+            Width128::Flag51 => "Flag51",
+            Width128::Flag52 => "Flag52",
+            Width128::Flag53 => "Flag53",
+            Width128::Flag54 => "Flag54",
+            Width128::Flag55 => "Flag55",
+            Width128::Flag56 => "Flag56",
+            Width128::Flag57 => "Flag57",
+            Width128::Flag58 => "Flag58",
+            Width128::Flag59 => "Flag59",
+            Width128::Flag60 => "Flag60",
+            Width128::Flag61 => "Flag61",
+            Width128::Flag62 => "Flag62",
+            Width128::Flag63 => "Flag63",
+            Width128::Flag64 => "Flag64",
+            Width128::Flag65 => "Flag65",
+            Width128::Flag66 => "Flag66",
+            Width128::Flag67 => "Flag67",
+            Width128::Flag68 => "Flag68",
+            Width128::Flag69 => "Flag69",
+            Width128::Flag70 => "Flag70",
+            Width128::Flag71 => "Flag71",
+            Width128::Flag72 => "Flag72",
+            Width128::Flag73 => "Flag73",
+            Width128::Flag74 => "Flag74",
+            Width128::Flag75 => "Flag75",
+            Width128::Flag76 => "Flag76",
+            Width128::Flag77 => "Flag77",
+            Width128::Flag78 => "Flag78",
+            Width128::Flag79 => "Flag79",
+            Width128::Flag80 => "Flag80",
+            Width128::Flag81 => "Flag81",
+            Width128::Flag82 => "Flag82",
+            Width128::Flag83 => "Flag83",
+            Width128::Flag84 => "Flag84",
+            Width128::Flag85 => "Flag85",
+            Width128::Flag86 => "Flag86",
+            Width128::Flag87 => "Flag87",
+            Width128::Flag88 => "Flag88",
+            Width128::Flag89 => "Flag89",
+            Width128::Flag90 => "Flag90",
+            Width128::Flag91 => "Flag91",
+            Width128::Flag92 => "Flag92",
+            Width128::Flag93 => "Flag93",
+            Width128::Flag94 => "Flag94",
+            Width128::Flag95 => "Flag95",
+            Width128::Flag96 => "Flag96",
+            Width128::Flag97 => "Flag97",
+            Width128::Flag98 => "Flag98",
+            Width128::Flag99 => "Flag99",
+            Width128::Flag100 => "Flag100",
+            Width128::Flag101 => "Flag101",
+            Width128::Flag102 => "Flag102",
+            Width128::Flag103 => "Flag103",
+            Width128::Flag104 => "Flag104",
+            Width128::Flag105 => "Flag105",
+            Width128::Flag106 => "Flag106",
+            Width128::Flag107 => "Flag107",
+            Width128::Flag108 => "Flag108",
+            Width128::Flag109 => "Flag109",
+            Width128::Flag110 => "Flag110",
+            Width128::Flag111 => "Flag111",
+            Width128::Flag112 => "Flag112",
+            Width128::Flag113 => "Flag113",
+            Width128::Flag114 => "Flag114",
+            Width128::Flag115 => "Flag115",
+            Width128::Flag116 => "Flag116",
+            Width128::Flag117 => "Flag117",
+            Width128::Flag118 => "Flag118",
+            Width128::Flag119 => "Flag119",
+            Width128::Flag120 => "Flag120",
+            Width128::Flag121 => "Flag121",
+            Width128::Flag122 => "Flag122",
+            Width128::Flag123 => "Flag123",
+            Width128::Flag124 => "Flag124",
+            Width128::Flag125 => "Flag125",
+            Width128::Flag126 => "Flag126",
+            Width128::Flag127 => "Flag127",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Width128::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Width128::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Width128 {
+    pub const COUNT: usize = 128;
+    pub const ALL_MASK: u128 = 0xffffffffffffffffffffffffffffffff;
+    pub const VARIANTS: [Width128; Width128::COUNT] = [
+        Width128::Flag0,
+        Width128::Flag1,
+        Width128::Flag2,
+        Width128::Flag3,
+        Width128::Flag4,
+        Width128::Flag5,
+        Width128::Flag6,
+        Width128::Flag7,
+        Width128::Flag8,
+        Width128::Flag9,
+        Width128::Flag10,
+        Width128::Flag11,
+        Width128::Flag12,
+        Width128::Flag13,
+        Width128::Flag14,
+        Width128::Flag15,
+        Width128::Flag16,
+        Width128::Flag17,
+        Width128::Flag18,
+        Width128::Flag19,
+        Width128::Flag20,
+        Width128::Flag21,
+        Width128::Flag22,
+        Width128::Flag23,
+        Width128::Flag24,
+        Width128::Flag25,
+        Width128::Flag26,
+        Width128::Flag27,
+        Width128::Flag28,
+        Width128::Flag29,
+        Width128::Flag30,
+        Width128::Flag31,
+        Width128::Flag32,
+        Width128::Flag33,
+        Width128::Flag34,
+        Width128::Flag35,
+        Width128::Flag36,
+        Width128::Flag37,
+        Width128::Flag38,
+        Width128::Flag39,
+        Width128::Flag40,
+        Width128::Flag41,
+        Width128::Flag42,
+        Width128::Flag43,
+        Width128::Flag44,
+        Width128::Flag45,
+        Width128::Flag46,
+        Width128::Flag47,
+        Width128::Flag48,
+        Width128::Flag49,
+        Width128::Flag50,
+        Width128::Flag51,
+        Width128::Flag52,
+        Width128::Flag53,
+        Width128::Flag54,
+        Width128::Flag55,
+        Width128::Flag56,
+        Width128::Flag57,
+        Width128::Flag58,
+        Width128::Flag59,
+        Width128::Flag60,
+        Width128::Flag61,
+        Width128::Flag62,
+        Width128::Flag63,
+        Width128::Flag64,
+        Width128::Flag65,
+        Width128::Flag66,
+        Width128::Flag67,
+        Width128::Flag68,
+        Width128::Flag69,
+        Width128::Flag70,
+        Width128::Flag71,
+        Width128::Flag72,
+        Width128::Flag73,
+        Width128::Flag74,
+        Width128::Flag75,
+        Width128::Flag76,
+        Width128::Flag77,
+        Width128::Flag78,
+        Width128::Flag79,
+        Width128::Flag80,
+        Width128::Flag81,
+        Width128::Flag82,
+        Width128::Flag83,
+        Width128::Flag84,
+        Width128::Flag85,
+        Width128::Flag86,
+        Width128::Flag87,
+        Width128::Flag88,
+        Width128::Flag89,
+        Width128::Flag90,
+        Width128::Flag91,
+        Width128::Flag92,
+        Width128::Flag93,
+        Width128::Flag94,
+        Width128::Flag95,
+        Width128::Flag96,
+        Width128::Flag97,
+        Width128::Flag98,
+        Width128::Flag99,
+        Width128::Flag100,
+        Width128::Flag101,
+        Width128::Flag102,
+        Width128::Flag103,
+        Width128::Flag104,
+        Width128::Flag105,
+        Width128::Flag106,
+        Width128::Flag107,
+        Width128::Flag108,
+        Width128::Flag109,
+        Width128::Flag110,
+        Width128::Flag111,
+        Width128::Flag112,
+        Width128::Flag113,
+        Width128::Flag114,
+        Width128::Flag115,
+        Width128::Flag116,
+        Width128::Flag117,
+        Width128::Flag118,
+        Width128::Flag119,
+        Width128::Flag120,
+        Width128::Flag121,
+        Width128::Flag122,
+        Width128::Flag123,
+        Width128::Flag124,
+        Width128::Flag125,
+        Width128::Flag126,
+        Width128::Flag127,
+    ];
+
+    pub const fn max_position() -> u8 {
+        127
+    }
+}
@@ -0,0 +1,114 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: b8f07769c5b5bb0e
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Width16 {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+    Flag8 = 8,
+    Flag9 = 9,
+    Flag10 = 10,
+    Flag11 = 11,
+    Flag12 = 12,
+    Flag13 = 13,
+    Flag14 = 14,
+    Flag15 = 15,
+}
+
+impl BairiakEnum for Width16 {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U16(0u16)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Width16::Flag0),
+            1 => Some(Width16::Flag1),
+            2 => Some(Width16::Flag2),
+            3 => Some(Width16::Flag3),
+            4 => Some(Width16::Flag4),
+            5 => Some(Width16::Flag5),
+            6 => Some(Width16::Flag6),
+            7 => Some(Width16::Flag7),
+            8 => Some(Width16::Flag8),
+            9 => Some(Width16::Flag9),
+            10 => Some(Width16::Flag10),
+            11 => Some(Width16::Flag11),
+            12 => Some(Width16::Flag12),
+            13 => Some(Width16::Flag13),
+            14 => Some(Width16::Flag14),
+            15 => Some(Width16::Flag15),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Width16::Flag0 => "Flag0",
+            Width16::Flag1 => "Flag1",
+            Width16::Flag2 => "Flag2",
+            Width16::Flag3 => "Flag3",
+            Width16::Flag4 => "Flag4",
+            Width16::Flag5 => "Flag5",
+            Width16::Flag6 => "Flag6",
+            Width16::Flag7 => "Flag7",
+            Width16::Flag8 => "Flag8",
+            Width16::Flag9 => "Flag9",
+            Width16::Flag10 => "Flag10",
+            Width16::Flag11 => "Flag11",
+            Width16::Flag12 => "Flag12",
+            Width16::Flag13 => "Flag13",
+            Width16::Flag14 => "Flag14",
+            Width16::Flag15 => "Flag15",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Width16::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Width16::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Width16 {
+    pub const COUNT: usize = 16;
+    pub const ALL_MASK: u128 = 0xffff;
+    pub const VARIANTS: [Width16; Width16::COUNT] = [
+        Width16::Flag0,
+        Width16::Flag1,
+        Width16::Flag2,
+        Width16::Flag3,
+        Width16::Flag4,
+        Width16::Flag5,
+        Width16::Flag6,
+        Width16::Flag7,
+        Width16::Flag8,
+        Width16::Flag9,
+        Width16::Flag10,
+        Width16::Flag11,
+        Width16::Flag12,
+        Width16::Flag13,
+        Width16::Flag14,
+        Width16::Flag15,
+    ];
+
+    pub const fn max_position() -> u8 {
+        15
+    }
+}
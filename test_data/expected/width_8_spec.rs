@@ -0,0 +1,82 @@
+// @generated by bairiak. Do not edit by hand.
+// bairiak-hash: 2c034562acbb5f9d
+use bairiak::{Bairiak, BairiakEnum};
+
+#[repr(u8)]
+#[allow(dead_code)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+enum Width8 {
+    Flag0 = 0,
+    Flag1 = 1,
+    Flag2 = 2,
+    Flag3 = 3,
+    Flag4 = 4,
+    Flag5 = 5,
+    Flag6 = 6,
+    Flag7 = 7,
+}
+
+impl BairiakEnum for Width8 {
+    fn get_zero_bairiak() -> Bairiak {
+        Bairiak::U8(0u8)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        match position {
+            0 => Some(Width8::Flag0),
+            1 => Some(Width8::Flag1),
+            2 => Some(Width8::Flag2),
+            3 => Some(Width8::Flag3),
+            4 => Some(Width8::Flag4),
+            5 => Some(Width8::Flag5),
+            6 => Some(Width8::Flag6),
+            7 => Some(Width8::Flag7),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Width8::Flag0 => "Flag0",
+            Width8::Flag1 => "Flag1",
+            Width8::Flag2 => "Flag2",
+            Width8::Flag3 => "Flag3",
+            Width8::Flag4 => "Flag4",
+            Width8::Flag5 => "Flag5",
+            Width8::Flag6 => "Flag6",
+            Width8::Flag7 => "Flag7",
+        }
+    }
+
+    fn defined_mask() -> u128 {
+        Width8::ALL_MASK
+    }
+
+    fn variant_count() -> u32 {
+        Width8::COUNT as u32
+    }
+}
+
+#[allow(dead_code)]
+impl Width8 {
+    pub const COUNT: usize = 8;
+    pub const ALL_MASK: u128 = 0xff;
+    pub const VARIANTS: [Width8; Width8::COUNT] = [
+        Width8::Flag0,
+        Width8::Flag1,
+        Width8::Flag2,
+        Width8::Flag3,
+        Width8::Flag4,
+        Width8::Flag5,
+        Width8::Flag6,
+        Width8::Flag7,
+    ];
+
+    pub const fn max_position() -> u8 {
+        7
+    }
+}
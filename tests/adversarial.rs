@@ -0,0 +1,100 @@
+// Malformed input through the public API: every spec of the fuzz corpus and a
+// few hostile ones, and bytes and strings no encoder wrote. Each must give an
+// error or a value, never a panic.
+
+use std::fs;
+
+use bairiak::{load_spec_from, PackedLayout};
+
+include!("../test_data/expected/fixtures_spec.rs");
+
+const HOSTILE_SPECS: &[&str] = &[
+    "enums:\n  - name: Perm\n    variants: [{name: Audit, position: 200}]\n",
+    "enums:\n  - name: Perm\n    bits: 255\n    variants: [Read]\n",
+    "enums:\n  - name: Perm\n    arbitrary: true\n    variants: []\n",
+    "enums:\n  - name: Perm\n    variants: [Read]\n    exclusive: [[Read, Nope]]\n",
+    "enums:\n  - name: Perm\n    extends: Perm\n    variants: [Read]\n",
+    "enums:\n  - name: ''\n    variants: ['']\n",
+    "enums: [{name: Perm, variants: [Read]}]\n---\nenums: [{name: Perm, variants: [Read]}]\n",
+];
+
+fn check_spec(yaml: &str) {
+    if let Ok(spec) = load_spec_from(&yaml) {
+        let _ = spec.warnings();
+        let _ = spec.validate_all();
+    }
+    if let Err(err) = bairiak::generate_bairiak_enums_from_str(yaml) {
+        assert!(err.is_spec(), "{:?} for {:?}", err.kind(), yaml);
+    }
+}
+
+#[test]
+fn test_hostile_specs() {
+    for yaml in HOSTILE_SPECS {
+        check_spec(yaml);
+    }
+}
+
+#[test]
+fn test_spec_corpus() {
+    let mut count = 0;
+    for entry in fs::read_dir("fuzz/corpus/spec").unwrap() {
+        let content = fs::read(entry.unwrap().path()).unwrap();
+        if let Ok(yaml) = std::str::from_utf8(&content) {
+            check_spec(yaml);
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn test_hostile_bytes_and_strings() {
+    let bytes: &[&[u8]] = &[
+        b"",
+        b"\x00",
+        b"\xff\xff\xff",
+        b"\x01\x80",
+        b"\x01\x10\x01",
+        &[0x01; 40],
+    ];
+    for data in bytes {
+        let _ = Bairiak::decode_compact(data);
+        let _ = Bairiak::from_stable_bytes(data);
+    }
+    for s in [
+        "", "0x", "0b_", "256u8", "1u7", "-1", "0xffu", "1__u16", "é", "0o9",
+    ] {
+        let _ = s.parse::<Bairiak>();
+    }
+}
+
+#[test]
+fn test_packed_layout_non_member() {
+    let layout = PackedLayout::new(8)
+        .and_then(|layout| layout.member::<Perm>())
+        .unwrap();
+    let mut value = Bairiak::U8(0b1);
+    assert!(layout.set(&mut value, TestEnum::Flag9).is_err());
+    assert!(layout.clear(&mut value, TestEnum::Flag0).is_err());
+    assert!(layout
+        .insert::<TestEnum>(&mut value, &Bairiak::U16(u16::MAX))
+        .is_err());
+    assert_eq!(value, Bairiak::U8(0b1));
+    assert!(layout
+        .is_true(&Bairiak::U128(u128::MAX), TestEnum::Flag0)
+        .is_err());
+    assert!(layout.extract::<TestEnum>(&value).is_err());
+    assert_eq!(layout.offset_of::<TestEnum>(), None);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_hostile_densities() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+    for density in [-1.0, 1.0 + f64::EPSILON, f64::NAN, f64::INFINITY] {
+        assert!(Bairiak::random_with_density_for::<Perm, _>(&mut rng, density).is_err());
+    }
+}
@@ -0,0 +1,303 @@
+// The edges of every width: the lowest bit, the highest bit and one past it,
+// enums with exactly width-many variants, positions beyond `u8` and empty
+// flag sets. Past the width, the ignoring setters and readers must neither
+// panic nor wrap around onto a lower bit.
+
+use bairiak::{
+    generate_bairiak, generate_bairiak_checked, generate_bairiak_from_slice, Bairiak, BairiakEnum,
+    BairiakErrorKind, PackedLayout, UndefinedBits,
+};
+
+const WIDTHS: [u8; 5] = [8, 16, 32, 64, 128];
+
+// A flag at any position of an enum `WIDTH` bits wide, defining exactly
+// `WIDTH` variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bit<const WIDTH: u8>(u16);
+
+impl<const WIDTH: u8> BairiakEnum for Bit<WIDTH> {
+    fn get_zero_bairiak() -> Bairiak {
+        zero(WIDTH)
+    }
+
+    fn to_u8(self) -> u8 {
+        self.0 as u8
+    }
+
+    fn position(&self) -> u16 {
+        self.0
+    }
+
+    fn from_u8(position: u8) -> Option<Self> {
+        (position < WIDTH).then_some(Bit(position as u16))
+    }
+
+    fn name(&self) -> &'static str {
+        "Bit"
+    }
+}
+
+fn zero(width: u8) -> Bairiak {
+    match width {
+        8 => Bairiak::U8(0),
+        16 => Bairiak::U16(0),
+        32 => Bairiak::U32(0),
+        64 => Bairiak::U64(0),
+        _ => Bairiak::U128(0),
+    }
+}
+
+fn all_set(width: u8) -> u128 {
+    u128::MAX >> (128 - width as u32)
+}
+
+// Runs `check` with every width and a flag at its lowest bit, its highest bit
+// and one past it. The flags are of the 128 bits wide enum, so that they can
+// be one past any width.
+fn for_each_edge(check: impl Fn(u8, [Bit<128>; 3])) {
+    for width in WIDTHS {
+        let w = width as u16;
+        check(width, [Bit(0), Bit(w - 1), Bit(w)]);
+    }
+}
+
+#[test]
+fn test_set_clear_toggle_at_edges() {
+    for_each_edge(|width, [lowest, highest, past]| {
+        let mut value = zero(width);
+        value.set(lowest);
+        value.set(highest);
+        value.set(past);
+        assert_eq!(value.width(), width);
+        assert_eq!(value.bits(), 1 | 1 << (width - 1));
+        assert!(value.is_true(lowest) && value.is_true(highest));
+        assert!(value.is_false(past) && !value.is_true(past));
+
+        value.toggle(past);
+        assert_eq!(value.bits(), 1 | 1 << (width - 1));
+        value.toggle(highest);
+        assert_eq!(value.bits(), 1);
+        value.clear(past);
+        value.clear(lowest);
+        assert_eq!(value, zero(width));
+    });
+}
+
+#[test]
+fn test_try_setters_at_edges() {
+    for_each_edge(|width, [lowest, highest, past]| {
+        let mut value = zero(width);
+        value.try_set(lowest).unwrap();
+        value.try_toggle(highest).unwrap();
+        for result in [
+            value.try_set(past),
+            value.try_clear(past),
+            value.try_toggle(past),
+        ] {
+            let err = result.unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+            assert_eq!(err.position(), Some(width as u16));
+        }
+        assert_eq!(value.bits(), 1 | 1 << (width - 1));
+        value.try_clear(highest).unwrap();
+        assert_eq!(value.bits(), 1);
+    });
+}
+
+#[test]
+fn test_positions_at_edges() {
+    for width in WIDTHS {
+        let mut value = zero(width);
+        value.set_position(0);
+        value.set_position(width - 1);
+        value.set_position(width);
+        value.set_position(u8::MAX);
+        assert_eq!(value.positions().collect::<Vec<_>>(), [0, width - 1]);
+
+        value.clear_position(width);
+        value.clear_position(u8::MAX);
+        assert_eq!(value.bits(), 1 | 1 << (width - 1));
+        value.clear_position(width - 1);
+        assert_eq!(value.bits(), 1);
+    }
+}
+
+#[test]
+fn test_positions_beyond_u8() {
+    let past = [Bit::<128>(128), Bit(255), Bit(256), Bit(u16::MAX)];
+    for width in WIDTHS {
+        let mut value = zero(width);
+        for flag in past {
+            value.set(flag);
+            value.toggle(flag);
+            value.clear(flag);
+            assert!(value.is_false(flag));
+            assert!(value.try_set(flag).is_err());
+        }
+        assert_eq!(value, zero(width));
+        assert_eq!(value.count_set_among(past), 0);
+    }
+
+    let err = generate_bairiak_checked(past).unwrap_err();
+    assert_eq!(err.kind(), BairiakErrorKind::PositionOutOfRangeError);
+    assert_eq!(err.position(), Some(128));
+    assert_eq!(generate_bairiak_from_slice(&past), Bairiak::U128(0));
+}
+
+// Every position of the enum is taken, so its mask is all ones.
+fn check_full_enum<const WIDTH: u8>() {
+    let all = Bit::<WIDTH>::all_variants();
+    assert_eq!(all.len(), WIDTH as usize);
+    assert_eq!(Bit::<WIDTH>::variant_count(), WIDTH as u32);
+    assert_eq!(Bit::<WIDTH>::defined_mask(), all_set(WIDTH));
+
+    let value = generate_bairiak(all.iter().copied());
+    assert_eq!(value.width(), WIDTH);
+    assert_eq!(value.bits(), all_set(WIDTH));
+    assert_eq!(
+        generate_bairiak_checked(all.iter().copied()).unwrap(),
+        value
+    );
+    assert_eq!(generate_bairiak_from_slice(&all), value);
+    assert_eq!(value.positions().count(), WIDTH as usize);
+    assert_eq!(value.count_set_for::<Bit<WIDTH>>(), WIDTH as u32);
+    assert_eq!(value.to_names_for::<Bit<WIDTH>>().len(), WIDTH as usize);
+    bairiak::validate_impl_for::<Bit<WIDTH>>().unwrap();
+
+    let mut retained = value;
+    retained.retain_for::<Bit<WIDTH>, _>(UndefinedBits::Clear, |flag| flag.0 == WIDTH as u16 - 1);
+    assert_eq!(retained.bits(), 1 << (WIDTH - 1));
+}
+
+#[test]
+fn test_enums_with_width_many_variants() {
+    check_full_enum::<8>();
+    check_full_enum::<16>();
+    check_full_enum::<32>();
+    check_full_enum::<64>();
+    check_full_enum::<128>();
+}
+
+fn check_empty<const WIDTH: u8>() {
+    let none: [Bit<WIDTH>; 0] = [];
+    assert_eq!(generate_bairiak(none), zero(WIDTH));
+    assert_eq!(generate_bairiak_checked(none).unwrap(), zero(WIDTH));
+    assert_eq!(generate_bairiak_from_slice(&none), zero(WIDTH));
+    assert_eq!(Bairiak::from(&none[..]), zero(WIDTH));
+
+    let value = zero(WIDTH);
+    assert_eq!(value.positions().next(), None);
+    assert_eq!(value.count_set_for::<Bit<WIDTH>>(), 0);
+    assert!(value.to_names_for::<Bit<WIDTH>>().is_empty());
+    assert_eq!(value.count_set_among(none), 0);
+}
+
+#[test]
+fn test_empty_flag_sets() {
+    check_empty::<8>();
+    check_empty::<16>();
+    check_empty::<32>();
+    check_empty::<64>();
+    check_empty::<128>();
+}
+
+// `generate_bairiak` requires every flag to fit, which debug builds assert.
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_generate_bairiak_asserts_past_the_width() {
+    let _ = generate_bairiak([Bit::<8>(8)]);
+}
+
+// Release builds drop the flag instead of setting the bit it wraps onto.
+#[cfg(not(debug_assertions))]
+#[test]
+fn test_generate_bairiak_ignores_past_the_width() {
+    assert_eq!(generate_bairiak([Bit::<8>(0), Bit(9)]), Bairiak::U8(1));
+}
+
+#[test]
+fn test_parse_at_edges() {
+    for width in WIDTHS {
+        let highest: Bairiak = format!("{:#x}u{}", 1u128 << (width - 1), width)
+            .parse()
+            .unwrap();
+        assert_eq!(highest.width(), width);
+        assert_eq!(highest.bits(), 1 << (width - 1));
+
+        let max: Bairiak = format!("{}u{}", all_set(width), width).parse().unwrap();
+        assert_eq!(max.bits(), all_set(width));
+
+        if width < 128 {
+            let err = format!("{}u{}", 1u128 << width, width)
+                .parse::<Bairiak>()
+                .unwrap_err();
+            assert_eq!(err.kind(), BairiakErrorKind::ValueOutOfRangeError);
+        }
+    }
+    assert!(format!("{}0", u128::MAX).parse::<Bairiak>().is_err());
+}
+
+#[test]
+fn test_compact_encoding_at_edges() {
+    for width in WIDTHS {
+        let mut buf = [0u8; 17];
+        for bits in [0, 1, 1 << (width - 1), all_set(width)] {
+            let mut value = zero(width);
+            for position in 0..width {
+                if bits >> position & 1 == 1 {
+                    value.set_position(position);
+                }
+            }
+            let len = value.encode_compact(&mut buf).unwrap();
+            assert_eq!(len, 1 + width as usize / 8);
+            assert_eq!(Bairiak::decode_compact(&buf[..len]).unwrap(), (value, len));
+            assert!(Bairiak::decode_compact(&buf[..len - 1]).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_bit_fields_at_edges() {
+    for width in WIDTHS {
+        // The whole value, the lowest bit and the highest bit.
+        let mut value = zero(width);
+        value.assign_bits(0, width, all_set(width)).unwrap();
+        assert_eq!(value.bits(), all_set(width));
+        value.assign_bits(0, 1, 0).unwrap();
+        value.assign_bits(width - 1, 1, 0).unwrap();
+        assert_eq!(value.bits(), all_set(width) >> 1 & !1);
+        assert_eq!(value.extract_bits(width - 2, 2).unwrap(), 0b01);
+        assert_eq!(value.width(), width);
+
+        // One past the highest bit, and a value one bit too wide.
+        assert!(value.extract_bits(width, 1).is_err());
+        assert!(value.extract_bits(1, width).is_err());
+        assert!(value.assign_bits(width - 1, 2, 0).is_err());
+        assert!(value.assign_bits(0, width - 1, all_set(width)).is_err());
+        assert_eq!(value.bits(), all_set(width) >> 1 & !1);
+    }
+}
+
+#[test]
+fn test_packed_layout_on_narrower_values() {
+    let layout = PackedLayout::new(32)
+        .unwrap()
+        .member::<Bit<8>>()
+        .unwrap()
+        .member::<Bit<16>>()
+        .unwrap();
+    assert_eq!(layout.offset_of::<Bit<16>>(), Some(8));
+
+    // The highest bit of the second member is beyond a `u8` value.
+    let mut value = Bairiak::U8(u8::MAX);
+    layout.clear(&mut value, Bit::<16>(15)).unwrap();
+    assert!(!layout.is_true(&value, Bit::<16>(15)).unwrap());
+    assert_eq!(value, Bairiak::U8(u8::MAX));
+
+    layout.set(&mut value, Bit::<16>(15)).unwrap();
+    assert_eq!(value, Bairiak::U32(1 << 23 | 0xff));
+    assert!(layout.is_true(&value, Bit::<16>(15)).unwrap());
+    layout.clear(&mut value, Bit::<16>(15)).unwrap();
+    assert_eq!(value, Bairiak::U32(0xff));
+}
@@ -0,0 +1,100 @@
+// The public items each feature adds, checked at compile time so that a build
+// with a combination of features fails if one of its items went missing or
+// changed shape. `cargo run -p xtask -- features` runs this file under every
+// combination it builds; under a single `cargo test`, only the items of the
+// features enabled there are checked.
+
+#![allow(dead_code)]
+
+// `Perm` is `Read`, `Write` and `Admin`, eight bits wide.
+include!("../test_data/expected/fixtures_spec.rs");
+
+// Compiles only if `T` implements the traits of the bound.
+macro_rules! implements {
+    ($type:ty: $($bound:tt)+) => {
+        const _: fn() = || {
+            fn check<T: $($bound)+>() {}
+            check::<$type>();
+        };
+    };
+}
+
+// The items every combination has, features or not.
+implements!(Bairiak: Copy + Eq + std::hash::Hash + std::fmt::Debug + std::str::FromStr + Send + Sync);
+const _: fn(&str) -> bairiak::Result<String> = bairiak::generate_bairiak_enums_from_str;
+const _: fn() -> bairiak::GenerateOptions = bairiak::GenerateOptions::default;
+const _: fn(&mut Bairiak, u8, u8, u128) -> bairiak::Result<()> = Bairiak::assign_bits;
+
+#[cfg(feature = "serde")]
+implements!(Bairiak: serde::Serialize + serde::de::DeserializeOwned);
+#[cfg(feature = "serde")]
+implements!(bairiak::BairiakPatch: serde::Serialize + serde::de::DeserializeOwned);
+
+#[cfg(feature = "schemars")]
+implements!(Bairiak: bairiak::schemars::JsonSchema);
+
+#[cfg(feature = "proptest")]
+implements!(Bairiak: bairiak::proptest::arbitrary::Arbitrary);
+
+#[cfg(feature = "rand")]
+const _: fn(&mut rand::rngs::StdRng) -> Bairiak = Bairiak::random_for::<Perm, _>;
+#[cfg(feature = "rand")]
+const _: fn(&mut rand::rngs::StdRng, f64) -> bairiak::Result<Bairiak> =
+    Bairiak::random_with_density_for::<Perm, _>;
+
+#[cfg(feature = "clap")]
+implements!(bairiak::BairiakValueParser<Perm>: clap::builder::TypedValueParser);
+
+#[cfg(feature = "rkyv")]
+implements!(Bairiak: rkyv::Archive<Archived = bairiak::ArchivedBairiak>);
+
+#[cfg(feature = "bitflags")]
+const _: fn(&Bairiak) -> bool = |value| {
+    bitflags::bitflags! {
+        struct Bits: u8 {
+            const READ = 1;
+        }
+    }
+    value.to_flags_retain::<Bits>().is_ok()
+};
+
+#[cfg(feature = "sqlx-postgres")]
+implements!(Bairiak: sqlx::Type<sqlx::Postgres>);
+#[cfg(feature = "sqlx-postgres")]
+implements!(bairiak::BairiakColumn<Perm>: for<'r> sqlx::Decode<'r, sqlx::Postgres>);
+
+#[cfg(feature = "defmt")]
+implements!(Bairiak: defmt::Format);
+
+#[cfg(feature = "wasm")]
+const _: fn(&str) -> bool = |value| bairiak::WasmBairiak::new(value).is_ok();
+
+#[cfg(feature = "registry")]
+const _: fn() -> Vec<&'static bairiak::registry::RegisteredEnum> = || {
+    bairiak::registry::inventory::iter::<bairiak::registry::RegisteredEnum>
+        .into_iter()
+        .collect()
+};
+
+#[cfg(feature = "verify")]
+const _: fn(&bairiak::EnumSpec, &str) -> bairiak::Result<Vec<bairiak::VerifyMismatch>> =
+    bairiak::verify_generated;
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_assert_flags() {
+    let value = bairiak::generate_bairiak([Perm::Read]);
+    bairiak::assert_flags!(value, set: [Perm::Read], clear: [Perm::Write]);
+}
+
+// Generation works whatever the features, including `rayon` generating the
+// enums in parallel and `log` reporting its phases.
+#[test]
+fn test_generation_under_every_combination() {
+    let code = bairiak::generate_bairiak_enums_from_str(
+        "enums:\n  - name: Perm\n    variants: [Read, Write]\n",
+    )
+    .unwrap();
+    assert!(code.contains("impl BairiakEnum for Perm {"));
+    assert_eq!(bairiak::generate_bairiak([Perm::Write]), Bairiak::U8(0b10));
+}
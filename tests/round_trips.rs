@@ -0,0 +1,204 @@
+// Compiles the code generated for each fixture spec into a binary of its own
+// and runs checks inside it, catching code that generates but doesn't compile,
+// or compiles but disagrees with itself: positions against masks, names
+// against `FromStr` and `Display`, constants against variants.
+//
+// The binaries are built with trybuild in a crate under `target/`. Each runs
+// the canned checks of `round_trips/checks.rs` on every enum of its spec, then
+// the checks of its fixture. A new generator feature adds a fixture, and a
+// check to `checks.rs` if other fixtures can use it.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bairiak::{generate_bairiak_enums_from_str_with, list_enums, load_spec, GenerateOptions};
+
+struct Fixture {
+    name: &'static str,
+    spec: &'static str,
+    options: GenerateOptions,
+    // Statements run after the canned checks, with the generated items and
+    // `checks` in scope.
+    checks: &'static [&'static str],
+}
+
+fn fixtures() -> Vec<Fixture> {
+    let fixture = |name, spec, checks| Fixture {
+        name,
+        spec,
+        options: GenerateOptions::default(),
+        checks,
+    };
+    vec![
+        fixture("minimal", "minimal_spec", &[]),
+        fixture("valid", "valid_spec", &[]),
+        Fixture {
+            options: GenerateOptions::default().display(true),
+            ..fixture(
+                "display",
+                "valid_spec",
+                &["checks::check_display!(TestEnum);"],
+            )
+        },
+        fixture("multi_enum", "multi_enum_spec", &[]),
+        fixture("width_8", "width_8_spec", &[]),
+        fixture("width_16", "width_16_spec", &[]),
+        fixture("width_128", "width_128_spec", &[]),
+        fixture("settings", "settings_spec", &[]),
+        fixture(
+            "positions",
+            "positions_spec",
+            &["assert_eq!(Wide::get_zero_bairiak().width(), 64);"],
+        ),
+        fixture(
+            "from_str",
+            "from_str_spec",
+            &[
+                "checks::check_from_str!(Permissions);",
+                "checks::check_from_str!(Mode);",
+                "assert_eq!(\"modify\".parse::<Permissions>(), Ok(Permissions::Write));",
+                "assert_eq!(\"READWRITE\".parse::<Permissions>(), Ok(Permissions::ReadWrite));",
+                "assert!(\"fast\".parse::<Mode>().is_err());",
+            ],
+        ),
+        fixture(
+            "groups",
+            "groups_spec",
+            &[
+                "checks::check_groups!(Permissions);",
+                "checks::check_groups!(Mode);",
+                "assert_eq!(Mode::group(\"ALL\"), Mode::group(\"All\"));",
+            ],
+        ),
+        fixture(
+            "exclusive",
+            "exclusive_spec",
+            &[
+                "checks::check_exclusive!(Codec);",
+                "let both = bairiak::generate_bairiak([Codec::CompressGzip, Codec::CompressZstd]);",
+                "assert!(Codec::validate_exclusive(&both).is_err());",
+            ],
+        ),
+        fixture(
+            "requires",
+            "requires_spec",
+            &[
+                "checks::check_requires!(Access);",
+                "assert!(Access::validate_requires(&Access::Admin.mask()).is_err());",
+            ],
+        ),
+        fixture(
+            "position_consts",
+            "position_consts_spec",
+            &[
+                "assert_eq!(Permissions::ADMIN_POS, Permissions::Admin.to_u8());",
+                "assert_eq!(Permissions::ADMIN_MASK as u128, Permissions::Admin.mask().bits());",
+                "assert_eq!(HIGH_BIT_POS, Wide::HighBit.to_u8());",
+                "assert_eq!(HIGH_BIT_MASK as u128, Wide::HighBit.mask().bits());",
+                "assert_eq!(SLOW_POS, Mode::Slow.to_u8());",
+            ],
+        ),
+        fixture(
+            "migration",
+            "migration_spec",
+            &["assert_eq!(migrate_perm_v1_to_v2(Bairiak::U8(0b111)), Bairiak::U8(0b101));"],
+        ),
+        fixture("match", "match_spec", &[]),
+        fixture(
+            "abi",
+            "abi_spec",
+            &[
+                "checks::check_abi!(Perm, perm_abi);",
+                "checks::check_abi!(HttpMethod, http_method_abi);",
+            ],
+        ),
+        fixture(
+            "status",
+            "status_spec",
+            &[
+                "checks::check_from_str!(Feature);",
+                "assert_eq!(Feature::status_at(3), Some(bairiak::FlagStatus::Removed));",
+                "assert_eq!(Stage::Beta.status(), bairiak::FlagStatus::Deprecated);",
+            ],
+        ),
+        fixture(
+            "lookup",
+            "lookup_spec",
+            &[
+                "checks::check_from_str!(ExactMatch);",
+                "checks::check_from_str!(ExactBytes);",
+                "checks::check_from_str!(FoldedMatch);",
+                "checks::check_from_str!(FoldedBytes);",
+            ],
+        ),
+        fixture(
+            "table",
+            "table_spec",
+            &["checks::check_same_lookup!(MatchLookup, TableLookup);"],
+        ),
+        fixture(
+            "bool_struct",
+            "bool_struct_spec",
+            &[
+                "let value: Bairiak = [Perm::Write, Perm::Crate].into_iter().collect();",
+                "assert_eq!(Bairiak::from(&PermBools::from(&value)), value);",
+                "assert!(PermBools::from(&value).crate_);",
+                "assert_eq!(Bairiak::from(&ModeBools::from(&Bairiak::U8(0xff))), Bairiak::U8(0b11));",
+            ],
+        ),
+        fixture(
+            "packed",
+            "packed_spec",
+            &[
+                "let layout = device_state_layout();",
+                "assert_eq!(layout.offset_of::<Codec>(), Some(4));",
+                "let mut value = layout.zero();",
+                "layout.set(&mut value, Mode::Eco).unwrap();",
+                "assert_eq!(layout.extract::<Mode>(&value).unwrap(), Mode::Eco.mask());",
+            ],
+        ),
+    ]
+}
+
+// Writes the binary of `fixture` to `dir`, returning its path.
+fn write_binary(fixture: &Fixture, dir: &Path) -> PathBuf {
+    let spec_path = format!("test_data/{}.yaml", fixture.spec);
+    let content = fs::read_to_string(&spec_path).unwrap();
+    let code = generate_bairiak_enums_from_str_with(&content, &fixture.options).unwrap();
+    let enums = list_enums(&load_spec(&spec_path).unwrap()).unwrap();
+
+    let checks_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/round_trips/checks.rs");
+    let mut main = format!(
+        "#[path = {:?}]\nmod checks;\n\ninclude!(\"flags.rs\");\n\nfn main() {{\n",
+        checks_path
+    );
+    for e in &enums {
+        writeln!(main, "    checks::check_enum::<{}>();", e.name).unwrap();
+        writeln!(main, "    checks::check_consts!({});", e.name).unwrap();
+    }
+    for check in fixture.checks {
+        writeln!(main, "    {}", check).unwrap();
+    }
+    main.push_str("}\n");
+
+    let dir = dir.join(fixture.name);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("flags.rs"), code).unwrap();
+    let main_path = dir.join("main.rs");
+    fs::write(&main_path, main).unwrap();
+    main_path
+}
+
+#[test]
+fn test_generated_code_round_trips() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target/round_trips");
+    let _ = fs::remove_dir_all(&dir);
+
+    let cases = trybuild::TestCases::new();
+    for fixture in fixtures() {
+        cases.pass(write_binary(&fixture, &dir));
+    }
+}
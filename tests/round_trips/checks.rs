@@ -0,0 +1,160 @@
+// The canned checks the round-trip harness runs on generated enums, compiled
+// into each generated crate next to the code. `check_enum` covers what every
+// `BairiakEnum` impl must satisfy, and the macros cover the inherent items a
+// spec generates, which no trait describes.
+
+// Each binary only uses the checks its spec needs.
+#![allow(unused_imports, unused_macros)]
+
+use std::{collections::HashSet, fmt::Debug};
+
+use bairiak::{generate_bairiak, validate_impl_for, BairiakEnum};
+
+pub fn check_enum<B: BairiakEnum + Debug + PartialEq>() {
+    let zero = B::get_zero_bairiak();
+    assert_eq!(zero.bits(), 0);
+    validate_impl_for::<B>().unwrap();
+
+    let all = B::all_variants();
+    let mut names = HashSet::new();
+    let mut mask = 0u128;
+    for &flag in &all {
+        assert_eq!(B::from_u8(flag.to_u8()), Some(flag));
+        assert_eq!(flag.position(), flag.to_u8() as u16);
+        assert!(names.insert(flag.name()), "`{}` named twice", flag.name());
+        assert!(zero.is_false(flag));
+
+        let single = flag.mask();
+        assert_eq!(single.width(), zero.width());
+        assert_eq!(single.bits(), 1 << flag.position());
+        assert_eq!(single.positions().collect::<Vec<_>>(), [flag.to_u8()]);
+        mask |= single.bits();
+    }
+    assert_eq!(B::defined_mask(), mask);
+    assert_eq!(B::variant_count() as usize, all.len());
+
+    let value = generate_bairiak(all.iter().copied());
+    assert_eq!(value.bits(), mask);
+    assert_eq!(
+        value.to_names_for::<B>(),
+        all.iter().map(|flag| flag.name()).collect::<Vec<_>>()
+    );
+    assert!(value.positions().all(|position| B::from_u8(position).is_some()));
+}
+
+// `COUNT`, `ALL_MASK`, `VARIANTS` and `max_position()` agree with the
+// variants.
+macro_rules! check_consts {
+    ($enum:ty) => {{
+        use bairiak::BairiakEnum;
+        let all = <$enum>::all_variants();
+        assert_eq!(<$enum>::COUNT, all.len());
+        assert_eq!(<$enum>::ALL_MASK, <$enum>::defined_mask());
+        let highest = all.iter().map(|flag| flag.to_u8()).max().unwrap_or(0);
+        assert_eq!(<$enum>::max_position(), highest);
+        // `VARIANTS` is usable in `const` contexts and loops by value.
+        const LEN: usize = <$enum>::VARIANTS.len();
+        assert_eq!(LEN, <$enum>::COUNT);
+        let mut variants = Vec::new();
+        for flag in <$enum>::VARIANTS {
+            variants.push(flag);
+        }
+        assert_eq!(variants, all);
+    }};
+}
+pub(crate) use check_consts;
+
+// Every variant parses from its name, and a name no variant has doesn't.
+macro_rules! check_from_str {
+    ($enum:ty) => {{
+        use bairiak::BairiakEnum;
+        for flag in <$enum>::all_variants() {
+            assert_eq!(flag.name().parse::<$enum>(), Ok(flag));
+        }
+        assert!("NoSuchFlag".parse::<$enum>().is_err());
+    }};
+}
+pub(crate) use check_from_str;
+
+// Every variant displays as its name.
+macro_rules! check_display {
+    ($enum:ty) => {{
+        use bairiak::BairiakEnum;
+        for flag in <$enum>::all_variants() {
+            assert_eq!(flag.to_string(), flag.name());
+        }
+    }};
+}
+pub(crate) use check_display;
+
+// Every group only holds defined flags, at the enum's width, and is found by
+// its name.
+macro_rules! check_groups {
+    ($enum:ty) => {{
+        use bairiak::BairiakEnum;
+        let width = <$enum>::get_zero_bairiak().width();
+        for &(name, mask) in <$enum>::groups() {
+            assert_eq!(mask.width(), width);
+            assert_eq!(mask.bits() & !<$enum>::defined_mask(), 0);
+            assert_eq!(<$enum>::group(name), Some(mask));
+        }
+    }};
+}
+pub(crate) use check_groups;
+
+// Every exclusive group only holds defined flags, and no value with a single
+// flag set violates one.
+macro_rules! check_exclusive {
+    ($enum:ty) => {{
+        use bairiak::BairiakEnum;
+        for mask in <$enum>::EXCLUSIVE_MASKS {
+            assert_eq!(mask & !<$enum>::ALL_MASK, 0);
+            assert!(mask.count_ones() >= 2);
+        }
+        assert!(<$enum>::validate_exclusive(&<$enum>::get_zero_bairiak()).is_ok());
+        for flag in <$enum>::all_variants() {
+            assert!(<$enum>::validate_exclusive(&flag.mask()).is_ok());
+        }
+    }};
+}
+pub(crate) use check_exclusive;
+
+// Every requirement is of a defined flag on defined flags, and setting every
+// flag meets them all.
+macro_rules! check_requires {
+    ($enum:ty) => {{
+        use bairiak::BairiakEnum;
+        for (position, mask) in <$enum>::REQUIREMENTS {
+            assert!(<$enum>::from_u8(position).is_some());
+            assert_eq!(mask & !<$enum>::ALL_MASK, 0);
+        }
+        let all = bairiak::generate_bairiak(<$enum>::all_variants());
+        assert!(<$enum>::validate_requires(&all).is_ok());
+    }};
+}
+pub(crate) use check_requires;
+
+// The layout constants of an enum with `abi: true` describe it.
+macro_rules! check_abi {
+    ($enum:ty, $abi:ident) => {{
+        use bairiak::BairiakEnum;
+        assert_eq!($abi::WIDTH_BITS, <$enum>::get_zero_bairiak().width() as u32);
+        assert_eq!($abi::VARIANT_COUNT, <$enum>::variant_count());
+        assert_eq!($abi::LAYOUT_HASH, bairiak::layout_hash_for::<$enum>());
+    }};
+}
+pub(crate) use check_abi;
+
+// Two enums of the same variants, found by different lookups, agree on every
+// position.
+macro_rules! check_same_lookup {
+    ($left:ty, $right:ty) => {{
+        use bairiak::BairiakEnum;
+        for position in 0..=u8::MAX {
+            let left = <$left>::from_u8(position).map(|flag| (flag.to_u8(), flag.name()));
+            let right = <$right>::from_u8(position).map(|flag| (flag.to_u8(), flag.name()));
+            assert_eq!(left, right, "at position {}", position);
+        }
+    }};
+}
+pub(crate) use check_same_lookup;
@@ -0,0 +1,110 @@
+// Maintenance tasks for bairiak, run from anywhere in the repository:
+//
+//     cargo run -p xtask -- features
+//
+// `features` builds and tests bairiak with each combination of features in
+// `COMBINATIONS`, which `tests/feature_matrix.rs` checks the public items of,
+// and then checks every feature on its own. It stops at the first failure.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+};
+
+// The combinations worth building and testing in full: no features, which
+// bairiak has no defaults beyond, the features only code generation uses, the
+// runtime features of serialized values, and everything.
+const COMBINATIONS: [(&str, &[&str]); 4] = [
+    ("no-features", &["--no-default-features"]),
+    (
+        "codegen",
+        &[
+            "--no-default-features",
+            "--features",
+            "log,rayon,verify,watch",
+        ],
+    ),
+    (
+        "runtime+serde",
+        &[
+            "--no-default-features",
+            "--features",
+            "serde,schemars,rkyv,bitflags",
+        ],
+    ),
+    ("all-features", &["--all-features"]),
+];
+
+fn main() -> ExitCode {
+    let task = env::args().nth(1);
+    let result = match task.as_deref() {
+        Some("features") => features(),
+        _ => Err(String::from("usage: cargo run -p xtask -- features")),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("xtask: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn features() -> Result<(), String> {
+    for (name, args) in COMBINATIONS {
+        eprintln!("xtask: {}", name);
+        cargo(&["build", "-p", "bairiak", "--all-targets"], args)?;
+        cargo(&["test", "-p", "bairiak"], args)?;
+    }
+    for feature in bairiak_features()? {
+        eprintln!("xtask: {} alone", feature);
+        cargo(
+            &["check", "-p", "bairiak", "--all-targets"],
+            &["--no-default-features", "--features", &feature],
+        )?;
+    }
+    Ok(())
+}
+
+// Runs cargo with `command` and `features` in the repository root.
+fn cargo(command: &[&str], features: &[&str]) -> Result<(), String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let status = Command::new(&cargo)
+        .args(command)
+        .args(features)
+        .current_dir(root())
+        .status()
+        .map_err(|err| format!("running {}: {}", cargo, err))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "`cargo {} {}` failed",
+            command.join(" "),
+            features.join(" ")
+        ))
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// The features in the `[features]` table of bairiak's manifest, read line by
+// line rather than with a TOML parser to keep the task free of dependencies.
+fn bairiak_features() -> Result<Vec<String>, String> {
+    let path = root().join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&path)
+        .map_err(|err| format!("reading {}: {}", path.display(), err))?;
+    let features = manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[features]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| line.split_once('=').map(|(name, _)| name.trim()))
+        .filter(|name| !name.is_empty() && !name.starts_with('#') && *name != "default")
+        .map(String::from)
+        .collect();
+    Ok(features)
+}